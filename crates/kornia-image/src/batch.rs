@@ -0,0 +1,371 @@
+use crate::{allocator::ImageAllocator, error::ImageError, Image, ImageSize};
+use kornia_tensor::{storage::TensorStorage, view::TensorView, CpuAllocator, Tensor4};
+
+/// A batch of images with the same size.
+///
+/// The images are stored in a single tensor with shape (N, H, W, C), i.e. NHWC, which is the
+/// usual input layout of the inference runtimes.
+///
+/// # Examples
+///
+/// ```
+/// use kornia_image::{Image, ImageBatch, ImageSize};
+/// use kornia_image::allocator::CpuAllocator;
+///
+/// let size = ImageSize { width: 4, height: 2 };
+/// let mut batch = ImageBatch::<u8, 3>::new(size);
+/// batch.push(&Image::from_size_val(size, 1u8, CpuAllocator).unwrap()).unwrap();
+/// batch.push(&Image::from_size_val(size, 2u8, CpuAllocator).unwrap()).unwrap();
+///
+/// assert_eq!(batch.len(), 2);
+/// assert_eq!(batch.as_tensor().shape, [2, 2, 4, 3]);
+/// assert_eq!(batch.get(1).unwrap().as_slice()[0], 2);
+/// ```
+pub struct ImageBatch<T, const C: usize> {
+    tensor: Tensor4<T, CpuAllocator>,
+    size: ImageSize,
+}
+
+impl<T, const C: usize> ImageBatch<T, C> {
+    /// Create an empty batch of images with the given size.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - The size of the images of the batch.
+    pub fn new(size: ImageSize) -> Self {
+        Self::from_vec(size, 0, Vec::new())
+    }
+
+    /// Create an empty batch with room for `capacity` images of the given size.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - The size of the images of the batch.
+    /// * `capacity` - The number of images which can be pushed without reallocating.
+    pub fn with_capacity(size: ImageSize, capacity: usize) -> Self {
+        Self::from_vec(
+            size,
+            0,
+            Vec::with_capacity(capacity * size.width * size.height * C),
+        )
+    }
+
+    /// Create a batch of `len` images with the given size and pixel value.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - The size of the images of the batch.
+    /// * `len` - The number of images.
+    /// * `val` - The value of the pixel data.
+    pub fn from_size_val(size: ImageSize, len: usize, val: T) -> Self
+    where
+        T: Clone,
+    {
+        Self::from_vec(size, len, vec![val; len * size.width * size.height * C])
+    }
+
+    /// Build the batch tensor from its data in NHWC order.
+    ///
+    /// PRECONDITION: `data` has `len` images of the given size.
+    fn from_vec(size: ImageSize, len: usize, data: Vec<T>) -> Self {
+        let (h, w) = (size.height, size.width);
+        Self {
+            tensor: Tensor4 {
+                storage: TensorStorage::from_vec(data, CpuAllocator),
+                shape: [len, h, w, C],
+                strides: [h * w * C, w * C, C, 1],
+            },
+            size,
+        }
+    }
+
+    /// The size of the images of the batch.
+    pub fn size(&self) -> ImageSize {
+        self.size
+    }
+
+    /// The number of images of the batch.
+    pub fn len(&self) -> usize {
+        self.tensor.shape[0]
+    }
+
+    /// Whether the batch has no images.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The number of elements of each image.
+    fn image_numel(&self) -> usize {
+        self.size.width * self.size.height * C
+    }
+
+    /// Append an image to the batch.
+    ///
+    /// The pixels are appended to the vector backing the tensor, which is moved out of the
+    /// tensor and back without copying, so a push takes amortized constant time in the number
+    /// of images of the batch.
+    ///
+    /// # Arguments
+    ///
+    /// * `image` - The image to append, with the size of the batch.
+    ///
+    /// # Errors
+    ///
+    /// If the size of the image does not match the size of the batch, an error is returned.
+    pub fn push<A: ImageAllocator>(&mut self, image: &Image<T, C, A>) -> Result<(), ImageError>
+    where
+        T: Clone,
+    {
+        if image.size() != self.size {
+            return Err(ImageError::InvalidImageSize(
+                image.cols(),
+                image.rows(),
+                self.size.width,
+                self.size.height,
+            ));
+        }
+
+        let (size, len) = (self.size, self.len());
+        let tensor = std::mem::replace(&mut self.tensor, Self::new(size).tensor);
+        let mut data = tensor.into_vec();
+        data.extend_from_slice(image.as_slice());
+        self.tensor = Self::from_vec(size, len + 1, data).tensor;
+
+        Ok(())
+    }
+
+    /// Get a view of an image of the batch with shape (H, W, C).
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the image in the batch.
+    ///
+    /// # Errors
+    ///
+    /// If the index is out of bounds, an error is returned.
+    pub fn get(&self, index: usize) -> Result<TensorView<'_, T, 3, CpuAllocator>, ImageError> {
+        if index >= self.len() {
+            return Err(ImageError::BatchIndexOutOfBounds(index, self.len()));
+        }
+
+        let [_, h, w, c] = self.tensor.shape;
+        Ok(TensorView::from_storage(
+            &self.tensor.storage,
+            [h, w, c],
+            [w * c, c, 1],
+            index * self.image_numel(),
+        )?)
+    }
+
+    /// Get a copy of an image of the batch.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the image in the batch.
+    ///
+    /// # Errors
+    ///
+    /// If the index is out of bounds, an error is returned.
+    pub fn get_image(&self, index: usize) -> Result<Image<T, C, CpuAllocator>, ImageError>
+    where
+        T: Clone,
+    {
+        Image::new(
+            self.size,
+            self.get(index)?.as_slice().to_vec(),
+            CpuAllocator,
+        )
+    }
+
+    /// Overwrite an image of the batch.
+    ///
+    /// # Arguments
+    ///
+    /// * `index` - The index of the image in the batch.
+    /// * `image` - The new image, with the size of the batch.
+    ///
+    /// # Errors
+    ///
+    /// If the index is out of bounds or the image size does not match, an error is returned.
+    pub fn set<A: ImageAllocator>(
+        &mut self,
+        index: usize,
+        image: &Image<T, C, A>,
+    ) -> Result<(), ImageError>
+    where
+        T: Clone,
+    {
+        if index >= self.len() {
+            return Err(ImageError::BatchIndexOutOfBounds(index, self.len()));
+        }
+        if image.size() != self.size {
+            return Err(ImageError::InvalidImageSize(
+                image.cols(),
+                image.rows(),
+                self.size.width,
+                self.size.height,
+            ));
+        }
+
+        let numel = self.image_numel();
+        self.tensor.as_slice_mut()[index * numel..(index + 1) * numel]
+            .clone_from_slice(image.as_slice());
+
+        Ok(())
+    }
+
+    /// Apply an image operation to each image of the batch.
+    ///
+    /// The images are processed one by one with the same per-image function, e.g. a resize, so
+    /// the batched operations share the logic of the single image ones. The source and the
+    /// destination images are allocated once and reused for all the images: each source image
+    /// is copied in, and the destination image is copied straight into the destination batch.
+    ///
+    /// The destination image passed to `f` does not hold the previous content of `dst`, so `f`
+    /// must write all its pixels, as the per-image operations do.
+    ///
+    /// # Arguments
+    ///
+    /// * `dst` - The destination batch, with the same number of images.
+    /// * `f` - The operation from a source image to a destination image.
+    ///
+    /// # Errors
+    ///
+    /// If the batches do not have the same number of images, or the operation fails, an error
+    /// is returned.
+    pub fn apply<U, const C2: usize, F>(
+        &self,
+        dst: &mut ImageBatch<U, C2>,
+        mut f: F,
+    ) -> Result<(), ImageError>
+    where
+        T: Clone,
+        U: Clone,
+        F: FnMut(
+            &Image<T, C, CpuAllocator>,
+            &mut Image<U, C2, CpuAllocator>,
+        ) -> Result<(), ImageError>,
+    {
+        if self.len() != dst.len() {
+            return Err(ImageError::BatchLengthMismatch(self.len(), dst.len()));
+        }
+        if self.is_empty() {
+            return Ok(());
+        }
+
+        let (src_numel, dst_numel) = (self.image_numel(), dst.image_numel());
+        let mut src_image = self.get_image(0)?;
+        let mut dst_image = dst.get_image(0)?;
+        for i in 0..self.len() {
+            if i > 0 {
+                src_image
+                    .as_slice_mut()
+                    .clone_from_slice(&self.tensor.as_slice()[i * src_numel..(i + 1) * src_numel]);
+            }
+            f(&src_image, &mut dst_image)?;
+            dst.tensor.as_slice_mut()[i * dst_numel..(i + 1) * dst_numel]
+                .clone_from_slice(dst_image.as_slice());
+        }
+
+        Ok(())
+    }
+
+    /// The tensor of the batch with shape (N, H, W, C).
+    pub fn as_tensor(&self) -> &Tensor4<T, CpuAllocator> {
+        &self.tensor
+    }
+
+    /// Consume the batch and return its tensor with shape (N, H, W, C).
+    pub fn into_tensor(self) -> Tensor4<T, CpuAllocator> {
+        self.tensor
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn batch_push_get() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 3,
+            height: 2,
+        };
+        let mut batch = ImageBatch::<u8, 1>::new(size);
+        assert!(batch.is_empty());
+
+        for i in 0..3u8 {
+            let image = Image::new(size, (0..6).map(|v| v + 10 * i).collect(), CpuAllocator)?;
+            batch.push(&image)?;
+        }
+        assert_eq!(batch.len(), 3);
+        assert_eq!(batch.as_tensor().shape, [3, 2, 3, 1]);
+        assert_eq!(batch.get(2)?.as_slice(), &[20, 21, 22, 23, 24, 25]);
+        assert_eq!(*batch.get(1)?.get_unchecked([1, 0, 0]), 13);
+
+        let other = Image::<u8, 1, _>::from_size_val([2, 2].into(), 0, CpuAllocator)?;
+        assert!(matches!(
+            batch.push(&other),
+            Err(ImageError::InvalidImageSize(2, 2, 3, 2))
+        ));
+        assert!(matches!(
+            batch.get(3),
+            Err(ImageError::BatchIndexOutOfBounds(3, 3))
+        ));
+
+        batch.set(0, &Image::from_size_val(size, 7, CpuAllocator)?)?;
+        assert_eq!(batch.get_image(0)?.as_slice(), &[7; 6]);
+        assert_eq!(batch.get_image(1)?.as_slice(), &[10, 11, 12, 13, 14, 15]);
+
+        Ok(())
+    }
+    #[test]
+    fn batch_push_in_place() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 4,
+            height: 4,
+        };
+        let mut batch = ImageBatch::<f32, 3>::with_capacity(size, 8);
+        let ptr = batch.as_tensor().as_ptr();
+
+        // the pixels are appended to the reserved memory, without moving the previous images
+        for i in 0..8 {
+            batch.push(&Image::from_size_val(size, i as f32, CpuAllocator)?)?;
+        }
+        assert_eq!(batch.as_tensor().as_ptr(), ptr);
+        assert_eq!(batch.len(), 8);
+        assert_eq!(batch.get(7)?.as_slice(), &[7.0; 48]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn batch_apply() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 2,
+            height: 1,
+        };
+        let mut batch = ImageBatch::<u8, 1>::new(size);
+        for i in 0..3u8 {
+            batch.push(&Image::new(size, vec![i, 10 + i], CpuAllocator)?)?;
+        }
+
+        let mut doubled = ImageBatch::<u16, 1>::from_size_val(size, 3, 0);
+        batch.apply(&mut doubled, |src, dst| {
+            dst.as_slice_mut()
+                .iter_mut()
+                .zip(src.as_slice())
+                .for_each(|(d, &s)| *d = 2 * s as u16);
+            Ok(())
+        })?;
+        assert_eq!(doubled.as_tensor().as_slice(), &[0, 20, 2, 22, 4, 24]);
+
+        let mut short = ImageBatch::<u16, 1>::from_size_val(size, 2, 0);
+        assert!(matches!(
+            batch.apply(&mut short, |_, _| Ok(())),
+            Err(ImageError::BatchLengthMismatch(3, 2))
+        ));
+
+        Ok(())
+    }
+}
@@ -21,6 +21,10 @@ pub enum ImageError {
     #[error("Invalid image size ({0}, {1}) mismatch ({2}, {3})")]
     InvalidImageSize(usize, usize, usize, usize),
 
+    /// Error when the width or the height of an image is zero.
+    #[error("Invalid image size ({0}, {1}), the width and height must be at least 1")]
+    InvalidSize(usize, usize),
+
     /// Error when channel and shape are not valid.
     #[error("Data length ({0}) does not match the image size ({1})")]
     InvalidChannelShape(usize, usize),
@@ -56,4 +60,32 @@ pub enum ImageError {
     /// Error when the channel count is unsupported.
     #[error("Unsupported channel count {0}")]
     UnsupportedChannelCount(usize),
+
+    /// Error when the number of columns of an image grid is invalid.
+    #[error("Invalid number of grid columns {0}")]
+    InvalidGridColumns(usize),
+
+    /// Error when the channel order is not a permutation of the channel indices.
+    #[error("Invalid channel order {0:?}, expected a permutation of 0..{1}")]
+    InvalidChannelOrder(Vec<usize>, usize),
+
+    /// Error when the percentiles are not an increasing range within 0..=100.
+    #[error("Invalid percentile range {0} and {1}")]
+    InvalidPercentiles(f32, f32),
+
+    /// Error when the index of an image is out of the bounds of a batch.
+    #[error("Batch index {0} is out of bounds {1}")]
+    BatchIndexOutOfBounds(usize, usize),
+
+    /// Error when two batches do not have the same number of images.
+    #[error("Batch length {0} mismatch {1}")]
+    BatchLengthMismatch(usize, usize),
+
+    /// Error when the low threshold of a hysteresis is greater than the high threshold.
+    #[error("Invalid hysteresis thresholds {0} and {1}")]
+    InvalidHysteresisThresholds(f32, f32),
+
+    /// Error when an operation does not support the interpolation mode.
+    #[error("Unsupported interpolation mode {0}")]
+    UnsupportedInterpolation(String),
 }
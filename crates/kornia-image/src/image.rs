@@ -1,5 +1,5 @@
 use crate::{allocator::ImageAllocator, error::ImageError};
-use kornia_tensor::{Tensor, Tensor2, Tensor3};
+use kornia_tensor::{CpuAllocator, Tensor, Tensor2, Tensor3};
 
 /// Image size in pixels
 ///
@@ -451,6 +451,99 @@ impl<T, const C: usize, A: ImageAllocator> Image<T, C, A> {
         Image::new(self.size(), casted_data, alloc.clone())
     }
 
+    /// Compute the root mean squared error (RMSE) with another image.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The image to compare with.
+    ///
+    /// # Returns
+    ///
+    /// The square root of the mean of the squared differences of the pixel values, 0 for
+    /// equal images.
+    ///
+    /// # Errors
+    ///
+    /// If the two images have different sizes, or a pixel value cannot be cast to `f64`, an
+    /// error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kornia_image::{Image, ImageSize};
+    /// use kornia_image::allocator::CpuAllocator;
+    ///
+    /// let size = ImageSize { width: 2, height: 1 };
+    /// let image1 = Image::<u8, 1, _>::new(size, vec![10, 20], CpuAllocator).unwrap();
+    /// let image2 = Image::<u8, 1, _>::new(size, vec![13, 16], CpuAllocator).unwrap();
+    ///
+    /// assert_eq!(image1.rmse(&image2).unwrap(), 12.5f64.sqrt());
+    /// ```
+    pub fn rmse<A2: ImageAllocator>(&self, other: &Image<T, C, A2>) -> Result<f64, ImageError>
+    where
+        T: num_traits::NumCast + Copy,
+    {
+        if self.size() != other.size() {
+            return Err(ImageError::InvalidImageSize(
+                self.cols(),
+                self.rows(),
+                other.cols(),
+                other.rows(),
+            ));
+        }
+
+        let mut sum = 0.0;
+        for (&a, &b) in self.as_slice().iter().zip(other.as_slice()) {
+            let a = a.to_f64().ok_or(ImageError::CastError)?;
+            let b = b.to_f64().ok_or(ImageError::CastError)?;
+            sum += (a - b) * (a - b);
+        }
+
+        Ok((sum / self.numel().max(1) as f64).sqrt())
+    }
+
+    /// Compute the peak signal-to-noise ratio (PSNR) with another image.
+    ///
+    /// The PSNR is `20 * log10(max_value / RMSE)` in decibels, and infinite for equal images.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The image to compare with.
+    /// * `max_value` - The maximum possible pixel value, e.g. 255 for `u8` images.
+    ///
+    /// # Errors
+    ///
+    /// If the two images have different sizes, or a pixel value cannot be cast to `f64`, an
+    /// error is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kornia_image::{Image, ImageSize};
+    /// use kornia_image::allocator::CpuAllocator;
+    ///
+    /// let size = ImageSize { width: 2, height: 1 };
+    /// let image1 = Image::<f32, 1, _>::new(size, vec![0.5, 0.5], CpuAllocator).unwrap();
+    /// let image2 = Image::<f32, 1, _>::new(size, vec![0.5, 0.6], CpuAllocator).unwrap();
+    ///
+    /// assert!(image1.psnr(&image1, 1.0).unwrap().is_infinite());
+    /// assert!((image1.psnr(&image2, 1.0).unwrap() - 23.01).abs() < 1e-2);
+    /// ```
+    pub fn psnr<A2: ImageAllocator>(
+        &self,
+        other: &Image<T, C, A2>,
+        max_value: f64,
+    ) -> Result<f64, ImageError>
+    where
+        T: num_traits::NumCast + Copy,
+    {
+        let rmse = self.rmse(other)?;
+        if rmse == 0.0 {
+            return Ok(f64::INFINITY);
+        }
+        Ok(20.0 * (max_value / rmse).log10())
+    }
+
     /// Get the pixel data of the image.
     ///
     /// NOTE: this is method is for convenience and not optimized for performance.
@@ -534,6 +627,136 @@ impl<T, const C: usize, A: ImageAllocator> Image<T, C, A> {
     {
         self.as_slice().to_vec()
     }
+
+    /// Copy the image into a new contiguous buffer of the system allocator.
+    ///
+    /// The copy does not depend on the memory of the image, e.g. a frame wrapping the buffer
+    /// of a capture, so it can be kept after the capture is stopped or the buffer reused.
+    ///
+    /// # Returns
+    ///
+    /// A new image with the same pixels and a [`CpuAllocator`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kornia_image::{Image, ImageSize};
+    /// use kornia_image::allocator::CpuAllocator;
+    ///
+    /// let image = Image::<u8, 1, _>::new([2, 1].into(), vec![1, 2], CpuAllocator).unwrap();
+    /// let owned = image.to_owned_contiguous();
+    /// drop(image);
+    ///
+    /// assert_eq!(owned.as_slice(), &[1, 2]);
+    /// ```
+    pub fn to_owned_contiguous(&self) -> Image<T, C, CpuAllocator>
+    where
+        T: Clone,
+    {
+        Image(self.0.view().as_contiguous())
+    }
+
+    /// Convert the image to a planar tensor with shape (C, H, W).
+    ///
+    /// # Returns
+    ///
+    /// A new tensor where each channel is stored contiguously.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kornia_image::{Image, ImageSize};
+    /// use kornia_image::allocator::CpuAllocator;
+    ///
+    /// let image = Image::<u8, 2, _>::new(
+    ///     ImageSize {
+    ///         width: 2,
+    ///         height: 1,
+    ///     },
+    ///     vec![1, 2, 3, 4],
+    ///     CpuAllocator,
+    /// ).unwrap();
+    ///
+    /// let chw = image.to_planar().unwrap();
+    /// assert_eq!(chw.shape, [2, 1, 2]);
+    /// assert_eq!(chw.as_slice(), &[1, 3, 2, 4]);
+    /// ```
+    pub fn to_planar(&self) -> Result<Tensor3<T, A>, ImageError>
+    where
+        T: Clone,
+    {
+        let mut planar_data = Vec::with_capacity(self.numel());
+        for ch in 0..C {
+            planar_data.extend(self.as_slice().iter().skip(ch).step_by(C).cloned());
+        }
+
+        let alloc = self.storage.alloc();
+
+        Ok(Tensor3::from_shape_vec(
+            [C, self.rows(), self.cols()],
+            planar_data,
+            alloc.clone(),
+        )?)
+    }
+
+    /// Create a new image from a planar tensor with shape (C, H, W).
+    ///
+    /// The channels are interleaved back into a (H, W, C) image.
+    ///
+    /// # Arguments
+    ///
+    /// * `chw` - The planar tensor, e.g. the input or output of a neural network.
+    ///
+    /// # Returns
+    ///
+    /// A new image with the interleaved channels.
+    ///
+    /// # Errors
+    ///
+    /// If the first dimension of the tensor does not match the number of channels, an error
+    /// is returned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use kornia_image::Image;
+    /// use kornia_image::allocator::CpuAllocator;
+    /// use kornia_tensor::Tensor3;
+    ///
+    /// let chw = Tensor3::from_shape_vec([2, 1, 2], vec![1u8, 3, 2, 4], CpuAllocator).unwrap();
+    ///
+    /// let image = Image::<u8, 2, _>::from_planar(&chw).unwrap();
+    /// assert_eq!(image.as_slice(), &[1, 2, 3, 4]);
+    /// ```
+    pub fn from_planar(chw: &Tensor3<T, A>) -> Result<Self, ImageError>
+    where
+        T: Clone,
+    {
+        let [channels, rows, cols] = chw.shape;
+        if channels != C {
+            return Err(ImageError::InvalidChannelShape(channels, C));
+        }
+
+        let mut data = Vec::with_capacity(chw.numel());
+        for y in 0..rows {
+            for x in 0..cols {
+                for ch in 0..C {
+                    data.push(chw.get_unchecked([ch, y, x]).clone());
+                }
+            }
+        }
+
+        let alloc = chw.storage.alloc();
+
+        Image::new(
+            ImageSize {
+                width: cols,
+                height: rows,
+            },
+            data,
+            alloc.clone(),
+        )
+    }
 }
 
 /// helper to convert an single channel tensor to a kornia image with try into
@@ -577,6 +800,19 @@ impl<T, const C: usize, A: ImageAllocator> TryInto<Tensor3<T, A>> for Image<T, C
     }
 }
 
+/// Images are equal when they have the same size and pixel values.
+///
+/// Images with different sizes compare unequal, even with the same number of pixels.
+impl<T, const C: usize, A1: ImageAllocator, A2: ImageAllocator> PartialEq<Image<T, C, A2>>
+    for Image<T, C, A1>
+where
+    T: PartialEq,
+{
+    fn eq(&self, other: &Image<T, C, A2>) -> bool {
+        self.size() == other.size() && self.as_slice() == other.as_slice()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::image::{Image, ImageError, ImageSize};
@@ -850,4 +1086,121 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_image_planar_roundtrip() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 4,
+            height: 3,
+        };
+        let data = (0..size.width * size.height * 3).map(|i| i as u8).collect();
+        let image = Image::<u8, 3, CpuAllocator>::new(size, data, CpuAllocator)?;
+
+        let chw = image.to_planar()?;
+        assert_eq!(chw.shape, [3, 3, 4]);
+        // the first plane holds the first channel of every pixel
+        assert_eq!(chw.as_slice()[..4], [0, 3, 6, 9]);
+        assert_eq!(chw.get([1, 2, 3]), image.get([2, 3, 1]));
+
+        let image_back = Image::<u8, 3, CpuAllocator>::from_planar(&chw)?;
+        assert_eq!(image_back.size(), size);
+        assert_eq!(image_back.as_slice(), image.as_slice());
+
+        // the number of planes must match the number of channels
+        let res = Image::<u8, 4, CpuAllocator>::from_planar(&chw);
+        assert!(matches!(res, Err(ImageError::InvalidChannelShape(3, 4))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn image_eq_and_rmse() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 3,
+            height: 2,
+        };
+        let image = Image::<u8, 1, _>::new(size, vec![0, 10, 20, 30, 40, 50], CpuAllocator)?;
+        assert!(image == image.clone());
+        assert_eq!(image.rmse(&image)?, 0.0);
+        assert!(image.psnr(&image, 255.0)?.is_infinite());
+
+        let mut changed = image.clone();
+        changed.set_pixel(1, 1, 0, 46)?;
+        assert!(image != changed);
+        let rmse = image.rmse(&changed)?;
+        assert!(rmse.is_finite());
+        assert_eq!(rmse, 6f64.sqrt());
+        assert!((image.psnr(&changed, 255.0)? - 40.35).abs() < 1e-2);
+
+        // same pixels with another size are not equal
+        let transposed = Image::<u8, 1, _>::new(
+            ImageSize {
+                width: 2,
+                height: 3,
+            },
+            image.as_slice().to_vec(),
+            CpuAllocator,
+        )?;
+        assert!(image != transposed);
+        assert!(matches!(
+            image.rmse(&transposed),
+            Err(ImageError::InvalidImageSize(3, 2, 2, 3))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn to_owned_contiguous() -> Result<(), ImageError> {
+        use crate::allocator::ImageAllocator;
+        use kornia_tensor::{allocator::TensorAllocatorError, TensorAllocator};
+        use std::{alloc::Layout, sync::Arc};
+
+        // an allocator wrapping the buffer of a parent, as the frames of a capture
+        #[derive(Clone)]
+        struct ParentAllocator {
+            parent: Arc<Vec<u8>>,
+        }
+
+        impl TensorAllocator for ParentAllocator {
+            fn alloc(&self, layout: Layout) -> Result<*mut u8, TensorAllocatorError> {
+                CpuAllocator.alloc(layout)
+            }
+
+            // the memory is owned and freed by the parent
+            fn dealloc(&self, ptr: *mut u8, _layout: Layout) {
+                assert_eq!(ptr as *const u8, self.parent.as_ptr());
+            }
+        }
+
+        impl ImageAllocator for ParentAllocator {}
+
+        let buffer = Arc::new((0..12).collect::<Vec<u8>>());
+        let frame = Image::<u8, 3, _>(unsafe {
+            Tensor::from_raw_parts(
+                [2, 2, 3],
+                buffer.as_ptr(),
+                12,
+                ParentAllocator {
+                    parent: buffer.clone(),
+                },
+            )?
+        });
+
+        let owned = frame.to_owned_contiguous();
+        assert_ne!(owned.as_ptr(), frame.as_ptr());
+
+        // the copy outlives the frame and its parent buffer
+        drop(frame);
+        drop(buffer);
+        assert_eq!(owned.size(), [2, 2].into());
+        assert_eq!(owned.as_slice(), (0..12).collect::<Vec<u8>>().as_slice());
+
+        // a non-contiguous image is copied in its logical order
+        let mut transposed = Image::<u8, 1, _>::new([2, 2].into(), vec![1, 2, 3, 4], CpuAllocator)?;
+        transposed.0.strides = [1, 2, 1];
+        assert_eq!(transposed.to_owned_contiguous().as_slice(), &[1, 3, 2, 4]);
+
+        Ok(())
+    }
 }
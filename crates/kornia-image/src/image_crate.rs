@@ -0,0 +1,143 @@
+use crate::{allocator::ImageAllocator, Image, ImageError, ImageSize};
+
+impl<A: ImageAllocator> Image<u8, 3, A> {
+    /// Create a new RGB image from an `image` crate [`image::RgbImage`] buffer.
+    ///
+    /// The pixel data is copied in interleaved RGB order, which matches the memory layout
+    /// of both libraries.
+    ///
+    /// # Arguments
+    ///
+    /// * `buf` - The source RGB buffer.
+    /// * `alloc` - The allocator of the image.
+    ///
+    /// # Returns
+    ///
+    /// A new image with the same size and pixel data as the buffer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_image::{Image, allocator::CpuAllocator};
+    ///
+    /// let buf = image::RgbImage::from_pixel(4, 2, image::Rgb([1, 2, 3]));
+    /// let img = Image::<u8, 3, _>::from_image_buffer(&buf, CpuAllocator).unwrap();
+    /// assert_eq!(img.width(), 4);
+    /// assert_eq!(img.height(), 2);
+    /// ```
+    pub fn from_image_buffer(buf: &::image::RgbImage, alloc: A) -> Result<Self, ImageError> {
+        let size = ImageSize {
+            width: buf.width() as usize,
+            height: buf.height() as usize,
+        };
+        Self::from_size_slice(size, buf.as_raw(), alloc)
+    }
+
+    /// Convert the image to an `image` crate [`image::RgbImage`] buffer.
+    ///
+    /// # Returns
+    ///
+    /// A new RGB buffer with a copy of the pixel data.
+    ///
+    /// # Errors
+    ///
+    /// If the pixel data does not match the image size, an error is returned.
+    pub fn to_image_buffer(&self) -> Result<::image::RgbImage, ImageError> {
+        ::image::RgbImage::from_raw(
+            self.width() as u32,
+            self.height() as u32,
+            self.as_slice().to_vec(),
+        )
+        .ok_or(ImageError::InvalidChannelShape(
+            self.as_slice().len(),
+            self.width() * self.height() * 3,
+        ))
+    }
+}
+
+impl<A: ImageAllocator> Image<u8, 1, A> {
+    /// Create a new grayscale image from an `image` crate [`image::GrayImage`] buffer.
+    ///
+    /// # Arguments
+    ///
+    /// * `buf` - The source Luma8 buffer.
+    /// * `alloc` - The allocator of the image.
+    ///
+    /// # Returns
+    ///
+    /// A new image with the same size and pixel data as the buffer.
+    pub fn from_image_buffer(buf: &::image::GrayImage, alloc: A) -> Result<Self, ImageError> {
+        let size = ImageSize {
+            width: buf.width() as usize,
+            height: buf.height() as usize,
+        };
+        Self::from_size_slice(size, buf.as_raw(), alloc)
+    }
+
+    /// Convert the image to an `image` crate [`image::GrayImage`] buffer.
+    ///
+    /// # Returns
+    ///
+    /// A new Luma8 buffer with a copy of the pixel data.
+    ///
+    /// # Errors
+    ///
+    /// If the pixel data does not match the image size, an error is returned.
+    pub fn to_image_buffer(&self) -> Result<::image::GrayImage, ImageError> {
+        ::image::GrayImage::from_raw(
+            self.width() as u32,
+            self.height() as u32,
+            self.as_slice().to_vec(),
+        )
+        .ok_or(ImageError::InvalidChannelShape(
+            self.as_slice().len(),
+            self.width() * self.height(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{allocator::CpuAllocator, Image, ImageError, ImageSize};
+
+    #[test]
+    fn test_rgb_image_buffer_roundtrip() -> Result<(), ImageError> {
+        let buf = ::image::RgbImage::from_fn(3, 2, |x, y| {
+            ::image::Rgb([x as u8, y as u8, (10 * x + y) as u8])
+        });
+
+        let image = Image::<u8, 3, _>::from_image_buffer(&buf, CpuAllocator)?;
+        assert_eq!(
+            image.size(),
+            ImageSize {
+                width: 3,
+                height: 2
+            }
+        );
+
+        // the channels are kept in RGB order
+        assert_eq!(image.get_pixel(2, 1, 0)?, &2);
+        assert_eq!(image.get_pixel(2, 1, 1)?, &1);
+        assert_eq!(image.get_pixel(2, 1, 2)?, &21);
+
+        let buf_back = image.to_image_buffer()?;
+        assert_eq!(buf_back.dimensions(), (3, 2));
+        assert_eq!(buf_back, buf);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_gray_image_buffer_roundtrip() -> Result<(), ImageError> {
+        let buf = ::image::GrayImage::from_fn(2, 3, |x, y| ::image::Luma([(x + 2 * y) as u8]));
+
+        let image = Image::<u8, 1, _>::from_image_buffer(&buf, CpuAllocator)?;
+        assert_eq!(image.width(), 2);
+        assert_eq!(image.height(), 3);
+        assert_eq!(image.as_slice(), &[0, 1, 2, 3, 4, 5]);
+
+        assert_eq!(image.to_image_buffer()?, buf);
+
+        Ok(())
+    }
+}
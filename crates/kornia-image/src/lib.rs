@@ -7,6 +7,9 @@ pub mod allocator;
 /// image representation for computer vision purposes.
 pub mod image;
 
+/// batches of same-sized images.
+pub mod batch;
+
 /// Error types for the image module.
 pub mod error;
 
@@ -16,9 +19,14 @@ pub mod ops;
 /// Typed color space wrappers for compile-time type safety.
 pub mod color_spaces;
 
+pub use crate::batch::ImageBatch;
 pub use crate::error::ImageError;
 pub use crate::image::{Image, ImageSize};
 
 /// Arrow integration for converting images to Arrow format
 #[cfg(feature = "arrow")]
 pub mod arrow;
+
+/// Conversions between images and the `image` crate buffers
+#[cfg(feature = "image-crate")]
+pub mod image_crate;
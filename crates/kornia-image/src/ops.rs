@@ -61,6 +61,73 @@ where
     Ok(())
 }
 
+/// Convert an 8-bit image to a 16-bit image using the full range of both types.
+///
+/// Each value is multiplied by 257 so that `0` maps to `0` and `255` maps to `65535`.
+///
+/// # Arguments
+///
+/// * `src` - The source 8-bit image.
+/// * `dst` - The destination 16-bit image.
+///
+/// PRECONDITION: `src` and `dst` must have the same size.
+pub fn u16_from_u8<const C: usize, A1: ImageAllocator, A2: ImageAllocator>(
+    src: &Image<u8, C, A1>,
+    dst: &mut Image<u16, C, A2>,
+) -> Result<(), ImageError> {
+    if src.size() != dst.size() {
+        return Err(ImageError::InvalidImageSize(
+            src.width(),
+            src.height(),
+            dst.width(),
+            dst.height(),
+        ));
+    }
+
+    dst.as_slice_mut()
+        .iter_mut()
+        .zip(src.as_slice().iter())
+        .for_each(|(out, &inp)| {
+            *out = inp as u16 * 257;
+        });
+
+    Ok(())
+}
+
+/// Convert a 16-bit image to an 8-bit image using the full range of both types.
+///
+/// Each value is divided by 257 and rounded to the nearest integer, which is the inverse
+/// of [`u16_from_u8`].
+///
+/// # Arguments
+///
+/// * `src` - The source 16-bit image.
+/// * `dst` - The destination 8-bit image.
+///
+/// PRECONDITION: `src` and `dst` must have the same size.
+pub fn u8_from_u16<const C: usize, A1: ImageAllocator, A2: ImageAllocator>(
+    src: &Image<u16, C, A1>,
+    dst: &mut Image<u8, C, A2>,
+) -> Result<(), ImageError> {
+    if src.size() != dst.size() {
+        return Err(ImageError::InvalidImageSize(
+            src.width(),
+            src.height(),
+            dst.width(),
+            dst.height(),
+        ));
+    }
+
+    dst.as_slice_mut()
+        .iter_mut()
+        .zip(src.as_slice().iter())
+        .for_each(|(out, &inp)| {
+            *out = ((inp as u32 + 128) / 257) as u8;
+        });
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -89,4 +156,28 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_u8_u16_roundtrip() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 4,
+            height: 1,
+        };
+        let image = Image::<u8, 1, _>::new(size, vec![0, 1, 128, 255], CpuAllocator)?;
+
+        let mut image_u16 = Image::<u16, 1, _>::from_size_val(size, 0, CpuAllocator)?;
+        u16_from_u8(&image, &mut image_u16)?;
+        assert_eq!(image_u16.as_slice(), &[0, 257, 32896, 65535]);
+
+        let mut image_u8 = Image::<u8, 1, _>::from_size_val(size, 0, CpuAllocator)?;
+        u8_from_u16(&image_u16, &mut image_u8)?;
+        assert_eq!(image_u8.as_slice(), image.as_slice());
+
+        // values in between are rounded to the nearest 8-bit value
+        let image_u16 = Image::<u16, 1, _>::new(size, vec![128, 129, 65406, 65535], CpuAllocator)?;
+        u8_from_u16(&image_u16, &mut image_u8)?;
+        assert_eq!(image_u8.as_slice(), &[0, 1, 254, 255]);
+
+        Ok(())
+    }
 }
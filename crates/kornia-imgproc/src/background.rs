@@ -0,0 +1,394 @@
+use kornia_image::{allocator::ImageAllocator, Image, ImageError, ImageSize};
+use kornia_tensor::CpuAllocator;
+use rayon::prelude::*;
+
+use crate::parallel;
+
+/// The value of the foreground pixels in the mask.
+const FOREGROUND: u8 = 255;
+
+/// A background subtractor for video with a running Gaussian model per pixel.
+///
+/// Each pixel keeps the mean and the variance of its channels, updated with an exponential
+/// moving average on every frame. A pixel is foreground when any of its channels is further
+/// than `threshold` standard deviations from the mean.
+///
+/// The mean of every pixel follows the frames, so gradual illumination changes are absorbed
+/// and a stationary object blends into the background after about `1 / learning_rate`
+/// frames. The variance is only updated on the background pixels, so that the moving objects
+/// do not widen the model.
+pub struct BackgroundSubtractor<const C: usize> {
+    image_size: ImageSize,
+    learning_rate: f32,
+    threshold: f32,
+    min_variance: f32,
+    initialized: bool,
+    mean: Vec<f32>,
+    variance: Vec<f32>,
+}
+
+impl<const C: usize> BackgroundSubtractor<C> {
+    /// Creates a background subtractor with default values
+    ///
+    /// # Arguments
+    ///
+    /// * `image_size` - The size of the frames of the video.
+    pub fn new(image_size: ImageSize) -> Self {
+        Self {
+            image_size,
+            learning_rate: 0.05,
+            threshold: 2.5,
+            min_variance: 16.0,
+            initialized: false,
+            mean: vec![0.0; image_size.width * image_size.height * C],
+            variance: vec![0.0; image_size.width * image_size.height * C],
+        }
+    }
+
+    /// Sets the learning rate of the model, between 0 and 1 (0.05 by default)
+    pub fn with_learning_rate(self, learning_rate: f32) -> Self {
+        Self {
+            learning_rate,
+            ..self
+        }
+    }
+
+    /// Sets the foreground threshold in standard deviations (2.5 by default)
+    pub fn with_threshold(self, threshold: f32) -> Self {
+        Self { threshold, ..self }
+    }
+
+    /// Sets the lower bound of the variance of the model (16 by default)
+    pub fn with_min_variance(self, min_variance: f32) -> Self {
+        Self {
+            min_variance,
+            ..self
+        }
+    }
+
+    /// Forgets the model, so that the next frame initializes the background.
+    pub fn reset(&mut self) {
+        self.initialized = false;
+    }
+
+    /// Computes the foreground mask of a frame and updates the model with it.
+    ///
+    /// The first frame after [`BackgroundSubtractor::new`] or [`BackgroundSubtractor::reset`]
+    /// initializes the background, so its mask is empty.
+    ///
+    /// # Arguments
+    ///
+    /// * `frame` - The frame of the video with shape (H, W, C).
+    ///
+    /// # Returns
+    ///
+    /// The foreground mask with shape (H, W), 255 for the foreground and 0 for the background.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the size of the frame does not match the size of the model.
+    pub fn apply<A: ImageAllocator>(
+        &mut self,
+        frame: &Image<u8, C, A>,
+    ) -> Result<Image<u8, 1, CpuAllocator>, ImageError> {
+        if frame.size() != self.image_size {
+            return Err(ImageError::InvalidImageSize(
+                frame.size().width,
+                frame.size().height,
+                self.image_size.width,
+                self.image_size.height,
+            ));
+        }
+
+        let mut mask = Image::from_size_val(self.image_size, 0u8, CpuAllocator)?;
+
+        if !self.initialized {
+            self.mean
+                .iter_mut()
+                .zip(frame.as_slice())
+                .for_each(|(mean, &v)| *mean = v as f32);
+            // start with a wide model, narrowed by the next frames
+            self.variance.fill(self.min_variance.max(225.0));
+            self.initialized = true;
+            return Ok(mask);
+        }
+
+        let alpha = self.learning_rate;
+        let threshold_sq = self.threshold * self.threshold;
+        let min_variance = self.min_variance;
+
+        mask.as_slice_mut()
+            .par_iter_mut()
+            .zip(frame.as_slice().par_chunks_exact(C))
+            .zip(self.mean.par_chunks_exact_mut(C))
+            .zip(self.variance.par_chunks_exact_mut(C))
+            .for_each(|(((mask_pixel, frame_pixel), mean), variance)| {
+                let mut dist_sq = [0.0; C];
+                for c in 0..C {
+                    let d = frame_pixel[c] as f32 - mean[c];
+                    dist_sq[c] = d * d;
+                    mean[c] += alpha * d;
+                }
+
+                let foreground = (0..C).any(|c| dist_sq[c] > threshold_sq * variance[c]);
+
+                if foreground {
+                    *mask_pixel = FOREGROUND;
+                } else {
+                    for c in 0..C {
+                        variance[c] =
+                            (variance[c] + alpha * (dist_sq[c] - variance[c])).max(min_variance);
+                    }
+                }
+            });
+
+        Ok(mask)
+    }
+}
+
+/// Computes the motion mask of two consecutive frames.
+///
+/// A pixel is in motion when the absolute difference of the frames is greater than the
+/// threshold. The difference is computed without wrapping around, so a pixel going from 250
+/// to 10 differs by 240 as one going from 10 to 250.
+///
+/// # Arguments
+///
+/// * `prev` - The previous frame with shape (H, W).
+/// * `curr` - The current frame with shape (H, W).
+/// * `dst` - The motion mask with shape (H, W), 255 for the motion and 0 elsewhere.
+/// * `threshold` - The smallest difference that is not motion.
+///
+/// # Errors
+///
+/// Returns an error if the sizes of the images do not match.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_image::allocator::CpuAllocator;
+/// use kornia_imgproc::background::frame_diff_mask;
+///
+/// let size = ImageSize { width: 3, height: 1 };
+/// let prev = Image::<u8, 1, _>::new(size, vec![10, 250, 100], CpuAllocator).unwrap();
+/// let curr = Image::<u8, 1, _>::new(size, vec![250, 10, 105], CpuAllocator).unwrap();
+///
+/// let mut mask = Image::<u8, 1, _>::from_size_val(size, 0, CpuAllocator).unwrap();
+/// frame_diff_mask(&prev, &curr, &mut mask, 20).unwrap();
+/// assert_eq!(mask.as_slice(), &[255, 255, 0]);
+/// ```
+pub fn frame_diff_mask<A1: ImageAllocator, A2: ImageAllocator, A3: ImageAllocator>(
+    prev: &Image<u8, 1, A1>,
+    curr: &Image<u8, 1, A2>,
+    dst: &mut Image<u8, 1, A3>,
+    threshold: u8,
+) -> Result<(), ImageError> {
+    check_sizes(prev.size(), curr.size())?;
+    check_sizes(prev.size(), dst.size())?;
+
+    parallel::par_iter_rows_val_two(prev, curr, dst, |&p, &c, m| {
+        *m = if p.abs_diff(c) > threshold {
+            FOREGROUND
+        } else {
+            0
+        };
+    });
+
+    Ok(())
+}
+
+/// Updates a running average of the frames with a new frame.
+///
+/// The average is updated as `acc = (1 - alpha) * acc + alpha * src`, so it follows the
+/// background of the scene while the objects moving through it fade out. Initialize the
+/// average with the first frame, e.g. with `Image::cast_and_scale`.
+///
+/// # Arguments
+///
+/// * `src` - The new frame with shape (H, W, C).
+/// * `acc` - The running average with shape (H, W, C).
+/// * `alpha` - The weight of the new frame, between 0 and 1.
+///
+/// # Errors
+///
+/// Returns an error if the sizes of the images do not match.
+pub fn accumulate_weighted<const C: usize, A1: ImageAllocator, A2: ImageAllocator>(
+    src: &Image<u8, C, A1>,
+    acc: &mut Image<f32, C, A2>,
+    alpha: f32,
+) -> Result<(), ImageError> {
+    check_sizes(src.size(), acc.size())?;
+
+    parallel::par_iter_rows_val(src, acc, |&s, a| {
+        *a += alpha * (s as f32 - *a);
+    });
+
+    Ok(())
+}
+
+/// Computes the motion mask of a frame against a running average background.
+///
+/// A pixel is in motion when the absolute difference between the frame and the background
+/// is greater than the threshold. The background is usually updated with
+/// [`accumulate_weighted`].
+///
+/// # Arguments
+///
+/// * `background` - The background with shape (H, W).
+/// * `curr` - The current frame with shape (H, W).
+/// * `dst` - The motion mask with shape (H, W), 255 for the motion and 0 elsewhere.
+/// * `threshold` - The smallest difference that is not motion.
+///
+/// # Errors
+///
+/// Returns an error if the sizes of the images do not match.
+pub fn background_diff_mask<A1: ImageAllocator, A2: ImageAllocator, A3: ImageAllocator>(
+    background: &Image<f32, 1, A1>,
+    curr: &Image<u8, 1, A2>,
+    dst: &mut Image<u8, 1, A3>,
+    threshold: f32,
+) -> Result<(), ImageError> {
+    check_sizes(background.size(), curr.size())?;
+    check_sizes(background.size(), dst.size())?;
+
+    parallel::par_iter_rows_val_two(background, curr, dst, |&b, &c, m| {
+        *m = if (c as f32 - b).abs() > threshold {
+            FOREGROUND
+        } else {
+            0
+        };
+    });
+
+    Ok(())
+}
+
+fn check_sizes(expected: ImageSize, actual: ImageSize) -> Result<(), ImageError> {
+    if expected != actual {
+        return Err(ImageError::InvalidImageSize(
+            actual.width,
+            actual.height,
+            expected.width,
+            expected.height,
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_background_subtractor() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 12,
+            height: 8,
+        };
+        let scene = |offset: f32| {
+            let data = (0..size.width * size.height)
+                .flat_map(|i| {
+                    let v = 40.0 + (i % size.width) as f32 * 4.0 + offset;
+                    [v as u8, (v * 0.5) as u8, 100]
+                })
+                .collect();
+            Image::<u8, 3, _>::new(size, data, CpuAllocator)
+        };
+
+        let mut subtractor = BackgroundSubtractor::<3>::new(size).with_learning_rate(0.1);
+
+        // learn the static scene
+        for _ in 0..50 {
+            let mask = subtractor.apply(&scene(0.0)?)?;
+            assert!(mask.as_slice().iter().all(|&v| v == 0));
+        }
+
+        // a slow illumination change is absorbed by the model
+        for i in 0..40 {
+            let mask = subtractor.apply(&scene(0.5 * (i + 1) as f32)?)?;
+            assert!(mask.as_slice().iter().all(|&v| v == 0));
+        }
+
+        // a block moving into the scene is foreground
+        let mut frame = scene(20.0)?;
+        for y in 2..5 {
+            for x in 3..7 {
+                frame.as_slice_mut()[(y * size.width + x) * 3] = 250;
+            }
+        }
+        let mask = subtractor.apply(&frame)?;
+        for y in 0..size.height {
+            for x in 0..size.width {
+                let expected = if (2..5).contains(&y) && (3..7).contains(&x) {
+                    255
+                } else {
+                    0
+                };
+                assert_eq!(mask.as_slice()[y * size.width + x], expected);
+            }
+        }
+
+        let wrong = Image::<u8, 3, _>::from_size_val([4, 4].into(), 0, CpuAllocator)?;
+        assert!(matches!(
+            subtractor.apply(&wrong),
+            Err(ImageError::InvalidImageSize(4, 4, 12, 8))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_frame_diff_mask() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 8,
+            height: 6,
+        };
+        let frame_with_block = |x0: usize| {
+            let mut frame = Image::<u8, 1, _>::from_size_val(size, 30, CpuAllocator)?;
+            for y in 1..3 {
+                for x in x0..x0 + 2 {
+                    frame.as_slice_mut()[y * size.width + x] = 220;
+                }
+            }
+            Ok::<_, ImageError>(frame)
+        };
+
+        // the block moves two pixels to the right
+        let prev = frame_with_block(1)?;
+        let curr = frame_with_block(3)?;
+
+        let mut mask = Image::<u8, 1, _>::from_size_val(size, 0, CpuAllocator)?;
+        frame_diff_mask(&prev, &curr, &mut mask, 25)?;
+        for y in 0..size.height {
+            for x in 0..size.width {
+                let changed = (1..3).contains(&y) && (1..5).contains(&x);
+                let expected = if changed { 255 } else { 0 };
+                assert_eq!(mask.as_slice()[y * size.width + x], expected);
+            }
+        }
+
+        // the difference is the same in both directions
+        let mut reverse = Image::<u8, 1, _>::from_size_val(size, 0, CpuAllocator)?;
+        frame_diff_mask(&curr, &prev, &mut reverse, 25)?;
+        assert_eq!(reverse.as_slice(), mask.as_slice());
+
+        // the running average keeps the static background
+        let mut background = Image::<f32, 1, _>::from_size_val(size, 30.0, CpuAllocator)?;
+        for _ in 0..5 {
+            accumulate_weighted(&curr, &mut background, 0.1)?;
+        }
+        assert_eq!(background.as_slice()[0], 30.0);
+        let mut moving = Image::<u8, 1, _>::from_size_val(size, 0, CpuAllocator)?;
+        background_diff_mask(&background, &curr, &mut moving, 25.0)?;
+        assert_eq!(moving.as_slice()[size.width + 3], 255);
+        assert_eq!(moving.as_slice()[size.width + 1], 0);
+
+        let small = Image::<u8, 1, _>::from_size_val([4, 4].into(), 0, CpuAllocator)?;
+        assert!(matches!(
+            frame_diff_mask(&prev, &small, &mut mask, 25),
+            Err(ImageError::InvalidImageSize(4, 4, 8, 6))
+        ));
+
+        Ok(())
+    }
+}
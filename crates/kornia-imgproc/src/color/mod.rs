@@ -51,6 +51,17 @@
 //! # Ok(())
 //! # }
 //! ```
+//!
+//! # In-place Conversions
+//!
+//! Most conversions read several channels of a pixel to write another one, so the input and
+//! output need separate buffers. The conversions that only permute the channels of a pixel
+//! have in-place variants that reuse the input buffer:
+//!
+//! - [`swap_rb_inplace`](crate::color::swap_rb_inplace): RGB to BGR and RGBA to BGRA, and
+//!   back.
+//! - [`reorder_channels_inplace`](crate::color::reorder_channels_inplace): any permutation of
+//!   the channels.
 
 // Re-export color spaces from kornia-image
 pub use kornia_image::color_spaces::{
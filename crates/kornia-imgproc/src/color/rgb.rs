@@ -1,5 +1,6 @@
 use crate::parallel;
 use kornia_image::{allocator::ImageAllocator, Image, ImageError};
+use rayon::prelude::*;
 
 /// Convert an RGBA image to RGB image.
 ///
@@ -126,6 +127,157 @@ pub fn rgb_from_bgra<A1: ImageAllocator, A2: ImageAllocator>(
     Ok(())
 }
 
+/// Reorder the channels of an image.
+///
+/// The channel `i` of the output image is taken from the channel `order[i]` of the input
+/// image, e.g. `[2, 1, 0]` converts RGB to BGR and `[2, 1, 0, 3]` converts RGBA to BGRA.
+///
+/// # Arguments
+///
+/// * `src` - The input image.
+/// * `dst` - The output image.
+/// * `order` - The index of the source channel for each output channel.
+///
+/// Precondition: `order` must be a permutation of `0..C`.
+/// Precondition: the input and output images must have the same size.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_image::allocator::CpuAllocator;
+/// use kornia_imgproc::color::reorder_channels;
+///
+/// let src = Image::<u8, 4, _>::new(
+///     ImageSize { width: 1, height: 1 },
+///     vec![10, 20, 30, 255],
+///     CpuAllocator,
+/// ).unwrap();
+///
+/// let mut dst = Image::<u8, 4, _>::from_size_val(src.size(), 0, CpuAllocator).unwrap();
+///
+/// reorder_channels(&src, &mut dst, [2, 1, 0, 3]).unwrap();
+/// assert_eq!(dst.as_slice(), &[30, 20, 10, 255]);
+/// ```
+pub fn reorder_channels<T, const C: usize, A1: ImageAllocator, A2: ImageAllocator>(
+    src: &Image<T, C, A1>,
+    dst: &mut Image<T, C, A2>,
+    order: [usize; C],
+) -> Result<(), ImageError>
+where
+    T: Copy + Send + Sync,
+{
+    if src.size() != dst.size() {
+        return Err(ImageError::InvalidImageSize(
+            src.cols(),
+            src.rows(),
+            dst.cols(),
+            dst.rows(),
+        ));
+    }
+
+    // every channel must be used exactly once
+    let mut used = [false; C];
+    for &ch in order.iter() {
+        if ch >= C || used[ch] {
+            return Err(ImageError::InvalidChannelOrder(order.to_vec(), C));
+        }
+        used[ch] = true;
+    }
+
+    parallel::par_iter_rows(src, dst, |src_pixel, dst_pixel| {
+        dst_pixel
+            .iter_mut()
+            .zip(order.iter())
+            .for_each(|(d, &ch)| *d = src_pixel[ch]);
+    });
+
+    Ok(())
+}
+
+/// Swap the red and blue channels of an image in place.
+///
+/// This converts RGB to BGR and RGBA to BGRA, and back, without a second buffer. The
+/// channels 0 and 2 of each pixel are exchanged and the other channels are kept as is.
+///
+/// # Arguments
+///
+/// * `img` - The image to convert, with at least 3 channels.
+///
+/// # Errors
+///
+/// Returns an error if the image has less than 3 channels.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_image::allocator::CpuAllocator;
+/// use kornia_imgproc::color::swap_rb_inplace;
+///
+/// let mut img = Image::<u8, 3, _>::new(
+///     ImageSize { width: 1, height: 1 },
+///     vec![10, 20, 30],
+///     CpuAllocator,
+/// ).unwrap();
+///
+/// swap_rb_inplace(&mut img).unwrap();
+/// assert_eq!(img.as_slice(), &[30, 20, 10]);
+/// ```
+pub fn swap_rb_inplace<T, const C: usize, A: ImageAllocator>(
+    img: &mut Image<T, C, A>,
+) -> Result<(), ImageError>
+where
+    T: Send,
+{
+    if C < 3 {
+        return Err(ImageError::UnsupportedChannelCount(C));
+    }
+
+    img.as_slice_mut()
+        .par_chunks_exact_mut(C)
+        .for_each(|pixel| pixel.swap(0, 2));
+
+    Ok(())
+}
+
+/// Reorder the channels of an image in place.
+///
+/// This is the in-place version of [`reorder_channels`]. Each pixel is permuted through a
+/// copy on the stack, so the channels can be read and written in any order.
+///
+/// # Arguments
+///
+/// * `img` - The image to convert.
+/// * `order` - The index of the source channel for each output channel.
+///
+/// Precondition: `order` must be a permutation of `0..C`.
+pub fn reorder_channels_inplace<T, const C: usize, A: ImageAllocator>(
+    img: &mut Image<T, C, A>,
+    order: [usize; C],
+) -> Result<(), ImageError>
+where
+    T: Copy + Send + Sync,
+{
+    // every channel must be used exactly once
+    let mut used = [false; C];
+    for &ch in order.iter() {
+        if ch >= C || used[ch] {
+            return Err(ImageError::InvalidChannelOrder(order.to_vec(), C));
+        }
+        used[ch] = true;
+    }
+
+    img.as_slice_mut()
+        .par_chunks_exact_mut(C)
+        .for_each(|pixel| {
+            let src: [T; C] = std::array::from_fn(|i| pixel[order[i]]);
+            pixel.copy_from_slice(&src);
+        });
+
+    Ok(())
+}
+
 #[inline]
 fn alpha_blend(r: u8, g: u8, b: u8, a: u8, bg: &[u8; 3], rgb: &mut [u8]) {
     let alpha = a as f32 / 255.0;
@@ -272,4 +424,66 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_reorder_channels() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 3,
+            height: 2,
+        };
+        let src = Image::<u8, 3, CpuAllocator>::new(size, (0..18).collect(), CpuAllocator)?;
+
+        let mut dst = Image::<u8, 3, CpuAllocator>::from_size_val(size, 0, CpuAllocator)?;
+        reorder_channels(&src, &mut dst, [2, 1, 0])?;
+
+        let mut expected = Image::<u8, 3, CpuAllocator>::from_size_val(size, 0, CpuAllocator)?;
+        crate::color::bgr_from_rgb(&src, &mut expected)?;
+        assert_eq!(dst.as_slice(), expected.as_slice());
+
+        // move the last channel to the front
+        reorder_channels(&src, &mut dst, [2, 0, 1])?;
+        assert_eq!(&dst.as_slice()[..6], &[2, 0, 1, 5, 3, 4]);
+
+        // not a permutation
+        for order in [[0, 0, 1], [0, 1, 3]] {
+            let res = reorder_channels(&src, &mut dst, order);
+            assert!(matches!(res, Err(ImageError::InvalidChannelOrder(_, 3))));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_swap_rb_inplace() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 3,
+            height: 2,
+        };
+        let data = (0..18).collect::<Vec<u8>>();
+        let mut img = Image::<u8, 3, _>::new(size, data.clone(), CpuAllocator)?;
+
+        // matches the conversion with a second buffer
+        let mut expected = Image::<u8, 3, _>::from_size_val(size, 0, CpuAllocator)?;
+        reorder_channels(&img, &mut expected, [2, 1, 0])?;
+        swap_rb_inplace(&mut img)?;
+        assert_eq!(img.as_slice(), expected.as_slice());
+
+        // swapping twice is the identity
+        swap_rb_inplace(&mut img)?;
+        assert_eq!(img.as_slice(), data.as_slice());
+
+        // the alpha channel is kept
+        let mut rgba = Image::<u8, 4, _>::new([1, 1].into(), vec![1, 2, 3, 4], CpuAllocator)?;
+        swap_rb_inplace(&mut rgba)?;
+        assert_eq!(rgba.as_slice(), &[3, 2, 1, 4]);
+
+        reorder_channels_inplace(&mut rgba, [3, 0, 1, 2])?;
+        assert_eq!(rgba.as_slice(), &[4, 3, 2, 1]);
+        assert!(reorder_channels_inplace(&mut rgba, [0, 0, 1, 2]).is_err());
+
+        let mut gray = Image::<u8, 1, _>::new([1, 1].into(), vec![1], CpuAllocator)?;
+        assert!(swap_rb_inplace(&mut gray).is_err());
+
+        Ok(())
+    }
 }
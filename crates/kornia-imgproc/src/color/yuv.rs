@@ -101,6 +101,95 @@ pub fn convert_yuyv_to_rgb_u8<A: ImageAllocator>(
     Ok(())
 }
 
+/// Convert an NV12 image to an RGB image.
+///
+/// NV12 is a semi-planar format with a full resolution Y plane followed by a plane of
+/// interleaved U and V samples subsampled by two in both directions. The rows of each plane
+/// can be padded, as it is common for the buffers produced by hardware decoders.
+///
+/// # Arguments
+///
+/// * `y_plane` - The Y plane data, starting at the first pixel.
+/// * `y_stride` - The number of bytes between two rows of the Y plane.
+/// * `uv_plane` - The interleaved UV plane data, starting at the first sample.
+/// * `uv_stride` - The number of bytes between two rows of the UV plane.
+/// * `dst` - The RGB image to store the result.
+/// * `mode` - The mode to convert YUV to RGB.
+///
+/// Precondition: the planes must contain the rows of an image with the size of `dst`.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_image::allocator::CpuAllocator;
+/// use kornia_imgproc::color::{convert_nv12_to_rgb_u8, YuvToRgbMode};
+///
+/// let y_plane = vec![128u8; 4];
+/// let uv_plane = vec![128u8; 2];
+///
+/// let mut dst = Image::<u8, 3, _>::from_size_val(
+///     ImageSize { width: 2, height: 2 },
+///     0,
+///     CpuAllocator,
+/// ).unwrap();
+///
+/// convert_nv12_to_rgb_u8(&y_plane, 2, &uv_plane, 2, &mut dst, YuvToRgbMode::Bt601Full).unwrap();
+/// assert_eq!(dst.as_slice(), &[128; 12]);
+/// ```
+pub fn convert_nv12_to_rgb_u8<A: ImageAllocator>(
+    y_plane: &[u8],
+    y_stride: usize,
+    uv_plane: &[u8],
+    uv_stride: usize,
+    dst: &mut Image<u8, 3, A>,
+    mode: YuvToRgbMode,
+) -> Result<(), ImageError> {
+    let (width, height) = (dst.width(), dst.height());
+    if width == 0 || height == 0 {
+        return Ok(());
+    }
+
+    // the last row of each plane does not need to be padded
+    let (uv_width, uv_height) = (width.div_ceil(2), height.div_ceil(2));
+    let y_len = (height - 1) * y_stride + width;
+    let uv_len = (uv_height - 1) * uv_stride + uv_width * 2;
+
+    if y_stride < width || y_plane.len() < y_len {
+        return Err(ImageError::InvalidChannelShape(y_plane.len(), y_len));
+    }
+
+    if uv_stride < uv_width * 2 || uv_plane.len() < uv_len {
+        return Err(ImageError::InvalidChannelShape(uv_plane.len(), uv_len));
+    }
+
+    dst.as_slice_mut()
+        .par_chunks_exact_mut(width * 3)
+        .enumerate()
+        .for_each(|(row, rgb_row)| {
+            let y_row = &y_plane[row * y_stride..row * y_stride + width];
+            let uv_row = &uv_plane[(row / 2) * uv_stride..(row / 2) * uv_stride + uv_width * 2];
+
+            rgb_row
+                .chunks_exact_mut(3)
+                .zip(y_row.iter())
+                .enumerate()
+                .for_each(|(col, (rgb, &y))| {
+                    let (u, v) = (uv_row[(col / 2) * 2], uv_row[(col / 2) * 2 + 1]);
+                    let (r, g, b) = match mode {
+                        YuvToRgbMode::Bt601Full => yuv_to_rgb_u8_bt601_full(y, u, v),
+                        YuvToRgbMode::Bt709Full => yuv_to_rgb_u8_bt709_full(y, u, v),
+                        YuvToRgbMode::Bt601Limited => yuv_to_rgb_u8_bt601_limited(y, u, v),
+                    };
+                    rgb[0] = r;
+                    rgb[1] = g;
+                    rgb[2] = b;
+                });
+        });
+
+    Ok(())
+}
+
 #[inline]
 fn yuv_to_rgb_u8_bt601_full(y: u8, u: u8, v: u8) -> (u8, u8, u8) {
     // Convert to signed integers and apply offsets
@@ -166,3 +255,55 @@ fn yuv_to_rgb_u8_bt601_limited(y: u8, u: u8, v: u8) -> (u8, u8, u8) {
         b.clamp(0, 255) as u8,
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kornia_image::ImageSize;
+    use kornia_tensor::CpuAllocator;
+
+    #[test]
+    fn test_convert_nv12_to_rgb_u8() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 4,
+            height: 2,
+        };
+
+        // rows padded to 6 bytes, the left 2x2 block is gray and the right one is red
+        #[rustfmt::skip]
+        let y_plane = vec![
+            128, 128, 76, 76, 0, 0,
+            128, 128, 76, 76, 0, 0,
+        ];
+        #[rustfmt::skip]
+        let uv_plane = vec![
+            128, 128, 85, 255, 0, 0,
+        ];
+
+        let mut dst = Image::<u8, 3, _>::from_size_val(size, 0, CpuAllocator)?;
+        convert_nv12_to_rgb_u8(&y_plane, 6, &uv_plane, 6, &mut dst, YuvToRgbMode::Bt601Full)?;
+
+        let gray = [128, 128, 128];
+        let red = [254, 0, 0];
+        for y in 0..size.height {
+            for x in 0..size.width {
+                let expected = if x < 2 { gray } else { red };
+                let offset = (y * size.width + x) * 3;
+                assert_eq!(dst.as_slice()[offset..offset + 3], expected, "({x}, {y})");
+            }
+        }
+
+        // the planes are too short for the padded rows
+        let res = convert_nv12_to_rgb_u8(
+            &y_plane,
+            10,
+            &uv_plane,
+            6,
+            &mut dst,
+            YuvToRgbMode::Bt601Full,
+        );
+        assert!(res.is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,673 @@
+use kornia_image::{allocator::ImageAllocator, Image};
+
+/// Offsets (row, col) of the 8-neighbours of a pixel in clockwise order, starting from east.
+const NEIGHBOURS: [(isize, isize); 8] = [
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+];
+
+/// Find the outer contours of the connected foreground regions of a binary image.
+///
+/// The borders are traced with the Suzuki-Abe border following algorithm using
+/// 8-connectivity. Hole borders are followed internally so that regions nested inside
+/// holes are reported as well, but only the outer borders are returned.
+///
+/// All the non-zero pixels are treated as foreground.
+///
+/// # Arguments
+///
+/// * `src` - The input binary image.
+///
+/// # Returns
+///
+/// A list of contours in raster scan order of their first point. Each contour is a list of
+/// the (x, y) boundary pixels ordered along the border. Isolated pixels produce a contour
+/// with a single point.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_image::allocator::CpuAllocator;
+/// use kornia_imgproc::contours::find_contours;
+///
+/// let image = Image::<u8, 1, _>::new(
+///     ImageSize {
+///         width: 4,
+///         height: 4,
+///     },
+///     vec![
+///         0, 0, 0, 0,
+///         0, 1, 1, 0,
+///         0, 1, 1, 0,
+///         0, 0, 0, 0,
+///     ],
+///     CpuAllocator,
+/// ).unwrap();
+///
+/// let contours = find_contours(&image);
+/// assert_eq!(contours, vec![vec![(1, 1), (1, 2), (2, 2), (2, 1)]]);
+/// ```
+pub fn find_contours<A: ImageAllocator>(src: &Image<u8, 1, A>) -> Vec<Vec<(usize, usize)>> {
+    let (rows, cols) = (src.rows(), src.cols());
+
+    // label map padded with a one pixel background frame
+    let stride = cols + 2;
+    let mut labels = vec![0i32; (rows + 2) * stride];
+    src.as_slice()
+        .chunks_exact(cols.max(1))
+        .enumerate()
+        .for_each(|(r, row)| {
+            row.iter().enumerate().for_each(|(c, &val)| {
+                labels[(r + 1) * stride + c + 1] = (val != 0) as i32;
+            });
+        });
+
+    let mut contours = Vec::new();
+    let mut nbd = 1;
+
+    for r in 1..=rows {
+        for c in 1..=cols {
+            let idx = r * stride + c;
+            let val = labels[idx];
+
+            let from = if val == 1 && labels[idx - 1] == 0 {
+                // outer border starting point
+                (r, c - 1)
+            } else if val >= 1 && labels[idx + 1] == 0 {
+                // hole border starting point
+                (r, c + 1)
+            } else {
+                continue;
+            };
+
+            nbd += 1;
+            let border = follow_border(&mut labels, stride, (r, c), from, nbd);
+
+            if from.1 < c {
+                contours.push(border.into_iter().map(|(r, c)| (c - 1, r - 1)).collect());
+            }
+        }
+    }
+
+    contours
+}
+
+/// Get the index in [`NEIGHBOURS`] of `p` as seen from `center`.
+fn neighbour_index(center: (usize, usize), p: (usize, usize)) -> usize {
+    let offset = (
+        p.0 as isize - center.0 as isize,
+        p.1 as isize - center.1 as isize,
+    );
+    // SAFETY: `p` is always one of the 8-neighbours of `center`
+    NEIGHBOURS.iter().position(|&o| o == offset).unwrap()
+}
+
+/// Get the `k`-th neighbour of `center` in the padded label map.
+fn neighbour(center: (usize, usize), k: usize) -> (usize, usize) {
+    let (dr, dc) = NEIGHBOURS[k % 8];
+    (
+        (center.0 as isize + dr) as usize,
+        (center.1 as isize + dc) as usize,
+    )
+}
+
+/// Follow a border starting at `start` and return its pixels in the padded coordinates.
+///
+/// `from` is the background pixel next to `start` from which the border was detected.
+/// The visited pixels are labelled with `nbd`, or `-nbd` when their right neighbour is
+/// background, as described by Suzuki and Abe.
+fn follow_border(
+    labels: &mut [i32],
+    stride: usize,
+    start: (usize, usize),
+    from: (usize, usize),
+    nbd: i32,
+) -> Vec<(usize, usize)> {
+    let at = |p: (usize, usize)| p.0 * stride + p.1;
+
+    // look clockwise around the start pixel for the first foreground pixel
+    let from_idx = neighbour_index(start, from);
+    let Some(first) = (0..8)
+        .map(|k| neighbour(start, from_idx + k))
+        .find(|&p| labels[at(p)] != 0)
+    else {
+        // isolated pixel
+        labels[at(start)] = -nbd;
+        return vec![start];
+    };
+
+    let mut border = Vec::new();
+    let mut prev = first;
+    let mut current = start;
+
+    loop {
+        // look counter-clockwise around the current pixel, starting after the previous one
+        let prev_idx = neighbour_index(current, prev);
+        let mut east_is_background = false;
+        let mut next = prev;
+        for k in 1..=8 {
+            let k = (prev_idx + 8 - k) % 8;
+            let p = neighbour(current, k);
+            if labels[at(p)] != 0 {
+                next = p;
+                break;
+            }
+            if k == 0 {
+                east_is_background = true;
+            }
+        }
+
+        if east_is_background {
+            labels[at(current)] = -nbd;
+        } else if labels[at(current)] == 1 {
+            labels[at(current)] = nbd;
+        }
+
+        border.push(current);
+
+        if next == start && current == first {
+            break;
+        }
+
+        prev = current;
+        current = next;
+    }
+
+    border
+}
+
+/// Approximate a polygonal curve with fewer points using the Ramer-Douglas-Peucker algorithm.
+///
+/// # Arguments
+///
+/// * `points` - The (x, y) points of the curve, e.g. a contour from [`find_contours`].
+/// * `epsilon` - The maximum distance between the original curve and its approximation.
+/// * `closed` - Whether the curve is closed, i.e. the last point connects to the first one.
+///
+/// # Returns
+///
+/// The simplified curve as a subset of the input points in the same order. For closed
+/// curves the first point is not repeated at the end.
+///
+/// # Example
+///
+/// ```
+/// use kornia_imgproc::contours::approx_poly_dp;
+///
+/// let points = [(0.0, 0.0), (1.0, 0.1), (2.0, -0.1), (3.0, 0.0)];
+/// let approx = approx_poly_dp(&points, 0.5, false);
+/// assert_eq!(approx, vec![(0.0, 0.0), (3.0, 0.0)]);
+/// ```
+pub fn approx_poly_dp(points: &[(f32, f32)], epsilon: f32, closed: bool) -> Vec<(f32, f32)> {
+    if points.len() < 3 {
+        return points.to_vec();
+    }
+
+    if !closed {
+        let mut keep = vec![false; points.len()];
+        keep[0] = true;
+        keep[points.len() - 1] = true;
+        douglas_peucker(points, epsilon, &mut keep);
+        return select_points(points, &keep);
+    }
+
+    // split the closed curve at two points far apart from each other, which are likely to
+    // be kept in the approximation, and simplify the two halves including the wrap-around
+    let first = farthest_point(points, 0);
+    let second = farthest_point(points, first);
+    let (i, j) = (first.min(second), first.max(second));
+
+    if i == j {
+        // all the points are equal
+        return vec![points[0]];
+    }
+
+    let mut keep = vec![false; points.len()];
+    keep[i] = true;
+    keep[j] = true;
+    douglas_peucker(&points[i..=j], epsilon, &mut keep[i..=j]);
+
+    let wrapped = [&points[j..], &points[..=i]].concat();
+    let mut keep_wrapped = vec![false; wrapped.len()];
+    douglas_peucker(&wrapped, epsilon, &mut keep_wrapped);
+    let (head, tail) = keep.split_at_mut(j);
+    tail.iter_mut()
+        .chain(head[..=i].iter_mut())
+        .zip(keep_wrapped.iter())
+        .for_each(|(k, &kw)| *k |= kw);
+
+    select_points(points, &keep)
+}
+
+/// A rotated rectangle.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RotatedRect {
+    /// The (x, y) center of the rectangle.
+    pub center: (f32, f32),
+    /// The (width, height) of the rectangle, where the width is measured along `angle`.
+    pub size: (f32, f32),
+    /// The rotation of the width side with respect to the x axis in radians, in [0, pi/2).
+    pub angle: f32,
+}
+
+/// Compute the convex hull of a set of points using the monotone chain algorithm.
+///
+/// # Arguments
+///
+/// * `points` - The (x, y) points.
+///
+/// # Returns
+///
+/// The vertices of the convex hull in counter-clockwise order, assuming the y axis points
+/// up, starting from the point with the lowest x. Collinear points on the hull edges are
+/// discarded and the first point is not repeated at the end.
+///
+/// # Example
+///
+/// ```
+/// use kornia_imgproc::contours::convex_hull;
+///
+/// let points = [(0.0, 0.0), (1.0, 1.0), (2.0, 0.0), (1.0, 0.5), (1.0, -1.0)];
+/// let hull = convex_hull(&points);
+/// assert_eq!(hull, vec![(0.0, 0.0), (1.0, -1.0), (2.0, 0.0), (1.0, 1.0)]);
+/// ```
+pub fn convex_hull(points: &[(f32, f32)]) -> Vec<(f32, f32)> {
+    let mut sorted = points.to_vec();
+    sorted.sort_by(|a, b| a.0.total_cmp(&b.0).then(a.1.total_cmp(&b.1)));
+    sorted.dedup();
+
+    if sorted.len() < 3 {
+        return sorted;
+    }
+
+    let cross = |o: (f32, f32), a: (f32, f32), b: (f32, f32)| {
+        (a.0 - o.0) * (b.1 - o.1) - (a.1 - o.1) * (b.0 - o.0)
+    };
+
+    let mut hull: Vec<(f32, f32)> = Vec::with_capacity(2 * sorted.len());
+
+    // lower hull
+    for &p in sorted.iter() {
+        while hull.len() >= 2 && cross(hull[hull.len() - 2], hull[hull.len() - 1], p) <= 0.0 {
+            hull.pop();
+        }
+        hull.push(p);
+    }
+
+    // upper hull
+    let lower_len = hull.len() + 1;
+    for &p in sorted.iter().rev().skip(1) {
+        while hull.len() >= lower_len && cross(hull[hull.len() - 2], hull[hull.len() - 1], p) <= 0.0
+        {
+            hull.pop();
+        }
+        hull.push(p);
+    }
+
+    // the last point is the first point of the lower hull
+    hull.pop();
+    hull
+}
+
+/// Compute the minimum area rectangle enclosing a set of points.
+///
+/// The convex hull of the points is computed first and the rectangle is found with the
+/// rotating calipers algorithm, using the fact that one side of the optimal rectangle is
+/// collinear with a hull edge.
+///
+/// # Arguments
+///
+/// * `points` - The (x, y) points, e.g. a contour from [`find_contours`].
+///
+/// # Returns
+///
+/// The minimum area rotated rectangle. For less than three non-collinear points the
+/// rectangle has zero height.
+///
+/// # Example
+///
+/// ```
+/// use kornia_imgproc::contours::min_area_rect;
+///
+/// let points = [(0.0, 0.0), (4.0, 0.0), (4.0, 2.0), (0.0, 2.0), (1.0, 1.0)];
+/// let rect = min_area_rect(&points);
+/// assert_eq!(rect.center, (2.0, 1.0));
+/// assert_eq!(rect.size, (4.0, 2.0));
+/// assert_eq!(rect.angle, 0.0);
+/// ```
+pub fn min_area_rect(points: &[(f32, f32)]) -> RotatedRect {
+    let hull = convex_hull(points);
+    let n = hull.len();
+
+    let dot = |a: (f32, f32), b: (f32, f32)| a.0 * b.0 + a.1 * b.1;
+    let sub = |a: (f32, f32), b: (f32, f32)| (a.0 - b.0, a.1 - b.1);
+
+    match n {
+        0 => {
+            return RotatedRect {
+                center: (0.0, 0.0),
+                size: (0.0, 0.0),
+                angle: 0.0,
+            }
+        }
+        1 => {
+            return RotatedRect {
+                center: hull[0],
+                size: (0.0, 0.0),
+                angle: 0.0,
+            }
+        }
+        _ => {}
+    }
+
+    let mut best_area = f32::INFINITY;
+    let mut rect = RotatedRect {
+        center: hull[0],
+        size: (0.0, 0.0),
+        angle: 0.0,
+    };
+
+    // indices of the points with max projection along the edge, max distance from the
+    // edge and min projection along the edge
+    let (mut right, mut top, mut left) = (0, 0, 0);
+
+    for i in 0..n {
+        let origin = hull[i];
+        let edge = sub(hull[(i + 1) % n], origin);
+        let norm = dot(edge, edge).sqrt();
+        let u = (edge.0 / norm, edge.1 / norm);
+        let v = (-u.1, u.0);
+
+        let proj_u = |k: usize| dot(sub(hull[k % n], origin), u);
+        let proj_v = |k: usize| dot(sub(hull[k % n], origin), v);
+
+        if i == 0 {
+            right = i;
+        }
+        while proj_u(right + 1) > proj_u(right) {
+            right = (right + 1) % n;
+        }
+        if i == 0 {
+            top = right;
+        }
+        while proj_v(top + 1) > proj_v(top) {
+            top = (top + 1) % n;
+        }
+        if i == 0 {
+            left = top;
+        }
+        while proj_u(left + 1) < proj_u(left) {
+            left = (left + 1) % n;
+        }
+
+        let (max_u, min_u, max_v) = (proj_u(right), proj_u(left), proj_v(top));
+        let area = (max_u - min_u) * max_v;
+
+        if area < best_area {
+            let mid_u = 0.5 * (max_u + min_u);
+            let mid_v = 0.5 * max_v;
+            best_area = area;
+            rect = RotatedRect {
+                center: (
+                    origin.0 + u.0 * mid_u + v.0 * mid_v,
+                    origin.1 + u.1 * mid_u + v.1 * mid_v,
+                ),
+                size: (max_u - min_u, max_v),
+                angle: u.1.atan2(u.0),
+            };
+        }
+    }
+
+    // normalize the angle to [0, pi/2) swapping the sides when rotating by 90 degrees
+    while rect.angle < 0.0 {
+        rect.angle += std::f32::consts::FRAC_PI_2;
+        rect.size = (rect.size.1, rect.size.0);
+    }
+    while rect.angle >= std::f32::consts::FRAC_PI_2 {
+        rect.angle -= std::f32::consts::FRAC_PI_2;
+        rect.size = (rect.size.1, rect.size.0);
+    }
+
+    rect
+}
+
+/// Mark the points of the open curve to keep, assuming both endpoints are kept.
+fn douglas_peucker(points: &[(f32, f32)], epsilon: f32, keep: &mut [bool]) {
+    // use an explicit stack of ranges to avoid deep recursion on long contours
+    let mut stack = vec![(0, points.len() - 1)];
+    while let Some((first, last)) = stack.pop() {
+        if last <= first + 1 {
+            continue;
+        }
+
+        let (start, end) = (points[first], points[last]);
+        let (dx, dy) = (end.0 - start.0, end.1 - start.1);
+        let norm = (dx * dx + dy * dy).sqrt();
+
+        let (farthest, max_dist) = (first + 1..last)
+            .map(|i| {
+                let (px, py) = (points[i].0 - start.0, points[i].1 - start.1);
+                let dist = if norm > 0.0 {
+                    (px * dy - py * dx).abs() / norm
+                } else {
+                    (px * px + py * py).sqrt()
+                };
+                (i, dist)
+            })
+            .fold((first, 0.0), |acc, x| if x.1 > acc.1 { x } else { acc });
+
+        if max_dist > epsilon {
+            keep[farthest] = true;
+            stack.push((first, farthest));
+            stack.push((farthest, last));
+        }
+    }
+}
+
+/// Get the index of the point farthest from `points[from]`.
+fn farthest_point(points: &[(f32, f32)], from: usize) -> usize {
+    let origin = points[from];
+    points
+        .iter()
+        .enumerate()
+        .map(|(i, &p)| {
+            let (dx, dy) = (p.0 - origin.0, p.1 - origin.1);
+            (i, dx * dx + dy * dy)
+        })
+        .fold((from, 0.0), |acc, x| if x.1 > acc.1 { x } else { acc })
+        .0
+}
+
+fn select_points(points: &[(f32, f32)], keep: &[bool]) -> Vec<(f32, f32)> {
+    points
+        .iter()
+        .zip(keep.iter())
+        .filter_map(|(&p, &k)| k.then_some(p))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kornia_image::{ImageError, ImageSize};
+    use kornia_tensor::CpuAllocator;
+
+    #[test]
+    fn test_find_contours_circle() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 32,
+            height: 32,
+        };
+        let (cx, cy, radius) = (15.0f64, 16.0f64, 10.0f64);
+
+        let data = (0..size.height)
+            .flat_map(|y| {
+                (0..size.width).map(move |x| {
+                    let (dx, dy) = (x as f64 - cx, y as f64 - cy);
+                    if dx * dx + dy * dy <= radius * radius {
+                        255
+                    } else {
+                        0
+                    }
+                })
+            })
+            .collect::<Vec<u8>>();
+        let image = Image::<u8, 1, _>::new(size, data, CpuAllocator)?;
+
+        let contours = find_contours(&image);
+        assert_eq!(contours.len(), 1);
+
+        let contour = &contours[0];
+        let dist = |a: (usize, usize), b: (usize, usize)| {
+            let (dx, dy) = (a.0 as f64 - b.0 as f64, a.1 as f64 - b.1 as f64);
+            (dx * dx + dy * dy).sqrt()
+        };
+
+        // the contour is closed and each point is an 8-neighbour of the next one
+        let mut perimeter = 0.0;
+        for (i, &p) in contour.iter().enumerate() {
+            let d = dist(p, contour[(i + 1) % contour.len()]);
+            assert!(d > 0.0 && d < 1.5, "points {i} are not adjacent");
+            perimeter += d;
+        }
+
+        let expected = 2.0 * std::f64::consts::PI * radius;
+        assert!(
+            (perimeter - expected).abs() / expected < 0.1,
+            "perimeter {perimeter} expected {expected}"
+        );
+
+        // all the points lie on the boundary of the circle
+        for &(x, y) in contour {
+            let r = dist((x, y), (cx as usize, cy as usize));
+            assert!((r - radius).abs() < 1.5);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_find_contours_nested() -> Result<(), ImageError> {
+        // a ring with a single pixel island inside the hole
+        #[rustfmt::skip]
+        let data = vec![
+            0, 0, 0, 0, 0, 0, 0,
+            0, 1, 1, 1, 1, 1, 0,
+            0, 1, 0, 0, 0, 1, 0,
+            0, 1, 0, 1, 0, 1, 0,
+            0, 1, 0, 0, 0, 1, 0,
+            0, 1, 1, 1, 1, 1, 0,
+            0, 0, 0, 0, 0, 0, 0,
+        ];
+        let image = Image::<u8, 1, _>::new([7, 7].into(), data, CpuAllocator)?;
+
+        let contours = find_contours(&image);
+        assert_eq!(contours.len(), 2);
+        assert_eq!(contours[0].len(), 16);
+        assert_eq!(contours[0][0], (1, 1));
+        assert_eq!(contours[1], vec![(3, 3)]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_approx_poly_dp_square() {
+        // noisy square contour of side 10 starting at the middle of the top edge
+        let side = 10;
+        let mut points = Vec::new();
+        for i in 0..side {
+            points.push((i as f32, 0.0));
+        }
+        for i in 0..side {
+            points.push((side as f32, i as f32));
+        }
+        for i in 0..side {
+            points.push(((side - i) as f32, side as f32));
+        }
+        for i in 0..side {
+            points.push((0.0, (side - i) as f32));
+        }
+        let noisy = points
+            .iter()
+            .enumerate()
+            .map(|(i, &(x, y))| {
+                let noise = if i % 2 == 0 { 0.2 } else { -0.2 };
+                let on_corner = (x == 0.0 || x == side as f32) && (y == 0.0 || y == side as f32);
+                if on_corner {
+                    (x, y)
+                } else {
+                    (x + noise, y - noise)
+                }
+            })
+            .collect::<Vec<_>>();
+        let noisy = [&noisy[5..], &noisy[..5]].concat();
+
+        let mut approx = approx_poly_dp(&noisy, 1.0, true);
+        approx.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        assert_eq!(
+            approx,
+            vec![(0.0, 0.0), (0.0, 10.0), (10.0, 0.0), (10.0, 10.0)]
+        );
+
+        // as an open curve the endpoints are kept
+        let approx = approx_poly_dp(&noisy, 1.0, false);
+        assert_eq!(approx.len(), 6);
+        assert_eq!(approx[0], noisy[0]);
+        assert_eq!(approx[5], noisy[noisy.len() - 1]);
+    }
+
+    #[test]
+    fn test_convex_hull() {
+        // square corners with points on the edges and inside
+        let points = [
+            (1.0, 1.0),
+            (0.0, 0.0),
+            (2.0, 0.0),
+            (1.0, 0.0),
+            (2.0, 2.0),
+            (0.0, 2.0),
+            (0.5, 1.5),
+            (0.0, 1.0),
+        ];
+        let hull = convex_hull(&points);
+        assert_eq!(hull, vec![(0.0, 0.0), (2.0, 0.0), (2.0, 2.0), (0.0, 2.0)]);
+    }
+
+    #[test]
+    fn test_min_area_rect_tilted() {
+        let (cx, cy) = (10.0f32, -4.0f32);
+        let (w, h) = (8.0f32, 3.0f32);
+        let angle = 30f32.to_radians();
+        let (cos, sin) = (angle.cos(), angle.sin());
+
+        // sample the border and the interior of the rectangle in its local frame
+        let mut points = Vec::new();
+        for i in 0..=8 {
+            for j in 0..=3 {
+                let (x, y) = (i as f32 - 0.5 * w, j as f32 - 0.5 * h);
+                points.push((cx + x * cos - y * sin, cy + x * sin + y * cos));
+            }
+        }
+
+        let rect = min_area_rect(&points);
+        assert!((rect.center.0 - cx).abs() < 1e-4);
+        assert!((rect.center.1 - cy).abs() < 1e-4);
+        assert!((rect.size.0 - w).abs() < 1e-4);
+        assert!((rect.size.1 - h).abs() < 1e-4);
+        assert!((rect.angle - angle).abs() < 1e-4);
+
+        // rotating by 90 degrees swaps the sides
+        let rotated = points.iter().map(|&(x, y)| (-y, x)).collect::<Vec<_>>();
+        let rect = min_area_rect(&rotated);
+        assert!((rect.size.0 - h).abs() < 1e-4);
+        assert!((rect.size.1 - w).abs() < 1e-4);
+        assert!((rect.angle - angle).abs() < 1e-4);
+    }
+}
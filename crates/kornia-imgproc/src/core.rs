@@ -1,5 +1,6 @@
 // reference: https://www.strchr.com/standard_deviation_in_one_pass
-use kornia_image::{allocator::ImageAllocator, Image, ImageError};
+use kornia_image::{allocator::ImageAllocator, Image, ImageError, ImageSize};
+use kornia_tensor::CpuAllocator;
 use rayon::{
     iter::{IndexedParallelIterator, ParallelIterator},
     slice::{ParallelSlice, ParallelSliceMut},
@@ -269,6 +270,112 @@ pub fn hconcat<const C: usize, A1: ImageAllocator, A2: ImageAllocator>(
     Ok(())
 }
 
+/// Arrange a list of equally sized images into a grid.
+///
+/// The images are placed in row-major order, separated by `gap` pixels filled with the
+/// background color. The last row is left partially filled if the number of images is not
+/// a multiple of `cols`.
+///
+/// # Arguments
+///
+/// * `images` - The images to arrange. All of them must have the same size.
+/// * `cols` - The maximum number of images per row.
+/// * `gap` - The number of pixels between neighbouring images.
+/// * `bg` - The color used to fill the gaps and the empty cells.
+///
+/// # Returns
+///
+/// A new image with the mosaic, or an empty image if no images are given.
+///
+/// # Errors
+///
+/// If `cols` is zero or the images have different sizes, an error is returned.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_image::allocator::CpuAllocator;
+/// use kornia_imgproc::core::mosaic;
+///
+/// let size = ImageSize {
+///     width: 4,
+///     height: 3,
+/// };
+/// let image1 = Image::<u8, 1, _>::from_size_val(size, 1, CpuAllocator).unwrap();
+/// let image2 = Image::<u8, 1, _>::from_size_val(size, 2, CpuAllocator).unwrap();
+/// let image3 = Image::<u8, 1, _>::from_size_val(size, 3, CpuAllocator).unwrap();
+///
+/// let output = mosaic(&[&image1, &image2, &image3], 2, 1, [0]).unwrap();
+/// assert_eq!(output.width(), 9);
+/// assert_eq!(output.height(), 7);
+/// ```
+pub fn mosaic<const C: usize, A: ImageAllocator>(
+    images: &[&Image<u8, C, A>],
+    cols: usize,
+    gap: usize,
+    bg: [u8; C],
+) -> Result<Image<u8, C, CpuAllocator>, ImageError> {
+    if cols == 0 {
+        return Err(ImageError::InvalidGridColumns(cols));
+    }
+
+    let Some(first) = images.first() else {
+        let size = ImageSize {
+            width: 0,
+            height: 0,
+        };
+        return Image::from_size_val(size, 0, CpuAllocator);
+    };
+
+    let tile_size = first.size();
+    for img in images.iter() {
+        if img.size() != tile_size {
+            return Err(ImageError::InvalidImageSize(
+                img.cols(),
+                img.rows(),
+                tile_size.width,
+                tile_size.height,
+            ));
+        }
+    }
+
+    let grid_cols = cols.min(images.len());
+    let grid_rows = images.len().div_ceil(cols);
+
+    let size = ImageSize {
+        width: grid_cols * tile_size.width + (grid_cols - 1) * gap,
+        height: grid_rows * tile_size.height + (grid_rows - 1) * gap,
+    };
+
+    let mut dst = Image::from_size_val(size, 0, CpuAllocator)?;
+    dst.as_slice_mut()
+        .chunks_exact_mut(C)
+        .for_each(|pixel| pixel.copy_from_slice(&bg));
+
+    let dst_row_len = size.width * C;
+    let tile_row_len = tile_size.width * C;
+    if tile_row_len == 0 {
+        // the tiles have no pixels, so the mosaic only has the gaps
+        return Ok(dst);
+    }
+
+    for (i, img) in images.iter().enumerate() {
+        let x = (i % cols) * (tile_size.width + gap);
+        let y = (i / cols) * (tile_size.height + gap);
+
+        dst.as_slice_mut()
+            .par_chunks_exact_mut(dst_row_len)
+            .skip(y)
+            .zip(img.as_slice().par_chunks_exact(tile_row_len))
+            .for_each(|(dst_row, src_row)| {
+                dst_row[x * C..x * C + tile_row_len].copy_from_slice(src_row);
+            });
+    }
+
+    Ok(dst)
+}
+
 #[cfg(test)]
 mod tests {
     use kornia_image::{Image, ImageError, ImageSize};
@@ -387,4 +494,50 @@ mod tests {
         assert_eq!(output.as_slice(), expected);
         Ok(())
     }
+
+    #[test]
+    fn test_mosaic() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 10,
+            height: 10,
+        };
+        let images = (1..=4)
+            .map(|i| Image::<u8, 3, _>::from_size_val(size, 10 * i, CpuAllocator))
+            .collect::<Result<Vec<_>, _>>()?;
+        let images = images.iter().collect::<Vec<_>>();
+
+        let output = super::mosaic(&images, 2, 2, [255, 0, 128])?;
+        assert_eq!(output.width(), 22);
+        assert_eq!(output.height(), 22);
+
+        // each tile is placed in row-major order
+        for (i, (x0, y0)) in [(0, 0), (12, 0), (0, 12), (12, 12)].into_iter().enumerate() {
+            let val = 10 * (i as u8 + 1);
+            for (x, y) in [(x0, y0), (x0 + 9, y0), (x0, y0 + 9), (x0 + 9, y0 + 9)] {
+                assert_eq!(output.get_pixel(x, y, 0)?, &val);
+                assert_eq!(output.get_pixel(x, y, 2)?, &val);
+            }
+        }
+
+        // the gaps have the background color
+        for (x, y) in [(10, 0), (11, 21), (0, 10), (21, 11)] {
+            assert_eq!(output.get_pixel(x, y, 0)?, &255);
+            assert_eq!(output.get_pixel(x, y, 1)?, &0);
+            assert_eq!(output.get_pixel(x, y, 2)?, &128);
+        }
+
+        // different tile sizes are rejected
+        let small = Image::<u8, 3, _>::from_size_val([5, 5].into(), 0, CpuAllocator)?;
+        assert!(super::mosaic(&[images[0], &small], 2, 2, [0, 0, 0]).is_err());
+
+        // tiles without columns only leave the gaps
+        let empty = Image::<u8, 3, _>::from_size_val([0, 4].into(), 0, CpuAllocator)?;
+        let output = super::mosaic(&[&empty, &empty, &empty], 2, 1, [7, 7, 7])?;
+        assert_eq!(output.size(), [1, 9].into());
+        assert_eq!(output.as_slice(), &[7; 27]);
+        let output = super::mosaic(&[&empty], 2, 1, [7, 7, 7])?;
+        assert_eq!(output.size(), [0, 4].into());
+
+        Ok(())
+    }
 }
@@ -179,9 +179,145 @@ pub fn draw_filled_polygon<const C: usize, A: ImageAllocator>(
     draw_polygon(img, points, line_color, thickness);
 }
 
+/// Draws a text label on an image inplace.
+///
+/// The text is rendered with a built-in 5x7 bitmap font covering the digits, the letters and
+/// the common punctuation. Lowercase letters are drawn as uppercase and unsupported
+/// characters as `?`. The parts of the text outside of the image are clipped.
+///
+/// # Arguments
+///
+/// * `img` - The image to draw on.
+/// * `text` - The text to draw.
+/// * `origin` - The top-left corner of the text as a tuple of (x, y).
+/// * `scale` - The size of a font pixel in image pixels, e.g. 2.0 gives 10x14 characters.
+/// * `color` - The color of the text as an array of `C` elements.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_image::allocator::CpuAllocator;
+/// use kornia_imgproc::draw::draw_text;
+///
+/// let mut img = Image::<u8, 3, _>::from_size_val(
+///     ImageSize { width: 64, height: 16 },
+///     0,
+///     CpuAllocator,
+/// ).unwrap();
+///
+/// draw_text(&mut img, "person", (2, 2), 1.0, [0, 255, 0]);
+/// ```
+pub fn draw_text<const C: usize, A: ImageAllocator>(
+    img: &mut Image<u8, C, A>,
+    text: &str,
+    origin: (i64, i64),
+    scale: f32,
+    color: [u8; C],
+) {
+    if scale <= 0.0 {
+        return;
+    }
+
+    let (cols, rows) = (img.cols() as i64, img.rows() as i64);
+    let glyph_width = (GLYPH_WIDTH as f32 * scale).round() as i64;
+    let glyph_height = (GLYPH_HEIGHT as f32 * scale).round() as i64;
+    let advance = ((GLYPH_WIDTH + 1) as f32 * scale).round() as i64;
+
+    let (x0, y0) = origin;
+    for (i, c) in text.chars().enumerate() {
+        let gx0 = x0 + i as i64 * advance;
+        if gx0 >= cols {
+            break;
+        }
+        if gx0 + glyph_width <= 0 {
+            continue;
+        }
+
+        let glyph = glyph(c);
+        for dy in 0..glyph_height {
+            let y = y0 + dy;
+            if y < 0 || y >= rows {
+                continue;
+            }
+            let row = glyph[((dy as f32 / scale) as usize).min(GLYPH_HEIGHT - 1)];
+            for dx in 0..glyph_width {
+                let x = gx0 + dx;
+                let bit = ((dx as f32 / scale) as usize).min(GLYPH_WIDTH - 1);
+                if x < 0 || x >= cols || row & (1 << (GLYPH_WIDTH - 1 - bit)) == 0 {
+                    continue;
+                }
+                let base = ((y * cols + x) * C as i64) as usize;
+                img.as_slice_mut()[base..base + C].copy_from_slice(&color);
+            }
+        }
+    }
+}
+
+const GLYPH_WIDTH: usize = 5;
+const GLYPH_HEIGHT: usize = 7;
+
+/// Returns the rows of the 5x7 glyph of a character, the leftmost pixel being the highest bit.
+#[rustfmt::skip]
+fn glyph(c: char) -> [u8; GLYPH_HEIGHT] {
+    match c.to_ascii_uppercase() {
+        ' ' => [0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b00000],
+        '0' => [0b01110, 0b10001, 0b10011, 0b10101, 0b11001, 0b10001, 0b01110],
+        '1' => [0b00100, 0b01100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110],
+        '2' => [0b01110, 0b10001, 0b00001, 0b00010, 0b00100, 0b01000, 0b11111],
+        '3' => [0b11111, 0b00010, 0b00100, 0b00010, 0b00001, 0b10001, 0b01110],
+        '4' => [0b00010, 0b00110, 0b01010, 0b10010, 0b11111, 0b00010, 0b00010],
+        '5' => [0b11111, 0b10000, 0b11110, 0b00001, 0b00001, 0b10001, 0b01110],
+        '6' => [0b00110, 0b01000, 0b10000, 0b11110, 0b10001, 0b10001, 0b01110],
+        '7' => [0b11111, 0b00001, 0b00010, 0b00100, 0b01000, 0b01000, 0b01000],
+        '8' => [0b01110, 0b10001, 0b10001, 0b01110, 0b10001, 0b10001, 0b01110],
+        '9' => [0b01110, 0b10001, 0b10001, 0b01111, 0b00001, 0b00010, 0b01100],
+        'A' => [0b01110, 0b10001, 0b10001, 0b11111, 0b10001, 0b10001, 0b10001],
+        'B' => [0b11110, 0b10001, 0b10001, 0b11110, 0b10001, 0b10001, 0b11110],
+        'C' => [0b01110, 0b10001, 0b10000, 0b10000, 0b10000, 0b10001, 0b01110],
+        'D' => [0b11100, 0b10010, 0b10001, 0b10001, 0b10001, 0b10010, 0b11100],
+        'E' => [0b11111, 0b10000, 0b10000, 0b11110, 0b10000, 0b10000, 0b11111],
+        'F' => [0b11111, 0b10000, 0b10000, 0b11110, 0b10000, 0b10000, 0b10000],
+        'G' => [0b01110, 0b10001, 0b10000, 0b10111, 0b10001, 0b10001, 0b01111],
+        'H' => [0b10001, 0b10001, 0b10001, 0b11111, 0b10001, 0b10001, 0b10001],
+        'I' => [0b01110, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b01110],
+        'J' => [0b00111, 0b00010, 0b00010, 0b00010, 0b00010, 0b10010, 0b01100],
+        'K' => [0b10001, 0b10010, 0b10100, 0b11000, 0b10100, 0b10010, 0b10001],
+        'L' => [0b10000, 0b10000, 0b10000, 0b10000, 0b10000, 0b10000, 0b11111],
+        'M' => [0b10001, 0b11011, 0b10101, 0b10101, 0b10001, 0b10001, 0b10001],
+        'N' => [0b10001, 0b10001, 0b11001, 0b10101, 0b10011, 0b10001, 0b10001],
+        'O' => [0b01110, 0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01110],
+        'P' => [0b11110, 0b10001, 0b10001, 0b11110, 0b10000, 0b10000, 0b10000],
+        'Q' => [0b01110, 0b10001, 0b10001, 0b10001, 0b10101, 0b10010, 0b01101],
+        'R' => [0b11110, 0b10001, 0b10001, 0b11110, 0b10100, 0b10010, 0b10001],
+        'S' => [0b01111, 0b10000, 0b10000, 0b01110, 0b00001, 0b00001, 0b11110],
+        'T' => [0b11111, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b00100],
+        'U' => [0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01110],
+        'V' => [0b10001, 0b10001, 0b10001, 0b10001, 0b10001, 0b01010, 0b00100],
+        'W' => [0b10001, 0b10001, 0b10001, 0b10101, 0b10101, 0b10101, 0b01010],
+        'X' => [0b10001, 0b10001, 0b01010, 0b00100, 0b01010, 0b10001, 0b10001],
+        'Y' => [0b10001, 0b10001, 0b10001, 0b01010, 0b00100, 0b00100, 0b00100],
+        'Z' => [0b11111, 0b00001, 0b00010, 0b00100, 0b01000, 0b10000, 0b11111],
+        '.' => [0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b01100, 0b01100],
+        ',' => [0b00000, 0b00000, 0b00000, 0b00000, 0b01100, 0b00100, 0b01000],
+        ':' => [0b00000, 0b01100, 0b01100, 0b00000, 0b01100, 0b01100, 0b00000],
+        '-' => [0b00000, 0b00000, 0b00000, 0b11111, 0b00000, 0b00000, 0b00000],
+        '_' => [0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b00000, 0b11111],
+        '+' => [0b00000, 0b00100, 0b00100, 0b11111, 0b00100, 0b00100, 0b00000],
+        '=' => [0b00000, 0b00000, 0b11111, 0b00000, 0b11111, 0b00000, 0b00000],
+        '/' => [0b00000, 0b00001, 0b00010, 0b00100, 0b01000, 0b10000, 0b00000],
+        '%' => [0b11000, 0b11001, 0b00010, 0b00100, 0b01000, 0b10011, 0b00011],
+        '#' => [0b01010, 0b01010, 0b11111, 0b01010, 0b11111, 0b01010, 0b01010],
+        '(' => [0b00010, 0b00100, 0b01000, 0b01000, 0b01000, 0b00100, 0b00010],
+        ')' => [0b01000, 0b00100, 0b00010, 0b00010, 0b00010, 0b00100, 0b01000],
+        '!' => [0b00100, 0b00100, 0b00100, 0b00100, 0b00100, 0b00000, 0b00100],
+        _ => [0b01110, 0b10001, 0b00001, 0b00010, 0b00100, 0b00000, 0b00100],
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::{draw_filled_polygon, draw_line, draw_polygon};
+    use super::{draw_filled_polygon, draw_line, draw_polygon, draw_text};
     use kornia_image::{Image, ImageError, ImageSize};
     use kornia_tensor::CpuAllocator;
 
@@ -295,4 +431,79 @@ mod tests {
         );
         Ok(())
     }
+
+    #[test]
+    fn test_draw_text() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 40,
+            height: 20,
+        };
+        let mut img = Image::<u8, 3, _>::from_size_val(size, 0, CpuAllocator)?;
+
+        // 10x14 glyphs: "O" spans x in [2, 12) and "K" spans x in [14, 24)
+        draw_text(&mut img, "OK", (2, 3), 2.0, [255, 0, 0]);
+
+        let is_set = |x: usize, y: usize| img.as_slice()[(y * size.width + x) * 3] == 255;
+        for y in 0..size.height {
+            for x in 0..size.width {
+                if is_set(x, y) {
+                    assert!((2..24).contains(&x) && (3..17).contains(&y));
+                    assert!(!(12..14).contains(&x));
+                }
+            }
+        }
+        assert!(img
+            .as_slice()
+            .chunks_exact(3)
+            .all(|p| p[1] == 0 && p[2] == 0));
+
+        // the top row of "O" skips the corners
+        assert!(!is_set(2, 3) && is_set(4, 3) && is_set(9, 4) && !is_set(10, 3));
+        // the left stroke of "K"
+        assert!((3..17).all(|y| is_set(14, y)));
+        // the inside of "O"
+        assert!(!is_set(7, 10));
+
+        // the text is clipped at the borders
+        let mut clipped = Image::<u8, 3, _>::from_size_val(size, 0, CpuAllocator)?;
+        draw_text(&mut clipped, "OK", (-5, -4), 2.0, [255, 0, 0]);
+        draw_text(&mut clipped, "OK", (30, 12), 2.0, [255, 0, 0]);
+
+        let is_set = |x: usize, y: usize| clipped.as_slice()[(y * size.width + x) * 3] == 255;
+        // the middle row of the first "O" keeps its right stroke only
+        assert!(!is_set(0, 0) && !is_set(2, 0) && is_set(3, 0) && is_set(4, 0));
+        // the left column crosses the bottom row of the first "O" only
+        assert!((0..size.height).all(|y| is_set(0, y) == (8..10).contains(&y)));
+        // the second "O" is cut at the bottom-right corner and its "K" is not drawn
+        assert!(is_set(39, 19) && is_set(31, 19) && !is_set(34, 19) && !is_set(39, 12));
+
+        // the clipped pixels are the ones of the text drawn unclipped on a larger image
+        let pad = 20;
+        let padded_size = ImageSize {
+            width: size.width + 2 * pad,
+            height: size.height + 2 * pad,
+        };
+        let mut padded = Image::<u8, 3, _>::from_size_val(padded_size, 0, CpuAllocator)?;
+        draw_text(
+            &mut padded,
+            "OK",
+            (-5 + pad as i64, -4 + pad as i64),
+            2.0,
+            [255, 0, 0],
+        );
+        draw_text(
+            &mut padded,
+            "OK",
+            (30 + pad as i64, 12 + pad as i64),
+            2.0,
+            [255, 0, 0],
+        );
+        for y in 0..size.height {
+            let start = ((y + pad) * padded_size.width + pad) * 3;
+            let row = &clipped.as_slice()[y * size.width * 3..(y + 1) * size.width * 3];
+            assert_eq!(row, &padded.as_slice()[start..start + size.width * 3]);
+        }
+
+        Ok(())
+    }
 }
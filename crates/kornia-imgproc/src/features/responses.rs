@@ -1,4 +1,4 @@
-use crate::filter::gaussian_blur;
+use crate::filter::{gaussian_blur, gradients_xy};
 use kornia_image::{allocator::ImageAllocator, Image, ImageError, ImageSize};
 use kornia_tensor::CpuAllocator;
 use rayon::prelude::*;
@@ -13,6 +13,96 @@ pub enum GradsMode {
     Diff,
 }
 
+/// How a feature response handles the channels of a multi-channel image.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChannelMode {
+    /// Compute the response on the luma of an RGB image, with the weights of
+    /// [`crate::color::gray_from_rgb`]. Requires 3 channels.
+    Luma,
+    /// Compute the response on the mean of the channels.
+    Mean,
+    /// Compute the response on a single channel.
+    Channel(usize),
+    /// Compute the response on each channel and keep the maximum of each pixel.
+    MaxResponse,
+}
+
+/// Reduce a multi-channel image to the single channel a response is computed on.
+///
+/// PRECONDITION: `mode` is not [`ChannelMode::MaxResponse`].
+fn reduce_channels<const C: usize, A: ImageAllocator>(
+    src: &Image<f32, C, A>,
+    mode: ChannelMode,
+) -> Result<Image<f32, 1, CpuAllocator>, ImageError> {
+    let data = match mode {
+        ChannelMode::Luma => {
+            if C != 3 {
+                return Err(ImageError::UnsupportedChannelCount(C));
+            }
+            let (rw, gw, bw) = (0.299f32, 0.587f32, 0.114f32);
+            src.as_slice()
+                .chunks_exact(C)
+                .map(|p| rw * p[0] + gw * p[1] + bw * p[2])
+                .collect()
+        }
+        ChannelMode::Mean => src
+            .as_slice()
+            .chunks_exact(C)
+            .map(|p| p.iter().sum::<f32>() / C as f32)
+            .collect(),
+        ChannelMode::Channel(ch) => {
+            if ch >= C {
+                return Err(ImageError::ChannelIndexOutOfBounds(ch, C));
+            }
+            src.as_slice().iter().skip(ch).step_by(C).copied().collect()
+        }
+        ChannelMode::MaxResponse => unreachable!("the max response is not a single channel"),
+    };
+    Image::new(src.size(), data, CpuAllocator)
+}
+
+/// Compute a single-channel response on a multi-channel image as configured by `mode`.
+fn multichannel_response<const C: usize, A1: ImageAllocator, A2: ImageAllocator>(
+    src: &Image<f32, C, A1>,
+    dst: &mut Image<f32, 1, A2>,
+    mode: ChannelMode,
+    mut response: impl FnMut(
+        &Image<f32, 1, CpuAllocator>,
+        &mut Image<f32, 1, CpuAllocator>,
+    ) -> Result<(), ImageError>,
+) -> Result<(), ImageError> {
+    if src.size() != dst.size() {
+        return Err(ImageError::InvalidImageSize(
+            src.cols(),
+            src.rows(),
+            dst.cols(),
+            dst.rows(),
+        ));
+    }
+
+    let mut channel_dst = Image::<f32, 1, _>::from_size_val(src.size(), 0.0, CpuAllocator)?;
+
+    if mode != ChannelMode::MaxResponse {
+        response(&reduce_channels(src, mode)?, &mut channel_dst)?;
+        dst.as_slice_mut().copy_from_slice(channel_dst.as_slice());
+        return Ok(());
+    }
+
+    dst.as_slice_mut().fill(f32::NEG_INFINITY);
+    for ch in 0..C {
+        response(
+            &reduce_channels(src, ChannelMode::Channel(ch))?,
+            &mut channel_dst,
+        )?;
+        dst.as_slice_mut()
+            .iter_mut()
+            .zip(channel_dst.as_slice())
+            .for_each(|(d, &r)| *d = d.max(r));
+    }
+
+    Ok(())
+}
+
 fn _get_kernel_size(sigma: f32) -> usize {
     let mut ksize = (2.0 * 4.0 * sigma + 1.0) as usize;
 
@@ -284,6 +374,76 @@ impl HarrisResponse {
 
         Ok(())
     }
+
+    /// Computes the harris response of a multi-channel image.
+    ///
+    /// The channels are handled as set by `mode`: either reduced to a single channel before
+    /// computing the response, or with the response computed on each channel and the maximum
+    /// kept for each pixel.
+    ///
+    /// Args:
+    ///     src: The source image with shape (H, W, C).
+    ///     dst: The destination image with shape (H, W).
+    ///     mode: How to handle the channels of the source image.
+    pub fn compute_multichannel<const C: usize, A1: ImageAllocator, A2: ImageAllocator>(
+        &mut self,
+        src: &Image<f32, C, A1>,
+        dst: &mut Image<f32, 1, A2>,
+        mode: ChannelMode,
+    ) -> Result<(), ImageError> {
+        multichannel_response(src, dst, mode, |gray, response| {
+            self.compute(gray, response)
+        })
+    }
+}
+
+/// Compute the structure tensor of an image.
+///
+/// The structure tensor is built from the products of the first order derivatives,
+/// computed with the normalized 3x3 Sobel operator used by [`HarrisResponse`], and
+/// weighted with a Gaussian window:
+///
+/// M = G_sigma * [[I_x^2, I_x I_y], [I_x I_y, I_y^2]]
+///
+/// The Gaussian kernel size is derived from `sigma` in the same way as in [`dog_response`].
+///
+/// Args:
+///     src: The source image with shape (H, W).
+///     dst: The destination image with shape (H, W, 3) holding the (Ixx, Ixy, Iyy) components.
+///     sigma: The sigma of the Gaussian window.
+pub fn structure_tensor<A1: ImageAllocator, A2: ImageAllocator>(
+    src: &Image<f32, 1, A1>,
+    dst: &mut Image<f32, 3, A2>,
+    sigma: f32,
+) -> Result<(), ImageError> {
+    if src.size() != dst.size() {
+        return Err(ImageError::InvalidImageSize(
+            src.cols(),
+            src.rows(),
+            dst.cols(),
+            dst.rows(),
+        ));
+    }
+
+    let mut grads = Image::<f32, 2, _>::from_size_val(src.size(), 0.0, CpuAllocator)?;
+    gradients_xy(src, &mut grads)?;
+
+    let mut products = Image::<f32, 3, _>::from_size_val(src.size(), 0.0, CpuAllocator)?;
+    products
+        .as_slice_mut()
+        .par_chunks_exact_mut(3)
+        .zip(grads.as_slice().par_chunks_exact(2))
+        .for_each(|(m, g)| {
+            let (dx, dy) = (g[0], g[1]);
+            m[0] = dx * dx;
+            m[1] = dx * dy;
+            m[2] = dy * dy;
+        });
+
+    let ksize = _get_kernel_size(sigma);
+    gaussian_blur(&products, dst, (ksize, ksize), (sigma, sigma))?;
+
+    Ok(())
 }
 
 /// Compute the DoG response of an image.
@@ -332,6 +492,152 @@ pub fn dog_response<A1: ImageAllocator, A2: ImageAllocator>(
     Ok(())
 }
 
+/// Compute the DoG response of a multi-channel image.
+///
+/// The channels are handled as set by `mode`, see [`HarrisResponse::compute_multichannel`].
+///
+/// Args:
+///     src: The source image with shape (H, W, C).
+///     dst: The destination image with shape (H, W).
+///     sigma1: The sigma of the first Gaussian kernel.
+///     sigma2: The sigma of the second Gaussian kernel.
+///     mode: How to handle the channels of the source image.
+pub fn dog_response_multichannel<const C: usize, A1: ImageAllocator, A2: ImageAllocator>(
+    src: &Image<f32, C, A1>,
+    dst: &mut Image<f32, 1, A2>,
+    sigma1: f32,
+    sigma2: f32,
+    mode: ChannelMode,
+) -> Result<(), ImageError> {
+    multichannel_response(src, dst, mode, |gray, response| {
+        dog_response(gray, response, sigma1, sigma2)
+    })
+}
+
+/// Compute the scale-normalized Laplacian of Gaussian (LoG) response of an image.
+///
+/// The image is smoothed with a Gaussian kernel and the Laplacian L_{xx} + L_{yy} is
+/// approximated with the 5-point finite difference stencil, replicating the border pixels.
+/// The result is multiplied by sigma^2 so that the responses are comparable across scales.
+/// Bright blobs produce negative responses and dark blobs positive ones.
+///
+/// Args:
+///     src: The source image with shape (H, W).
+///     dst: The destination image with shape (H, W).
+///     sigma: The sigma of the Gaussian kernel.
+pub fn log_response<A1: ImageAllocator, A2: ImageAllocator>(
+    src: &Image<f32, 1, A1>,
+    dst: &mut Image<f32, 1, A2>,
+    sigma: f32,
+) -> Result<(), ImageError> {
+    if src.size() != dst.size() {
+        return Err(ImageError::InvalidImageSize(
+            src.cols(),
+            src.rows(),
+            dst.cols(),
+            dst.rows(),
+        ));
+    }
+
+    if src.cols() == 0 {
+        return Ok(());
+    }
+
+    let mut gauss = Image::<f32, 1, _>::from_size_val(src.size(), 0.0, CpuAllocator)?;
+    let ksize = _get_kernel_size(sigma);
+    gaussian_blur(src, &mut gauss, (ksize, ksize), (sigma, sigma))?;
+
+    let (rows, cols) = (src.rows(), src.cols());
+    let gauss_data = gauss.as_slice();
+    let scale = sigma * sigma;
+
+    dst.as_slice_mut()
+        .par_chunks_exact_mut(cols)
+        .enumerate()
+        .for_each(|(r, dst_row)| {
+            let row = &gauss_data[r * cols..(r + 1) * cols];
+            let prev_row = &gauss_data[r.saturating_sub(1) * cols..][..cols];
+            let next_row = &gauss_data[(r + 1).min(rows - 1) * cols..][..cols];
+
+            dst_row.iter_mut().enumerate().for_each(|(c, dst_pixel)| {
+                let center = row[c];
+                let left = row[c.saturating_sub(1)];
+                let right = row[(c + 1).min(cols - 1)];
+                let laplacian = left + right + prev_row[c] + next_row[c] - 4.0 * center;
+                *dst_pixel = scale * laplacian;
+            });
+        });
+
+    Ok(())
+}
+
+/// Detect blobs as the extrema of the scale-normalized LoG response across scales.
+///
+/// The [`log_response`] is computed for every sigma and a blob is reported where the
+/// absolute response is above the threshold and not smaller than any of its 26 neighbours
+/// in the 3x3 windows of the same and the adjacent scales. The first and last scales are
+/// only compared with the scales that exist.
+///
+/// Args:
+///     src: The source image with shape (H, W).
+///     sigmas: The scales to evaluate in increasing order.
+///     threshold: The minimum absolute response of a blob.
+///
+/// Returns:
+///     The blobs as (x, y, sigma, response) sorted by decreasing absolute response. The
+///     radius of a blob is approximately sqrt(2) * sigma.
+pub fn detect_blobs<A: ImageAllocator>(
+    src: &Image<f32, 1, A>,
+    sigmas: &[f32],
+    threshold: f32,
+) -> Result<Vec<(usize, usize, f32, f32)>, ImageError> {
+    let responses = sigmas
+        .iter()
+        .map(|&sigma| {
+            let mut response = Image::<f32, 1, _>::from_size_val(src.size(), 0.0, CpuAllocator)?;
+            log_response(src, &mut response, sigma)?;
+            Ok(response)
+        })
+        .collect::<Result<Vec<_>, ImageError>>()?;
+
+    let (rows, cols) = (src.rows() as isize, src.cols() as isize);
+
+    let mut blobs = (0..responses.len())
+        .into_par_iter()
+        .flat_map_iter(|s| {
+            let responses = &responses;
+            let scales = s.saturating_sub(1)..(s + 2).min(responses.len());
+            (0..rows).flat_map(move |y| {
+                let scales = scales.clone();
+                (0..cols).filter_map(move |x| {
+                    let value = responses[s].as_slice()[(y * cols + x) as usize];
+                    if value.abs() <= threshold {
+                        return None;
+                    }
+
+                    let is_extremum = scales.clone().all(|sn| {
+                        let data = responses[sn].as_slice();
+                        (y - 1..=y + 1).all(|yn| {
+                            (x - 1..=x + 1).all(|xn| {
+                                if yn < 0 || yn >= rows || xn < 0 || xn >= cols {
+                                    return true;
+                                }
+                                data[(yn * cols + xn) as usize].abs() <= value.abs()
+                            })
+                        })
+                    });
+
+                    is_extremum.then_some((x as usize, y as usize, sigmas[s], value))
+                })
+            })
+        })
+        .collect::<Vec<_>>();
+
+    blobs.sort_by(|a, b| b.3.abs().total_cmp(&a.3.abs()));
+
+    Ok(blobs)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -529,4 +835,144 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_multichannel_responses() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 9,
+            height: 9,
+        };
+        let mut data = vec![0.0f32; size.width * size.height * 3];
+        for y in 2..7 {
+            for x in 2..7 {
+                let i = (y * size.width + x) * 3;
+                data[i..i + 3].copy_from_slice(&[1.0, 0.5, 0.25]);
+            }
+        }
+        let rgb = Image::<f32, 3, _>::new(size, data, CpuAllocator)?;
+
+        // the luma mode matches the grayscale conversion followed by the response
+        let mut gray = Image::from_size_val(size, 0.0, CpuAllocator)?;
+        crate::color::gray_from_rgb(&rgb, &mut gray)?;
+        let mut expected = Image::from_size_val(size, 0.0, CpuAllocator)?;
+        HarrisResponse::new(size).compute(&gray, &mut expected)?;
+
+        let mut dst = Image::from_size_val(size, 0.0, CpuAllocator)?;
+        HarrisResponse::new(size).compute_multichannel(&rgb, &mut dst, ChannelMode::Luma)?;
+        for (a, b) in dst.as_slice().iter().zip(expected.as_slice()) {
+            assert!((a - b).abs() < 1e-5);
+        }
+
+        // the brightest channel has the strongest response
+        let mut red = Image::from_size_val(size, 0.0, CpuAllocator)?;
+        HarrisResponse::new(size).compute_multichannel(&rgb, &mut red, ChannelMode::Channel(0))?;
+        HarrisResponse::new(size).compute_multichannel(&rgb, &mut dst, ChannelMode::MaxResponse)?;
+        assert_eq!(dst.as_slice(), red.as_slice());
+
+        let mut dog = Image::from_size_val(size, 0.0, CpuAllocator)?;
+        dog_response(&gray, &mut expected, 0.5, 1.0)?;
+        dog_response_multichannel(&rgb, &mut dog, 0.5, 1.0, ChannelMode::Luma)?;
+        for (a, b) in dog.as_slice().iter().zip(expected.as_slice()) {
+            assert!((a - b).abs() < 1e-5);
+        }
+
+        assert!(matches!(
+            dog_response_multichannel(&rgb, &mut dog, 0.5, 1.0, ChannelMode::Channel(3)),
+            Err(ImageError::ChannelIndexOutOfBounds(3, 3))
+        ));
+
+        let rg = Image::<f32, 2, _>::from_size_val(size, 1.0, CpuAllocator)?;
+        assert!(matches!(
+            dog_response_multichannel(&rg, &mut dog, 0.5, 1.0, ChannelMode::Luma),
+            Err(ImageError::UnsupportedChannelCount(2))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_structure_tensor() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 11,
+            height: 11,
+        };
+
+        // flat region
+        let src = Image::from_size_val(size, 0.5, CpuAllocator)?;
+        let mut dst = Image::from_size_val(size, 1.0, CpuAllocator)?;
+        structure_tensor(&src, &mut dst, 1.0)?;
+        assert!(dst.as_slice().iter().all(|v| v.abs() < 1e-6));
+
+        // bright quadrant with a corner at (5, 5)
+        let data = (0..size.height)
+            .flat_map(|r| (0..size.width).map(move |c| if r >= 5 && c >= 5 { 1.0 } else { 0.0 }))
+            .collect::<Vec<f32>>();
+        let src = Image::from_size_slice(size, &data, CpuAllocator)?;
+        structure_tensor(&src, &mut dst, 1.0)?;
+
+        let eigenvalues = |x: usize, y: usize| -> Result<(f32, f32), ImageError> {
+            let ixx = *dst.get_pixel(x, y, 0)?;
+            let ixy = *dst.get_pixel(x, y, 1)?;
+            let iyy = *dst.get_pixel(x, y, 2)?;
+            let half_trace = 0.5 * (ixx + iyy);
+            let disc = (0.25 * (ixx - iyy) * (ixx - iyy) + ixy * ixy).sqrt();
+            Ok((half_trace - disc, half_trace + disc))
+        };
+
+        // both eigenvalues are large on the corner
+        let (l1, l2) = eigenvalues(5, 5)?;
+        assert!(l1 > 0.05, "min eigenvalue at corner: {l1}");
+        assert!(l2 > 0.1, "max eigenvalue at corner: {l2}");
+
+        // only one eigenvalue is large along the vertical edge
+        let (l1, l2) = eigenvalues(5, 8)?;
+        assert!(l1 < 1e-3, "min eigenvalue at edge: {l1}");
+        assert!(l2 > 0.1, "max eigenvalue at edge: {l2}");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_detect_blobs() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 41,
+            height: 41,
+        };
+
+        // bright gaussian blob with sigma 3 centered at (22, 18)
+        let blob_sigma = 3.0f32;
+        let data = (0..size.height)
+            .flat_map(|y| {
+                (0..size.width).map(move |x| {
+                    let (dx, dy) = (x as f32 - 22.0, y as f32 - 18.0);
+                    (-(dx * dx + dy * dy) / (2.0 * blob_sigma * blob_sigma)).exp()
+                })
+            })
+            .collect();
+        let src = Image::<f32, 1, _>::new(size, data, CpuAllocator)?;
+
+        // the scale normalized response peaks at the sigma of the blob
+        let mut dst = Image::from_size_val(size, 0.0, CpuAllocator)?;
+        let mut peaks = Vec::new();
+        for sigma in [1.5, 3.0, 4.5] {
+            log_response(&src, &mut dst, sigma)?;
+            peaks.push(*dst.get_pixel(22, 18, 0)?);
+        }
+        assert!(peaks.iter().all(|&v| v < 0.0));
+        assert!(peaks[1] < peaks[0] && peaks[1] < peaks[2]);
+
+        let sigmas = [1.0, 1.5, 2.0, 2.5, 3.0, 3.5, 4.0, 5.0];
+        let blobs = detect_blobs(&src, &sigmas, 0.1)?;
+        let (x, y, sigma, response) = blobs[0];
+        assert_eq!((x, y), (22, 18));
+        assert!((sigma - blob_sigma).abs() <= 0.5, "sigma {sigma}");
+        assert!(response < 0.0);
+
+        // an image without columns has no response
+        let empty = Image::<f32, 1, _>::from_size_val([0, 4].into(), 0.0, CpuAllocator)?;
+        let mut dst = Image::from_size_val(empty.size(), 0.0, CpuAllocator)?;
+        log_response(&empty, &mut dst, 1.0)?;
+
+        Ok(())
+    }
 }
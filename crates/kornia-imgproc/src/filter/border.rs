@@ -0,0 +1,78 @@
+/// Strategy used to extrapolate the pixels outside of the image borders.
+///
+/// The examples show how a row `abcdefgh` is extended on both sides.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum BorderType {
+    /// The pixels outside of the image are zero: `000000|abcdefgh|0000000`.
+    Zero,
+    /// The border pixel is repeated: `aaaaaa|abcdefgh|hhhhhhh`.
+    Replicate,
+    /// The image is mirrored including the border pixel: `fedcba|abcdefgh|hgfedcb`.
+    Reflect,
+    /// The image is mirrored around the border pixel: `gfedcb|abcdefgh|gfedcba`.
+    #[default]
+    Reflect101,
+}
+
+/// Map a possibly out of bounds coordinate to a valid index in `[0, len)`.
+///
+/// Returns `None` if the coordinate falls outside of the image and the border is [`BorderType::Zero`].
+pub(crate) fn border_index(i: isize, len: usize, border_type: BorderType) -> Option<usize> {
+    let n = len as isize;
+    if (0..n).contains(&i) {
+        return Some(i as usize);
+    }
+
+    let idx = match border_type {
+        BorderType::Zero => return None,
+        BorderType::Replicate => i.clamp(0, n - 1),
+        _ if n == 1 => 0,
+        BorderType::Reflect | BorderType::Reflect101 => {
+            // the shift is 1 when the border pixel is repeated in the reflection
+            let shift = (border_type == BorderType::Reflect) as isize;
+            let mut i = i;
+            while !(0..n).contains(&i) {
+                i = if i < 0 {
+                    -i - shift
+                } else {
+                    2 * n - i - 2 + shift
+                };
+            }
+            i
+        }
+    };
+
+    Some(idx as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_border_index() {
+        let map = |border_type| {
+            (-3..8)
+                .map(|i| {
+                    border_index(i, 5, border_type)
+                        .map(|i| i as isize)
+                        .unwrap_or(-1)
+                })
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            map(BorderType::Zero),
+            [-1, -1, -1, 0, 1, 2, 3, 4, -1, -1, -1]
+        );
+        assert_eq!(
+            map(BorderType::Replicate),
+            [0, 0, 0, 0, 1, 2, 3, 4, 4, 4, 4]
+        );
+        assert_eq!(map(BorderType::Reflect), [2, 1, 0, 0, 1, 2, 3, 4, 4, 3, 2]);
+        assert_eq!(
+            map(BorderType::Reflect101),
+            [3, 2, 1, 0, 1, 2, 3, 4, 3, 2, 1]
+        );
+    }
+}
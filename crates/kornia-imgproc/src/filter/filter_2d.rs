@@ -0,0 +1,166 @@
+use kornia_image::{allocator::ImageAllocator, Image, ImageError};
+use rayon::prelude::*;
+
+use super::{border_index, BorderType, FloatConversion};
+
+/// Apply a 2D filter to an image.
+///
+/// The kernel is correlated with the image, i.e. it is not flipped, and is centered at
+/// `(kernel_x / 2, kernel_y / 2)`. The pixels outside of the image are extrapolated with the
+/// given border type so that the output keeps the size of the input.
+///
+/// # Arguments
+///
+/// * `src` - The source image with shape (H, W, C).
+/// * `dst` - The destination image with shape (H, W, C).
+/// * `kernel` - The kernel weights in row-major order.
+/// * `kernel_size` - The size of the kernel (kernel_x, kernel_y).
+/// * `border_type` - The strategy to extrapolate the pixels outside of the image.
+///
+/// PRECONDITION: `src` and `dst` must have the same shape.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_image::allocator::CpuAllocator;
+/// use kornia_imgproc::filter::{filter_2d, BorderType};
+///
+/// let size = ImageSize { width: 3, height: 3 };
+/// let src = Image::<f32, 1, _>::from_size_val(size, 1.0, CpuAllocator).unwrap();
+/// let mut dst = Image::<f32, 1, _>::from_size_val(size, 0.0, CpuAllocator).unwrap();
+///
+/// filter_2d(&src, &mut dst, &[1.0; 9], (3, 3), BorderType::Zero).unwrap();
+/// assert_eq!(dst.get_pixel(0, 0, 0).unwrap(), &4.0);
+/// assert_eq!(dst.get_pixel(1, 1, 0).unwrap(), &9.0);
+/// ```
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(skip_all, fields(width = src.width(), height = src.height(), channels = C))
+)]
+pub fn filter_2d<T, const C: usize, A1: ImageAllocator, A2: ImageAllocator>(
+    src: &Image<T, C, A1>,
+    dst: &mut Image<T, C, A2>,
+    kernel: &[f32],
+    kernel_size: (usize, usize),
+    border_type: BorderType,
+) -> Result<(), ImageError>
+where
+    T: FloatConversion + Send + Sync,
+{
+    let (kernel_x, kernel_y) = kernel_size;
+    if kernel.is_empty() || kernel.len() != kernel_x * kernel_y {
+        return Err(ImageError::InvalidKernelLength(kernel_x, kernel_y));
+    }
+
+    if src.size() != dst.size() {
+        return Err(ImageError::InvalidImageSize(
+            src.cols(),
+            src.rows(),
+            dst.cols(),
+            dst.rows(),
+        ));
+    }
+
+    let (rows, cols) = (src.rows(), src.cols());
+    if cols == 0 {
+        return Ok(());
+    }
+
+    let (half_x, half_y) = ((kernel_x / 2) as isize, (kernel_y / 2) as isize);
+
+    // precompute the source column of each kernel tap for every output column
+    let col_indices = (0..cols as isize)
+        .flat_map(|c| (0..kernel_x as isize).map(move |kx| c + kx - half_x))
+        .map(|x| border_index(x, cols, border_type))
+        .collect::<Vec<_>>();
+
+    let src_data = src.as_slice();
+
+    dst.as_slice_mut()
+        .par_chunks_exact_mut(cols * C)
+        .enumerate()
+        .for_each(|(r, dst_row)| {
+            dst_row
+                .chunks_exact_mut(C)
+                .enumerate()
+                .for_each(|(c, dst_pixel)| {
+                    let mut acc = [0.0f32; C];
+                    for (ky, kernel_row) in kernel.chunks_exact(kernel_x).enumerate() {
+                        let y = r as isize + ky as isize - half_y;
+                        let Some(y) = border_index(y, rows, border_type) else {
+                            continue;
+                        };
+                        let taps = &col_indices[c * kernel_x..(c + 1) * kernel_x];
+                        for (&k, &x) in kernel_row.iter().zip(taps) {
+                            let Some(x) = x else {
+                                continue;
+                            };
+                            let offset = (y * cols + x) * C;
+                            acc.iter_mut()
+                                .zip(&src_data[offset..offset + C])
+                                .for_each(|(a, v)| *a += v.to_f32() * k);
+                        }
+                    }
+                    dst_pixel
+                        .iter_mut()
+                        .zip(acc)
+                        .for_each(|(d, a)| *d = T::from_f32(a));
+                });
+        });
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kornia_image::ImageSize;
+    use kornia_tensor::CpuAllocator;
+
+    #[test]
+    fn test_filter_2d_border_corners() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 5,
+            height: 5,
+        };
+
+        // pixel (x, y) has value 5 * y + x + 1
+        let data = (1..=25).map(|v| v as f32).collect();
+        let src = Image::<f32, 1, _>::new(size, data, CpuAllocator)?;
+        let mut dst = Image::<f32, 1, _>::from_size_val(size, 0.0, CpuAllocator)?;
+
+        // with a 5x5 box the corners sample two pixels beyond both borders, e.g. the rows
+        // used for the top-left corner are {1, 0, 0, 1, 2} when reflecting and the sum is
+        // 25 * sum(rows) + 5 * sum(cols) + 25 for the multiset of rows and cols
+        let kernel = [1.0; 25];
+        for (border_type, top_left, bottom_right) in [
+            (BorderType::Zero, 63.0, 171.0),
+            (BorderType::Replicate, 115.0, 535.0),
+            (BorderType::Reflect, 145.0, 505.0),
+            (BorderType::Reflect101, 205.0, 445.0),
+        ] {
+            filter_2d(&src, &mut dst, &kernel, (5, 5), border_type)?;
+            assert_eq!(dst.size(), size);
+            assert_eq!(dst.get_pixel(0, 0, 0)?, &top_left, "{border_type:?}");
+            assert_eq!(dst.get_pixel(4, 4, 0)?, &bottom_right, "{border_type:?}");
+            // the center does not touch the borders
+            assert_eq!(dst.get_pixel(2, 2, 0)?, &325.0, "{border_type:?}");
+        }
+
+        // invalid kernel shape
+        assert!(filter_2d(&src, &mut dst, &kernel, (3, 3), BorderType::Zero).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_filter_2d_empty() -> Result<(), ImageError> {
+        for size in [[0, 3], [3, 0], [0, 0]] {
+            let src = Image::<f32, 2, _>::from_size_val(size.into(), 1.0, CpuAllocator)?;
+            let mut dst = Image::<f32, 2, _>::from_size_val(size.into(), 0.0, CpuAllocator)?;
+            filter_2d(&src, &mut dst, &[1.0; 9], (3, 3), BorderType::Reflect)?;
+        }
+        Ok(())
+    }
+}
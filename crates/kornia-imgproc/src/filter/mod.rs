@@ -5,6 +5,15 @@
 /// Filter kernels
 pub mod kernels;
 
+/// Border extrapolation for filters
+mod border;
+pub(crate) use border::border_index;
+pub use border::BorderType;
+
+/// Generic 2D filter operations
+mod filter_2d;
+pub use filter_2d::*;
+
 /// Filter operations
 mod ops;
 pub use ops::*;
@@ -6,6 +6,7 @@ use rayon::{
 };
 
 use super::{fast_horizontal_filter, kernels, separable_filter};
+use crate::integral::{integral_image, integral_rect_sum};
 
 /// Blur an image using a box blur filter
 ///
@@ -16,6 +17,10 @@ use super::{fast_horizontal_filter, kernels, separable_filter};
 /// * `kernel_size` - The size of the kernel (kernel_x, kernel_y).
 ///
 /// PRECONDITION: `src` and `dst` must have the same shape.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(skip_all, fields(width = src.width(), height = src.height(), channels = C))
+)]
 pub fn box_blur<const C: usize, A1: ImageAllocator, A2: ImageAllocator>(
     src: &Image<f32, C, A1>,
     dst: &mut Image<f32, C, A2>,
@@ -27,6 +32,65 @@ pub fn box_blur<const C: usize, A1: ImageAllocator, A2: ImageAllocator>(
     Ok(())
 }
 
+/// Blur an image with a box filter computed from the integral image.
+///
+/// The cost per pixel does not depend on the radius, which makes it faster than [`box_blur`]
+/// for large kernels. At the borders the window is cropped to the image and the sum is
+/// divided by the number of pixels inside it.
+///
+/// # Arguments
+///
+/// * `src` - The source image with shape (H, W, C).
+/// * `dst` - The destination image with shape (H, W, C).
+/// * `radius` - The radius of the square window, which has a side of `2 * radius + 1` pixels.
+///
+/// PRECONDITION: `src` and `dst` must have the same shape.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(skip_all, fields(width = src.width(), height = src.height(), channels = C))
+)]
+pub fn box_blur_integral<const C: usize, A1: ImageAllocator, A2: ImageAllocator>(
+    src: &Image<f32, C, A1>,
+    dst: &mut Image<f32, C, A2>,
+    radius: usize,
+) -> Result<(), ImageError> {
+    if src.size() != dst.size() {
+        return Err(ImageError::InvalidImageSize(
+            src.cols(),
+            src.rows(),
+            dst.cols(),
+            dst.rows(),
+        ));
+    }
+
+    let mut sums = Image::<f64, C, _>::from_size_val(src.size(), 0.0, CpuAllocator)?;
+    integral_image(src, &mut sums)?;
+
+    let (cols, rows) = (src.cols(), src.rows());
+    if cols == 0 {
+        return Ok(());
+    }
+
+    dst.as_slice_mut()
+        .par_chunks_exact_mut(cols * C)
+        .enumerate()
+        .for_each(|(y, dst_row)| {
+            let (y0, y1) = (y.saturating_sub(radius), (y + radius).min(rows - 1));
+            dst_row
+                .chunks_exact_mut(C)
+                .enumerate()
+                .for_each(|(x, dst_pixel)| {
+                    let (x0, x1) = (x.saturating_sub(radius), (x + radius).min(cols - 1));
+                    let count = ((x1 - x0 + 1) * (y1 - y0 + 1)) as f64;
+                    for (ch, d) in dst_pixel.iter_mut().enumerate() {
+                        *d = (integral_rect_sum(&sums, (x0, y0), (x1, y1), ch) / count) as f32;
+                    }
+                });
+        });
+
+    Ok(())
+}
+
 /// Blur an image using a gaussian blur filter
 ///
 /// # Arguments
@@ -44,6 +108,10 @@ pub fn box_blur<const C: usize, A1: ImageAllocator, A2: ImageAllocator>(
 ///
 /// PRECONDITION: `src` and `dst` must have the same shape.
 /// NOTE: This function uses a constant border type.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(skip_all, fields(width = src.width(), height = src.height(), channels = C))
+)]
 pub fn gaussian_blur<const C: usize, A1: ImageAllocator, A2: ImageAllocator>(
     src: &Image<f32, C, A1>,
     dst: &mut Image<f32, C, A2>,
@@ -99,6 +167,10 @@ pub fn gaussian_blur<const C: usize, A1: ImageAllocator, A2: ImageAllocator>(
 /// * `kernel_size` - The size of the kernel (kernel_x, kernel_y).
 ///
 /// PRECONDITION: `src` and `dst` must have the same shape.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(skip_all, fields(width = src.width(), height = src.height(), channels = C))
+)]
 pub fn sobel<const C: usize, A1: ImageAllocator, A2: ImageAllocator>(
     src: &Image<f32, C, A1>,
     dst: &mut Image<f32, C, A2>,
@@ -126,6 +198,118 @@ pub fn sobel<const C: usize, A1: ImageAllocator, A2: ImageAllocator>(
     Ok(())
 }
 
+/// Compute the magnitude of the 3x3 sobel gradient of an 8-bit image with integer arithmetic.
+///
+/// This is a fast path of [`sobel`] for grayscale `u8` images, e.g. for real-time edge
+/// overlays. No float conversion is involved: the image is zero padded as in [`sobel`] and
+/// the magnitude is rounded to the nearest integer and saturated to 255.
+///
+/// # Arguments
+///
+/// * `src` - The source image with shape (H, W, 1).
+/// * `dst` - The destination image with shape (H, W, 1).
+///
+/// PRECONDITION: `src` and `dst` must have the same shape.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_image::allocator::CpuAllocator;
+/// use kornia_imgproc::filter::sobel_magnitude_u8;
+///
+/// let image = Image::<u8, 1, _>::new(
+///     ImageSize { width: 4, height: 3 },
+///     vec![0, 0, 10, 10, 0, 0, 10, 10, 0, 0, 10, 10],
+///     CpuAllocator,
+/// ).unwrap();
+///
+/// let mut edges = Image::<u8, 1, _>::from_size_val(image.size(), 0, CpuAllocator).unwrap();
+///
+/// sobel_magnitude_u8(&image, &mut edges).unwrap();
+/// assert_eq!(&edges.as_slice()[4..8], &[0, 40, 40, 40]);
+/// ```
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(skip_all, fields(width = src.width(), height = src.height()))
+)]
+pub fn sobel_magnitude_u8<A1: ImageAllocator, A2: ImageAllocator>(
+    src: &Image<u8, 1, A1>,
+    dst: &mut Image<u8, 1, A2>,
+) -> Result<(), ImageError> {
+    if src.size() != dst.size() {
+        return Err(ImageError::InvalidImageSize(
+            src.cols(),
+            src.rows(),
+            dst.cols(),
+            dst.rows(),
+        ));
+    }
+
+    let (rows, cols) = (src.rows(), src.cols());
+    if cols == 0 {
+        return Ok(());
+    }
+
+    let src_data = src.as_slice();
+    let row = |r: usize| &src_data[r * cols..(r + 1) * cols];
+    let zeros = vec![0u8; cols];
+
+    dst.as_slice_mut()
+        .par_chunks_exact_mut(cols)
+        .enumerate()
+        .for_each(|(r, dst_row)| {
+            // the rows outside of the image are zero
+            let top = if r > 0 { row(r - 1) } else { &zeros };
+            let mid = row(r);
+            let bottom = if r + 1 < rows { row(r + 1) } else { &zeros };
+
+            let px = |line: &[u8], c: isize| -> i32 {
+                if c >= 0 && (c as usize) < cols {
+                    line[c as usize] as i32
+                } else {
+                    0
+                }
+            };
+
+            for (c, d) in dst_row.iter_mut().enumerate() {
+                let (left, right) = (c as isize - 1, c as isize + 1);
+                let gx = (px(top, right) + 2 * px(mid, right) + px(bottom, right))
+                    - (px(top, left) + 2 * px(mid, left) + px(bottom, left));
+                let gy = (px(bottom, left) + 2 * bottom[c] as i32 + px(bottom, right))
+                    - (px(top, left) + 2 * top[c] as i32 + px(top, right));
+                *d = isqrt_round_u8((gx * gx + gy * gy) as u32);
+            }
+        });
+
+    Ok(())
+}
+
+/// Compute the square root of an integer rounded to the nearest integer and saturated to 255.
+fn isqrt_round_u8(n: u32) -> u8 {
+    // n is an integer, so sqrt(n) rounds up if n > root * (root + 1)
+    if n > 255 * 256 {
+        return 255;
+    }
+
+    // digit by digit square root
+    let (mut rem, mut root, mut bit) = (n, 0u32, 1u32 << 16);
+    while bit > n {
+        bit >>= 2;
+    }
+    while bit != 0 {
+        if rem >= root + bit {
+            rem -= root + bit;
+            root = (root >> 1) + bit;
+        } else {
+            root >>= 1;
+        }
+        bit >>= 2;
+    }
+
+    (root + (n > root * (root + 1)) as u32) as u8
+}
+
 /// Blur an image using a box blur filter multiple times to achieve a near gaussian blur
 ///
 /// # Arguments
@@ -136,6 +320,10 @@ pub fn sobel<const C: usize, A1: ImageAllocator, A2: ImageAllocator>(
 /// * `sigma` - The sigma of the gaussian kernel, xy-ordered.
 ///
 /// PRECONDITION: `src` and `dst` must have the same shape.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(skip_all, fields(width = src.width(), height = src.height(), channels = C))
+)]
 pub fn box_blur_fast<const C: usize, A: ImageAllocator>(
     src: &Image<f32, C, A>,
     dst: &mut Image<f32, C, A>,
@@ -379,6 +567,71 @@ pub fn spatial_gradient_float_parallel<
     Ok(())
 }
 
+/// Compute the first order image derivatives in x and y packed into a two channel image.
+///
+/// The gradients are computed in a single pass with the same normalized 3x3 Sobel operator
+/// and replicated border as [`spatial_gradient_float`], so both channels see exactly the same
+/// neighbourhood at the image borders.
+///
+/// # Arguments
+///
+/// * `src` - The source image with shape (H, W, 1).
+/// * `dst` - The destination image with shape (H, W, 2), where channel 0 holds the
+///   derivative in x and channel 1 the derivative in y.
+///
+/// PRECONDITION: `src` and `dst` must have the same size.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(skip_all, fields(width = src.width(), height = src.height(), channels = 1))
+)]
+pub fn gradients_xy<A1: ImageAllocator, A2: ImageAllocator>(
+    src: &Image<f32, 1, A1>,
+    dst: &mut Image<f32, 2, A2>,
+) -> Result<(), ImageError> {
+    if src.size() != dst.size() {
+        return Err(ImageError::InvalidImageSize(
+            src.cols(),
+            src.rows(),
+            dst.cols(),
+            dst.rows(),
+        ));
+    }
+
+    let (sobel_x, sobel_y) = kernels::normalized_sobel_kernel3();
+    let (rows, cols) = (src.rows(), src.cols());
+    if cols == 0 {
+        return Ok(());
+    }
+
+    let src_data = src.as_slice();
+
+    dst.as_slice_mut()
+        .par_chunks_mut(cols * 2)
+        .enumerate()
+        .for_each(|(r, dst_row)| {
+            dst_row
+                .chunks_exact_mut(2)
+                .enumerate()
+                .for_each(|(c, dst_c)| {
+                    let mut sum_x = 0.0;
+                    let mut sum_y = 0.0;
+                    for dy in 0..3 {
+                        let row = (r + dy).min(rows).max(1) - 1;
+                        for dx in 0..3 {
+                            let col = (c + dx).min(cols).max(1) - 1;
+                            let val = unsafe { src_data.get_unchecked(row * cols + col) };
+                            sum_x += val * sobel_x[dy][dx];
+                            sum_y += val * sobel_y[dy][dx];
+                        }
+                    }
+                    dst_c[0] = sum_x;
+                    dst_c[1] = sum_y;
+                });
+        });
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -585,4 +838,138 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_gradients_xy() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 5,
+            height: 4,
+        };
+
+        let img = Image::<f32, 1, _>::new(
+            size,
+            (0..20).map(|x| ((x * x) % 7) as f32).collect(),
+            CpuAllocator,
+        )?;
+
+        let mut grads = Image::<f32, 2, _>::from_size_val(size, 0.0, CpuAllocator)?;
+        gradients_xy(&img, &mut grads)?;
+
+        let mut dx = Image::<f32, 1, _>::from_size_val(size, 0.0, CpuAllocator)?;
+        let mut dy = Image::<f32, 1, _>::from_size_val(size, 0.0, CpuAllocator)?;
+        spatial_gradient_float(&img, &mut dx, &mut dy)?;
+
+        assert_eq!(grads.channel(0)?.as_slice(), dx.as_slice());
+        assert_eq!(grads.channel(1)?.as_slice(), dy.as_slice());
+
+        let mut wrong = Image::<f32, 2, _>::from_size_val([4, 4].into(), 0.0, CpuAllocator)?;
+        assert!(gradients_xy(&img, &mut wrong).is_err());
+
+        // an image without columns has no gradients
+        let empty = Image::<f32, 1, _>::from_size_val([0, 3].into(), 0.0, CpuAllocator)?;
+        let mut grads = Image::<f32, 2, _>::from_size_val([0, 3].into(), 0.0, CpuAllocator)?;
+        gradients_xy(&empty, &mut grads)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sobel_magnitude_u8() -> Result<(), ImageError> {
+        // a diagonal step edge with a strong contrast
+        let size = ImageSize {
+            width: 9,
+            height: 7,
+        };
+        let data = (0..size.width * size.height)
+            .map(|i| {
+                let (x, y) = (i % size.width, i / size.width);
+                if x + y > 7 {
+                    200
+                } else {
+                    20 + (x * 3) as u8
+                }
+            })
+            .collect();
+        let src = Image::<u8, 1, _>::new(size, data, CpuAllocator)?;
+
+        let mut dst = Image::<u8, 1, _>::from_size_val(size, 0, CpuAllocator)?;
+        sobel_magnitude_u8(&src, &mut dst)?;
+
+        let src_f32 = src.cast::<f32>()?;
+        let mut dst_f32 = Image::<f32, 1, _>::from_size_val(size, 0.0, CpuAllocator)?;
+        sobel(&src_f32, &mut dst_f32, 3)?;
+
+        for (&v, &v_f32) in dst.as_slice().iter().zip(dst_f32.as_slice()) {
+            assert!((v as f32 - v_f32.min(255.0)).abs() <= 1.0);
+        }
+
+        // the edge saturates while the smooth ramp stays low
+        let at = |x: usize, y: usize| dst.as_slice()[y * size.width + x];
+        assert_eq!(at(4, 4), 255);
+        assert_eq!(at(2, 2), 24);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_box_blur_integral() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 9,
+            height: 7,
+        };
+
+        // a constant image is unchanged, including at the borders
+        let img = Image::<f32, 2, _>::from_size_val(size, 5.0, CpuAllocator)?;
+        let mut dst = Image::<f32, 2, _>::from_size_val(size, 0.0, CpuAllocator)?;
+        box_blur_integral(&img, &mut dst, 3)?;
+        assert!(dst.as_slice().iter().all(|&v| (v - 5.0).abs() < 1e-6));
+
+        // a delta spreads into a flat square
+        let mut data = vec![0.0; size.width * size.height];
+        data[3 * size.width + 4] = 9.0;
+        let img = Image::<f32, 1, _>::new(size, data, CpuAllocator)?;
+        let mut dst = Image::<f32, 1, _>::from_size_val(size, 0.0, CpuAllocator)?;
+        box_blur_integral(&img, &mut dst, 1)?;
+        for y in 0..size.height {
+            for x in 0..size.width {
+                let expected = if (3..=5).contains(&x) && (2..=4).contains(&y) {
+                    1.0
+                } else {
+                    0.0
+                };
+                assert!((dst.as_slice()[y * size.width + x] - expected).abs() < 1e-6);
+            }
+        }
+
+        // the window shrinks at the corner
+        let mut data = vec![0.0; size.width * size.height];
+        data[0] = 4.0;
+        let img = Image::<f32, 1, _>::new(size, data, CpuAllocator)?;
+        box_blur_integral(&img, &mut dst, 1)?;
+        assert!((dst.as_slice()[0] - 1.0).abs() < 1e-6);
+        assert!((dst.as_slice()[size.width + 1] - 4.0 / 9.0).abs() < 1e-6);
+
+        // matches the separable box blur away from the borders
+        let img = Image::<f32, 1, _>::new(
+            size,
+            (0..size.width * size.height)
+                .map(|i| ((i * 37) % 11) as f32)
+                .collect(),
+            CpuAllocator,
+        )?;
+        let mut expected = Image::<f32, 1, _>::from_size_val(size, 0.0, CpuAllocator)?;
+        box_blur(&img, &mut expected, (5, 5))?;
+        box_blur_integral(&img, &mut dst, 2)?;
+        for y in 2..size.height - 2 {
+            for x in 2..size.width - 2 {
+                let idx = y * size.width + x;
+                assert!((dst.as_slice()[idx] - expected.as_slice()[idx]).abs() < 1e-4);
+            }
+        }
+
+        let mut wrong = Image::<f32, 1, _>::from_size_val([3, 3].into(), 0.0, CpuAllocator)?;
+        assert!(box_blur_integral(&img, &mut wrong, 1).is_err());
+
+        Ok(())
+    }
 }
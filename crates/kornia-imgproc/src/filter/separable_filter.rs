@@ -47,6 +47,10 @@ impl FloatConversion for u8 {
 /// * `dst` - The destination image with shape (H, W, C).
 /// * `kernel_x` - The horizontal kernel.
 /// * `kernel_y` - The vertical kernel.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(skip_all, fields(width = src.width(), height = src.height(), channels = C))
+)]
 pub fn separable_filter<T, const C: usize, A1: ImageAllocator, A2: ImageAllocator>(
     src: &Image<T, C, A1>,
     dst: &mut Image<T, C, A2>,
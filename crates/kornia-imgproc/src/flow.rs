@@ -0,0 +1,238 @@
+use crate::interpolation::sample_bilinear_rgb8;
+use kornia_image::{allocator::ImageAllocator, Image, ImageError};
+use rayon::prelude::*;
+
+/// Size of the square blocks used to estimate the motion.
+const FLOW_BLOCK_SIZE: usize = 8;
+
+/// Maximum displacement in pixels searched for each block.
+const FLOW_SEARCH_RADIUS: isize = 7;
+
+/// Synthesize an intermediate frame between two frames using motion compensation.
+///
+/// The dense motion from `a` to `b` is estimated with block matching and both frames are
+/// warped towards the intermediate time `t`. The warped frames are blended with weights
+/// `1 - t` and `t`, and pixels whose motion falls outside one of the frames are taken
+/// from the other one to avoid holes.
+///
+/// # Arguments
+///
+/// * `a` - The first frame, at time 0.
+/// * `b` - The second frame, at time 1.
+/// * `dst` - The interpolated frame.
+/// * `t` - The time of the interpolated frame in the range [0, 1].
+///
+/// PRECONDITION: `a`, `b` and `dst` must have the same size.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_image::allocator::CpuAllocator;
+/// use kornia_imgproc::flow::interpolate_frame;
+///
+/// let size = ImageSize { width: 16, height: 16 };
+/// let a = Image::<u8, 3, _>::from_size_val(size, 0, CpuAllocator).unwrap();
+/// let b = Image::<u8, 3, _>::from_size_val(size, 100, CpuAllocator).unwrap();
+///
+/// let mut dst = Image::<u8, 3, _>::from_size_val(size, 0, CpuAllocator).unwrap();
+/// interpolate_frame(&a, &b, &mut dst, 0.5).unwrap();
+/// assert_eq!(dst.get_pixel(0, 0, 0).unwrap(), &50);
+/// ```
+pub fn interpolate_frame<A1: ImageAllocator, A2: ImageAllocator, A3: ImageAllocator>(
+    a: &Image<u8, 3, A1>,
+    b: &Image<u8, 3, A2>,
+    dst: &mut Image<u8, 3, A3>,
+    t: f32,
+) -> Result<(), ImageError> {
+    for img_size in [b.size(), dst.size()] {
+        if a.size() != img_size {
+            return Err(ImageError::InvalidImageSize(
+                a.cols(),
+                a.rows(),
+                img_size.width,
+                img_size.height,
+            ));
+        }
+    }
+
+    let (rows, cols) = (a.rows(), a.cols());
+    if cols == 0 {
+        return Ok(());
+    }
+    let t = t.clamp(0.0, 1.0);
+
+    let flow = block_matching_flow(&to_gray(a), &to_gray(b), rows, cols);
+    let blocks_per_row = cols.div_ceil(FLOW_BLOCK_SIZE);
+
+    let (a_data, b_data) = (a.as_slice(), b.as_slice());
+
+    dst.as_slice_mut()
+        .par_chunks_exact_mut(cols * 3)
+        .enumerate()
+        .for_each(|(y, dst_row)| {
+            dst_row
+                .chunks_exact_mut(3)
+                .enumerate()
+                .for_each(|(x, dst_pixel)| {
+                    let (fx, fy) =
+                        flow[(y / FLOW_BLOCK_SIZE) * blocks_per_row + x / FLOW_BLOCK_SIZE];
+                    let (u, v) = (x as f32, y as f32);
+
+                    // the pixel moves from `p - t * flow` in `a` to `p + (1 - t) * flow` in `b`
+                    let pixel_a = sample_bilinear_rgb8(a_data, rows, cols, u - t * fx, v - t * fy);
+                    let (ub, vb) = (u + (1.0 - t) * fx, v + (1.0 - t) * fy);
+                    let pixel_b = sample_bilinear_rgb8(b_data, rows, cols, ub, vb);
+
+                    let pixel = match (pixel_a, pixel_b) {
+                        (Some(pa), Some(pb)) => [0, 1, 2].map(|c| (1.0 - t) * pa[c] + t * pb[c]),
+                        (Some(p), None) | (None, Some(p)) => p,
+                        (None, None) => {
+                            // fall back to blending the frames without motion compensation
+                            let idx = (y * cols + x) * 3;
+                            let blend = |c: usize| {
+                                (1.0 - t) * a_data[idx + c] as f32 + t * b_data[idx + c] as f32
+                            };
+                            [0, 1, 2].map(blend)
+                        }
+                    };
+
+                    dst_pixel
+                        .iter_mut()
+                        .zip(pixel.iter())
+                        .for_each(|(d, &val)| *d = val.round().clamp(0.0, 255.0) as u8);
+                });
+        });
+
+    Ok(())
+}
+
+/// Convert an RGB image to a grayscale buffer by averaging the channels.
+fn to_gray<A: ImageAllocator>(src: &Image<u8, 3, A>) -> Vec<f32> {
+    src.as_slice()
+        .chunks_exact(3)
+        .map(|p| (p[0] as f32 + p[1] as f32 + p[2] as f32) / 3.0)
+        .collect()
+}
+
+/// Estimate the displacement of each block from `a` to `b` minimizing the mean absolute
+/// difference. Blocks displaced partially outside the frame are compared on their overlap,
+/// as long as it covers at least half of the block. Ties are resolved in favour of the
+/// smallest displacement.
+fn block_matching_flow(a: &[f32], b: &[f32], rows: usize, cols: usize) -> Vec<(f32, f32)> {
+    let blocks_per_row = cols.div_ceil(FLOW_BLOCK_SIZE);
+    let blocks_per_col = rows.div_ceil(FLOW_BLOCK_SIZE);
+
+    // candidate displacements sorted by magnitude
+    let mut candidates = (-FLOW_SEARCH_RADIUS..=FLOW_SEARCH_RADIUS)
+        .flat_map(|dy| (-FLOW_SEARCH_RADIUS..=FLOW_SEARCH_RADIUS).map(move |dx| (dx, dy)))
+        .collect::<Vec<_>>();
+    candidates.sort_by_key(|&(dx, dy)| dx * dx + dy * dy);
+
+    (0..blocks_per_row * blocks_per_col)
+        .into_par_iter()
+        .map(|block| {
+            let x0 = (block % blocks_per_row) * FLOW_BLOCK_SIZE;
+            let y0 = (block / blocks_per_row) * FLOW_BLOCK_SIZE;
+            let x1 = (x0 + FLOW_BLOCK_SIZE).min(cols);
+            let y1 = (y0 + FLOW_BLOCK_SIZE).min(rows);
+
+            let mut best = (0, 0);
+            let mut best_cost = f32::INFINITY;
+
+            for &(dx, dy) in candidates.iter() {
+                // compare only the part of the block that remains inside the second frame
+                let (xs0, xs1) = (
+                    (x0 as isize).max(-dx),
+                    (x1 as isize).min(cols as isize - dx),
+                );
+                let (ys0, ys1) = (
+                    (y0 as isize).max(-dy),
+                    (y1 as isize).min(rows as isize - dy),
+                );
+                if xs1 <= xs0 || ys1 <= ys0 {
+                    continue;
+                }
+                let (xs, ys) = (xs0 as usize..xs1 as usize, ys0 as usize..ys1 as usize);
+
+                let count = xs.len() * ys.len();
+                if 2 * count < (x1 - x0) * (y1 - y0) {
+                    continue;
+                }
+
+                let mut cost = 0.0;
+                for y in ys {
+                    let row_a = &a[y * cols + xs.start..y * cols + xs.end];
+                    let offset_b =
+                        (y as isize + dy) as usize * cols + (xs.start as isize + dx) as usize;
+                    let row_b = &b[offset_b..offset_b + xs.len()];
+                    cost += row_a
+                        .iter()
+                        .zip(row_b.iter())
+                        .map(|(va, vb)| (va - vb).abs())
+                        .sum::<f32>();
+                }
+                let cost = cost / count as f32;
+
+                if cost < best_cost {
+                    best_cost = cost;
+                    best = (dx, dy);
+                }
+            }
+
+            (best.0 as f32, best.1 as f32)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kornia_image::ImageSize;
+    use kornia_tensor::CpuAllocator;
+
+    // textured pattern translated by `shift` pixels along x
+    fn pattern(size: ImageSize, shift: usize) -> Result<Image<u8, 3, CpuAllocator>, ImageError> {
+        let data = (0..size.height)
+            .flat_map(|y| {
+                (0..size.width).flat_map(move |x| {
+                    let x = x + 64 - shift;
+                    let v = ((x * 7 + y * 13) ^ (x * y)) % 251;
+                    [v as u8, (255 - v) as u8, (v / 2) as u8]
+                })
+            })
+            .collect();
+        Image::new(size, data, CpuAllocator)
+    }
+
+    #[test]
+    fn test_interpolate_frame() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 32,
+            height: 24,
+        };
+        let a = pattern(size, 0)?;
+        let b = pattern(size, 4)?;
+
+        let mut dst = Image::<u8, 3, _>::from_size_val(size, 0, CpuAllocator)?;
+
+        interpolate_frame(&a, &b, &mut dst, 0.0)?;
+        assert_eq!(dst.as_slice(), a.as_slice());
+
+        interpolate_frame(&a, &b, &mut dst, 1.0)?;
+        assert_eq!(dst.as_slice(), b.as_slice());
+
+        // the intermediate frame is the pattern translated half way, including the borders
+        // where only one of the frames is visible
+        interpolate_frame(&a, &b, &mut dst, 0.5)?;
+        let expected = pattern(size, 2)?;
+        assert_eq!(dst.as_slice(), expected.as_slice());
+
+        // a frame without columns has nothing to interpolate
+        let empty = Image::<u8, 3, _>::from_size_val([0, 4].into(), 0, CpuAllocator)?;
+        let mut dst = Image::<u8, 3, _>::from_size_val(empty.size(), 0, CpuAllocator)?;
+        interpolate_frame(&empty, &empty, &mut dst, 0.5)?;
+
+        Ok(())
+    }
+}
@@ -87,6 +87,204 @@ pub fn compute_histogram<A: ImageAllocator>(
     Ok(())
 }
 
+/// Match the intensity distribution of an image to a reference image.
+///
+/// Each channel is remapped independently with a look-up table that sends every source
+/// intensity to the smallest reference intensity whose cumulative distribution is not below
+/// the source one. The mapping is monotonic, so the order of the intensities is preserved.
+///
+/// # Arguments
+///
+/// * `src` - The input image to remap.
+/// * `reference` - The image with the target distribution. It can have a different size.
+/// * `dst` - The output image.
+///
+/// PRECONDITION: `src` and `dst` must have the same size.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_image::allocator::CpuAllocator;
+/// use kornia_imgproc::histogram::match_histogram;
+///
+/// let size = ImageSize { width: 2, height: 1 };
+/// let src = Image::<u8, 1, _>::new(size, vec![10, 20], CpuAllocator).unwrap();
+/// let reference = Image::<u8, 1, _>::new(size, vec![100, 200], CpuAllocator).unwrap();
+///
+/// let mut dst = Image::<u8, 1, _>::from_size_val(size, 0, CpuAllocator).unwrap();
+/// match_histogram(&src, &reference, &mut dst).unwrap();
+/// assert_eq!(dst.as_slice(), &[100, 200]);
+/// ```
+pub fn match_histogram<
+    const C: usize,
+    A1: ImageAllocator,
+    A2: ImageAllocator,
+    A3: ImageAllocator,
+>(
+    src: &Image<u8, C, A1>,
+    reference: &Image<u8, C, A2>,
+    dst: &mut Image<u8, C, A3>,
+) -> Result<(), ImageError> {
+    if src.size() != dst.size() {
+        return Err(ImageError::InvalidImageSize(
+            src.cols(),
+            src.rows(),
+            dst.cols(),
+            dst.rows(),
+        ));
+    }
+
+    let src_cdfs = channel_cdfs(src.as_slice(), C);
+    let ref_cdfs = channel_cdfs(reference.as_slice(), C);
+    let (src_total, ref_total) = (src_cdfs[0][255], ref_cdfs[0][255]);
+
+    // compare the normalized cdfs with integer arithmetic: a / src_total >= b / ref_total
+    let luts = (0..C)
+        .map(|ch| {
+            let mut lut = [0u8; 256];
+            let mut target = 0;
+            for (val, &src_count) in src_cdfs[ch].iter().enumerate() {
+                while target < 255 && ref_cdfs[ch][target] * src_total < src_count * ref_total {
+                    target += 1;
+                }
+                lut[val] = target as u8;
+            }
+            lut
+        })
+        .collect::<Vec<_>>();
+
+    dst.as_slice_mut()
+        .par_chunks_exact_mut(C)
+        .zip(src.as_slice().par_chunks_exact(C))
+        .for_each(|(dst_pixel, src_pixel)| {
+            for (ch, (d, &s)) in dst_pixel.iter_mut().zip(src_pixel).enumerate() {
+                *d = luts[ch][s as usize];
+            }
+        });
+
+    Ok(())
+}
+
+/// Stretch the contrast of an image between two percentiles of its histogram.
+///
+/// For each channel, the intensities at the low and high percentiles are mapped to 0 and 255
+/// and the ones in between are scaled linearly. The intensities outside the range are clipped,
+/// so a few outliers do not prevent the stretching. Channels with a single intensity in the
+/// range, e.g. constant images, are copied unchanged.
+///
+/// # Arguments
+///
+/// * `src` - The input image.
+/// * `dst` - The output image.
+/// * `low_percentile` - The percentile mapped to 0, in the range [0, 100].
+/// * `high_percentile` - The percentile mapped to 255, in the range [0, 100].
+///
+/// PRECONDITION: `src` and `dst` must have the same size.
+///
+/// # Errors
+///
+/// Returns an error if the sizes differ or `low_percentile < high_percentile` does not hold.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_image::allocator::CpuAllocator;
+/// use kornia_imgproc::histogram::stretch_contrast;
+///
+/// let size = ImageSize { width: 3, height: 1 };
+/// let src = Image::<u8, 1, _>::new(size, vec![100, 110, 120], CpuAllocator).unwrap();
+///
+/// let mut dst = Image::<u8, 1, _>::from_size_val(size, 0, CpuAllocator).unwrap();
+/// stretch_contrast(&src, &mut dst, 0.0, 100.0).unwrap();
+/// assert_eq!(dst.as_slice(), &[0, 128, 255]);
+/// ```
+pub fn stretch_contrast<const C: usize, A1: ImageAllocator, A2: ImageAllocator>(
+    src: &Image<u8, C, A1>,
+    dst: &mut Image<u8, C, A2>,
+    low_percentile: f32,
+    high_percentile: f32,
+) -> Result<(), ImageError> {
+    if src.size() != dst.size() {
+        return Err(ImageError::InvalidImageSize(
+            src.cols(),
+            src.rows(),
+            dst.cols(),
+            dst.rows(),
+        ));
+    }
+
+    if !(0.0..=100.0).contains(&low_percentile)
+        || !(0.0..=100.0).contains(&high_percentile)
+        || low_percentile >= high_percentile
+    {
+        return Err(ImageError::InvalidPercentiles(
+            low_percentile,
+            high_percentile,
+        ));
+    }
+
+    let cdfs = channel_cdfs(src.as_slice(), C);
+    let total = cdfs[0][255] as f64;
+
+    let luts = cdfs
+        .iter()
+        .map(|cdf| {
+            // the low intensity leaves at most the low fraction of the pixels below it and the
+            // high intensity at least the high fraction of the pixels up to it
+            let low_count = total * low_percentile as f64 / 100.0;
+            let high_count = total * high_percentile as f64 / 100.0;
+            let low = cdf.iter().position(|&n| n as f64 > low_count).unwrap_or(0);
+            let high = cdf
+                .iter()
+                .position(|&n| n as f64 >= high_count)
+                .unwrap_or(255);
+
+            let mut lut: [u8; 256] = std::array::from_fn(|v| v as u8);
+            if high > low {
+                let scale = 255.0 / (high - low) as f32;
+                lut.iter_mut().enumerate().for_each(|(v, out)| {
+                    let v = v.clamp(low, high);
+                    *out = ((v - low) as f32 * scale).round() as u8;
+                });
+            }
+            lut
+        })
+        .collect::<Vec<_>>();
+
+    dst.as_slice_mut()
+        .par_chunks_exact_mut(C)
+        .zip(src.as_slice().par_chunks_exact(C))
+        .for_each(|(dst_pixel, src_pixel)| {
+            for (ch, (d, &s)) in dst_pixel.iter_mut().zip(src_pixel).enumerate() {
+                *d = luts[ch][s as usize];
+            }
+        });
+
+    Ok(())
+}
+
+/// Compute the cumulative histogram of each channel of an interleaved 8-bit buffer.
+fn channel_cdfs(data: &[u8], num_channels: usize) -> Vec<[u64; 256]> {
+    let mut cdfs = vec![[0u64; 256]; num_channels];
+    for pixel in data.chunks_exact(num_channels) {
+        for (cdf, &val) in cdfs.iter_mut().zip(pixel) {
+            cdf[val as usize] += 1;
+        }
+    }
+
+    for cdf in cdfs.iter_mut() {
+        let mut acc = 0;
+        cdf.iter_mut().for_each(|count| {
+            acc += *count;
+            *count = acc;
+        });
+    }
+
+    cdfs
+}
+
 #[cfg(test)]
 mod tests {
     use kornia_image::{Image, ImageError, ImageSize};
@@ -109,4 +307,115 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_match_histogram() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 16,
+            height: 8,
+        };
+
+        // dark image where the channels follow different distributions
+        let data = (0..size.width * size.height)
+            .flat_map(|i| [(i % 64) as u8, (i / 4) as u8, 10])
+            .collect();
+        let src = Image::<u8, 3, _>::new(size, data, CpuAllocator)?;
+
+        // matching an image to itself does not change it
+        let mut dst = Image::<u8, 3, _>::from_size_val(size, 0, CpuAllocator)?;
+        super::match_histogram(&src, &src, &mut dst)?;
+        assert_eq!(dst.as_slice(), src.as_slice());
+
+        // bright reference with a smaller size
+        let ref_size = ImageSize {
+            width: 8,
+            height: 8,
+        };
+        let ref_data = (0..ref_size.width * ref_size.height)
+            .flat_map(|i| [128 + 2 * i as u8, 255 - i as u8, 200])
+            .collect();
+        let reference = Image::<u8, 3, _>::new(ref_size, ref_data, CpuAllocator)?;
+
+        super::match_histogram(&src, &reference, &mut dst)?;
+
+        let channel_mean = |img: &Image<u8, 3, CpuAllocator>, ch: usize| {
+            img.as_slice()
+                .iter()
+                .skip(ch)
+                .step_by(3)
+                .map(|&v| v as f32)
+                .sum::<f32>()
+                / (size.width * size.height) as f32
+        };
+
+        for ch in 0..3 {
+            assert!(channel_mean(&dst, ch) > channel_mean(&src, ch) + 100.0);
+            // the output only takes values present in the reference channel
+            assert!(dst.as_slice().iter().skip(ch).step_by(3).all(|v| reference
+                .as_slice()
+                .iter()
+                .skip(ch)
+                .step_by(3)
+                .any(|r| r == v)));
+        }
+
+        // the mapping is monotonic
+        let mut pairs = src
+            .as_slice()
+            .iter()
+            .zip(dst.as_slice())
+            .step_by(3)
+            .collect::<Vec<_>>();
+        pairs.sort();
+        assert!(pairs.windows(2).all(|w| w[0].1 <= w[1].1));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_stretch_contrast() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 8,
+            height: 5,
+        };
+
+        // a low contrast ramp in the first channel and a constant second channel
+        let data = (0..size.width * size.height)
+            .flat_map(|i| [100 + i as u8, 42])
+            .collect();
+        let src = Image::<u8, 2, _>::new(size, data, CpuAllocator)?;
+        let mut dst = Image::<u8, 2, _>::from_size_val(size, 0, CpuAllocator)?;
+
+        super::stretch_contrast(&src, &mut dst, 0.0, 100.0)?;
+        let ramp = dst
+            .as_slice()
+            .iter()
+            .step_by(2)
+            .copied()
+            .collect::<Vec<_>>();
+        assert_eq!(ramp.first(), Some(&0));
+        assert_eq!(ramp.last(), Some(&255));
+        assert!(ramp.windows(2).all(|w| w[0] < w[1]));
+
+        // the constant channel is left unchanged
+        assert!(dst.as_slice().iter().skip(1).step_by(2).all(|&v| v == 42));
+
+        // 10% of the 40 pixels are clipped at each end, plus the percentile values themselves
+        super::stretch_contrast(&src, &mut dst, 10.0, 90.0)?;
+        let ramp = dst
+            .as_slice()
+            .iter()
+            .step_by(2)
+            .copied()
+            .collect::<Vec<_>>();
+        assert_eq!(ramp.iter().filter(|&&v| v == 0).count(), 5);
+        assert_eq!(ramp.iter().filter(|&&v| v == 255).count(), 5);
+        assert_eq!(ramp[5], 8);
+
+        assert!(super::stretch_contrast(&src, &mut dst, 50.0, 50.0).is_err());
+        assert!(super::stretch_contrast(&src, &mut dst, -1.0, 50.0).is_err());
+        assert!(super::stretch_contrast(&src, &mut dst, 0.0, 101.0).is_err());
+
+        Ok(())
+    }
 }
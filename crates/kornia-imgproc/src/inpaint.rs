@@ -0,0 +1,326 @@
+use std::{cmp::Ordering, collections::BinaryHeap};
+
+use kornia_image::{allocator::ImageAllocator, Image, ImageError};
+
+// the state of a pixel during the fast marching
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum PixelState {
+    // the pixel value is final
+    Known,
+    // the pixel value is estimated, but its distance to the boundary can still change
+    Band,
+    // the pixel has not been reached yet
+    Inside,
+}
+
+// a band pixel ordered by its distance to the initial boundary, the closest first
+struct BandPixel {
+    dist: f32,
+    idx: usize,
+}
+
+impl PartialEq for BandPixel {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for BandPixel {}
+
+impl PartialOrd for BandPixel {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BandPixel {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other
+            .dist
+            .total_cmp(&self.dist)
+            .then_with(|| other.idx.cmp(&self.idx))
+    }
+}
+
+/// Fill the masked regions of an image from their surroundings.
+///
+/// This implements the fast marching method of Telea (2004): the masked pixels are filled
+/// from the boundary of the mask inwards, in order of their distance to the boundary. Each
+/// pixel is estimated from the known pixels within `radius`, extrapolated with their
+/// gradient and weighted by their closeness, so smooth shading continues through the fill
+/// without seams.
+///
+/// # Arguments
+///
+/// * `src` - The input image with shape (H, W, C).
+/// * `mask` - The mask with shape (H, W, 1). The non-zero pixels are filled.
+/// * `dst` - The output image with shape (H, W, C).
+/// * `radius` - The radius of the neighborhood used to estimate each pixel.
+///
+/// PRECONDITION: `src`, `mask` and `dst` must have the same size.
+///
+/// # Errors
+///
+/// Returns an error if the sizes of the images differ.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_image::allocator::CpuAllocator;
+/// use kornia_imgproc::inpaint::inpaint;
+///
+/// let size = ImageSize { width: 3, height: 1 };
+/// let src = Image::<u8, 1, _>::new(size, vec![10, 0, 30], CpuAllocator).unwrap();
+/// let mask = Image::<u8, 1, _>::new(size, vec![0, 255, 0], CpuAllocator).unwrap();
+///
+/// let mut dst = Image::<u8, 1, _>::from_size_val(size, 0, CpuAllocator).unwrap();
+/// inpaint(&src, &mask, &mut dst, 3).unwrap();
+/// assert_eq!(dst.as_slice(), &[10, 20, 30]);
+/// ```
+pub fn inpaint<const C: usize, A1: ImageAllocator, A2: ImageAllocator, A3: ImageAllocator>(
+    src: &Image<u8, C, A1>,
+    mask: &Image<u8, 1, A2>,
+    dst: &mut Image<u8, C, A3>,
+    radius: usize,
+) -> Result<(), ImageError> {
+    for size in [mask.size(), dst.size()] {
+        if src.size() != size {
+            return Err(ImageError::InvalidImageSize(
+                src.cols(),
+                src.rows(),
+                size.width,
+                size.height,
+            ));
+        }
+    }
+
+    let (cols, rows) = (src.cols(), src.rows());
+    let radius = radius.max(1) as isize;
+
+    let state = mask
+        .as_slice()
+        .iter()
+        .map(|&m| {
+            if m == 0 {
+                PixelState::Known
+            } else {
+                PixelState::Inside
+            }
+        })
+        .collect::<Vec<_>>();
+    let mut fm = FastMarching::<C> {
+        values: src.as_slice().iter().map(|&v| v as f32).collect(),
+        dist: state
+            .iter()
+            .map(|&s| {
+                if s == PixelState::Known {
+                    0.0
+                } else {
+                    f32::MAX
+                }
+            })
+            .collect(),
+        state,
+        cols,
+        rows,
+    };
+
+    let neighbors = |idx: usize| {
+        let (x, y) = ((idx % cols) as isize, (idx / cols) as isize);
+        [(x - 1, y), (x + 1, y), (x, y - 1), (x, y + 1)]
+    };
+
+    // the known pixels next to the mask form the initial band
+    let mut heap = BinaryHeap::new();
+    for idx in 0..fm.state.len() {
+        let next_to_mask = neighbors(idx)
+            .into_iter()
+            .any(|(nx, ny)| fm.state_at(nx, ny) == Some(PixelState::Inside));
+        if fm.state[idx] == PixelState::Known && next_to_mask {
+            fm.state[idx] = PixelState::Band;
+            heap.push(BandPixel { dist: 0.0, idx });
+        }
+    }
+
+    while let Some(BandPixel { idx, .. }) = heap.pop() {
+        fm.state[idx] = PixelState::Known;
+
+        for (x, y) in neighbors(idx) {
+            if fm.state_at(x, y) != Some(PixelState::Inside) {
+                continue;
+            }
+
+            let n = y as usize * cols + x as usize;
+            fm.dist[n] = fm.solve_eikonal(x, y);
+            let pixel = fm.estimate_pixel(x, y, radius);
+            fm.values[n * C..(n + 1) * C].copy_from_slice(&pixel);
+
+            fm.state[n] = PixelState::Band;
+            heap.push(BandPixel {
+                dist: fm.dist[n],
+                idx: n,
+            });
+        }
+    }
+
+    dst.as_slice_mut()
+        .iter_mut()
+        .zip(fm.values.iter())
+        .for_each(|(d, &v)| *d = v.round().clamp(0.0, 255.0) as u8);
+
+    Ok(())
+}
+
+// the pixel values, distances to the boundary and states during the fast marching
+struct FastMarching<const C: usize> {
+    values: Vec<f32>,
+    dist: Vec<f32>,
+    state: Vec<PixelState>,
+    cols: usize,
+    rows: usize,
+}
+
+impl<const C: usize> FastMarching<C> {
+    fn index(&self, x: isize, y: isize) -> Option<usize> {
+        let inside = x >= 0 && y >= 0 && x < self.cols as isize && y < self.rows as isize;
+        inside.then(|| y as usize * self.cols + x as usize)
+    }
+
+    fn state_at(&self, x: isize, y: isize) -> Option<PixelState> {
+        self.index(x, y).map(|idx| self.state[idx])
+    }
+
+    // the pixels with a value, either final or estimated
+    fn reached(&self, x: isize, y: isize) -> bool {
+        self.state_at(x, y)
+            .is_some_and(|state| state != PixelState::Inside)
+    }
+
+    fn value(&self, x: isize, y: isize, ch: usize) -> f32 {
+        self.values[(y as usize * self.cols + x as usize) * C + ch]
+    }
+
+    // the distance of a pixel to the boundary from the distances of its reached neighbors
+    fn solve_eikonal(&self, x: isize, y: isize) -> f32 {
+        let dist = |nx: isize, ny: isize| match self.index(nx, ny) {
+            Some(idx) if self.state[idx] != PixelState::Inside => self.dist[idx],
+            _ => f32::MAX,
+        };
+
+        let a = dist(x - 1, y).min(dist(x + 1, y));
+        let b = dist(x, y - 1).min(dist(x, y + 1));
+        if (a - b).abs() >= 1.0 {
+            a.min(b) + 1.0
+        } else {
+            (a + b + (2.0 - (a - b) * (a - b)).sqrt()) / 2.0
+        }
+    }
+
+    // the derivative of a reached pixel along a direction, with central differences where
+    // possible and one-sided differences at the edges of the fill
+    fn derivative(&self, (x, y): (isize, isize), (dx, dy): (isize, isize), ch: usize) -> f32 {
+        let (prev, next) = ((x - dx, y - dy), (x + dx, y + dy));
+        match (self.reached(prev.0, prev.1), self.reached(next.0, next.1)) {
+            (true, true) => (self.value(next.0, next.1, ch) - self.value(prev.0, prev.1, ch)) / 2.0,
+            (false, true) => self.value(next.0, next.1, ch) - self.value(x, y, ch),
+            (true, false) => self.value(x, y, ch) - self.value(prev.0, prev.1, ch),
+            (false, false) => 0.0,
+        }
+    }
+
+    // the weighted mean of the reached pixels within the radius, extrapolated with their
+    // gradient to the estimated pixel
+    fn estimate_pixel(&self, x: isize, y: isize, radius: isize) -> [f32; C] {
+        let dist_p = self.dist[y as usize * self.cols + x as usize];
+
+        let mut sum = [0.0; C];
+        let mut weight_sum = 0.0;
+        for dy in -radius..=radius {
+            for dx in -radius..=radius {
+                let (qx, qy) = (x + dx, y + dy);
+                let dist2 = dx * dx + dy * dy;
+                if dist2 == 0 || dist2 > radius * radius || !self.reached(qx, qy) {
+                    continue;
+                }
+
+                // closer pixels and pixels at the same distance to the boundary weigh more
+                let dist_q = self.dist[qy as usize * self.cols + qx as usize];
+                let weight = 1.0 / (1.0 + (dist_q - dist_p).abs()) / dist2 as f32;
+
+                for (ch, s) in sum.iter_mut().enumerate() {
+                    let gx = self.derivative((qx, qy), (1, 0), ch);
+                    let gy = self.derivative((qx, qy), (0, 1), ch);
+                    *s += weight * (self.value(qx, qy, ch) - gx * dx as f32 - gy * dy as f32);
+                }
+                weight_sum += weight;
+            }
+        }
+
+        sum.map(|s| s / weight_sum)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use kornia_image::{Image, ImageError, ImageSize};
+    use kornia_tensor::CpuAllocator;
+
+    #[test]
+    fn test_inpaint() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 32,
+            height: 16,
+        };
+
+        // a smooth gradient with a different slope in each channel
+        let in_block = |x: usize, y: usize| (12..18).contains(&x) && (5..10).contains(&y);
+        let pixels = (0..size.height).flat_map(|y| (0..size.width).map(move |x| (x, y)));
+        let expected = pixels
+            .clone()
+            .flat_map(|(x, y)| [(4 * x + 2 * y) as u8, (200 - 3 * x) as u8])
+            .collect::<Vec<_>>();
+
+        // the masked block is destroyed in the input
+        let damaged = pixels
+            .clone()
+            .zip(expected.chunks_exact(2))
+            .flat_map(|((x, y), px)| {
+                if in_block(x, y) {
+                    [255, 0]
+                } else {
+                    [px[0], px[1]]
+                }
+            })
+            .collect();
+        let damaged = Image::<u8, 2, _>::new(size, damaged, CpuAllocator)?;
+        let mask = pixels
+            .map(|(x, y)| if in_block(x, y) { 255 } else { 0 })
+            .collect();
+        let mask = Image::<u8, 1, _>::new(size, mask, CpuAllocator)?;
+
+        let mut dst = Image::<u8, 2, _>::from_size_val(size, 0, CpuAllocator)?;
+        super::inpaint(&damaged, &mask, &mut dst, 3)?;
+
+        for (i, (d, s)) in dst
+            .as_slice()
+            .chunks_exact(2)
+            .zip(expected.chunks_exact(2))
+            .enumerate()
+        {
+            for ch in 0..2 {
+                assert!(
+                    (d[ch] as i32 - s[ch] as i32).abs() <= 2,
+                    "pixel {i} channel {ch}: {} != {}",
+                    d[ch],
+                    s[ch]
+                );
+            }
+        }
+
+        let wrong = Image::<u8, 1, _>::from_size_val([4, 4].into(), 0, CpuAllocator)?;
+        assert!(super::inpaint(&damaged, &wrong, &mut dst, 3).is_err());
+
+        Ok(())
+    }
+}
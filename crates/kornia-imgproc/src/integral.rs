@@ -0,0 +1,148 @@
+use kornia_image::{allocator::ImageAllocator, Image, ImageError};
+
+/// Compute the integral image, also known as summed-area table, of an image.
+///
+/// Each output pixel holds the sum of the input pixels above and to the left of it, including
+/// itself. The sums are accumulated in double precision to keep them exact for large images.
+///
+/// # Arguments
+///
+/// * `src` - The input image with shape (H, W, C).
+/// * `dst` - The output integral image with shape (H, W, C).
+///
+/// PRECONDITION: `src` and `dst` must have the same size.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_image::allocator::CpuAllocator;
+/// use kornia_imgproc::integral::integral_image;
+///
+/// let image = Image::<f32, 1, _>::new(
+///     ImageSize { width: 2, height: 2 },
+///     vec![1.0, 2.0, 3.0, 4.0],
+///     CpuAllocator,
+/// ).unwrap();
+///
+/// let mut sums = Image::<f64, 1, _>::from_size_val(image.size(), 0.0, CpuAllocator).unwrap();
+///
+/// integral_image(&image, &mut sums).unwrap();
+/// assert_eq!(sums.as_slice(), &[1.0, 3.0, 4.0, 10.0]);
+/// ```
+pub fn integral_image<const C: usize, A1: ImageAllocator, A2: ImageAllocator>(
+    src: &Image<f32, C, A1>,
+    dst: &mut Image<f64, C, A2>,
+) -> Result<(), ImageError> {
+    if src.size() != dst.size() {
+        return Err(ImageError::InvalidImageSize(
+            src.cols(),
+            src.rows(),
+            dst.cols(),
+            dst.rows(),
+        ));
+    }
+
+    let row_len = src.cols() * C;
+    if row_len == 0 {
+        return Ok(());
+    }
+
+    let dst_data = dst.as_slice_mut();
+    for (y, src_row) in src.as_slice().chunks_exact(row_len).enumerate() {
+        let mut row_sum = [0.0f64; C];
+        for (x, src_pixel) in src_row.chunks_exact(C).enumerate() {
+            let idx = y * row_len + x * C;
+            for ch in 0..C {
+                row_sum[ch] += src_pixel[ch] as f64;
+                let above = if y > 0 {
+                    dst_data[idx - row_len + ch]
+                } else {
+                    0.0
+                };
+                dst_data[idx + ch] = row_sum[ch] + above;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Compute the sum of a channel over an inclusive rectangle of an integral image.
+///
+/// # Arguments
+///
+/// * `sums` - The integral image computed with [`integral_image`].
+/// * `top_left` - The (x, y) coordinates of the top-left corner of the rectangle.
+/// * `bottom_right` - The (x, y) coordinates of the bottom-right corner of the rectangle.
+/// * `ch` - The channel to sum.
+///
+/// PRECONDITION: the rectangle must be inside the image and `top_left <= bottom_right`.
+pub fn integral_rect_sum<const C: usize, A: ImageAllocator>(
+    sums: &Image<f64, C, A>,
+    top_left: (usize, usize),
+    bottom_right: (usize, usize),
+    ch: usize,
+) -> f64 {
+    let data = sums.as_slice();
+    let at = |x: usize, y: usize| data[(y * sums.cols() + x) * C + ch];
+
+    let (x0, y0) = top_left;
+    let (x1, y1) = bottom_right;
+
+    let mut sum = at(x1, y1);
+    if x0 > 0 {
+        sum -= at(x0 - 1, y1);
+    }
+    if y0 > 0 {
+        sum -= at(x1, y0 - 1);
+    }
+    if x0 > 0 && y0 > 0 {
+        sum += at(x0 - 1, y0 - 1);
+    }
+    sum
+}
+
+#[cfg(test)]
+mod tests {
+    use kornia_image::{Image, ImageError, ImageSize};
+    use kornia_tensor::CpuAllocator;
+
+    #[test]
+    fn test_integral_image() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 4,
+            height: 3,
+        };
+        let image = Image::<f32, 2, _>::new(
+            size,
+            (0..size.width * size.height)
+                .flat_map(|i| [i as f32, 1.0])
+                .collect(),
+            CpuAllocator,
+        )?;
+
+        let mut sums = Image::<f64, 2, _>::from_size_val(size, 0.0, CpuAllocator)?;
+        super::integral_image(&image, &mut sums)?;
+
+        #[rustfmt::skip]
+        assert_eq!(
+            sums.as_slice().iter().step_by(2).copied().collect::<Vec<_>>(),
+            vec![
+                0.0, 1.0, 3.0, 6.0,
+                4.0, 10.0, 18.0, 28.0,
+                12.0, 27.0, 45.0, 66.0,
+            ]
+        );
+
+        // the second channel counts the pixels
+        assert_eq!(super::integral_rect_sum(&sums, (1, 1), (3, 2), 1), 6.0);
+        assert_eq!(
+            super::integral_rect_sum(&sums, (1, 1), (2, 2), 0),
+            5.0 + 6.0 + 9.0 + 10.0
+        );
+        assert_eq!(super::integral_rect_sum(&sums, (0, 0), (0, 0), 0), 0.0);
+
+        Ok(())
+    }
+}
@@ -51,3 +51,41 @@ pub(crate) fn bilinear_interpolation<const C: usize, A: ImageAllocator>(
         + val10 * frac_uu * frac_v
         + val11 * frac_u * frac_v
 }
+
+/// Sample an interleaved RGB buffer with bilinear interpolation.
+///
+/// # Arguments
+///
+/// * `data` - The pixels of the image, with 3 channels per pixel.
+/// * `rows` - The number of rows of the image.
+/// * `cols` - The number of columns of the image.
+/// * `u` - The x coordinate of the sample.
+/// * `v` - The y coordinate of the sample.
+///
+/// # Returns
+///
+/// The interpolated channels, or `None` if the point is outside of the image.
+pub(crate) fn sample_bilinear_rgb8(
+    data: &[u8],
+    rows: usize,
+    cols: usize,
+    u: f32,
+    v: f32,
+) -> Option<[f32; 3]> {
+    if u < 0.0 || v < 0.0 || u > cols as f32 - 1.0 || v > rows as f32 - 1.0 {
+        return None;
+    }
+
+    let (iu, iv) = (u as usize, v as usize);
+    let (iu1, iv1) = ((iu + 1).min(cols - 1), (iv + 1).min(rows - 1));
+    let (fu, fv) = (u - iu as f32, v - iv as f32);
+
+    let at = |r: usize, c: usize, ch: usize| data[(r * cols + c) * 3 + ch] as f32;
+
+    Some([0, 1, 2].map(|ch| {
+        at(iv, iu, ch) * (1.0 - fu) * (1.0 - fv)
+            + at(iv, iu1, ch) * fu * (1.0 - fv)
+            + at(iv1, iu, ch) * (1.0 - fu) * fv
+            + at(iv1, iu1, ch) * fu * fv
+    }))
+}
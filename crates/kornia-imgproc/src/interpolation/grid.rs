@@ -1,4 +1,4 @@
-use kornia_tensor::{CpuAllocator, CpuTensor2, TensorError};
+use kornia_tensor::{CpuAllocator, CpuTensor2, Tensor, Tensor2, TensorAllocator, TensorError};
 use num_traits::Float;
 use rayon::iter::ParallelIterator;
 use rayon::{iter::IndexedParallelIterator, slice::ParallelSliceMut};
@@ -65,6 +65,113 @@ where
     Ok((map_x, map_y))
 }
 
+/// A pair of coordinate grids with the same shape.
+pub type GridPair<A> = (Tensor2<f32, A>, Tensor2<f32, A>);
+
+/// Create the grids of the x and y pixel coordinates of an image.
+///
+/// The coordinates follow the convention of the sampling functions, e.g. [`super::remap`]:
+/// `x` is the column and `y` the row index, so the grids are the identity map. They are built
+/// by broadcasting a row and a column of indices to the shape of the grid.
+///
+/// # Arguments
+///
+/// * `height` - The number of rows of the grid.
+/// * `width` - The number of columns of the grid.
+/// * `alloc` - The allocator of the grids.
+///
+/// # Returns
+///
+/// A tuple of two 2D tensors of shape (height, width) with the x and y coordinates.
+///
+/// # Errors
+///
+/// Returns a `TensorError` if the coordinates cannot be broadcast to the grid.
+///
+/// # Example
+///
+/// ```
+/// use kornia_imgproc::interpolation::grid::meshgrid;
+/// use kornia_tensor::CpuAllocator;
+///
+/// let (map_x, map_y) = meshgrid(2, 3, CpuAllocator).unwrap();
+///
+/// assert_eq!(map_x.as_slice(), &[0.0, 1.0, 2.0, 0.0, 1.0, 2.0]);
+/// assert_eq!(map_y.as_slice(), &[0.0, 0.0, 0.0, 1.0, 1.0, 1.0]);
+/// ```
+pub fn meshgrid<A: TensorAllocator>(
+    height: usize,
+    width: usize,
+    alloc: A,
+) -> Result<GridPair<A>, TensorError> {
+    let xs = Tensor::<f32, 1, _>::from_shape_fn([width], alloc.clone(), |[x]| x as f32);
+    let ys = Tensor::<f32, 2, _>::from_shape_fn([height, 1], alloc, |[y, _]| y as f32);
+
+    // the row of x and the column of y are read along the other axis with a zero stride
+    Ok((
+        xs.broadcast_to([height, width])?.to_contiguous(),
+        ys.broadcast_to([height, width])?.to_contiguous(),
+    ))
+}
+
+/// Create the grids of the polar coordinates of the pixels of an image around a center.
+///
+/// The radius is the distance to the center in pixels, and the angle is measured in radians
+/// from the x axis towards the y axis, i.e. clockwise on screen, in `[-pi, pi]`. The pixel
+/// coordinates are the ones of [`meshgrid`].
+///
+/// # Arguments
+///
+/// * `height` - The number of rows of the grid.
+/// * `width` - The number of columns of the grid.
+/// * `center` - The (x, y) coordinates of the pole.
+/// * `alloc` - The allocator of the grids.
+///
+/// # Returns
+///
+/// A tuple of two 2D tensors of shape (height, width) with the radius and the angle.
+///
+/// # Errors
+///
+/// Returns a `TensorError` if the coordinate grids cannot be created.
+///
+/// # Example
+///
+/// ```
+/// use kornia_imgproc::interpolation::grid::meshgrid_polar;
+/// use kornia_tensor::CpuAllocator;
+///
+/// let (radius, angle) = meshgrid_polar(3, 3, (1.0, 1.0), CpuAllocator).unwrap();
+///
+/// assert_eq!(*radius.get([1, 2]).unwrap(), 1.0);
+/// assert_eq!(*angle.get([2, 1]).unwrap(), std::f32::consts::FRAC_PI_2);
+/// ```
+pub fn meshgrid_polar<A: TensorAllocator>(
+    height: usize,
+    width: usize,
+    center: (f32, f32),
+    alloc: A,
+) -> Result<GridPair<A>, TensorError> {
+    let (map_x, map_y) = meshgrid(height, width, alloc)?;
+    let dx = map_x.map(|x| x - center.0);
+    let dy = map_y.map(|y| y - center.1);
+
+    let mut radius = dx.clone();
+    let mut angle = dx.clone();
+    for (((r, a), &dx), &dy) in radius
+        .as_slice_mut()
+        .iter_mut()
+        .zip(angle.as_slice_mut())
+        .zip(dx.as_slice())
+        .zip(dy.as_slice())
+    {
+        *r = dx.hypot(dy);
+        *a = dy.atan2(dx);
+    }
+
+    Ok((radius, angle))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -101,4 +208,40 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_meshgrid() -> Result<(), TensorError> {
+        let (map_x, map_y) = meshgrid(2, 3, CpuAllocator)?;
+
+        assert_eq!(map_x.shape, [2, 3]);
+        assert_eq!(map_y.shape, [2, 3]);
+        assert_eq!(map_x.as_slice(), &[0.0, 1.0, 2.0, 0.0, 1.0, 2.0]);
+        assert_eq!(map_y.as_slice(), &[0.0, 0.0, 0.0, 1.0, 1.0, 1.0]);
+
+        // the same grids as the identity function
+        let (fn_x, fn_y) = meshgrid_from_fn(3, 2, |u, v| Ok((u as f32, v as f32)))?;
+        assert_eq!(map_x.as_slice(), fn_x.as_slice());
+        assert_eq!(map_y.as_slice(), fn_y.as_slice());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_meshgrid_polar() -> Result<(), TensorError> {
+        let (radius, angle) = meshgrid_polar(2, 3, (1.0, 0.0), CpuAllocator)?;
+
+        let sqrt2 = 2f32.sqrt();
+        let expected = [1.0, 0.0, 1.0, sqrt2, 1.0, sqrt2];
+        for (r, e) in radius.as_slice().iter().zip(expected) {
+            assert!((r - e).abs() < 1e-6);
+        }
+
+        let pi = std::f32::consts::PI;
+        let expected = [pi, 0.0, 0.0, 3.0 * pi / 4.0, pi / 2.0, pi / 4.0];
+        for (a, e) in angle.as_slice().iter().zip(expected) {
+            assert!((a - e).abs() < 1e-6);
+        }
+
+        Ok(())
+    }
 }
@@ -1,7 +1,7 @@
 use super::bilinear::bilinear_interpolation;
 use super::nearest::nearest_neighbor_interpolation;
 use kornia_image::allocator::ImageAllocator;
-use kornia_image::Image;
+use kornia_image::{Image, ImageError};
 
 /// Interpolation mode for the resize operation
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -14,6 +14,8 @@ pub enum InterpolationMode {
     Lanczos,
     /// Bicubic interpolation
     Bicubic,
+    /// Area interpolation, averaging the source pixels covered by each output pixel
+    Area,
 }
 
 /// Kernel for interpolating a pixel value
@@ -29,6 +31,12 @@ pub enum InterpolationMode {
 /// # Returns
 ///
 /// The interpolated pixel value.
+///
+/// # Panics
+///
+/// Panics if the interpolation mode cannot sample single pixels, i.e. for
+/// [`InterpolationMode::Lanczos`], [`InterpolationMode::Bicubic`] and
+/// [`InterpolationMode::Area`].
 pub fn interpolate_pixel<const C: usize, A: ImageAllocator>(
     image: &Image<f32, C, A>,
     u: f32,
@@ -45,5 +53,23 @@ pub fn interpolate_pixel<const C: usize, A: ImageAllocator>(
         InterpolationMode::Bicubic => {
             unimplemented!("Bicubic interpolation is not yet implemented")
         }
+        InterpolationMode::Area => {
+            unimplemented!("Area interpolation is only supported for resizing")
+        }
+    }
+}
+
+/// Check that the interpolation mode can sample single pixels with [`interpolate_pixel`].
+///
+/// # Errors
+///
+/// Returns [`ImageError::UnsupportedInterpolation`] for the modes other than
+/// [`InterpolationMode::Nearest`] and [`InterpolationMode::Bilinear`].
+pub(crate) fn check_interpolation(interpolation: InterpolationMode) -> Result<(), ImageError> {
+    match interpolation {
+        InterpolationMode::Nearest | InterpolationMode::Bilinear => Ok(()),
+        _ => Err(ImageError::UnsupportedInterpolation(format!(
+            "{interpolation:?}"
+        ))),
     }
 }
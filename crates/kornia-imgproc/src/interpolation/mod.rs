@@ -11,3 +11,7 @@ pub use interpolate::InterpolationMode;
 pub use remap::remap;
 
 pub use interpolate::interpolate_pixel;
+
+pub(crate) use interpolate::check_interpolation;
+
+pub(crate) use bilinear::sample_bilinear_rgb8;
@@ -1,6 +1,6 @@
 use crate::parallel;
 
-use super::interpolate::interpolate_pixel;
+use super::interpolate::{check_interpolation, interpolate_pixel};
 use super::InterpolationMode;
 use kornia_image::{allocator::ImageAllocator, Image, ImageError};
 use kornia_tensor::{CpuAllocator, Tensor2};
@@ -19,6 +19,9 @@ use kornia_tensor::{CpuAllocator, Tensor2};
 ///
 /// * The mapx and mapy must have the same size.
 /// * The output image must have the same size as the mapx and mapy.
+/// * The interpolation mode must be [`InterpolationMode::Nearest`] or
+///   [`InterpolationMode::Bilinear`], otherwise [`ImageError::UnsupportedInterpolation`] is
+///   returned.
 pub fn remap<const C: usize, A1: ImageAllocator, A2: ImageAllocator>(
     src: &Image<f32, C, A1>,
     dst: &mut Image<f32, C, A2>,
@@ -44,6 +47,8 @@ pub fn remap<const C: usize, A1: ImageAllocator, A2: ImageAllocator>(
         ));
     }
 
+    check_interpolation(interpolation)?;
+
     // parallelize the remap operation by rows
     parallel::par_iter_rows_resample(dst, map_x, map_y, |&x, &y, dst_pixel| {
         // interpolate the pixel value
@@ -110,4 +115,16 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn remap_unsupported_interpolation() -> Result<(), ImageError> {
+        let image = Image::<f32, 1, _>::from_size_val([3, 3].into(), 1.0, CpuAllocator)?;
+        let map = Tensor2::from_shape_vec([2, 2], vec![0.0, 1.0, 0.0, 1.0], CpuAllocator)?;
+        let mut dst = Image::<f32, 1, _>::from_size_val([2, 2].into(), 0.0, CpuAllocator)?;
+
+        let res = super::remap(&image, &mut dst, &map, &map, super::InterpolationMode::Area);
+        assert!(matches!(res, Err(ImageError::UnsupportedInterpolation(_))));
+
+        Ok(())
+    }
 }
@@ -1,11 +1,17 @@
 #![deny(missing_docs)]
 #![doc = env!("CARGO_PKG_DESCRIPTION")]
+/// background subtraction module.
+pub mod background;
+
 /// image undistortion module.
 pub mod calibration;
 
 /// color transformations module.
 pub mod color;
 
+/// contour extraction from binary images module.
+pub mod contours;
+
 /// image basic operations module.
 pub mod core;
 
@@ -27,15 +33,30 @@ pub mod features;
 /// image filtering module.
 pub mod filter;
 
+/// optical flow and motion compensated frame interpolation module.
+pub mod flow;
+
 /// image flipping module.
 pub mod flip;
 
 /// compute image histogram module.
 pub mod histogram;
 
+/// image inpainting module.
+pub mod inpaint;
+
+/// integral image module.
+pub mod integral;
+
 /// utilities for interpolation.
 pub mod interpolation;
 
+/// boolean mask operations module.
+pub mod mask;
+
+/// image moments and shape descriptors module.
+pub mod moments;
+
 /// module containing parallization utilities.
 pub mod parallel;
 
@@ -48,11 +69,23 @@ pub mod normalize;
 /// utility functions for resizing images.
 pub mod resize;
 
+/// content-aware resizing with seam carving module.
+pub mod seam_carving;
+
+/// template matching module.
+pub mod template;
+
 /// operations to threshold images.
 pub mod threshold;
 
+/// object tracking module.
+pub mod tracking;
+
 /// image geometric transformations module.
 pub mod warp;
 
 /// Pyramid operations
 pub mod pyramid;
+
+#[cfg(all(test, feature = "tracing"))]
+mod tracing_tests;
@@ -0,0 +1,316 @@
+use kornia_image::{allocator::ImageAllocator, Image, ImageError};
+
+use crate::parallel;
+
+// NOTE: masks are stored with one `bool` per pixel instead of packed bits, so that they share
+// the layout and the parallel row iteration of the other single channel images.
+
+/// Create a boolean mask with the pixels greater than a threshold.
+///
+/// # Arguments
+///
+/// * `src` - The input image of an arbitrary type.
+/// * `dst` - The output mask, `true` where the input value is greater than the threshold.
+/// * `threshold` - The threshold value. Must be the same type as the image.
+///
+/// PRECONDITION: `src` and `dst` must have the same size.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_image::allocator::CpuAllocator;
+/// use kornia_imgproc::mask::threshold_mask;
+///
+/// let image = Image::<u8, 1, _>::new(ImageSize { width: 3, height: 1 }, vec![50, 100, 150], CpuAllocator).unwrap();
+///
+/// let mut mask = Image::<bool, 1, _>::from_size_val(image.size(), false, CpuAllocator).unwrap();
+///
+/// threshold_mask(&image, &mut mask, 100).unwrap();
+/// assert_eq!(mask.as_slice(), &[false, false, true]);
+/// ```
+pub fn threshold_mask<T, A1: ImageAllocator, A2: ImageAllocator>(
+    src: &Image<T, 1, A1>,
+    dst: &mut Image<bool, 1, A2>,
+    threshold: T,
+) -> Result<(), ImageError>
+where
+    T: Copy + Send + Sync + PartialOrd,
+{
+    check_same_size(src, dst)?;
+
+    parallel::par_iter_rows_val(src, dst, |&src_pixel, dst_pixel| {
+        *dst_pixel = src_pixel > threshold;
+    });
+
+    Ok(())
+}
+
+/// Convert an 8-bit mask, where any non-zero value is set, to a boolean mask.
+///
+/// # Arguments
+///
+/// * `src` - The input 8-bit mask.
+/// * `dst` - The output boolean mask.
+///
+/// PRECONDITION: `src` and `dst` must have the same size.
+pub fn mask_from_u8<A1: ImageAllocator, A2: ImageAllocator>(
+    src: &Image<u8, 1, A1>,
+    dst: &mut Image<bool, 1, A2>,
+) -> Result<(), ImageError> {
+    threshold_mask(src, dst, 0)
+}
+
+/// Convert a boolean mask to an 8-bit mask with the values 0 and 255.
+///
+/// # Arguments
+///
+/// * `src` - The input boolean mask.
+/// * `dst` - The output 8-bit mask.
+///
+/// PRECONDITION: `src` and `dst` must have the same size.
+pub fn mask_to_u8<A1: ImageAllocator, A2: ImageAllocator>(
+    src: &Image<bool, 1, A1>,
+    dst: &mut Image<u8, 1, A2>,
+) -> Result<(), ImageError> {
+    check_same_size(src, dst)?;
+
+    parallel::par_iter_rows_val(src, dst, |&src_pixel, dst_pixel| {
+        *dst_pixel = if src_pixel { 255 } else { 0 };
+    });
+
+    Ok(())
+}
+
+/// Compute the intersection of two boolean masks.
+///
+/// # Arguments
+///
+/// * `src1` - The first input mask.
+/// * `src2` - The second input mask.
+/// * `dst` - The output mask, `true` where both masks are set.
+///
+/// PRECONDITION: `src1`, `src2` and `dst` must have the same size.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_image::allocator::CpuAllocator;
+/// use kornia_imgproc::mask::mask_and;
+///
+/// let size = ImageSize { width: 2, height: 2 };
+/// let a = Image::<bool, 1, _>::new(size, vec![true, true, false, false], CpuAllocator).unwrap();
+/// let b = Image::<bool, 1, _>::new(size, vec![true, false, true, false], CpuAllocator).unwrap();
+///
+/// let mut mask = Image::<bool, 1, _>::from_size_val(size, false, CpuAllocator).unwrap();
+///
+/// mask_and(&a, &b, &mut mask).unwrap();
+/// assert_eq!(mask.as_slice(), &[true, false, false, false]);
+/// ```
+pub fn mask_and<A1: ImageAllocator, A2: ImageAllocator, A3: ImageAllocator>(
+    src1: &Image<bool, 1, A1>,
+    src2: &Image<bool, 1, A2>,
+    dst: &mut Image<bool, 1, A3>,
+) -> Result<(), ImageError> {
+    combine_masks(src1, src2, dst, |a, b| a & b)
+}
+
+/// Compute the union of two boolean masks.
+///
+/// # Arguments
+///
+/// * `src1` - The first input mask.
+/// * `src2` - The second input mask.
+/// * `dst` - The output mask, `true` where any of the masks is set.
+///
+/// PRECONDITION: `src1`, `src2` and `dst` must have the same size.
+pub fn mask_or<A1: ImageAllocator, A2: ImageAllocator, A3: ImageAllocator>(
+    src1: &Image<bool, 1, A1>,
+    src2: &Image<bool, 1, A2>,
+    dst: &mut Image<bool, 1, A3>,
+) -> Result<(), ImageError> {
+    combine_masks(src1, src2, dst, |a, b| a | b)
+}
+
+/// Compute the symmetric difference of two boolean masks.
+///
+/// # Arguments
+///
+/// * `src1` - The first input mask.
+/// * `src2` - The second input mask.
+/// * `dst` - The output mask, `true` where exactly one of the masks is set.
+///
+/// PRECONDITION: `src1`, `src2` and `dst` must have the same size.
+pub fn mask_xor<A1: ImageAllocator, A2: ImageAllocator, A3: ImageAllocator>(
+    src1: &Image<bool, 1, A1>,
+    src2: &Image<bool, 1, A2>,
+    dst: &mut Image<bool, 1, A3>,
+) -> Result<(), ImageError> {
+    combine_masks(src1, src2, dst, |a, b| a ^ b)
+}
+
+/// Compute the complement of a boolean mask.
+///
+/// # Arguments
+///
+/// * `src` - The input mask.
+/// * `dst` - The output mask, `true` where the input mask is not set.
+///
+/// PRECONDITION: `src` and `dst` must have the same size.
+pub fn mask_not<A1: ImageAllocator, A2: ImageAllocator>(
+    src: &Image<bool, 1, A1>,
+    dst: &mut Image<bool, 1, A2>,
+) -> Result<(), ImageError> {
+    check_same_size(src, dst)?;
+
+    parallel::par_iter_rows_val(src, dst, |&src_pixel, dst_pixel| {
+        *dst_pixel = !src_pixel;
+    });
+
+    Ok(())
+}
+
+/// Collect the pixels of an image where a boolean mask is set, in row-major order.
+///
+/// # Arguments
+///
+/// * `src` - The input image.
+/// * `mask` - The mask selecting the pixels.
+///
+/// # Returns
+///
+/// The selected pixels with all their channels.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_image::allocator::CpuAllocator;
+/// use kornia_imgproc::mask::masked_select;
+///
+/// let size = ImageSize { width: 2, height: 1 };
+/// let image = Image::<u8, 3, _>::new(size, vec![1, 2, 3, 4, 5, 6], CpuAllocator).unwrap();
+/// let mask = Image::<bool, 1, _>::new(size, vec![false, true], CpuAllocator).unwrap();
+///
+/// let pixels = masked_select(&image, &mask).unwrap();
+/// assert_eq!(pixels, vec![[4, 5, 6]]);
+/// ```
+pub fn masked_select<T, const C: usize, A1: ImageAllocator, A2: ImageAllocator>(
+    src: &Image<T, C, A1>,
+    mask: &Image<bool, 1, A2>,
+) -> Result<Vec<[T; C]>, ImageError>
+where
+    T: Copy + Default,
+{
+    check_same_size(src, mask)?;
+
+    let pixels = src
+        .as_slice()
+        .chunks_exact(C)
+        .zip(mask.as_slice())
+        .filter(|(_, is_set)| **is_set)
+        .map(|(pixel, _)| {
+            let mut value = [T::default(); C];
+            value.copy_from_slice(pixel);
+            value
+        })
+        .collect();
+
+    Ok(pixels)
+}
+
+/// Apply a logical operation to each pair of pixels of two boolean masks.
+fn combine_masks<A1: ImageAllocator, A2: ImageAllocator, A3: ImageAllocator>(
+    src1: &Image<bool, 1, A1>,
+    src2: &Image<bool, 1, A2>,
+    dst: &mut Image<bool, 1, A3>,
+    op: impl Fn(bool, bool) -> bool + Send + Sync,
+) -> Result<(), ImageError> {
+    check_same_size(src1, src2)?;
+    check_same_size(src1, dst)?;
+
+    parallel::par_iter_rows_val_two(src1, src2, dst, |&a, &b, dst_pixel| {
+        *dst_pixel = op(a, b);
+    });
+
+    Ok(())
+}
+
+fn check_same_size<T1, const C1: usize, A1, T2, const C2: usize, A2>(
+    src: &Image<T1, C1, A1>,
+    dst: &Image<T2, C2, A2>,
+) -> Result<(), ImageError>
+where
+    A1: ImageAllocator,
+    A2: ImageAllocator,
+{
+    if src.size() != dst.size() {
+        return Err(ImageError::InvalidImageSize(
+            src.cols(),
+            src.rows(),
+            dst.cols(),
+            dst.rows(),
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use kornia_image::{Image, ImageError, ImageSize};
+    use kornia_tensor::CpuAllocator;
+
+    #[test]
+    fn test_mask_combinators() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 4,
+            height: 2,
+        };
+        let image =
+            Image::<u8, 1, _>::new(size, vec![0, 50, 100, 150, 200, 250, 30, 120], CpuAllocator)?;
+
+        let mut above = Image::<bool, 1, _>::from_size_val(size, false, CpuAllocator)?;
+        super::threshold_mask(&image, &mut above, 40)?;
+
+        let mut below = Image::<bool, 1, _>::from_size_val(size, false, CpuAllocator)?;
+        let mut not_below = Image::<bool, 1, _>::from_size_val(size, false, CpuAllocator)?;
+        super::threshold_mask(&image, &mut not_below, 160)?;
+        super::mask_not(&not_below, &mut below)?;
+
+        // pixels in the range (40, 160]
+        let mut band = Image::<bool, 1, _>::from_size_val(size, false, CpuAllocator)?;
+        super::mask_and(&above, &below, &mut band)?;
+        assert_eq!(
+            band.as_slice(),
+            &[false, true, true, true, false, false, false, true]
+        );
+
+        let mut union = Image::<bool, 1, _>::from_size_val(size, false, CpuAllocator)?;
+        super::mask_or(&above, &below, &mut union)?;
+        assert!(union.as_slice().iter().all(|&v| v));
+
+        let mut outside = Image::<bool, 1, _>::from_size_val(size, false, CpuAllocator)?;
+        super::mask_xor(&above, &below, &mut outside)?;
+        assert_eq!(
+            outside.as_slice(),
+            &[true, false, false, false, true, true, true, false]
+        );
+
+        let mut band_u8 = Image::<u8, 1, _>::from_size_val(size, 0, CpuAllocator)?;
+        super::mask_to_u8(&band, &mut band_u8)?;
+        assert_eq!(band_u8.as_slice(), &[0, 255, 255, 255, 0, 0, 0, 255]);
+
+        let mut roundtrip = Image::<bool, 1, _>::from_size_val(size, false, CpuAllocator)?;
+        super::mask_from_u8(&band_u8, &mut roundtrip)?;
+        assert_eq!(roundtrip.as_slice(), band.as_slice());
+
+        let pixels = super::masked_select(&image, &band)?;
+        assert_eq!(pixels, vec![[50], [100], [150], [120]]);
+
+        let small = Image::<bool, 1, _>::from_size_val([2, 2].into(), false, CpuAllocator)?;
+        assert!(super::mask_and(&above, &small, &mut band).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,179 @@
+use kornia_image::{allocator::ImageAllocator, Image};
+
+/// The spatial and central moments of a binary image up to the second order.
+///
+/// The central moments are taken with respect to the centroid of the shape.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Moments {
+    /// Spatial moment m00, i.e. the area of the shape.
+    pub m00: f64,
+    /// Spatial moment m10.
+    pub m10: f64,
+    /// Spatial moment m01.
+    pub m01: f64,
+    /// Spatial moment m20.
+    pub m20: f64,
+    /// Spatial moment m11.
+    pub m11: f64,
+    /// Spatial moment m02.
+    pub m02: f64,
+    /// Central moment mu20.
+    pub mu20: f64,
+    /// Central moment mu11.
+    pub mu11: f64,
+    /// Central moment mu02.
+    pub mu02: f64,
+}
+
+impl Moments {
+    /// Get the centroid of the shape as (x, y).
+    ///
+    /// Returns `None` if the image has no foreground pixels.
+    pub fn centroid(&self) -> Option<(f64, f64)> {
+        if self.m00 == 0.0 {
+            return None;
+        }
+        Some((self.m10 / self.m00, self.m01 / self.m00))
+    }
+
+    /// Get the orientation of the major axis of the shape in radians.
+    ///
+    /// The angle is computed from the second order central moments and lies in the range
+    /// [-pi/2, pi/2], measured from the x axis towards the y axis.
+    pub fn orientation(&self) -> f64 {
+        0.5 * (2.0 * self.mu11).atan2(self.mu20 - self.mu02)
+    }
+}
+
+/// Compute the moments of a binary image.
+///
+/// All the non-zero pixels are treated as foreground with unit weight.
+///
+/// # Arguments
+///
+/// * `src` - The input binary image.
+///
+/// # Returns
+///
+/// The spatial and central moments of the foreground, all zero for an image without pixels.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_image::allocator::CpuAllocator;
+/// use kornia_imgproc::moments::moments;
+///
+/// let image = Image::<u8, 1, _>::new(
+///     ImageSize {
+///         width: 3,
+///         height: 2,
+///     },
+///     vec![0, 255, 255, 0, 255, 255],
+///     CpuAllocator,
+/// ).unwrap();
+///
+/// let m = moments(&image);
+/// assert_eq!(m.m00, 4.0);
+/// assert_eq!(m.centroid(), Some((1.5, 0.5)));
+/// ```
+pub fn moments<A: ImageAllocator>(src: &Image<u8, 1, A>) -> Moments {
+    let mut m = Moments::default();
+    if src.cols() == 0 {
+        return m;
+    }
+
+    src.as_slice()
+        .chunks_exact(src.cols())
+        .enumerate()
+        .for_each(|(y, row)| {
+            let y = y as f64;
+            row.iter()
+                .enumerate()
+                .filter(|(_, &val)| val != 0)
+                .for_each(|(x, _)| {
+                    let x = x as f64;
+                    m.m00 += 1.0;
+                    m.m10 += x;
+                    m.m01 += y;
+                    m.m20 += x * x;
+                    m.m11 += x * y;
+                    m.m02 += y * y;
+                });
+        });
+
+    if let Some((cx, cy)) = m.centroid() {
+        m.mu20 = m.m20 - cx * m.m10;
+        m.mu11 = m.m11 - cx * m.m01;
+        m.mu02 = m.m02 - cy * m.m01;
+    }
+
+    m
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kornia_image::{ImageError, ImageSize};
+    use kornia_tensor::CpuAllocator;
+
+    #[test]
+    fn test_moments_rectangle() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 10,
+            height: 8,
+        };
+
+        // filled rectangle spanning x in [2, 7] and y in [3, 4]
+        let data = (0..size.height)
+            .flat_map(|y| {
+                (0..size.width).map(move |x| {
+                    if (2..=7).contains(&x) && (3..=4).contains(&y) {
+                        255
+                    } else {
+                        0
+                    }
+                })
+            })
+            .collect::<Vec<u8>>();
+        let image = Image::<u8, 1, _>::new(size, data, CpuAllocator)?;
+
+        let m = moments(&image);
+        assert_eq!(m.m00, 12.0);
+        assert_eq!(m.centroid(), Some((4.5, 3.5)));
+
+        // the rectangle is wider than tall so the major axis is horizontal
+        assert!(m.mu20 > m.mu02);
+        assert!(m.mu11.abs() < 1e-9);
+        assert!(m.orientation().abs() < 1e-9);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_moments_diagonal() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 5,
+            height: 5,
+        };
+
+        let data = (0..25)
+            .map(|i| if i % 5 == i / 5 { 1 } else { 0 })
+            .collect::<Vec<u8>>();
+        let image = Image::<u8, 1, _>::new(size, data, CpuAllocator)?;
+
+        let m = moments(&image);
+        assert_eq!(m.centroid(), Some((2.0, 2.0)));
+        assert!((m.orientation() - std::f64::consts::FRAC_PI_4).abs() < 1e-9);
+
+        // empty image has no centroid
+        let empty = Image::<u8, 1, _>::from_size_val(size, 0, CpuAllocator)?;
+        assert_eq!(moments(&empty).centroid(), None);
+
+        // an image without pixels has zero moments
+        let empty = Image::<u8, 1, _>::from_size_val([0, 3].into(), 0, CpuAllocator)?;
+        assert_eq!(moments(&empty), Moments::default());
+
+        Ok(())
+    }
+}
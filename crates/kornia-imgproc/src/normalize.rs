@@ -1,6 +1,6 @@
 use num_traits::Float;
 
-use kornia_image::{allocator::ImageAllocator, Image, ImageError};
+use kornia_image::{allocator::ImageAllocator, Image, ImageBatch, ImageError};
 
 use crate::parallel;
 
@@ -87,6 +87,32 @@ where
     Ok(())
 }
 
+/// Normalize each image of a batch using the mean and standard deviation.
+///
+/// The images are normalized one by one with [`normalize_mean_std`].
+///
+/// # Arguments
+///
+/// * `src` - The input batch of images.
+/// * `dst` - The output batch, with the same number of images and image size.
+/// * `mean` - The mean value for each channel.
+/// * `std` - The standard deviation for each channel.
+///
+/// # Errors
+///
+/// Returns an error if the batches do not have the same number of images or image size.
+pub fn normalize_mean_std_batch<T, const C: usize>(
+    src: &ImageBatch<T, C>,
+    dst: &mut ImageBatch<T, C>,
+    mean: &[T; C],
+    std: &[T; C],
+) -> Result<(), ImageError>
+where
+    T: Send + Sync + Float,
+{
+    src.apply(dst, |src, dst| normalize_mean_std(src, dst, mean, std))
+}
+
 /// Find the minimum and maximum values in an image.
 ///
 /// # Arguments
@@ -230,9 +256,28 @@ where
 
 #[cfg(test)]
 mod tests {
-    use kornia_image::{Image, ImageError, ImageSize};
+    use kornia_image::{Image, ImageBatch, ImageError, ImageSize};
     use kornia_tensor::CpuAllocator;
 
+    #[test]
+    fn normalize_mean_std_batch() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 2,
+            height: 1,
+        };
+        let mut batch = ImageBatch::<f32, 2>::new(size);
+        batch.push(&Image::new(size, vec![1.0, 2.0, 3.0, 4.0], CpuAllocator)?)?;
+        batch.push(&Image::new(size, vec![0.0, 0.0, 2.0, 8.0], CpuAllocator)?)?;
+
+        let mut normalized = ImageBatch::from_size_val(size, 2, 0.0);
+        super::normalize_mean_std_batch(&batch, &mut normalized, &[1.0, 2.0], &[2.0, 4.0])?;
+
+        assert_eq!(normalized.get(0)?.as_slice(), &[0.0, 0.0, 1.0, 0.5]);
+        assert_eq!(normalized.get(1)?.as_slice(), &[-0.5, -0.5, 0.5, 1.5]);
+
+        Ok(())
+    }
+
     #[test]
     fn normalize_mean_std() -> Result<(), ImageError> {
         let image_data = vec![
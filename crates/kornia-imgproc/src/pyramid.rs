@@ -1,40 +1,180 @@
-use crate::filter::separable_filter;
-use crate::interpolation::InterpolationMode;
-use crate::resize::resize_native;
+use crate::filter::{border_index, BorderType};
 use kornia_image::{allocator::ImageAllocator, Image, ImageError};
-use kornia_tensor::CpuAllocator;
-
-fn get_pyramid_gaussian_kernel() -> (Vec<f32>, Vec<f32>) {
-    // The 2D kernel is:
-    // [
-    //   [1.0, 4.0, 6.0, 4.0, 1.0],
-    //   [4.0, 16.0, 24.0, 16.0, 4.0],
-    //   [6.0, 24.0, 36.0, 24.0, 6.0],
-    //   [4.0, 16.0, 24.0, 16.0, 4.0],
-    //   [1.0, 4.0, 6.0, 4.0, 1.0],
-    // ] / 256.0
-
-    let kernel_x = [1.0, 4.0, 6.0, 4.0, 1.0]
-        .iter()
-        .map(|&x| x / 16.0)
-        .collect();
-    let kernel_y = [1.0, 4.0, 6.0, 4.0, 1.0]
-        .iter()
-        .map(|&x| x / 16.0)
-        .collect();
-
-    (kernel_x, kernel_y)
+use rayon::prelude::*;
+
+/// The 5-tap binomial kernel of the Gaussian pyramid, the 2D kernel is its outer product / 256.
+const PYRAMID_KERNEL: [f32; 5] = [1.0, 4.0, 6.0, 4.0, 1.0];
+
+/// The source pixels and weights to compute each output pixel along one axis.
+type Taps = Vec<Vec<(usize, f32)>>;
+
+/// Compute the taps to blur and then take every other pixel along an axis of length `src_len`.
+fn pyrdown_taps(src_len: usize, dst_len: usize) -> Taps {
+    (0..dst_len)
+        .map(|x| {
+            PYRAMID_KERNEL
+                .iter()
+                .enumerate()
+                .filter_map(|(k, &w)| {
+                    let i = (2 * x + k) as isize - 2;
+                    border_index(i, src_len, BorderType::Reflect101).map(|i| (i, w / 16.0))
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/// Compute the taps to insert zeros between the pixels and then blur along an axis.
+///
+/// The weights are doubled to preserve the brightness of the zero interleaved signal. As in
+/// OpenCV, the left border is reflected and the right border replicated.
+fn pyrup_taps(src_len: usize, dst_len: usize) -> Taps {
+    let index = |i: isize| {
+        if i < 0 {
+            border_index(i, src_len, BorderType::Reflect101).unwrap_or(0)
+        } else {
+            (i as usize).min(src_len - 1)
+        }
+    };
+
+    (0..dst_len)
+        .map(|x| {
+            let i = (x / 2) as isize;
+            if x % 2 == 0 {
+                vec![
+                    (index(i - 1), PYRAMID_KERNEL[0] / 8.0),
+                    (index(i), PYRAMID_KERNEL[2] / 8.0),
+                    (index(i + 1), PYRAMID_KERNEL[4] / 8.0),
+                ]
+            } else {
+                vec![
+                    (index(i), PYRAMID_KERNEL[1] / 8.0),
+                    (index(i + 1), PYRAMID_KERNEL[3] / 8.0),
+                ]
+            }
+        })
+        .collect()
+}
+
+/// Apply the taps along the columns and then along the rows of the image.
+fn resample_separable<const C: usize, A1: ImageAllocator, A2: ImageAllocator>(
+    src: &Image<f32, C, A1>,
+    dst: &mut Image<f32, C, A2>,
+    x_taps: &Taps,
+    y_taps: &Taps,
+) {
+    let (src_cols, dst_cols) = (src.cols(), dst.cols());
+
+    // horizontal pass on all the source rows
+    let mut tmp = vec![0.0; src.rows() * dst_cols * C];
+    tmp.par_chunks_exact_mut(dst_cols * C)
+        .zip(src.as_slice().par_chunks_exact(src_cols * C))
+        .for_each(|(tmp_row, src_row)| {
+            tmp_row
+                .chunks_exact_mut(C)
+                .zip(x_taps)
+                .for_each(|(tmp_pixel, taps)| {
+                    for &(i, w) in taps {
+                        for (t, &s) in tmp_pixel.iter_mut().zip(&src_row[i * C..(i + 1) * C]) {
+                            *t += w * s;
+                        }
+                    }
+                });
+        });
+
+    // vertical pass
+    dst.as_slice_mut()
+        .par_chunks_exact_mut(dst_cols * C)
+        .zip(y_taps.par_iter())
+        .for_each(|(dst_row, taps)| {
+            dst_row.iter_mut().for_each(|d| *d = 0.0);
+            for &(i, w) in taps {
+                let tmp_row = &tmp[i * dst_cols * C..(i + 1) * dst_cols * C];
+                for (d, &t) in dst_row.iter_mut().zip(tmp_row) {
+                    *d += w * t;
+                }
+            }
+        });
+}
+
+/// Blur an image and then downsample it.
+///
+/// This function applies the 5x5 Gaussian kernel of the pyramid with a reflected border and
+/// keeps every other pixel, as `cv2.pyrDown`. The size of the output is half the size of the
+/// input rounded up, so odd sizes keep their last row and column.
+///
+/// # Arguments
+///
+/// * `src` - The source image to be downsampled.
+/// * `dst` - The destination image with size `((width + 1) / 2, (height + 1) / 2)`.
+///
+/// # Returns
+///
+/// * `Result<(), ImageError>` - Ok if successful, Err otherwise.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_image::allocator::CpuAllocator;
+/// use kornia_imgproc::pyramid::pyrdown;
+///
+/// let image = Image::<f32, 1, _>::from_size_val(
+///     ImageSize {
+///         width: 5,
+///         height: 4,
+///     },
+///     1.0,
+///     CpuAllocator
+/// ).unwrap();
+///
+/// let mut downsampled = Image::<f32, 1, _>::from_size_val(
+///     ImageSize {
+///         width: 3,
+///         height: 2,
+///     },
+///     0.0,
+///     CpuAllocator
+/// ).unwrap();
+///
+/// pyrdown(&image, &mut downsampled).unwrap();
+/// assert_eq!(downsampled.as_slice(), &[1.0; 6]);
+/// ```
+pub fn pyrdown<const C: usize, A1: ImageAllocator, A2: ImageAllocator>(
+    src: &Image<f32, C, A1>,
+    dst: &mut Image<f32, C, A2>,
+) -> Result<(), ImageError> {
+    let expected_width = src.width().div_ceil(2);
+    let expected_height = src.height().div_ceil(2);
+
+    if dst.width() != expected_width || dst.height() != expected_height {
+        return Err(ImageError::InvalidImageSize(
+            expected_width,
+            expected_height,
+            dst.width(),
+            dst.height(),
+        ));
+    }
+
+    let x_taps = pyrdown_taps(src.width(), dst.width());
+    let y_taps = pyrdown_taps(src.height(), dst.height());
+    resample_separable(src, dst, &x_taps, &y_taps);
+
+    Ok(())
 }
 
 /// Upsample an image and then blur it.
 ///
-/// This function doubles the size of the input image using bilinear interpolation
-/// and then applies a Gaussian blur to smooth the result.
+/// This function doubles the size of the input image by inserting zeros between the pixels
+/// and then applies the 5x5 Gaussian kernel of the pyramid multiplied by 4, as `cv2.pyrUp`.
+/// The output can be one pixel smaller than twice the input along each axis, to restore the
+/// odd sizes halved by [`pyrdown`].
 ///
 /// # Arguments
 ///
 /// * `src` - The source image to be upsampled.
-/// * `dst` - The destination image to store the result.
+/// * `dst` - The destination image with size `(2 * width, 2 * height)`, or one less along
+///   any axis.
 ///
 /// # Returns
 ///
@@ -74,7 +214,11 @@ pub fn pyrup<const C: usize, A1: ImageAllocator, A2: ImageAllocator>(
     let expected_width = src.width() * 2;
     let expected_height = src.height() * 2;
 
-    if dst.width() != expected_width || dst.height() != expected_height {
+    if src.width() == 0
+        || src.height() == 0
+        || !(expected_width - 1..=expected_width).contains(&dst.width())
+        || !(expected_height - 1..=expected_height).contains(&dst.height())
+    {
         return Err(ImageError::InvalidImageSize(
             expected_width,
             expected_height,
@@ -83,12 +227,9 @@ pub fn pyrup<const C: usize, A1: ImageAllocator, A2: ImageAllocator>(
         ));
     }
 
-    let mut upsampled = Image::<f32, C, _>::from_size_val(dst.size(), 0.0, CpuAllocator)?;
-
-    resize_native(src, &mut upsampled, InterpolationMode::Bilinear)?;
-
-    let (kernel_x, kernel_y) = get_pyramid_gaussian_kernel();
-    separable_filter(&upsampled, dst, &kernel_x, &kernel_y)?;
+    let x_taps = pyrup_taps(src.width(), dst.width());
+    let y_taps = pyrup_taps(src.height(), dst.height());
+    resample_separable(src, dst, &x_taps, &y_taps);
 
     Ok(())
 }
@@ -97,6 +238,7 @@ pub fn pyrup<const C: usize, A1: ImageAllocator, A2: ImageAllocator>(
 mod tests {
     use super::*;
     use kornia_image::ImageSize;
+    use kornia_tensor::CpuAllocator;
 
     #[test]
     fn test_pyrup() -> Result<(), ImageError> {
@@ -129,4 +271,64 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_pyrdown_pyrup() -> Result<(), ImageError> {
+        // smooth image with odd sizes
+        let size = ImageSize {
+            width: 33,
+            height: 25,
+        };
+        let data = (0..size.width * size.height)
+            .flat_map(|i| {
+                let (x, y) = ((i % size.width) as f32, (i / size.width) as f32);
+                [(x / 16.0).sin() + (y / 16.0).cos(), 0.02 * x + 0.01 * y]
+            })
+            .collect();
+        let src = Image::<f32, 2, _>::new(size, data, CpuAllocator)?;
+
+        let mut down = Image::<f32, 2, _>::from_size_val([17, 13].into(), 0.0, CpuAllocator)?;
+        pyrdown(&src, &mut down)?;
+
+        // the 5x5 kernel centered on the even pixels, away from the borders
+        let expected = |x: usize, y: usize, c: usize| {
+            let k = [1.0, 4.0, 6.0, 4.0, 1.0];
+            let mut sum = 0.0;
+            for (j, ky) in k.iter().enumerate() {
+                for (i, kx) in k.iter().enumerate() {
+                    let (sx, sy) = (2 * x + i - 2, 2 * y + j - 2);
+                    sum += kx * ky * src.as_slice()[(sy * size.width + sx) * 2 + c];
+                }
+            }
+            sum / 256.0
+        };
+        let at = |img: &Image<f32, 2, CpuAllocator>, x: usize, y: usize, c: usize| {
+            img.as_slice()[(y * img.width() + x) * 2 + c]
+        };
+        assert!((at(&down, 5, 4, 0) - expected(5, 4, 0)).abs() < 1e-5);
+        assert!((at(&down, 8, 6, 1) - expected(8, 6, 1)).abs() < 1e-5);
+
+        let mut wrong = Image::<f32, 2, _>::from_size_val([16, 13].into(), 0.0, CpuAllocator)?;
+        assert!(pyrdown(&src, &mut wrong).is_err());
+
+        // upsampling back to the odd size roughly inverts the downsampling
+        let mut up = Image::<f32, 2, _>::from_size_val(size, 0.0, CpuAllocator)?;
+        pyrup(&down, &mut up)?;
+        // the borders are extrapolated, compare the interior only
+        for y in 2..size.height - 2 {
+            for x in 2..size.width - 2 {
+                for c in 0..2 {
+                    assert!((at(&up, x, y, c) - at(&src, x, y, c)).abs() < 0.02);
+                }
+            }
+        }
+
+        // a constant image is preserved
+        let ones = Image::<f32, 1, _>::from_size_val([3, 3].into(), 1.0, CpuAllocator)?;
+        let mut up = Image::<f32, 1, _>::from_size_val([6, 5].into(), 0.0, CpuAllocator)?;
+        pyrup(&ones, &mut up)?;
+        assert!(up.as_slice().iter().all(|v| (v - 1.0).abs() < 1e-6));
+
+        Ok(())
+    }
 }
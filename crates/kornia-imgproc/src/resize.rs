@@ -1,14 +1,20 @@
 use crate::{
-    interpolation::{grid::meshgrid_from_fn, interpolate_pixel, InterpolationMode},
+    filter::{border_index, BorderType},
+    interpolation::{
+        check_interpolation, grid::meshgrid_from_fn, interpolate_pixel, InterpolationMode,
+    },
     parallel,
 };
 use fast_image_resize::{self as fr};
-use kornia_image::{allocator::ImageAllocator, Image, ImageError};
+use kornia_image::{allocator::ImageAllocator, Image, ImageBatch, ImageError, ImageSize};
+use rayon::prelude::*;
 
 /// Resize an image to a new size.
 ///
 /// The function resizes an image to a new size using the specified interpolation mode.
-/// It supports any number of channels and data types.
+/// It supports any number of channels and data types. The centers of the corner pixels of both
+/// images are aligned, as PyTorch with `align_corners=True` does; use [`resize_separable`] to
+/// choose the convention.
 ///
 /// # Arguments
 ///
@@ -20,6 +26,12 @@ use kornia_image::{allocator::ImageAllocator, Image, ImageError};
 ///
 /// The resized image with the new size.
 ///
+/// # Errors
+///
+/// Returns an error if the input image is empty, or [`ImageError::InvalidSize`] if the output
+/// image has a zero width or height. Returns [`ImageError::UnsupportedInterpolation`] for the
+/// [`InterpolationMode::Lanczos`] and [`InterpolationMode::Bicubic`] modes.
+///
 /// # Example
 ///
 /// ```
@@ -56,6 +68,10 @@ use kornia_image::{allocator::ImageAllocator, Image, ImageError};
 /// assert_eq!(image_resized.size().width, 2);
 /// assert_eq!(image_resized.size().height, 3);
 /// ```
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(skip_all, fields(width = src.width(), height = src.height(), channels = C))
+)]
 pub fn resize_native<const C: usize, A1: ImageAllocator, A2: ImageAllocator>(
     src: &Image<f32, C, A1>,
     dst: &mut Image<f32, C, A2>,
@@ -63,6 +79,8 @@ pub fn resize_native<const C: usize, A1: ImageAllocator, A2: ImageAllocator>(
 ) -> Result<(), ImageError>
 where
 {
+    check_resize_sizes(src.size(), dst.size())?;
+
     // check if the input and output images have the same size
     // and copy the input image to the output image if they have the same size
     if src.size() == dst.size() {
@@ -70,11 +88,20 @@ where
         return Ok(());
     }
 
+    // area interpolation integrates over the source pixels instead of sampling them
+    if interpolation == InterpolationMode::Area {
+        resize_with_weights(src, dst, interpolation, false, BorderType::Replicate);
+        return Ok(());
+    }
+
+    check_interpolation(interpolation)?;
+
     // create a grid of x and y coordinates for the output image
     // and interpolate the values from the input image.
     let (dst_rows, dst_cols) = (dst.rows(), dst.cols());
-    let step_x = (src.cols() - 1) as f32 / (dst.cols() - 1) as f32;
-    let step_y = (src.rows() - 1) as f32 / (dst.rows() - 1) as f32;
+    // a single output pixel samples the first source pixel
+    let step_x = (src.cols() - 1) as f32 / (dst.cols() - 1).max(1) as f32;
+    let step_y = (src.rows() - 1) as f32 / (dst.rows() - 1).max(1) as f32;
     let (map_x, map_y) = meshgrid_from_fn(dst_cols, dst_rows, |x, y| {
         Ok((x as f32 * step_x, y as f32 * step_y))
     })?;
@@ -90,6 +117,509 @@ where
     Ok(())
 }
 
+/// Choose an interpolation mode to resize an image between two sizes.
+///
+/// Downscaling uses [`InterpolationMode::Area`] to avoid aliasing and upscaling uses
+/// [`InterpolationMode::Bilinear`]. When one axis is upscaled and the other downscaled,
+/// the choice follows the total number of pixels: area if the image shrinks, bilinear otherwise.
+///
+/// # Arguments
+///
+/// * `src_size` - The size of the input image.
+/// * `dst_size` - The size of the output image.
+///
+/// # Returns
+///
+/// The interpolation mode to use for the resize operation.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::ImageSize;
+/// use kornia_imgproc::interpolation::InterpolationMode;
+/// use kornia_imgproc::resize::auto_interpolation_mode;
+///
+/// let src = ImageSize { width: 8, height: 8 };
+/// let small = ImageSize { width: 4, height: 4 };
+///
+/// assert_eq!(auto_interpolation_mode(src, small), InterpolationMode::Area);
+/// assert_eq!(auto_interpolation_mode(small, src), InterpolationMode::Bilinear);
+/// ```
+pub fn auto_interpolation_mode(src_size: ImageSize, dst_size: ImageSize) -> InterpolationMode {
+    let downscale = dst_size.width < src_size.width || dst_size.height < src_size.height;
+    let upscale = dst_size.width > src_size.width || dst_size.height > src_size.height;
+
+    match (downscale, upscale) {
+        (true, false) => InterpolationMode::Area,
+        (false, true) => InterpolationMode::Bilinear,
+        // mixed scaling: decide by the overall change in the number of pixels
+        (true, true) if dst_size.width * dst_size.height < src_size.width * src_size.height => {
+            InterpolationMode::Area
+        }
+        _ => InterpolationMode::Bilinear,
+    }
+}
+
+/// Compute the size of a preview of an image, e.g. a thumbnail of a captured frame in a UI.
+///
+/// The preview is the largest size with the aspect ratio of `size` whose width and height are
+/// within `max_dim` pixels. Images already within `max_dim` keep their size, and the dimensions
+/// of the preview are at least 1.
+///
+/// # Arguments
+///
+/// * `size` - The size of the image.
+/// * `max_dim` - The maximum width and height of the preview.
+///
+/// # Returns
+///
+/// The size of the preview.
+///
+/// # Errors
+///
+/// Returns [`ImageError::InvalidSize`] if `max_dim` is zero.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_image::allocator::CpuAllocator;
+/// use kornia_imgproc::interpolation::InterpolationMode;
+/// use kornia_imgproc::resize::{preview_size, resize_fast_rgb};
+///
+/// let frame = Image::<u8, 3, _>::from_size_val([1920, 1080].into(), 0, CpuAllocator).unwrap();
+///
+/// let size = preview_size(frame.size(), 320).unwrap();
+/// assert_eq!(size, ImageSize { width: 320, height: 180 });
+///
+/// let mut preview = Image::<u8, 3, _>::from_size_val(size, 0, CpuAllocator).unwrap();
+/// resize_fast_rgb(&frame, &mut preview, InterpolationMode::Area).unwrap();
+/// ```
+pub fn preview_size(size: ImageSize, max_dim: usize) -> Result<ImageSize, ImageError> {
+    if max_dim == 0 {
+        return Err(ImageError::InvalidSize(max_dim, max_dim));
+    }
+
+    let largest = size.width.max(size.height);
+    if largest <= max_dim {
+        return Ok(size);
+    }
+
+    let scale = |dim: usize| ((dim * max_dim + largest / 2) / largest).max(1);
+    Ok(ImageSize {
+        width: scale(size.width),
+        height: scale(size.height),
+    })
+}
+
+/// Resize an image to a new size choosing the interpolation mode automatically.
+///
+/// The interpolation mode is selected with [`auto_interpolation_mode`]: area for downscaling
+/// and bilinear for upscaling.
+///
+/// # Arguments
+///
+/// * `src` - The input image container.
+/// * `dst` - The output image container.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_image::allocator::CpuAllocator;
+/// use kornia_imgproc::resize::resize_auto;
+///
+/// let image = Image::<f32, 1, _>::from_size_val([4, 4].into(), 1.0, CpuAllocator).unwrap();
+/// let mut image_resized = Image::<f32, 1, _>::from_size_val([2, 2].into(), 0.0, CpuAllocator).unwrap();
+///
+/// resize_auto(&image, &mut image_resized).unwrap();
+///
+/// assert_eq!(image_resized.as_slice(), &[1.0; 4]);
+/// ```
+pub fn resize_auto<const C: usize, A1: ImageAllocator, A2: ImageAllocator>(
+    src: &Image<f32, C, A1>,
+    dst: &mut Image<f32, C, A2>,
+) -> Result<(), ImageError> {
+    let interpolation = auto_interpolation_mode(src.size(), dst.size());
+    resize_native(src, dst, interpolation)
+}
+
+/// Resize each image of a batch to the image size of the destination batch.
+///
+/// The images are resized one by one with [`resize_native`].
+///
+/// # Arguments
+///
+/// * `src` - The input batch of images.
+/// * `dst` - The output batch, with the same number of images and the new size.
+/// * `interpolation` - The interpolation mode to use.
+///
+/// # Errors
+///
+/// Returns an error if the batches do not have the same number of images, or the resize of an
+/// image fails.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageBatch};
+/// use kornia_image::allocator::CpuAllocator;
+/// use kornia_imgproc::interpolation::InterpolationMode;
+/// use kornia_imgproc::resize::resize_batch;
+///
+/// let mut batch = ImageBatch::<f32, 1>::new([4, 4].into());
+/// batch.push(&Image::from_size_val([4, 4].into(), 1.0, CpuAllocator).unwrap()).unwrap();
+///
+/// let mut resized = ImageBatch::<f32, 1>::from_size_val([2, 2].into(), batch.len(), 0.0);
+/// resize_batch(&batch, &mut resized, InterpolationMode::Bilinear).unwrap();
+///
+/// assert_eq!(resized.get(0).unwrap().as_slice(), &[1.0; 4]);
+/// ```
+pub fn resize_batch<const C: usize>(
+    src: &ImageBatch<f32, C>,
+    dst: &mut ImageBatch<f32, C>,
+    interpolation: InterpolationMode,
+) -> Result<(), ImageError> {
+    src.apply(dst, |src, dst| resize_native(src, dst, interpolation))
+}
+
+/// Compute the interpolation weights to resample an axis from `src_len` to `dst_len` pixels.
+///
+/// Each output pixel `i` is the weighted sum of the source pixels `bases[i]`,
+/// `bases[i] + 1`, ... with the weights in `weights[i]`. The weights of each output pixel sum
+/// to 1. [`InterpolationMode::Area`] averages the source pixels covered by the output pixel,
+/// while the sampling modes map the output pixel `i` to a source coordinate and replicate the
+/// border pixels. The source coordinate depends on `align_corners`, as in PyTorch:
+///
+/// - `false` aligns the outer edges of the images, as OpenCV does: the center of the output
+///   pixel `i` maps to `(i + 0.5) * src_len / dst_len - 0.5`, so the border output pixels
+///   blend the border source pixels with their neighbors.
+/// - `true` aligns the centers of the corner pixels: the output pixel `i` maps to
+///   `i * (src_len - 1) / (dst_len - 1)`, so the border output pixels are exact copies of the
+///   border source pixels.
+///
+/// The bicubic weights use the Keys kernel with `a = -0.75` and the Lanczos weights a
+/// window of 3 pixels, as in OpenCV. The kernels are not stretched when downscaling.
+///
+/// # Arguments
+///
+/// * `src_len` - The number of source pixels along the axis. Must be positive.
+/// * `dst_len` - The number of output pixels along the axis.
+/// * `mode` - The interpolation mode.
+/// * `align_corners` - Whether to align the centers of the corner pixels instead of the outer
+///   edges of the images. Ignored by [`InterpolationMode::Area`].
+///
+/// # Returns
+///
+/// The index of the first source pixel and the weights of each output pixel.
+///
+/// # Example
+///
+/// ```
+/// use kornia_imgproc::interpolation::InterpolationMode;
+/// use kornia_imgproc::resize::compute_resize_weights;
+///
+/// let (bases, weights) = compute_resize_weights(4, 2, InterpolationMode::Area, false);
+/// assert_eq!(bases, vec![0, 2]);
+/// assert_eq!(weights, vec![vec![0.5, 0.5], vec![0.5, 0.5]]);
+///
+/// // the corner output pixels copy the corner source pixels
+/// let (bases, weights) = compute_resize_weights(4, 3, InterpolationMode::Bilinear, true);
+/// assert_eq!((bases[0], weights[0][0]), (0, 1.0));
+/// assert_eq!((bases[2], weights[2][0]), (3, 1.0));
+/// ```
+pub fn compute_resize_weights(
+    src_len: usize,
+    dst_len: usize,
+    mode: InterpolationMode,
+    align_corners: bool,
+) -> (Vec<usize>, Vec<Vec<f32>>) {
+    compute_resize_weights_with_border(src_len, dst_len, mode, align_corners, BorderType::Replicate)
+}
+
+/// Compute the interpolation weights of [`compute_resize_weights`] with a border mode.
+///
+/// The kernels of the sampling modes may reach past the ends of the axis for the border output
+/// pixels. The taps outside of the axis are folded into the source pixels given by `border`:
+/// [`BorderType::Replicate`] adds them to the border pixel, the reflections to the mirrored
+/// pixels, and [`BorderType::Zero`] drops them before the weights are normalized.
+/// [`InterpolationMode::Nearest`] and [`InterpolationMode::Area`] never read outside of the
+/// axis, so they ignore the border.
+///
+/// # Arguments
+///
+/// * `src_len` - The number of source pixels along the axis. Must be positive.
+/// * `dst_len` - The number of output pixels along the axis.
+/// * `mode` - The interpolation mode.
+/// * `align_corners` - Whether to align the centers of the corner pixels instead of the outer
+///   edges of the images. Ignored by [`InterpolationMode::Area`].
+/// * `border` - The extrapolation of the pixels outside of the axis.
+///
+/// # Returns
+///
+/// The index of the first source pixel and the weights of each output pixel.
+///
+/// # Example
+///
+/// ```
+/// use kornia_imgproc::filter::BorderType;
+/// use kornia_imgproc::interpolation::InterpolationMode;
+/// use kornia_imgproc::resize::compute_resize_weights_with_border;
+///
+/// // the first output pixel samples the source at -0.25
+/// let mode = InterpolationMode::Bilinear;
+/// let (bases, weights) = compute_resize_weights_with_border(4, 8, mode, false, BorderType::Replicate);
+/// assert_eq!((bases[0], weights[0].clone()), (0, vec![1.0]));
+///
+/// let (bases, weights) = compute_resize_weights_with_border(4, 8, mode, false, BorderType::Reflect101);
+/// assert_eq!((bases[0], weights[0].clone()), (0, vec![0.75, 0.25]));
+/// ```
+pub fn compute_resize_weights_with_border(
+    src_len: usize,
+    dst_len: usize,
+    mode: InterpolationMode,
+    align_corners: bool,
+    border: BorderType,
+) -> (Vec<usize>, Vec<Vec<f32>>) {
+    if src_len == 0 {
+        return (vec![0; dst_len], vec![Vec::new(); dst_len]);
+    }
+
+    let scale = src_len as f32 / dst_len as f32;
+    let last = src_len as i64 - 1;
+
+    // the source coordinate of the output pixel i for the sampling modes, with the division
+    // last so that the last output pixel maps exactly to the last source pixel
+    let sample_at = |i: usize| {
+        if align_corners {
+            if dst_len > 1 {
+                (i as f32 * last as f32) / (dst_len - 1) as f32
+            } else {
+                0.0
+            }
+        } else {
+            (i as f32 + 0.5) * scale - 0.5
+        }
+    };
+
+    (0..dst_len)
+        .map(|i| {
+            let (base, mut weights) = match mode {
+                InterpolationMode::Nearest => {
+                    let x = ((sample_at(i) + 0.5).max(0.0) as usize).min(src_len - 1);
+                    (x, vec![1.0])
+                }
+                InterpolationMode::Area => {
+                    let start = i as f32 * scale;
+                    let end = start + scale;
+                    let first = (start.floor() as usize).min(src_len - 1);
+                    let weights = (first..(end.ceil() as usize).clamp(first + 1, src_len))
+                        .map(|j| (((j + 1) as f32).min(end) - (j as f32).max(start)).max(0.0))
+                        .collect();
+                    (first, weights)
+                }
+                InterpolationMode::Bilinear
+                | InterpolationMode::Bicubic
+                | InterpolationMode::Lanczos => {
+                    let (radius, kernel): (i64, fn(f32) -> f32) = match mode {
+                        InterpolationMode::Bilinear => (1, linear_kernel),
+                        InterpolationMode::Bicubic => (2, cubic_kernel),
+                        _ => (3, lanczos_kernel),
+                    };
+                    let center = sample_at(i);
+                    let (lo, hi) = (
+                        center.floor() as i64 - radius + 1,
+                        center.floor() as i64 + radius,
+                    );
+
+                    // the taps outside of the axis fold into the pixels given by the border
+                    let taps = (lo..=hi)
+                        .filter_map(|j| {
+                            border_index(j as isize, src_len, border)
+                                .map(|k| (k, kernel(center - j as f32)))
+                        })
+                        .collect::<Vec<_>>();
+                    let base = taps.iter().map(|&(k, _)| k).min().unwrap_or(0);
+                    let end = taps.iter().map(|&(k, _)| k).max().unwrap_or(base);
+                    let mut weights = vec![0.0; end - base + 1];
+                    for (k, w) in taps {
+                        weights[k - base] += w;
+                    }
+                    (base, weights)
+                }
+            };
+
+            let sum = weights.iter().sum::<f32>();
+            if sum != 0.0 {
+                weights.iter_mut().for_each(|w| *w /= sum);
+            }
+            (base, weights)
+        })
+        .unzip()
+}
+
+fn linear_kernel(x: f32) -> f32 {
+    (1.0 - x.abs()).max(0.0)
+}
+
+fn cubic_kernel(x: f32) -> f32 {
+    const A: f32 = -0.75;
+    let x = x.abs();
+    if x < 1.0 {
+        ((A + 2.0) * x - (A + 3.0)) * x * x + 1.0
+    } else if x < 2.0 {
+        ((A * x - 5.0 * A) * x + 8.0 * A) * x - 4.0 * A
+    } else {
+        0.0
+    }
+}
+
+fn lanczos_kernel(x: f32) -> f32 {
+    const A: f32 = 3.0;
+    if x == 0.0 {
+        1.0
+    } else if x.abs() < A {
+        let px = std::f32::consts::PI * x;
+        A * px.sin() * (px / A).sin() / (px * px)
+    } else {
+        0.0
+    }
+}
+
+/// Resize an image with the separable weights of [`compute_resize_weights`].
+///
+/// All the interpolation modes are supported. With `align_corners` set to `false` the outer
+/// edges of both images are aligned, as OpenCV and PyTorch with `align_corners=False` do, and
+/// with `true` the centers of the corner pixels are aligned as [`resize_native`] does. See
+/// [`compute_resize_weights`] for the source coordinates of each convention.
+///
+/// # Arguments
+///
+/// * `src` - The input image container.
+/// * `dst` - The output image container.
+/// * `interpolation` - The interpolation mode to use.
+/// * `align_corners` - Whether to align the centers of the corner pixels.
+///
+/// # Errors
+///
+/// Returns an error if the input image is empty, or [`ImageError::InvalidSize`] if the output
+/// image has a zero width or height.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::Image;
+/// use kornia_image::allocator::CpuAllocator;
+/// use kornia_imgproc::interpolation::InterpolationMode;
+/// use kornia_imgproc::resize::resize_separable;
+///
+/// let image = Image::<f32, 1, _>::new([2, 1].into(), vec![0.0, 4.0], CpuAllocator).unwrap();
+/// let mut resized = Image::<f32, 1, _>::from_size_val([4, 1].into(), 0.0, CpuAllocator).unwrap();
+///
+/// resize_separable(&image, &mut resized, InterpolationMode::Bilinear, false).unwrap();
+/// assert_eq!(resized.as_slice(), &[0.0, 1.0, 3.0, 4.0]);
+///
+/// resize_separable(&image, &mut resized, InterpolationMode::Bilinear, true).unwrap();
+/// assert_eq!((resized.as_slice()[0], resized.as_slice()[3]), (0.0, 4.0));
+/// ```
+pub fn resize_separable<const C: usize, A1: ImageAllocator, A2: ImageAllocator>(
+    src: &Image<f32, C, A1>,
+    dst: &mut Image<f32, C, A2>,
+    interpolation: InterpolationMode,
+    align_corners: bool,
+) -> Result<(), ImageError> {
+    resize_separable_with_border(
+        src,
+        dst,
+        interpolation,
+        align_corners,
+        BorderType::Replicate,
+    )
+}
+
+/// Resize an image as [`resize_separable`] with a border mode for the edge pixels.
+///
+/// The border extrapolates the source pixels read by the kernels past the edges of the image,
+/// see [`compute_resize_weights_with_border`]. [`resize_separable`] replicates the border
+/// pixels.
+///
+/// # Arguments
+///
+/// * `src` - The input image container.
+/// * `dst` - The output image container.
+/// * `interpolation` - The interpolation mode to use.
+/// * `align_corners` - Whether to align the centers of the corner pixels.
+/// * `border` - The extrapolation of the pixels outside of the image.
+///
+/// # Errors
+///
+/// Returns an error if the input image is empty, or [`ImageError::InvalidSize`] if the output
+/// image has a zero width or height.
+pub fn resize_separable_with_border<const C: usize, A1: ImageAllocator, A2: ImageAllocator>(
+    src: &Image<f32, C, A1>,
+    dst: &mut Image<f32, C, A2>,
+    interpolation: InterpolationMode,
+    align_corners: bool,
+    border: BorderType,
+) -> Result<(), ImageError> {
+    check_resize_sizes(src.size(), dst.size())?;
+    resize_with_weights(src, dst, interpolation, align_corners, border);
+    Ok(())
+}
+
+/// Resize an image as the weighted sum of the source pixels of each output pixel.
+fn resize_with_weights<const C: usize, A1: ImageAllocator, A2: ImageAllocator>(
+    src: &Image<f32, C, A1>,
+    dst: &mut Image<f32, C, A2>,
+    interpolation: InterpolationMode,
+    align_corners: bool,
+    border: BorderType,
+) {
+    let (bases_x, weights_x) = compute_resize_weights_with_border(
+        src.cols(),
+        dst.cols(),
+        interpolation,
+        align_corners,
+        border,
+    );
+    let (bases_y, weights_y) = compute_resize_weights_with_border(
+        src.rows(),
+        dst.rows(),
+        interpolation,
+        align_corners,
+        border,
+    );
+
+    let src_cols = src.cols();
+    let src_data = src.as_slice();
+    let dst_cols = dst.cols();
+    if dst_cols == 0 {
+        return;
+    }
+
+    dst.as_slice_mut()
+        .par_chunks_exact_mut(C * dst_cols)
+        .zip(bases_y.par_iter().zip(weights_y.par_iter()))
+        .for_each(|(dst_row, (&base_y, row_weights))| {
+            dst_row
+                .chunks_exact_mut(C)
+                .zip(bases_x.iter().zip(weights_x.iter()))
+                .for_each(|(dst_pixel, (&base_x, col_weights))| {
+                    dst_pixel.fill(0.0);
+                    for (y, &wy) in (base_y..).zip(row_weights) {
+                        for (x, &wx) in (base_x..).zip(col_weights) {
+                            let w = wy * wx;
+                            let offset = (y * src_cols + x) * C;
+                            dst_pixel
+                                .iter_mut()
+                                .zip(&src_data[offset..offset + C])
+                                .for_each(|(d, &s)| *d += w * s);
+                        }
+                    }
+                });
+        });
+}
+
 /// Resize an image to a new size using the [fast_image_resize](https://crates.io/crates/fast_image_resize) crate.
 ///
 /// The function resizes an image to a new size using the specified interpolation mode.
@@ -144,7 +674,12 @@ where
 ///
 /// # Errors
 ///
-/// The function returns an error if the image cannot be resized.
+/// The function returns an error if the image cannot be resized, e.g.
+/// [`ImageError::InvalidSize`] if the output image has a zero width or height.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(skip_all, fields(width = src.width(), height = src.height(), channels = 3))
+)]
 pub fn resize_fast_rgb<A1: ImageAllocator, A2: ImageAllocator>(
     src: &Image<u8, 3, A1>,
     dst: &mut Image<u8, 3, A2>,
@@ -170,7 +705,12 @@ pub fn resize_fast_rgb<A1: ImageAllocator, A2: ImageAllocator>(
 ///
 /// # Errors
 ///
-/// The function returns an error if the image cannot be resized.
+/// The function returns an error if the image cannot be resized, e.g.
+/// [`ImageError::InvalidSize`] if the output image has a zero width or height.
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(skip_all, fields(width = src.width(), height = src.height(), channels = 1))
+)]
 pub fn resize_fast_mono<A1: ImageAllocator, A2: ImageAllocator>(
     src: &Image<u8, 1, A1>,
     dst: &mut Image<u8, 1, A2>,
@@ -184,6 +724,8 @@ fn resize_fast_impl<const C: usize, A1: ImageAllocator, A2: ImageAllocator>(
     dst: &mut Image<u8, C, A2>,
     interpolation: InterpolationMode,
 ) -> Result<(), ImageError> {
+    check_resize_sizes(src.size(), dst.size())?;
+
     // prepare the input image for the fast_image_resize crate
     let (src_cols, src_rows) = (src.cols(), src.rows());
     let src_data_len = src.as_slice().len();
@@ -218,6 +760,7 @@ fn resize_fast_impl<const C: usize, A1: ImageAllocator, A2: ImageAllocator>(
         InterpolationMode::Nearest => fr::ResizeAlg::Nearest,
         InterpolationMode::Lanczos => fr::ResizeAlg::Convolution(fr::FilterType::Lanczos3),
         InterpolationMode::Bicubic => fr::ResizeAlg::Convolution(fr::FilterType::CatmullRom),
+        InterpolationMode::Area => fr::ResizeAlg::Convolution(fr::FilterType::Box),
     };
 
     let mut resizer = fr::Resizer::new();
@@ -228,11 +771,159 @@ fn resize_fast_impl<const C: usize, A1: ImageAllocator, A2: ImageAllocator>(
     Ok(())
 }
 
+/// Resize an image with nearest neighbor sampling that preserves the exact pixel values.
+///
+/// Unlike the interpolating modes, every output pixel is a copy of an input pixel, which makes
+/// this the resize to use for label images and segmentation masks. The pixel centers follow the
+/// half-pixel convention: the output pixel `x` samples the input pixel
+/// `floor((x + 0.5) * src_width / dst_width)`, computed with integer arithmetic so that no
+/// rounding error shifts the sampled pixel.
+///
+/// # Arguments
+///
+/// * `src` - The input image container of any type.
+/// * `dst` - The output image container with the new size.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_image::allocator::CpuAllocator;
+/// use kornia_imgproc::resize::resize_nearest_exact;
+///
+/// let mask = Image::<u8, 1, _>::new(
+///     ImageSize { width: 2, height: 1 },
+///     vec![1, 2],
+///     CpuAllocator,
+/// ).unwrap();
+///
+/// let mut resized = Image::<u8, 1, _>::from_size_val([4, 1].into(), 0, CpuAllocator).unwrap();
+///
+/// resize_nearest_exact(&mask, &mut resized).unwrap();
+/// assert_eq!(resized.as_slice(), &[1, 1, 2, 2]);
+/// ```
+pub fn resize_nearest_exact<T, const C: usize, A1: ImageAllocator, A2: ImageAllocator>(
+    src: &Image<T, C, A1>,
+    dst: &mut Image<T, C, A2>,
+) -> Result<(), ImageError>
+where
+    T: Copy + Send + Sync,
+{
+    check_resize_sizes(src.size(), dst.size())?;
+
+    let (src_cols, src_rows) = (src.cols(), src.rows());
+    let (dst_cols, dst_rows) = (dst.cols(), dst.rows());
+
+    // (x + 0.5) * src / dst = (2 * x + 1) * src / (2 * dst)
+    let src_index = |x: usize, src_len: usize, dst_len: usize| {
+        ((2 * x + 1) * src_len / (2 * dst_len)).min(src_len - 1)
+    };
+    let x_map = (0..dst_cols)
+        .map(|x| src_index(x, src_cols, dst_cols))
+        .collect::<Vec<_>>();
+
+    let src_data = src.as_slice();
+
+    dst.as_slice_mut()
+        .par_chunks_exact_mut(dst_cols * C)
+        .enumerate()
+        .for_each(|(y, dst_row)| {
+            let src_y = src_index(y, src_rows, dst_rows);
+            let src_row = &src_data[src_y * src_cols * C..(src_y + 1) * src_cols * C];
+            dst_row
+                .chunks_exact_mut(C)
+                .zip(x_map.iter())
+                .for_each(|(dst_pixel, &src_x)| {
+                    dst_pixel.copy_from_slice(&src_row[src_x * C..(src_x + 1) * C]);
+                });
+        });
+
+    Ok(())
+}
+
+/// Check that the images to resize between have at least one pixel.
+fn check_resize_sizes(src_size: ImageSize, dst_size: ImageSize) -> Result<(), ImageError> {
+    if src_size.width == 0 || src_size.height == 0 {
+        return Err(ImageError::InvalidImageSize(
+            src_size.width,
+            src_size.height,
+            dst_size.width,
+            dst_size.height,
+        ));
+    }
+
+    if dst_size.width == 0 || dst_size.height == 0 {
+        return Err(ImageError::InvalidSize(dst_size.width, dst_size.height));
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
-    use kornia_image::{Image, ImageError, ImageSize};
+    use super::resize_batch;
+    use crate::interpolation::InterpolationMode;
+    use kornia_image::{Image, ImageBatch, ImageError, ImageSize};
     use kornia_tensor::{CpuAllocator, TensorError};
 
+    #[test]
+    fn preview_size_keeps_aspect_ratio() -> Result<(), ImageError> {
+        let size = |width, height| ImageSize { width, height };
+        assert_eq!(super::preview_size(size(1920, 1080), 320)?, size(320, 180));
+        assert_eq!(super::preview_size(size(1080, 1920), 320)?, size(180, 320));
+        assert_eq!(super::preview_size(size(640, 480), 100)?, size(100, 75));
+        // thin images keep at least one pixel, and small images are not upscaled
+        assert_eq!(super::preview_size(size(1000, 1), 10)?, size(10, 1));
+        assert_eq!(super::preview_size(size(200, 100), 320)?, size(200, 100));
+        assert!(matches!(
+            super::preview_size(size(200, 100), 0),
+            Err(ImageError::InvalidSize(0, 0))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn resize_batch_matches_single_images() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 4,
+            height: 3,
+        };
+        let images = (0..3)
+            .map(|i| {
+                let data = (0..4 * 3 * 2).map(|x| (x + 100 * i) as f32).collect();
+                Image::<f32, 2, _>::new(size, data, CpuAllocator)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut batch = ImageBatch::new(size);
+        for image in &images {
+            batch.push(image)?;
+        }
+
+        let new_size = ImageSize {
+            width: 2,
+            height: 2,
+        };
+        let mut resized = ImageBatch::from_size_val(new_size, 3, 0.0);
+        resize_batch(&batch, &mut resized, InterpolationMode::Bilinear)?;
+        assert_eq!(resized.as_tensor().shape, [3, 2, 2, 2]);
+
+        // each image matches the resize of the single image
+        for (i, image) in images.iter().enumerate() {
+            let mut expected = Image::<f32, 2, _>::from_size_val(new_size, 0.0, CpuAllocator)?;
+            super::resize_native(image, &mut expected, InterpolationMode::Bilinear)?;
+            assert_eq!(resized.get(i)?.as_slice(), expected.as_slice());
+        }
+
+        let mut short = ImageBatch::<f32, 2>::from_size_val(new_size, 2, 0.0);
+        assert!(matches!(
+            resize_batch(&batch, &mut short, InterpolationMode::Bilinear),
+            Err(ImageError::BatchLengthMismatch(3, 2))
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn resize_smoke_ch3() -> Result<(), ImageError> {
         let image = Image::<_, 3, _>::new(
@@ -306,6 +997,286 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn resize_area_downscale() -> Result<(), ImageError> {
+        let image = Image::<_, 1, _>::new(
+            ImageSize {
+                width: 4,
+                height: 4,
+            },
+            (0..16).map(|x| x as f32).collect::<Vec<f32>>(),
+            CpuAllocator,
+        )?;
+
+        let mut image_resized = Image::<_, 1, _>::from_size_val([2, 2].into(), 0.0, CpuAllocator)?;
+        super::resize_native(&image, &mut image_resized, super::InterpolationMode::Area)?;
+
+        // each output pixel is the mean of a 2x2 block
+        assert_eq!(image_resized.as_slice(), [2.5, 4.5, 10.5, 12.5]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn resize_weights() -> Result<(), ImageError> {
+        use super::{compute_resize_weights, InterpolationMode};
+
+        // 2x upscale: the output centers fall at a quarter of the source pixels
+        let (bases, weights) = compute_resize_weights(4, 8, InterpolationMode::Bilinear, false);
+        assert_eq!(bases, vec![0, 0, 0, 1, 1, 2, 2, 3]);
+        assert_eq!(weights[0], vec![1.0]);
+        for (i, w) in weights.iter().enumerate().take(7).skip(1) {
+            let expected = if i % 2 == 1 {
+                vec![0.75, 0.25]
+            } else {
+                vec![0.25, 0.75]
+            };
+            assert_eq!(w, &expected);
+        }
+        assert_eq!(weights[7], vec![1.0]);
+
+        for mode in [
+            InterpolationMode::Nearest,
+            InterpolationMode::Bilinear,
+            InterpolationMode::Bicubic,
+            InterpolationMode::Lanczos,
+            InterpolationMode::Area,
+        ] {
+            for (src_len, dst_len) in [(4, 8), (8, 3), (5, 5), (1, 4), (7, 2), (6, 1)] {
+                for align_corners in [false, true] {
+                    let (bases, weights) =
+                        compute_resize_weights(src_len, dst_len, mode, align_corners);
+                    assert_eq!(bases.len(), dst_len);
+                    for (&base, w) in bases.iter().zip(&weights) {
+                        assert!((w.iter().sum::<f32>() - 1.0).abs() < 1e-5);
+                        assert!(base + w.len() <= src_len);
+                    }
+                }
+            }
+        }
+
+        // the separable resize consumes the same weights
+        let image = Image::<_, 1, _>::new(
+            ImageSize {
+                width: 4,
+                height: 1,
+            },
+            vec![0.0, 4.0, 8.0, 12.0],
+            CpuAllocator,
+        )?;
+        let mut resized = Image::<_, 1, _>::from_size_val([8, 1].into(), 0.0, CpuAllocator)?;
+        super::resize_separable(&image, &mut resized, InterpolationMode::Bilinear, false)?;
+        assert_eq!(
+            resized.as_slice(),
+            &[0.0, 1.0, 3.0, 5.0, 7.0, 9.0, 11.0, 12.0]
+        );
+
+        // a constant image is preserved by all the modes
+        let image = Image::<_, 2, _>::from_size_val([5, 4].into(), 3.0, CpuAllocator)?;
+        let mut resized = Image::<_, 2, _>::from_size_val([7, 3].into(), 0.0, CpuAllocator)?;
+        for mode in [InterpolationMode::Bicubic, InterpolationMode::Lanczos] {
+            super::resize_separable(&image, &mut resized, mode, false)?;
+            assert!(resized.as_slice().iter().all(|&v| (v - 3.0).abs() < 1e-5));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn resize_align_corners() -> Result<(), ImageError> {
+        use super::InterpolationMode;
+
+        let image = Image::<_, 1, _>::new(
+            ImageSize {
+                width: 3,
+                height: 2,
+            },
+            vec![10.0, 20.0, 30.0, 40.0, 50.0, 60.0],
+            CpuAllocator,
+        )?;
+        let mut aligned = Image::<_, 1, _>::from_size_val([7, 5].into(), 0.0, CpuAllocator)?;
+        let mut centered = Image::<_, 1, _>::from_size_val([7, 5].into(), 0.0, CpuAllocator)?;
+
+        // the corner source pixels map exactly to the corner output pixels
+        for mode in [
+            InterpolationMode::Nearest,
+            InterpolationMode::Bilinear,
+            InterpolationMode::Bicubic,
+        ] {
+            super::resize_separable(&image, &mut aligned, mode, true)?;
+            let at = |x: usize, y: usize| aligned.as_slice()[y * 7 + x];
+            assert_eq!(
+                [at(0, 0), at(6, 0), at(0, 4), at(6, 4)],
+                [10.0, 30.0, 40.0, 60.0]
+            );
+        }
+
+        // the second pixel of the first row samples the source at x = 1/3 with aligned
+        // corners and at x = 1/7 with half-pixel centers
+        super::resize_separable(&image, &mut aligned, InterpolationMode::Bilinear, true)?;
+        super::resize_separable(&image, &mut centered, InterpolationMode::Bilinear, false)?;
+        assert!((aligned.as_slice()[1] - (10.0 + 10.0 / 3.0)).abs() < 1e-4);
+        assert!((centered.as_slice()[1] - (10.0 + 10.0 / 7.0)).abs() < 1e-4);
+
+        // downscaling: with half-pixel centers the border output pixels blend the border
+        // source pixels with their neighbors
+        let image = Image::<_, 1, _>::new([4, 1].into(), vec![0.0, 3.0, 6.0, 9.0], CpuAllocator)?;
+        let mut resized = Image::<_, 1, _>::from_size_val([2, 1].into(), 0.0, CpuAllocator)?;
+        super::resize_separable(&image, &mut resized, InterpolationMode::Bilinear, true)?;
+        assert_eq!(resized.as_slice(), &[0.0, 9.0]);
+        super::resize_separable(&image, &mut resized, InterpolationMode::Bilinear, false)?;
+        assert_eq!(resized.as_slice(), &[1.5, 7.5]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn resize_border() -> Result<(), ImageError> {
+        use super::InterpolationMode;
+        use crate::filter::BorderType;
+
+        let image = Image::<_, 1, _>::new([4, 1].into(), vec![0.0, 3.0, 6.0, 9.0], CpuAllocator)?;
+        let mut replicated = Image::<_, 1, _>::from_size_val([8, 1].into(), 0.0, CpuAllocator)?;
+        let mut reflected = Image::<_, 1, _>::from_size_val([8, 1].into(), 0.0, CpuAllocator)?;
+
+        // the border output pixels sample the source at -0.25 and 3.25
+        super::resize_separable_with_border(
+            &image,
+            &mut replicated,
+            InterpolationMode::Bilinear,
+            false,
+            BorderType::Replicate,
+        )?;
+        super::resize_separable_with_border(
+            &image,
+            &mut reflected,
+            InterpolationMode::Bilinear,
+            false,
+            BorderType::Reflect101,
+        )?;
+        assert_eq!(
+            (replicated.as_slice()[0], replicated.as_slice()[7]),
+            (0.0, 9.0)
+        );
+        assert_eq!(
+            (reflected.as_slice()[0], reflected.as_slice()[7]),
+            (0.75, 8.25)
+        );
+        assert_eq!(replicated.as_slice()[1..7], reflected.as_slice()[1..7]);
+
+        // replicating the border is the default
+        let mut resized = Image::<_, 1, _>::from_size_val([8, 1].into(), 0.0, CpuAllocator)?;
+        super::resize_separable(&image, &mut resized, InterpolationMode::Bilinear, false)?;
+        assert_eq!(resized.as_slice(), replicated.as_slice());
+
+        // the weights still sum to one with the taps outside of the axis dropped
+        for border in [
+            BorderType::Zero,
+            BorderType::Reflect,
+            BorderType::Reflect101,
+        ] {
+            let (_, weights) = super::compute_resize_weights_with_border(
+                4,
+                8,
+                InterpolationMode::Lanczos,
+                false,
+                border,
+            );
+            for w in weights {
+                assert!((w.iter().sum::<f32>() - 1.0).abs() < 1e-5);
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn resize_zero_size() -> Result<(), ImageError> {
+        use super::InterpolationMode;
+
+        let image = Image::<f32, 1, _>::from_size_val([4, 3].into(), 1.0, CpuAllocator)?;
+        let mut empty = Image::<f32, 1, _>::from_size_val([4, 0].into(), 0.0, CpuAllocator)?;
+
+        assert!(matches!(
+            super::resize_native(&image, &mut empty, InterpolationMode::Bilinear),
+            Err(ImageError::InvalidSize(4, 0))
+        ));
+        assert!(matches!(
+            super::resize_auto(&image, &mut empty),
+            Err(ImageError::InvalidSize(4, 0))
+        ));
+        assert!(matches!(
+            super::resize_separable(&image, &mut empty, InterpolationMode::Bicubic, false),
+            Err(ImageError::InvalidSize(4, 0))
+        ));
+        assert!(matches!(
+            super::resize_nearest_exact(&image, &mut empty),
+            Err(ImageError::InvalidSize(4, 0))
+        ));
+
+        let image_u8 = Image::<u8, 1, _>::from_size_val([4, 3].into(), 1, CpuAllocator)?;
+        let mut empty_u8 = Image::<u8, 1, _>::from_size_val([0, 2].into(), 0, CpuAllocator)?;
+        assert!(matches!(
+            super::resize_fast_mono(&image_u8, &mut empty_u8, InterpolationMode::Bilinear),
+            Err(ImageError::InvalidSize(0, 2))
+        ));
+
+        // a single output row samples the first source row instead of dividing by zero
+        let image = Image::<f32, 1, _>::new([2, 2].into(), vec![1.0, 2.0, 3.0, 4.0], CpuAllocator)?;
+        let mut row = Image::<f32, 1, _>::from_size_val([2, 1].into(), 0.0, CpuAllocator)?;
+        super::resize_native(&image, &mut row, InterpolationMode::Bilinear)?;
+        assert_eq!(row.as_slice(), &[1.0, 2.0]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn resize_auto_mode() -> Result<(), ImageError> {
+        use super::InterpolationMode;
+
+        let image = Image::<_, 1, _>::new(
+            ImageSize {
+                width: 4,
+                height: 4,
+            },
+            (0..16).map(|x| (x * x) as f32).collect::<Vec<f32>>(),
+            CpuAllocator,
+        )?;
+
+        // downscaling follows the area path
+        let mut auto_down = Image::<_, 1, _>::from_size_val([2, 2].into(), 0.0, CpuAllocator)?;
+        let mut area_down = Image::<_, 1, _>::from_size_val([2, 2].into(), 0.0, CpuAllocator)?;
+        let mut bilinear_down = Image::<_, 1, _>::from_size_val([2, 2].into(), 0.0, CpuAllocator)?;
+        super::resize_auto(&image, &mut auto_down)?;
+        super::resize_native(&image, &mut area_down, InterpolationMode::Area)?;
+        super::resize_native(&image, &mut bilinear_down, InterpolationMode::Bilinear)?;
+        assert_eq!(auto_down.as_slice(), area_down.as_slice());
+        assert_ne!(auto_down.as_slice(), bilinear_down.as_slice());
+
+        // upscaling follows the bilinear path
+        let mut auto_up = Image::<_, 1, _>::from_size_val([7, 7].into(), 0.0, CpuAllocator)?;
+        let mut bilinear_up = Image::<_, 1, _>::from_size_val([7, 7].into(), 0.0, CpuAllocator)?;
+        super::resize_auto(&image, &mut auto_up)?;
+        super::resize_native(&image, &mut bilinear_up, InterpolationMode::Bilinear)?;
+        assert_eq!(auto_up.as_slice(), bilinear_up.as_slice());
+
+        // mixed scaling decides by the total number of pixels
+        let src = ImageSize {
+            width: 8,
+            height: 8,
+        };
+        assert_eq!(
+            super::auto_interpolation_mode(src, [16, 2].into()),
+            InterpolationMode::Area
+        );
+        assert_eq!(
+            super::auto_interpolation_mode(src, [16, 6].into()),
+            InterpolationMode::Bilinear
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn meshgrid() -> Result<(), TensorError> {
         let (map_x, map_y) =
@@ -351,4 +1322,43 @@ mod tests {
         assert_eq!(image_resized.size().height, 3);
         Ok(())
     }
+
+    #[test]
+    fn resize_nearest_exact_mask() -> Result<(), ImageError> {
+        // three classes in vertical bands of different widths
+        let size = ImageSize {
+            width: 7,
+            height: 5,
+        };
+        let data = (0..size.width * size.height)
+            .map(|i| match i % size.width {
+                0..=1 => 0u8,
+                2..=4 => 3,
+                _ => 7,
+            })
+            .collect();
+        let mask = Image::<u8, 1, _>::new(size, data, CpuAllocator)?;
+
+        for (width, height) in [(3, 2), (5, 5), (13, 9), (20, 11)] {
+            let mut resized =
+                Image::<u8, 1, _>::from_size_val([width, height].into(), 255, CpuAllocator)?;
+            super::resize_nearest_exact(&mask, &mut resized)?;
+            assert!(resized.as_slice().iter().all(|v| [0, 3, 7].contains(v)));
+        }
+
+        // half-pixel centers: downscaling 7 -> 3 samples the columns 1, 3 and 5
+        let mut resized = Image::<u8, 1, _>::from_size_val([3, 1].into(), 255, CpuAllocator)?;
+        super::resize_nearest_exact(&mask, &mut resized)?;
+        assert_eq!(resized.as_slice(), &[0, 3, 7]);
+
+        // upscaling by an integer factor repeats every pixel
+        let mut resized = Image::<u8, 1, _>::from_size_val([14, 10].into(), 255, CpuAllocator)?;
+        super::resize_nearest_exact(&mask, &mut resized)?;
+        assert_eq!(
+            &resized.as_slice()[..14],
+            &[0, 0, 0, 0, 3, 3, 3, 3, 3, 3, 7, 7, 7, 7]
+        );
+
+        Ok(())
+    }
 }
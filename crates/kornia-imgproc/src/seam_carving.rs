@@ -0,0 +1,195 @@
+use kornia_image::{allocator::ImageAllocator, Image, ImageError, ImageSize};
+use kornia_tensor::CpuAllocator;
+use rayon::prelude::*;
+
+/// Reduce the width of an image with seam carving.
+///
+/// The vertical seams, i.e. the 8-connected paths of one pixel per row, with the lowest
+/// gradient energy are removed one by one, so the textured regions of the image are kept
+/// while the flat regions shrink. The energy is recomputed after each removed seam.
+///
+/// # Arguments
+///
+/// * `src` - The input RGB image.
+/// * `new_width` - The width of the output image, at most the width of the input image.
+///
+/// # Returns
+///
+/// A new image with the same height and `new_width` columns.
+///
+/// # Errors
+///
+/// Returns [`ImageError::InvalidSize`] if `new_width` or the height of the image is zero, or
+/// [`ImageError::InvalidImageSize`] if `new_width` is larger than the width of the image.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_image::allocator::CpuAllocator;
+/// use kornia_imgproc::seam_carving::seam_carve;
+///
+/// let size = ImageSize {
+///     width: 8,
+///     height: 4,
+/// };
+/// let image = Image::<u8, 3, _>::from_size_val(size, 128, CpuAllocator).unwrap();
+///
+/// let carved = seam_carve(&image, 6).unwrap();
+/// assert_eq!(carved.width(), 6);
+/// assert_eq!(carved.height(), 4);
+/// ```
+pub fn seam_carve<A: ImageAllocator>(
+    src: &Image<u8, 3, A>,
+    new_width: usize,
+) -> Result<Image<u8, 3, CpuAllocator>, ImageError> {
+    let (cols, rows) = (src.cols(), src.rows());
+    if new_width == 0 || rows == 0 {
+        return Err(ImageError::InvalidSize(new_width, rows));
+    }
+    if new_width > cols {
+        return Err(ImageError::InvalidImageSize(cols, rows, new_width, rows));
+    }
+
+    // the rows keep their stride, only their first `width` pixels are valid
+    let stride = cols * 3;
+    let mut pixels = src.as_slice().to_vec();
+    let mut energy = vec![0u32; cols * rows];
+    let mut cost = vec![0u32; cols * rows];
+    let mut seam = vec![0usize; rows];
+
+    for width in (new_width + 1..=cols).rev() {
+        compute_energy(&pixels, cols, width, &mut energy);
+        find_vertical_seam(&energy, cols, width, &mut cost, &mut seam);
+
+        // shift the pixels at the right of the seam by one
+        for (row, &x) in pixels.chunks_exact_mut(stride).zip(seam.iter()) {
+            row[..width * 3].copy_within((x + 1) * 3.., x * 3);
+        }
+    }
+
+    let data = pixels
+        .chunks_exact(stride)
+        .flat_map(|row| &row[..new_width * 3])
+        .copied()
+        .collect();
+
+    let size = ImageSize {
+        width: new_width,
+        height: rows,
+    };
+    Image::new(size, data, CpuAllocator)
+}
+
+/// Compute the gradient energy of the first `width` pixels of each row.
+///
+/// The energy is the L1 norm of the central differences along both axes summed over the
+/// channels, with the border pixels replicated.
+fn compute_energy(pixels: &[u8], cols: usize, width: usize, energy: &mut [u32]) {
+    let rows = energy.len() / cols;
+    let pixel = |x: usize, y: usize| &pixels[(y * cols + x) * 3..][..3];
+
+    energy
+        .par_chunks_exact_mut(cols)
+        .enumerate()
+        .for_each(|(y, energy_row)| {
+            let (up, down) = (y.saturating_sub(1), (y + 1).min(rows - 1));
+            for (x, e) in energy_row[..width].iter_mut().enumerate() {
+                let (left, right) = (x.saturating_sub(1), (x + 1).min(width - 1));
+                let (l, r) = (pixel(left, y), pixel(right, y));
+                let (u, d) = (pixel(x, up), pixel(x, down));
+                *e = (0..3)
+                    .map(|c| r[c].abs_diff(l[c]) as u32 + d[c].abs_diff(u[c]) as u32)
+                    .sum();
+            }
+        });
+}
+
+/// Find the vertical seam with the lowest cumulative energy with dynamic programming.
+///
+/// `cost` receives the lowest cumulative energy of a seam ending at each pixel, and `seam`
+/// the column of the optimal seam in each row.
+fn find_vertical_seam(
+    energy: &[u32],
+    cols: usize,
+    width: usize,
+    cost: &mut [u32],
+    seam: &mut [usize],
+) {
+    let rows = seam.len();
+    let neighbors = |x: usize| x.saturating_sub(1)..=(x + 1).min(width - 1);
+
+    cost[..width].copy_from_slice(&energy[..width]);
+    for y in 1..rows {
+        let (prev, curr) = cost.split_at_mut(y * cols);
+        let prev = &prev[(y - 1) * cols..][..width];
+        for (x, c) in curr[..width].iter_mut().enumerate() {
+            let best = neighbors(x).map(|n| prev[n]).min().unwrap_or(0);
+            *c = energy[y * cols + x] + best;
+        }
+    }
+
+    // backtrack from the cheapest end, the leftmost one on ties
+    let last = &cost[(rows - 1) * cols..][..width];
+    let mut x = (0..width).min_by_key(|&x| last[x]).unwrap_or(0);
+    seam[rows - 1] = x;
+    for y in (0..rows - 1).rev() {
+        let row = &cost[y * cols..][..width];
+        // prefer the straight path on ties
+        x = neighbors(x).fold(x, |best, n| if row[n] < row[best] { n } else { best });
+        seam[y] = x;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::seam_carve;
+    use kornia_image::{Image, ImageError, ImageSize};
+    use kornia_tensor::CpuAllocator;
+
+    #[test]
+    fn test_seam_carve() -> Result<(), ImageError> {
+        // a textured left half and a flat right half
+        let size = ImageSize {
+            width: 8,
+            height: 5,
+        };
+        let pixel = |x: usize, y: usize| {
+            if x < 4 {
+                ((x * 7 + y * 13) * 37 % 256) as u8
+            } else {
+                128
+            }
+        };
+        let data = (0..size.height)
+            .flat_map(|y| (0..size.width).flat_map(move |x| [pixel(x, y); 3]))
+            .collect();
+        let image = Image::<u8, 3, _>::new(size, data, CpuAllocator)?;
+
+        let carved = seam_carve(&image, 6)?;
+        assert_eq!(carved.width(), 6);
+        assert_eq!(carved.height(), 5);
+
+        // the seams are taken from the flat region, the texture is untouched
+        for y in 0..size.height {
+            for x in 0..6 {
+                assert_eq!(carved.get_pixel(x, y, 0)?, &pixel(x, y));
+            }
+        }
+
+        // the same width returns a copy
+        let copy = seam_carve(&image, 8)?;
+        assert_eq!(copy.as_slice(), image.as_slice());
+
+        assert!(matches!(
+            seam_carve(&image, 9),
+            Err(ImageError::InvalidImageSize(8, 5, 9, 5))
+        ));
+        assert!(matches!(
+            seam_carve(&image, 0),
+            Err(ImageError::InvalidSize(0, 5))
+        ));
+
+        Ok(())
+    }
+}
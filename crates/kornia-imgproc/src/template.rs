@@ -0,0 +1,322 @@
+use kornia_image::{allocator::ImageAllocator, Image, ImageError, ImageSize};
+use kornia_tensor::CpuAllocator;
+use rayon::prelude::*;
+
+use crate::{interpolation::InterpolationMode, resize::resize_native};
+
+/// The method to compare a template with the image patches.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TemplateMatchMode {
+    /// The sum of squared differences. Lower is better.
+    SqDiff,
+    /// The sum of squared differences divided by the energy of the patch and the template.
+    /// Lower is better.
+    SqDiffNormed,
+    /// The cross correlation divided by the energy of the patch and the template, in [0, 1]
+    /// for non-negative images. Higher is better.
+    CcorrNormed,
+    /// The correlation coefficient of the patch and the template, in [-1, 1]. Patches with a
+    /// constant value have a score of 0. Higher is better.
+    CcoeffNormed,
+}
+
+impl TemplateMatchMode {
+    /// Check if lower scores are better matches.
+    pub fn lower_is_better(&self) -> bool {
+        matches!(self, Self::SqDiff | Self::SqDiffNormed)
+    }
+}
+
+/// Slide a template over an image and compute the matching score at each location.
+///
+/// # Arguments
+///
+/// * `src` - The input grayscale image with shape (H, W).
+/// * `template` - The template with shape (h, w), not larger than the image.
+/// * `dst` - The output scores with shape (H - h + 1, W - w + 1). The score at (x, y) is
+///   computed for the patch with its top-left corner at (x, y).
+/// * `mode` - The comparison method.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_image::allocator::CpuAllocator;
+/// use kornia_imgproc::template::{match_template, TemplateMatchMode};
+///
+/// let image = Image::<f32, 1, _>::new(
+///     ImageSize { width: 3, height: 1 },
+///     vec![0.0, 1.0, 2.0],
+///     CpuAllocator,
+/// ).unwrap();
+/// let template = Image::<f32, 1, _>::new(
+///     ImageSize { width: 2, height: 1 },
+///     vec![1.0, 2.0],
+///     CpuAllocator,
+/// ).unwrap();
+///
+/// let mut scores = Image::<f32, 1, _>::from_size_val([2, 1].into(), 0.0, CpuAllocator).unwrap();
+///
+/// match_template(&image, &template, &mut scores, TemplateMatchMode::SqDiff).unwrap();
+/// assert_eq!(scores.as_slice(), &[2.0, 0.0]);
+/// ```
+pub fn match_template<A1: ImageAllocator, A2: ImageAllocator, A3: ImageAllocator>(
+    src: &Image<f32, 1, A1>,
+    template: &Image<f32, 1, A2>,
+    dst: &mut Image<f32, 1, A3>,
+    mode: TemplateMatchMode,
+) -> Result<(), ImageError> {
+    let (tw, th) = (template.cols(), template.rows());
+    if tw == 0 || th == 0 || tw > src.cols() || th > src.rows() {
+        return Err(ImageError::InvalidImageSize(tw, th, src.cols(), src.rows()));
+    }
+
+    let (out_cols, out_rows) = (src.cols() - tw + 1, src.rows() - th + 1);
+    if dst.cols() != out_cols || dst.rows() != out_rows {
+        return Err(ImageError::InvalidImageSize(
+            out_cols,
+            out_rows,
+            dst.cols(),
+            dst.rows(),
+        ));
+    }
+
+    let src_data = src.as_slice();
+    let tmpl_data = template.as_slice();
+    let src_cols = src.cols();
+
+    let n = (tw * th) as f32;
+    let tmpl_mean = tmpl_data.iter().sum::<f32>() / n;
+    let tmpl_sq_sum = tmpl_data.iter().map(|t| t * t).sum::<f32>();
+    let tmpl_var_sum = tmpl_data
+        .iter()
+        .map(|t| (t - tmpl_mean) * (t - tmpl_mean))
+        .sum::<f32>();
+
+    dst.as_slice_mut()
+        .par_chunks_exact_mut(out_cols)
+        .enumerate()
+        .for_each(|(y, dst_row)| {
+            dst_row.iter_mut().enumerate().for_each(|(x, score)| {
+                let (mut sum, mut sq_sum, mut cross, mut sq_diff) = (0.0, 0.0, 0.0, 0.0);
+                for (ty, tmpl_row) in tmpl_data.chunks_exact(tw).enumerate() {
+                    let offset = (y + ty) * src_cols + x;
+                    for (&s, &t) in src_data[offset..offset + tw].iter().zip(tmpl_row) {
+                        sum += s;
+                        sq_sum += s * s;
+                        cross += s * t;
+                        sq_diff += (s - t) * (s - t);
+                    }
+                }
+
+                *score = match mode {
+                    TemplateMatchMode::SqDiff => sq_diff,
+                    TemplateMatchMode::SqDiffNormed => {
+                        normalized_ratio(sq_diff, sq_sum * tmpl_sq_sum)
+                    }
+                    TemplateMatchMode::CcorrNormed => normalized_ratio(cross, sq_sum * tmpl_sq_sum),
+                    TemplateMatchMode::CcoeffNormed => {
+                        // sum((s - mean_s) * (t - mean_t)) = sum(s * t) - sum(s) * mean_t
+                        let patch_var_sum = (sq_sum - sum * sum / n).max(0.0);
+                        normalized_ratio(cross - sum * tmpl_mean, patch_var_sum * tmpl_var_sum)
+                    }
+                };
+            });
+        });
+
+    Ok(())
+}
+
+/// Match a template over a set of scales and return the best match.
+///
+/// The template is resized with bilinear interpolation for each scale and matched against
+/// the image with [`match_template`]. Scales producing a template smaller than 2x2 pixels
+/// or larger than the image are skipped. The [`TemplateMatchMode::SqDiff`] scores are
+/// divided by the number of pixels of the resized template so that they are comparable
+/// across scales; the other modes are already normalized.
+///
+/// # Arguments
+///
+/// * `src` - The input grayscale image.
+/// * `template` - The template at its reference scale.
+/// * `scales` - The scale factors to apply to the template.
+/// * `mode` - The comparison method.
+///
+/// # Returns
+///
+/// The best score, the (x, y) location of the top-left corner of the match and the scale.
+///
+/// # Errors
+///
+/// Returns an error if no scale produces a template that fits in the image.
+pub fn match_template_multiscale<A1: ImageAllocator, A2: ImageAllocator>(
+    src: &Image<f32, 1, A1>,
+    template: &Image<f32, 1, A2>,
+    scales: &[f32],
+    mode: TemplateMatchMode,
+) -> Result<(f32, (usize, usize), f32), ImageError> {
+    let mut best: Option<(f32, (usize, usize), f32)> = None;
+
+    for &scale in scales {
+        let size = ImageSize {
+            width: (template.cols() as f32 * scale).round() as usize,
+            height: (template.rows() as f32 * scale).round() as usize,
+        };
+        if size.width < 2 || size.height < 2 || size.width > src.cols() || size.height > src.rows()
+        {
+            continue;
+        }
+
+        let mut scaled = Image::<f32, 1, _>::from_size_val(size, 0.0, CpuAllocator)?;
+        resize_native(template, &mut scaled, InterpolationMode::Bilinear)?;
+
+        let scores_size = ImageSize {
+            width: src.cols() - size.width + 1,
+            height: src.rows() - size.height + 1,
+        };
+        let mut scores = Image::<f32, 1, _>::from_size_val(scores_size, 0.0, CpuAllocator)?;
+        match_template(src, &scaled, &mut scores, mode)?;
+
+        let area = if mode == TemplateMatchMode::SqDiff {
+            (size.width * size.height) as f32
+        } else {
+            1.0
+        };
+
+        for (i, &score) in scores.as_slice().iter().enumerate() {
+            let score = score / area;
+            let is_better = match best {
+                None => true,
+                Some((best_score, _, _)) if mode.lower_is_better() => score < best_score,
+                Some((best_score, _, _)) => score > best_score,
+            };
+            if is_better {
+                best = Some((score, (i % scores_size.width, i / scores_size.width), scale));
+            }
+        }
+    }
+
+    best.ok_or(ImageError::InvalidImageSize(
+        template.cols(),
+        template.rows(),
+        src.cols(),
+        src.rows(),
+    ))
+}
+
+/// Divide by the square root of the denominator, returning 0 for a zero denominator.
+fn normalized_ratio(num: f32, den_sq: f32) -> f32 {
+    if den_sq > f32::EPSILON {
+        num / den_sq.sqrt()
+    } else {
+        0.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TemplateMatchMode;
+    use crate::{interpolation::InterpolationMode, resize::resize_native};
+    use kornia_image::{Image, ImageError, ImageSize};
+    use kornia_tensor::CpuAllocator;
+
+    #[test]
+    fn test_match_template() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 6,
+            height: 5,
+        };
+        let data = (0..size.width * size.height)
+            .map(|i| ((i * i * i + 5 * i) % 17) as f32)
+            .collect();
+        let image = Image::<f32, 1, _>::new(size, data, CpuAllocator)?;
+
+        // crop a 3x2 patch at (2, 1)
+        let patch = (1..3)
+            .flat_map(|y| (2..5).map(move |x| (x, y)))
+            .map(|(x, y)| image.as_slice()[y * size.width + x])
+            .collect();
+        let template = Image::<f32, 1, _>::new([3, 2].into(), patch, CpuAllocator)?;
+
+        let mut scores = Image::<f32, 1, _>::from_size_val([4, 4].into(), 0.0, CpuAllocator)?;
+        for mode in [
+            TemplateMatchMode::SqDiff,
+            TemplateMatchMode::SqDiffNormed,
+            TemplateMatchMode::CcoeffNormed,
+        ] {
+            super::match_template(&image, &template, &mut scores, mode)?;
+            let best = if mode.lower_is_better() {
+                scores
+                    .as_slice()
+                    .iter()
+                    .cloned()
+                    .fold(f32::INFINITY, f32::min)
+            } else {
+                scores.as_slice().iter().cloned().fold(f32::MIN, f32::max)
+            };
+            assert_eq!(scores.as_slice()[4 + 2], best);
+        }
+        assert!((scores.as_slice()[4 + 2] - 1.0).abs() < 1e-5);
+
+        let mut wrong = Image::<f32, 1, _>::from_size_val([3, 3].into(), 0.0, CpuAllocator)?;
+        assert!(
+            super::match_template(&image, &template, &mut wrong, TemplateMatchMode::SqDiff)
+                .is_err()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_match_template_multiscale() -> Result<(), ImageError> {
+        let tmpl_size = ImageSize {
+            width: 8,
+            height: 8,
+        };
+        let data = (0..tmpl_size.width * tmpl_size.height)
+            .map(|i| {
+                let (x, y) = ((i % 8) as f32 - 3.5, (i / 8) as f32 - 3.5);
+                (-(x * x + y * y) / 8.0).exp() + 0.05 * x
+            })
+            .collect();
+        let template = Image::<f32, 1, _>::new(tmpl_size, data, CpuAllocator)?;
+
+        // embed the template scaled by 1.5 at (10, 15)
+        let mut scaled = Image::<f32, 1, _>::from_size_val([12, 12].into(), 0.0, CpuAllocator)?;
+        resize_native(&template, &mut scaled, InterpolationMode::Bilinear)?;
+
+        let size = ImageSize {
+            width: 40,
+            height: 40,
+        };
+        let mut image = Image::<f32, 1, _>::from_size_val(size, 0.0, CpuAllocator)?;
+        for (y, row) in scaled.as_slice().chunks_exact(12).enumerate() {
+            let offset = (15 + y) * size.width + 10;
+            image.as_slice_mut()[offset..offset + 12].copy_from_slice(row);
+        }
+
+        let scales = [0.5, 0.75, 1.0, 1.25, 1.5, 1.75, 2.0];
+        for mode in [TemplateMatchMode::SqDiff, TemplateMatchMode::CcoeffNormed] {
+            let (score, location, scale) =
+                super::match_template_multiscale(&image, &template, &scales, mode)?;
+            assert_eq!(location, (10, 15));
+            assert_eq!(scale, 1.5);
+            if mode == TemplateMatchMode::CcoeffNormed {
+                assert!((score - 1.0).abs() < 1e-4);
+            } else {
+                assert!(score.abs() < 1e-6);
+            }
+        }
+
+        // a template larger than the image at all scales
+        assert!(super::match_template_multiscale(
+            &image,
+            &template,
+            &[10.0],
+            TemplateMatchMode::SqDiff
+        )
+        .is_err());
+
+        Ok(())
+    }
+}
@@ -3,7 +3,8 @@ use std::cmp::PartialOrd;
 
 use kornia_image::{allocator::ImageAllocator, Image, ImageError};
 
-use crate::parallel;
+use crate::{filter::kernels, parallel};
+use rayon::prelude::*;
 
 /// Apply a binary threshold to an image.
 ///
@@ -372,6 +373,218 @@ where
     Ok(())
 }
 
+/// The method used to compute the local threshold in [`adaptive_threshold`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AdaptiveMethod {
+    /// The threshold is the mean of the neighbourhood.
+    Mean,
+    /// The threshold is the Gaussian-weighted mean of the neighbourhood.
+    Gaussian,
+}
+
+/// Apply an adaptive binary threshold to an image.
+///
+/// The threshold is computed for each pixel as the (optionally Gaussian-weighted) mean of its
+/// `block_size x block_size` neighbourhood minus `c`. Pixels greater than their local threshold
+/// are set to 255 and the rest to 0. This makes the thresholding robust to uneven illumination.
+///
+/// # Arguments
+///
+/// * `src` - The input grayscale image.
+/// * `dst` - The output binary image.
+/// * `block_size` - The size of the neighbourhood. Must be odd and greater than 1.
+/// * `c` - The constant subtracted from the local mean.
+/// * `method` - The method used to compute the local mean.
+///
+/// PRECONDITION: `src` and `dst` must have the same size.
+/// NOTE: This function uses a replicate border type.
+///
+/// # Examples
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_image::allocator::CpuAllocator;
+/// use kornia_imgproc::threshold::{adaptive_threshold, AdaptiveMethod};
+///
+/// let data = vec![10u8, 10, 10, 200, 200, 10, 10, 10, 200];
+/// let image = Image::<_, 1, _>::new(ImageSize { width: 3, height: 3 }, data, CpuAllocator).unwrap();
+///
+/// let mut thresholded = Image::<_, 1, _>::from_size_val(image.size(), 0, CpuAllocator).unwrap();
+///
+/// adaptive_threshold(&image, &mut thresholded, 3, 0, AdaptiveMethod::Mean).unwrap();
+/// assert_eq!(thresholded.get_pixel(0, 1, 0).unwrap(), &255);
+/// assert_eq!(thresholded.get_pixel(0, 0, 0).unwrap(), &0);
+/// ```
+pub fn adaptive_threshold<A1: ImageAllocator, A2: ImageAllocator>(
+    src: &Image<u8, 1, A1>,
+    dst: &mut Image<u8, 1, A2>,
+    block_size: usize,
+    c: i32,
+    method: AdaptiveMethod,
+) -> Result<(), ImageError> {
+    if src.size() != dst.size() {
+        return Err(ImageError::InvalidImageSize(
+            src.cols(),
+            src.rows(),
+            dst.cols(),
+            dst.rows(),
+        ));
+    }
+
+    if block_size < 3 || block_size % 2 == 0 {
+        return Err(ImageError::InvalidKernelLength(block_size, block_size));
+    }
+
+    let kernel = match method {
+        AdaptiveMethod::Mean => kernels::box_blur_kernel_1d(block_size),
+        AdaptiveMethod::Gaussian => {
+            // same sigma as OpenCV for a given block size
+            let sigma = 0.3 * ((block_size as f32 - 1.0) * 0.5 - 1.0) + 0.8;
+            kernels::gaussian_kernel_1d(block_size, sigma)
+        }
+    };
+
+    let local_mean = local_mean_replicate(src, &kernel);
+    let c = c as f32;
+
+    dst.as_slice_mut()
+        .par_iter_mut()
+        .zip(src.as_slice().par_iter())
+        .zip(local_mean.par_iter())
+        .for_each(|((dst_pixel, &src_pixel), &mean)| {
+            *dst_pixel = if src_pixel as f32 > mean - c { 255 } else { 0 };
+        });
+
+    Ok(())
+}
+
+/// Apply a hysteresis threshold to a response map.
+///
+/// The pixels greater than `high` are strong and always kept. The pixels greater than `low`
+/// are weak and kept only if they are connected to a strong pixel through a chain of weak
+/// pixels, with 8-connectivity. This is the edge linking step of the Canny detector, and
+/// also segments the regions grown from confident seeds.
+///
+/// # Arguments
+///
+/// * `src` - The response map with shape (H, W).
+/// * `dst` - The output mask with shape (H, W), 255 for the kept pixels and 0 elsewhere.
+/// * `low` - The threshold of the weak pixels.
+/// * `high` - The threshold of the strong pixels.
+///
+/// # Errors
+///
+/// Returns an error if the sizes of the images do not match, or if `low` is greater than
+/// `high`.
+///
+/// # Examples
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_image::allocator::CpuAllocator;
+/// use kornia_imgproc::threshold::hysteresis_threshold;
+///
+/// // a strong pixel linked to a weak one, and an isolated weak pixel
+/// let data = vec![0.9, 0.4, 0.0, 0.0, 0.0, 0.4];
+/// let response = Image::<_, 1, _>::new(ImageSize { width: 6, height: 1 }, data, CpuAllocator).unwrap();
+///
+/// let mut mask = Image::<_, 1, _>::from_size_val(response.size(), 0, CpuAllocator).unwrap();
+///
+/// hysteresis_threshold(&response, &mut mask, 0.3, 0.8).unwrap();
+/// assert_eq!(mask.as_slice(), &[255, 255, 0, 0, 0, 0]);
+/// ```
+pub fn hysteresis_threshold<A1: ImageAllocator, A2: ImageAllocator>(
+    src: &Image<f32, 1, A1>,
+    dst: &mut Image<u8, 1, A2>,
+    low: f32,
+    high: f32,
+) -> Result<(), ImageError> {
+    if src.size() != dst.size() {
+        return Err(ImageError::InvalidImageSize(
+            src.cols(),
+            src.rows(),
+            dst.cols(),
+            dst.rows(),
+        ));
+    }
+
+    if low > high {
+        return Err(ImageError::InvalidHysteresisThresholds(low, high));
+    }
+
+    let (rows, cols) = (src.rows(), src.cols());
+    let src_data = src.as_slice();
+    let dst_data = dst.as_slice_mut();
+
+    // start from the strong pixels
+    let mut stack = Vec::new();
+    for (i, (&v, d)) in src_data.iter().zip(dst_data.iter_mut()).enumerate() {
+        if v > high {
+            *d = 255;
+            stack.push(i);
+        } else {
+            *d = 0;
+        }
+    }
+
+    // grow the strong pixels into their weak 8-connected neighbours
+    while let Some(i) = stack.pop() {
+        let (r, c) = (i / cols, i % cols);
+        for nr in r.saturating_sub(1)..(r + 2).min(rows) {
+            for nc in c.saturating_sub(1)..(c + 2).min(cols) {
+                let j = nr * cols + nc;
+                if dst_data[j] == 0 && src_data[j] > low {
+                    dst_data[j] = 255;
+                    stack.push(j);
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Compute the weighted mean of the neighbourhood of each pixel with a separable kernel,
+/// replicating the pixels at the image border.
+fn local_mean_replicate<A: ImageAllocator>(src: &Image<u8, 1, A>, kernel: &[f32]) -> Vec<f32> {
+    let (rows, cols) = (src.rows(), src.cols());
+    if rows == 0 || cols == 0 {
+        return Vec::new();
+    }
+    let half = kernel.len() / 2;
+    let src_data = src.as_slice();
+
+    // horizontal pass
+    let mut temp = vec![0.0f32; src_data.len()];
+    temp.par_chunks_exact_mut(cols)
+        .zip(src_data.par_chunks_exact(cols))
+        .for_each(|(temp_row, src_row)| {
+            temp_row.iter_mut().enumerate().for_each(|(c, acc)| {
+                *acc = kernel.iter().enumerate().fold(0.0, |acc, (k, w)| {
+                    let x = (c + k).saturating_sub(half).min(cols - 1);
+                    acc + src_row[x] as f32 * w
+                });
+            });
+        });
+
+    // vertical pass
+    let mut mean = vec![0.0f32; src_data.len()];
+    mean.par_chunks_exact_mut(cols)
+        .enumerate()
+        .for_each(|(r, mean_row)| {
+            kernel.iter().enumerate().for_each(|(k, w)| {
+                let y = (r + k).saturating_sub(half).min(rows - 1);
+                let temp_row = &temp[y * cols..(y + 1) * cols];
+                mean_row
+                    .iter_mut()
+                    .zip(temp_row.iter())
+                    .for_each(|(acc, &val)| *acc += val * w);
+            });
+        });
+
+    mean
+}
+
 // TODO: outsu, triangle
 
 #[cfg(test)]
@@ -534,4 +747,108 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_adaptive_threshold_uneven_illumination() -> Result<(), ImageError> {
+        use super::AdaptiveMethod;
+
+        // dark strokes over a background whose brightness grows from left to right
+        let size = ImageSize {
+            width: 20,
+            height: 9,
+        };
+        let is_stroke = |r: usize, c: usize| (r == 2 || r == 6) && c % 4 == 1;
+        let data = (0..size.height)
+            .flat_map(|r| {
+                (0..size.width).map(move |c| {
+                    let background = 40 + 9 * c as u8;
+                    if is_stroke(r, c) {
+                        background - 40
+                    } else {
+                        background
+                    }
+                })
+            })
+            .collect::<Vec<_>>();
+        let image = Image::<_, 1, _>::new(size, data, CpuAllocator)?;
+
+        let expected = (0..size.height)
+            .flat_map(|r| (0..size.width).map(move |c| if is_stroke(r, c) { 0 } else { 255 }))
+            .collect::<Vec<u8>>();
+
+        // no global threshold is able to separate the strokes from the background
+        let mut thresholded = Image::<_, 1, _>::from_size_val(size, 0, CpuAllocator)?;
+        for t in 0..=255 {
+            super::threshold_binary(&image, &mut thresholded, t, 255)?;
+            assert_ne!(thresholded.as_slice(), expected.as_slice());
+        }
+
+        for method in [AdaptiveMethod::Mean, AdaptiveMethod::Gaussian] {
+            super::adaptive_threshold(&image, &mut thresholded, 5, 10, method)?;
+            assert_eq!(thresholded.as_slice(), expected.as_slice(), "{method:?}");
+        }
+
+        // even block sizes are rejected
+        assert!(
+            super::adaptive_threshold(&image, &mut thresholded, 4, 10, AdaptiveMethod::Mean)
+                .is_err()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_adaptive_threshold_empty() -> Result<(), ImageError> {
+        for size in [[0, 3], [3, 0]] {
+            let image = Image::<u8, 1, _>::from_size_val(size.into(), 0, CpuAllocator)?;
+            let mut thresholded = Image::<u8, 1, _>::from_size_val(size.into(), 0, CpuAllocator)?;
+            super::adaptive_threshold(
+                &image,
+                &mut thresholded,
+                3,
+                0,
+                super::AdaptiveMethod::Gaussian,
+            )?;
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn hysteresis_threshold() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 6,
+            height: 6,
+        };
+
+        // a strong seed with a weak chain linked only through the diagonals, a weak pixel
+        // next to the chain but below the low threshold, and an isolated weak blob
+        let chain = [(0, 0), (1, 1), (2, 2), (3, 3), (3, 4), (4, 5)];
+        let blob = [(5, 0), (5, 1)];
+        let mut response = Image::<f32, 1, _>::from_size_val(size, 0.1, CpuAllocator)?;
+        for (i, &(r, c)) in chain.iter().chain(blob.iter()).enumerate() {
+            response.as_slice_mut()[r * size.width + c] = if i == 0 { 0.9 } else { 0.5 };
+        }
+        response.as_slice_mut()[2 * size.width + 3] = 0.25;
+
+        let mut mask = Image::<u8, 1, _>::from_size_val(size, 0, CpuAllocator)?;
+        super::hysteresis_threshold(&response, &mut mask, 0.3, 0.8)?;
+
+        let expected = (0..size.height)
+            .flat_map(|r| {
+                (0..size.width).map(move |c| if chain.contains(&(r, c)) { 255 } else { 0 })
+            })
+            .collect::<Vec<u8>>();
+        assert_eq!(mask.as_slice(), expected.as_slice());
+
+        // without a strong seed nothing is kept
+        super::hysteresis_threshold(&response, &mut mask, 0.3, 0.95)?;
+        assert!(mask.as_slice().iter().all(|&v| v == 0));
+
+        assert!(matches!(
+            super::hysteresis_threshold(&response, &mut mask, 0.8, 0.3),
+            Err(ImageError::InvalidHysteresisThresholds(_, _))
+        ));
+
+        Ok(())
+    }
 }
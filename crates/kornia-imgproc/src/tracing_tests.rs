@@ -0,0 +1,118 @@
+use std::sync::{
+    atomic::{AtomicU64, Ordering},
+    Arc, Mutex,
+};
+
+use kornia_image::{Image, ImageError, ImageSize};
+use kornia_tensor::CpuAllocator;
+use tracing::{
+    field::{Field, Visit},
+    span, Event, Metadata, Subscriber,
+};
+
+use crate::{filter, interpolation::InterpolationMode, resize, warp};
+
+/// A span recorded by the [`SpanRecorder`] with its name and fields.
+#[derive(Debug, Default)]
+struct RecordedSpan {
+    name: String,
+    fields: Vec<(String, String)>,
+}
+
+impl Visit for RecordedSpan {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.fields
+            .push((field.name().to_string(), format!("{value:?}")));
+    }
+}
+
+/// A minimal subscriber that keeps track of all the created spans.
+#[derive(Clone, Default)]
+struct SpanRecorder {
+    spans: Arc<Mutex<Vec<RecordedSpan>>>,
+    next_id: Arc<AtomicU64>,
+}
+
+impl SpanRecorder {
+    fn find(&self, name: &str) -> Option<Vec<(String, String)>> {
+        let spans = self.spans.lock().ok()?;
+        spans
+            .iter()
+            .find(|span| span.name == name)
+            .map(|span| span.fields.clone())
+    }
+}
+
+impl Subscriber for SpanRecorder {
+    fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, attrs: &span::Attributes<'_>) -> span::Id {
+        let mut span = RecordedSpan {
+            name: attrs.metadata().name().to_string(),
+            ..Default::default()
+        };
+        attrs.record(&mut span);
+        if let Ok(mut spans) = self.spans.lock() {
+            spans.push(span);
+        }
+        span::Id::from_u64(self.next_id.fetch_add(1, Ordering::Relaxed) + 1)
+    }
+
+    fn record(&self, _span: &span::Id, _values: &span::Record<'_>) {}
+
+    fn record_follows_from(&self, _span: &span::Id, _follows: &span::Id) {}
+
+    fn event(&self, _event: &Event<'_>) {}
+
+    fn enter(&self, _span: &span::Id) {}
+
+    fn exit(&self, _span: &span::Id) {}
+}
+
+fn size_fields(width: usize, height: usize, channels: usize) -> Vec<(String, String)> {
+    vec![
+        ("width".to_string(), width.to_string()),
+        ("height".to_string(), height.to_string()),
+        ("channels".to_string(), channels.to_string()),
+    ]
+}
+
+#[test]
+fn test_spans_resize_warp_filter() -> Result<(), ImageError> {
+    let recorder = SpanRecorder::default();
+
+    let size = ImageSize {
+        width: 4,
+        height: 5,
+    };
+    let src = Image::<f32, 3, _>::from_size_val(size, 1.0, CpuAllocator)?;
+
+    tracing::subscriber::with_default(recorder.clone(), || -> Result<(), ImageError> {
+        let mut resized = Image::<f32, 3, _>::from_size_val([2, 3].into(), 0.0, CpuAllocator)?;
+        resize::resize_native(&src, &mut resized, InterpolationMode::Bilinear)?;
+
+        let mut warped = Image::<f32, 3, _>::from_size_val(size, 0.0, CpuAllocator)?;
+        let m = [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0];
+        warp::warp_perspective(&src, &mut warped, &m, InterpolationMode::Bilinear)?;
+
+        let mut blurred = Image::<f32, 3, _>::from_size_val(size, 0.0, CpuAllocator)?;
+        filter::gaussian_blur(&src, &mut blurred, (3, 3), (0.5, 0.5))?;
+
+        Ok(())
+    })?;
+
+    assert_eq!(recorder.find("resize_native"), Some(size_fields(4, 5, 3)));
+    assert_eq!(
+        recorder.find("warp_perspective"),
+        Some(size_fields(4, 5, 3))
+    );
+    assert_eq!(recorder.find("gaussian_blur"), Some(size_fields(4, 5, 3)));
+    assert_eq!(
+        recorder.find("separable_filter"),
+        Some(size_fields(4, 5, 3))
+    );
+
+    Ok(())
+}
@@ -0,0 +1,196 @@
+/// The initial variance of the velocity, large as the velocity is unknown before tracking.
+const INITIAL_VELOCITY_VARIANCE: f32 = 100.0;
+
+/// A Kalman filter for a 2D point with a constant velocity model.
+///
+/// The state is the position and the velocity of the point. Between two frames the point
+/// keeps its velocity, and the changes of velocity are modeled as a white noise acceleration
+/// with variance `process_noise`. The measurements are the position of the point with
+/// variance `measurement_noise`.
+///
+/// The two axes are independent in this model, so each of them is filtered separately.
+///
+/// # Example
+///
+/// ```
+/// use kornia_imgproc::tracking::KalmanFilter2D;
+///
+/// let mut filter = KalmanFilter2D::new((10.0, 20.0)).with_measurement_noise(4.0);
+/// for t in 1..10 {
+///     filter.predict();
+///     filter.update((10.0 + 2.0 * t as f32, 20.0));
+/// }
+/// let (vx, vy) = filter.velocity();
+/// assert!((vx - 2.0).abs() < 0.1 && vy.abs() < 0.1);
+/// ```
+#[derive(Debug, Clone)]
+pub struct KalmanFilter2D {
+    x: AxisFilter,
+    y: AxisFilter,
+    process_noise: f32,
+    measurement_noise: f32,
+    time_step: f32,
+}
+
+impl KalmanFilter2D {
+    /// Creates a filter at the initial position with default values
+    pub fn new(position: (f32, f32)) -> Self {
+        let measurement_noise = 1.0;
+        Self {
+            x: AxisFilter::new(position.0, measurement_noise),
+            y: AxisFilter::new(position.1, measurement_noise),
+            process_noise: 1e-2,
+            measurement_noise,
+            time_step: 1.0,
+        }
+    }
+
+    /// Sets the variance of the acceleration of the point (0.01 by default)
+    pub fn with_process_noise(self, process_noise: f32) -> Self {
+        Self {
+            process_noise,
+            ..self
+        }
+    }
+
+    /// Sets the variance of the measured positions (1 by default)
+    ///
+    /// The variance of the initial position is reset to the measurement noise.
+    pub fn with_measurement_noise(self, measurement_noise: f32) -> Self {
+        Self {
+            x: AxisFilter::new(self.x.state[0], measurement_noise),
+            y: AxisFilter::new(self.y.state[0], measurement_noise),
+            measurement_noise,
+            ..self
+        }
+    }
+
+    /// Sets the time between two predictions (1 by default)
+    pub fn with_time_step(self, time_step: f32) -> Self {
+        Self { time_step, ..self }
+    }
+
+    /// Predicts the position of the point at the next time step.
+    ///
+    /// # Returns
+    ///
+    /// The predicted position.
+    pub fn predict(&mut self) -> (f32, f32) {
+        self.x.predict(self.time_step, self.process_noise);
+        self.y.predict(self.time_step, self.process_noise);
+        self.position()
+    }
+
+    /// Corrects the state with a measured position of the point.
+    ///
+    /// # Arguments
+    ///
+    /// * `measurement` - The measured position.
+    ///
+    /// # Returns
+    ///
+    /// The corrected position.
+    pub fn update(&mut self, measurement: (f32, f32)) -> (f32, f32) {
+        self.x.update(measurement.0, self.measurement_noise);
+        self.y.update(measurement.1, self.measurement_noise);
+        self.position()
+    }
+
+    /// The estimated position of the point.
+    pub fn position(&self) -> (f32, f32) {
+        (self.x.state[0], self.y.state[0])
+    }
+
+    /// The estimated velocity of the point, per time step.
+    pub fn velocity(&self) -> (f32, f32) {
+        (self.x.state[1], self.y.state[1])
+    }
+}
+
+/// A Kalman filter of the position and the velocity along one axis.
+#[derive(Debug, Clone)]
+struct AxisFilter {
+    state: [f32; 2],
+    covariance: [[f32; 2]; 2],
+}
+
+impl AxisFilter {
+    fn new(position: f32, variance: f32) -> Self {
+        Self {
+            state: [position, 0.0],
+            covariance: [[variance, 0.0], [0.0, INITIAL_VELOCITY_VARIANCE]],
+        }
+    }
+
+    fn predict(&mut self, dt: f32, q: f32) {
+        let [p, v] = self.state;
+        self.state = [p + v * dt, v];
+
+        // P = F * P * F^T + Q, with the white noise acceleration Q
+        let [[p00, p01], [p10, p11]] = self.covariance;
+        let (dt2, dt3, dt4) = (dt * dt, dt * dt * dt, dt * dt * dt * dt);
+        self.covariance = [
+            [
+                p00 + dt * (p01 + p10) + dt2 * p11 + q * dt4 / 4.0,
+                p01 + dt * p11 + q * dt3 / 2.0,
+            ],
+            [p10 + dt * p11 + q * dt3 / 2.0, p11 + q * dt2],
+        ];
+    }
+
+    fn update(&mut self, z: f32, r: f32) {
+        let [[p00, p01], [p10, p11]] = self.covariance;
+        let innovation = z - self.state[0];
+        let s = p00 + r;
+        let (k0, k1) = (p00 / s, p10 / s);
+
+        self.state[0] += k0 * innovation;
+        self.state[1] += k1 * innovation;
+        self.covariance = [
+            [(1.0 - k0) * p00, (1.0 - k0) * p01],
+            [p10 - k1 * p00, p11 - k1 * p01],
+        ];
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::KalmanFilter2D;
+
+    #[test]
+    fn test_kalman_filter_smooths_track() {
+        // a deterministic noise in [-1, 1]
+        let noise = |i: usize| ((i * 37 + 11) % 17) as f32 / 8.0 - 1.0;
+        let truth = |t: usize| (2.0 + 1.5 * t as f32, 10.0 - 0.5 * t as f32);
+        let measure = |t: usize| {
+            let (x, y) = truth(t);
+            (x + noise(t), y + noise(t + 100))
+        };
+
+        let mut filter = KalmanFilter2D::new(measure(0));
+
+        let (mut raw_error, mut filtered_error) = (0.0, 0.0);
+        for t in 1..60 {
+            filter.predict();
+            let measurement = measure(t);
+            let (x, y) = filter.update(measurement);
+
+            // skip the first frames while the velocity converges
+            if t >= 10 {
+                let (tx, ty) = truth(t);
+                raw_error += (measurement.0 - tx).powi(2) + (measurement.1 - ty).powi(2);
+                filtered_error += (x - tx).powi(2) + (y - ty).powi(2);
+            }
+        }
+        assert!(filtered_error < 0.5 * raw_error);
+
+        let (vx, vy) = filter.velocity();
+        assert!((vx - 1.5).abs() < 0.1);
+        assert!((vy + 0.5).abs() < 0.1);
+
+        // the prediction extrapolates the velocity
+        let (x, _) = filter.position();
+        let (px, _) = filter.predict();
+        assert!((px - x - vx).abs() < 1e-5);
+    }
+}
@@ -3,7 +3,8 @@ use std::f32::consts::PI;
 use kornia_image::allocator::ImageAllocator;
 use kornia_image::{Image, ImageError};
 
-use crate::interpolation::{grid::meshgrid_from_fn, interpolate_pixel, InterpolationMode};
+use super::border::{check_source_size, sample_with_border, BorderMode};
+use crate::interpolation::{check_interpolation, grid::meshgrid_from_fn, InterpolationMode};
 use crate::parallel;
 
 /// Inverts a 2x3 affine transformation matrix.
@@ -87,6 +88,9 @@ fn transform_point(x: f32, y: f32, m: &[f32; 6]) -> (f32, f32) {
 
 /// Applies an affine transformation to an image.
 ///
+/// The pixels which map outside of the source image are set to zero, use
+/// [`warp_affine_with_border`] to choose how they are filled.
+///
 /// # Arguments
 ///
 /// * `src` - The input image with shape (height, width, channels).
@@ -98,6 +102,10 @@ fn transform_point(x: f32, y: f32, m: &[f32; 6]) -> (f32, f32) {
 ///
 /// The output image with shape (new_height, new_width, channels).
 ///
+/// # Errors
+///
+/// If the source image is empty, an error is returned.
+///
 /// # Example
 ///
 /// ```
@@ -128,12 +136,79 @@ fn transform_point(x: f32, y: f32, m: &[f32; 6]) -> (f32, f32) {
 /// assert_eq!(dst.size().width, 4);
 /// assert_eq!(dst.size().height, 5);
 /// ```
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(skip_all, fields(width = src.width(), height = src.height(), channels = C))
+)]
 pub fn warp_affine<const C: usize, A1: ImageAllocator, A2: ImageAllocator>(
     src: &Image<f32, C, A1>,
     dst: &mut Image<f32, C, A2>,
     m: &[f32; 6],
     interpolation: InterpolationMode,
 ) -> Result<(), ImageError> {
+    warp_affine_with_border(src, dst, m, interpolation, BorderMode::default())
+}
+
+/// Applies an affine transformation to an image with a border mode.
+///
+/// Each pixel of `dst` is mapped to the source image with the inverse of `m` and sampled
+/// with the interpolation mode. The size of the output is the size of `dst`.
+///
+/// # Arguments
+///
+/// * `src` - The input image with shape (height, width, channels).
+/// * `dst` - The output image with shape (height, width, channels).
+/// * `m` - The 2x3 affine transformation matrix src -> dst.
+/// * `interpolation` - The interpolation mode to use, [`InterpolationMode::Nearest`] or
+///   [`InterpolationMode::Bilinear`].
+/// * `border` - How to fill the pixels which map outside of the source image.
+///
+/// # Errors
+///
+/// Returns [`ImageError::InvalidSize`] if the source image is empty, or
+/// [`ImageError::UnsupportedInterpolation`] for the interpolation modes other than
+/// [`InterpolationMode::Nearest`] and [`InterpolationMode::Bilinear`].
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_image::allocator::CpuAllocator;
+/// use kornia_imgproc::interpolation::InterpolationMode;
+/// use kornia_imgproc::warp::{warp_affine_with_border, BorderMode};
+///
+/// let size = ImageSize {
+///     width: 3,
+///     height: 1,
+/// };
+/// let src = Image::<f32, 1, _>::new(size, vec![1.0, 2.0, 3.0], CpuAllocator).unwrap();
+/// let mut dst = Image::<f32, 1, _>::from_size_val(size, 0.0, CpuAllocator).unwrap();
+///
+/// // shift the image by one pixel to the right
+/// let m = [1.0, 0.0, 1.0, 0.0, 1.0, 0.0];
+///
+/// warp_affine_with_border(&src, &mut dst, &m, InterpolationMode::Nearest, BorderMode::Constant(9.0))
+///     .unwrap();
+/// assert_eq!(dst.as_slice(), &[9.0, 1.0, 2.0]);
+///
+/// warp_affine_with_border(&src, &mut dst, &m, InterpolationMode::Nearest, BorderMode::Replicate)
+///     .unwrap();
+/// assert_eq!(dst.as_slice(), &[1.0, 1.0, 2.0]);
+/// ```
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(skip_all, fields(width = src.width(), height = src.height(), channels = C))
+)]
+pub fn warp_affine_with_border<const C: usize, A1: ImageAllocator, A2: ImageAllocator>(
+    src: &Image<f32, C, A1>,
+    dst: &mut Image<f32, C, A2>,
+    m: &[f32; 6],
+    interpolation: InterpolationMode,
+    border: BorderMode,
+) -> Result<(), ImageError> {
+    check_source_size(src)?;
+    check_interpolation(interpolation)?;
+
     // invert affine transform matrix to find corresponding positions in src from dst
     let m_inv = invert_affine_transform(m);
 
@@ -146,14 +221,10 @@ pub fn warp_affine<const C: usize, A1: ImageAllocator, A2: ImageAllocator>(
 
     // apply affine transformation
     parallel::par_iter_rows_resample(dst, &map_x, &map_y, |&x, &y, dst_pixel| {
-        // check if the position is within the bounds of the src image
-        if x >= 0.0f32 && x < src.cols() as f32 && y >= 0.0f32 && y < src.rows() as f32 {
-            // interpolate the pixel value for each channel
-            dst_pixel
-                .iter_mut()
-                .enumerate()
-                .for_each(|(k, pixel)| *pixel = interpolate_pixel(src, x, y, k, interpolation));
-        }
+        // interpolate the pixel value for each channel
+        dst_pixel.iter_mut().enumerate().for_each(|(k, pixel)| {
+            *pixel = sample_with_border(src, x, y, k, interpolation, border)
+        });
     });
 
     Ok(())
@@ -294,4 +365,57 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn warp_affine_border() -> Result<(), ImageError> {
+        use super::{warp_affine, warp_affine_with_border, BorderMode};
+        use crate::filter::BorderType;
+
+        let size = ImageSize {
+            width: 3,
+            height: 2,
+        };
+        let image =
+            Image::<f32, 1, _>::new(size, vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0], CpuAllocator)?;
+        let mut dst = Image::<f32, 1, _>::from_size_val(size, 7.0, CpuAllocator)?;
+
+        // shift by one pixel to the right
+        let m = [1.0, 0.0, 1.0, 0.0, 1.0, 0.0];
+        let nearest = super::InterpolationMode::Nearest;
+
+        // the pixels outside of the source are zero by default
+        warp_affine(&image, &mut dst, &m, nearest)?;
+        assert_eq!(dst.as_slice(), &[0.0, 0.0, 1.0, 0.0, 3.0, 4.0]);
+
+        warp_affine_with_border(&image, &mut dst, &m, nearest, BorderMode::Constant(9.0))?;
+        assert_eq!(dst.as_slice(), &[9.0, 0.0, 1.0, 9.0, 3.0, 4.0]);
+
+        warp_affine_with_border(&image, &mut dst, &m, nearest, BorderMode::Replicate)?;
+        assert_eq!(dst.as_slice(), &[0.0, 0.0, 1.0, 3.0, 3.0, 4.0]);
+
+        // shift by half a pixel, the border pixels are repeated before the interpolation
+        let m = [1.0, 0.0, 0.5, 0.0, 1.0, 0.0];
+        let bilinear = super::InterpolationMode::Bilinear;
+        warp_affine_with_border(&image, &mut dst, &m, bilinear, BorderMode::Replicate)?;
+        assert_eq!(dst.as_slice(), &[0.0, 0.5, 1.5, 3.0, 3.5, 4.5]);
+
+        // shift by two pixels, the source is mirrored
+        let m = [1.0, 0.0, 2.0, 0.0, 1.0, 0.0];
+        warp_affine_with_border(&image, &mut dst, &m, nearest, BorderMode::Reflect)?;
+        assert_eq!(dst.as_slice(), &[1.0, 0.0, 0.0, 4.0, 3.0, 3.0]);
+        let border = BorderMode::from(BorderType::Reflect101);
+        warp_affine_with_border(&image, &mut dst, &m, nearest, border)?;
+        assert_eq!(dst.as_slice(), &[2.0, 1.0, 0.0, 5.0, 4.0, 3.0]);
+
+        let empty = Image::<f32, 1, _>::new([0, 0].into(), vec![], CpuAllocator)?;
+        assert!(warp_affine(&empty, &mut dst, &m, nearest).is_err());
+
+        let bicubic = super::InterpolationMode::Bicubic;
+        assert!(matches!(
+            warp_affine(&image, &mut dst, &m, bicubic),
+            Err(ImageError::UnsupportedInterpolation(_))
+        ));
+
+        Ok(())
+    }
 }
@@ -0,0 +1,133 @@
+use kornia_image::allocator::ImageAllocator;
+use kornia_image::{Image, ImageError};
+
+use crate::filter::BorderType;
+use crate::interpolation::{interpolate_pixel, InterpolationMode};
+
+/// How to fill the pixels of a warped image which map outside of the source image.
+///
+/// The extrapolations are the ones of [`BorderType`], which converts to a border mode, and
+/// the constant can be any value.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BorderMode {
+    /// The pixels are filled with a constant value.
+    Constant(f32),
+    /// The border pixels of the source image are repeated.
+    Replicate,
+    /// The source image is mirrored including the border pixels.
+    Reflect,
+    /// The source image is mirrored around the border pixels.
+    Reflect101,
+}
+
+impl Default for BorderMode {
+    fn default() -> Self {
+        BorderMode::Constant(0.0)
+    }
+}
+
+impl From<BorderType> for BorderMode {
+    fn from(border_type: BorderType) -> Self {
+        match border_type {
+            BorderType::Zero => BorderMode::Constant(0.0),
+            BorderType::Replicate => BorderMode::Replicate,
+            BorderType::Reflect => BorderMode::Reflect,
+            BorderType::Reflect101 => BorderMode::Reflect101,
+        }
+    }
+}
+
+/// Check that the source image has pixels to sample from.
+pub(super) fn check_source_size<const C: usize, A: ImageAllocator>(
+    src: &Image<f32, C, A>,
+) -> Result<(), ImageError> {
+    if src.cols() == 0 || src.rows() == 0 {
+        return Err(ImageError::InvalidSize(src.cols(), src.rows()));
+    }
+    Ok(())
+}
+
+/// Sample a channel of the source image at a position which can be out of bounds.
+///
+/// PRECONDITION: the source image is not empty and the interpolation mode passed
+/// [`check_interpolation`](crate::interpolation::check_interpolation).
+pub(super) fn sample_with_border<const C: usize, A: ImageAllocator>(
+    src: &Image<f32, C, A>,
+    x: f32,
+    y: f32,
+    c: usize,
+    interpolation: InterpolationMode,
+    border: BorderMode,
+) -> f32 {
+    let (cols, rows) = (src.cols() as f32, src.rows() as f32);
+    if x >= 0.0 && x < cols && y >= 0.0 && y < rows {
+        return interpolate_pixel(src, x, y, c, interpolation);
+    }
+
+    let (x, y) = match border {
+        BorderMode::Constant(value) => return value,
+        // the interpolation at a clamped position repeats the border pixels
+        BorderMode::Replicate => (x.clamp(0.0, cols - 1.0), y.clamp(0.0, rows - 1.0)),
+        BorderMode::Reflect | BorderMode::Reflect101 => {
+            let reflect101 = border == BorderMode::Reflect101;
+            (
+                reflect_coordinate(x, src.cols(), reflect101),
+                reflect_coordinate(y, src.rows(), reflect101),
+            )
+        }
+    };
+    interpolate_pixel(src, x, y, c, interpolation)
+}
+
+/// Mirror a coordinate into `[0, len - 1]`.
+///
+/// The image is mirrored around the border pixels with `reflect101`, or around the edges of
+/// the border pixels otherwise, so that the border pixels are repeated. At the integer
+/// coordinates this matches the filter borders.
+fn reflect_coordinate(x: f32, len: usize, reflect101: bool) -> f32 {
+    let last = (len - 1) as f32;
+    if reflect101 {
+        if len == 1 {
+            return 0.0;
+        }
+        let t = x.rem_euclid(2.0 * last);
+        if t > last {
+            2.0 * last - t
+        } else {
+            t
+        }
+    } else {
+        let period = 2.0 * len as f32;
+        let t = (x + 0.5).rem_euclid(period);
+        let t = if t > len as f32 { period - t } else { t };
+        (t - 0.5).clamp(0.0, last)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::filter::border_index;
+
+    #[test]
+    fn test_reflect_coordinate() {
+        // the integer coordinates are mapped as the filter borders do
+        for (border_type, reflect101) in
+            [(BorderType::Reflect, false), (BorderType::Reflect101, true)]
+        {
+            for len in [1, 2, 5] {
+                for i in -12..12 {
+                    let expected = border_index(i, len, border_type).map(|i| i as f32);
+                    let actual = reflect_coordinate(i as f32, len, reflect101);
+                    assert_eq!(Some(actual), expected, "{border_type:?} {len} {i}");
+                }
+            }
+        }
+
+        // between the integers the coordinates are mirrored continuously
+        assert_eq!(reflect_coordinate(-0.5, 5, true), 0.5);
+        assert_eq!(reflect_coordinate(4.5, 5, true), 3.5);
+        assert_eq!(reflect_coordinate(-1.25, 5, false), 0.25);
+        assert_eq!(reflect_coordinate(4.25, 5, false), 4.0);
+    }
+}
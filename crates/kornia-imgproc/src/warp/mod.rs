@@ -1,5 +1,11 @@
 mod affine;
+mod border;
 mod perspective;
+mod stitch;
 
-pub use affine::{get_rotation_matrix2d, invert_affine_transform, warp_affine};
-pub use perspective::warp_perspective;
+pub use affine::{
+    get_rotation_matrix2d, invert_affine_transform, warp_affine, warp_affine_with_border,
+};
+pub use border::BorderMode;
+pub use perspective::{warp_perspective, warp_perspective_with_border};
+pub use stitch::stitch;
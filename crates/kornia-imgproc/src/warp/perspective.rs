@@ -1,5 +1,8 @@
+use super::border::{check_source_size, sample_with_border, BorderMode};
 use crate::{
-    interpolation::{grid::meshgrid_from_fn, interpolate_pixel, InterpolationMode},
+    interpolation::{
+        check_interpolation, grid::meshgrid_from_fn, interpolate_pixel, InterpolationMode,
+    },
     parallel,
 };
 
@@ -28,7 +31,7 @@ fn adjugate3x3(m: &[f32; 9]) -> [f32; 9] {
 }
 
 // TODO: use TensorError
-fn inverse_perspective_matrix(m: &[f32; 9]) -> Result<[f32; 9], ImageError> {
+pub(super) fn inverse_perspective_matrix(m: &[f32; 9]) -> Result<[f32; 9], ImageError> {
     let det = determinant3x3(m);
 
     if det == 0.0 {
@@ -47,7 +50,7 @@ fn inverse_perspective_matrix(m: &[f32; 9]) -> Result<[f32; 9], ImageError> {
 }
 
 // implement later as batched operation
-fn transform_point(x: f32, y: f32, m: &[f32; 9]) -> (f32, f32) {
+pub(super) fn transform_point(x: f32, y: f32, m: &[f32; 9]) -> (f32, f32) {
     let w = m[6] * x + m[7] * y + m[8];
     let x_out = (m[0] * x + m[1] * y + m[2]) / w;
     let y_out = (m[3] * x + m[4] * y + m[5]) / w;
@@ -56,6 +59,11 @@ fn transform_point(x: f32, y: f32, m: &[f32; 9]) -> (f32, f32) {
 
 /// Applies a perspective transformation to an image.
 ///
+/// The pixels of `dst` which map outside of the source image are left untouched, use
+/// [`warp_perspective_with_border`] to fill them.
+///
+/// # Arguments
+///
 /// * `src` - The input image with shape (height, width, channels).
 /// * `dst` - The output image with shape (height, width, channels).
 /// * `m` - The 3x3 perspective transformation matrix src -> dst.
@@ -65,6 +73,12 @@ fn transform_point(x: f32, y: f32, m: &[f32; 9]) -> (f32, f32) {
 ///
 /// The output image with shape (new_height, new_width, channels).
 ///
+/// # Errors
+///
+/// Returns [`ImageError::CannotComputeDeterminant`] if the matrix is not invertible, or
+/// [`ImageError::UnsupportedInterpolation`] for the interpolation modes other than
+/// [`InterpolationMode::Nearest`] and [`InterpolationMode::Bilinear`].
+///
 /// # Example
 ///
 /// ```
@@ -98,12 +112,95 @@ fn transform_point(x: f32, y: f32, m: &[f32; 9]) -> (f32, f32) {
 /// assert_eq!(dst.size().width, 2);
 /// assert_eq!(dst.size().height, 3);
 /// ```
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(skip_all, fields(width = src.width(), height = src.height(), channels = C))
+)]
 pub fn warp_perspective<const C: usize, A1: ImageAllocator, A2: ImageAllocator>(
     src: &Image<f32, C, A1>,
     dst: &mut Image<f32, C, A2>,
     m: &[f32; 9],
     interpolation: InterpolationMode,
 ) -> Result<(), ImageError> {
+    warp_perspective_impl(src, dst, m, interpolation, None)
+}
+
+/// Applies a perspective transformation to an image with a border mode.
+///
+/// Each pixel of `dst` is mapped to the source image with the inverse homography and sampled
+/// with the interpolation mode, e.g. to rectify a document. The size of the output is the
+/// size of `dst`.
+///
+/// # Arguments
+///
+/// * `src` - The input image with shape (height, width, channels).
+/// * `dst` - The output image with shape (height, width, channels).
+/// * `m` - The 3x3 perspective transformation matrix src -> dst.
+/// * `interpolation` - The interpolation mode to use, [`InterpolationMode::Nearest`] or
+///   [`InterpolationMode::Bilinear`].
+/// * `border` - How to fill the pixels which map outside of the source image.
+///
+/// # Errors
+///
+/// Returns [`ImageError::CannotComputeDeterminant`] if the matrix is not invertible,
+/// [`ImageError::InvalidSize`] if the source image is empty, or
+/// [`ImageError::UnsupportedInterpolation`] for the interpolation modes other than
+/// [`InterpolationMode::Nearest`] and [`InterpolationMode::Bilinear`].
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_image::allocator::CpuAllocator;
+/// use kornia_imgproc::interpolation::InterpolationMode;
+/// use kornia_imgproc::warp::{warp_perspective_with_border, BorderMode};
+///
+/// let size = ImageSize {
+///     width: 3,
+///     height: 1,
+/// };
+/// let src = Image::<f32, 1, _>::new(size, vec![1.0, 2.0, 3.0], CpuAllocator).unwrap();
+/// let mut dst = Image::<f32, 1, _>::from_size_val(size, 0.0, CpuAllocator).unwrap();
+///
+/// // shift the image by one pixel to the left
+/// let m = [1.0, 0.0, -1.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0];
+///
+/// warp_perspective_with_border(
+///     &src,
+///     &mut dst,
+///     &m,
+///     InterpolationMode::Nearest,
+///     BorderMode::Constant(255.0),
+/// )
+/// .unwrap();
+/// assert_eq!(dst.as_slice(), &[2.0, 3.0, 255.0]);
+/// ```
+#[cfg_attr(
+    feature = "tracing",
+    tracing::instrument(skip_all, fields(width = src.width(), height = src.height(), channels = C))
+)]
+pub fn warp_perspective_with_border<const C: usize, A1: ImageAllocator, A2: ImageAllocator>(
+    src: &Image<f32, C, A1>,
+    dst: &mut Image<f32, C, A2>,
+    m: &[f32; 9],
+    interpolation: InterpolationMode,
+    border: BorderMode,
+) -> Result<(), ImageError> {
+    check_source_size(src)?;
+    warp_perspective_impl(src, dst, m, interpolation, Some(border))
+}
+
+/// Warp `src` into `dst`, filling the pixels which map outside of the source image with
+/// `border`, or leaving them untouched if it is `None`.
+fn warp_perspective_impl<const C: usize, A1: ImageAllocator, A2: ImageAllocator>(
+    src: &Image<f32, C, A1>,
+    dst: &mut Image<f32, C, A2>,
+    m: &[f32; 9],
+    interpolation: InterpolationMode,
+    border: Option<BorderMode>,
+) -> Result<(), ImageError> {
+    check_interpolation(interpolation)?;
+
     // inverse perspective matrix
     // TODO: allow later to skip the inverse calculation if user provides it
     let inv_m = inverse_perspective_matrix(m)?;
@@ -115,13 +212,18 @@ pub fn warp_perspective<const C: usize, A1: ImageAllocator, A2: ImageAllocator>(
         Ok((xdst, ydst))
     })?;
 
-    // apply affine transformation
-    parallel::par_iter_rows_resample(dst, &map_x, &map_y, |&x, &y, dst_pixel| {
-        if x >= 0.0f32 && x < src.cols() as f32 && y >= 0.0f32 && y < src.rows() as f32 {
-            dst_pixel
-                .iter_mut()
-                .enumerate()
-                .for_each(|(k, pixel)| *pixel = interpolate_pixel(src, x, y, k, interpolation));
+    // apply perspective transformation
+    parallel::par_iter_rows_resample(dst, &map_x, &map_y, |&x, &y, dst_pixel| match border {
+        Some(border) => dst_pixel.iter_mut().enumerate().for_each(|(k, pixel)| {
+            *pixel = sample_with_border(src, x, y, k, interpolation, border)
+        }),
+        None => {
+            if x >= 0.0f32 && x < src.cols() as f32 && y >= 0.0f32 && y < src.rows() as f32 {
+                dst_pixel
+                    .iter_mut()
+                    .enumerate()
+                    .for_each(|(k, pixel)| *pixel = interpolate_pixel(src, x, y, k, interpolation));
+            }
         }
     });
 
@@ -320,4 +422,98 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn warp_perspective_border() -> Result<(), ImageError> {
+        use super::{inverse_perspective_matrix, transform_point, warp_perspective_with_border};
+        use crate::warp::BorderMode;
+
+        // a linear ramp, which the bilinear interpolation reproduces exactly
+        let size = ImageSize {
+            width: 16,
+            height: 12,
+        };
+        let data = (0..size.height)
+            .flat_map(|y| (0..size.width).map(move |x| (x + 2 * y) as f32))
+            .collect();
+        let image = Image::<f32, 1, _>::new(size, data, CpuAllocator)?;
+
+        #[rustfmt::skip]
+        let h = [
+            1.1, 0.05, 1.0,
+            0.02, 0.95, 0.5,
+            0.001, 0.0005, 1.0,
+        ];
+        let h_inv = inverse_perspective_matrix(&h)?;
+        let bilinear = super::InterpolationMode::Bilinear;
+
+        let mut warped = Image::<f32, 1, _>::from_size_val(size, 0.0, CpuAllocator)?;
+        warp_perspective_with_border(
+            &image,
+            &mut warped,
+            &h,
+            bilinear,
+            BorderMode::Constant(-1.0),
+        )?;
+        // the top-left corner maps outside of the source
+        assert_eq!(warped.get_pixel(0, 0, 0)?, &-1.0);
+
+        let mut recovered = Image::<f32, 1, _>::from_size_val(size, 0.0, CpuAllocator)?;
+        warp_perspective_with_border(
+            &warped,
+            &mut recovered,
+            &h_inv,
+            bilinear,
+            BorderMode::Constant(-1.0),
+        )?;
+
+        // the round trip recovers the pixels which stay away from the borders
+        let mut checked = 0;
+        for y in 0..size.height {
+            for x in 0..size.width {
+                let (u, v) = transform_point(x as f32, y as f32, &h);
+                let (max_u, max_v) = ((size.width - 3) as f32, (size.height - 3) as f32);
+                if (2.0..=max_u).contains(&u) && (2.0..=max_v).contains(&v) {
+                    let expected = image.get_pixel(x, y, 0)?;
+                    assert!((recovered.get_pixel(x, y, 0)? - expected).abs() < 1e-2);
+                    checked += 1;
+                }
+            }
+        }
+        assert!(checked > 50);
+
+        // the replicated border takes the value of the nearest source pixel
+        warp_perspective_with_border(&image, &mut warped, &h, bilinear, BorderMode::Replicate)?;
+        assert_eq!(warped.get_pixel(0, 0, 0)?, &0.0);
+
+        let lanczos = super::InterpolationMode::Lanczos;
+        assert!(matches!(
+            super::warp_perspective(&image, &mut warped, &h, lanczos),
+            Err(ImageError::UnsupportedInterpolation(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn warp_perspective_out_of_bounds_untouched() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 3,
+            height: 1,
+        };
+        let image = Image::<f32, 1, _>::new(size, vec![1.0, 2.0, 3.0], CpuAllocator)?;
+        let mut warped = Image::<f32, 1, _>::from_size_val(size, 7.0, CpuAllocator)?;
+
+        // shift left by 1 pixel, the last pixel maps outside of the source
+        let m = [1.0, 0.0, -1.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0];
+        super::warp_perspective(&image, &mut warped, &m, super::InterpolationMode::Nearest)?;
+        assert_eq!(warped.as_slice(), &[2.0, 3.0, 7.0]);
+
+        // an empty source leaves the whole image untouched
+        let empty = Image::<f32, 1, _>::new([0, 1].into(), vec![], CpuAllocator)?;
+        super::warp_perspective(&empty, &mut warped, &m, super::InterpolationMode::Nearest)?;
+        assert_eq!(warped.as_slice(), &[2.0, 3.0, 7.0]);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,254 @@
+use super::perspective::{inverse_perspective_matrix, transform_point};
+use crate::interpolation::sample_bilinear_rgb8;
+use kornia_image::{allocator::ImageAllocator, Image, ImageError, ImageSize};
+use kornia_tensor::CpuAllocator;
+use rayon::prelude::*;
+
+/// The largest size of the panorama along each axis, relative to the sum of the image sizes.
+const MAX_CANVAS_SCALE: f32 = 4.0;
+
+/// Stitch two overlapping images into a single panorama.
+///
+/// The second image is warped into the frame of the first one with a perspective
+/// transformation and the overlapping region is blended with feathering, i.e. each image is
+/// weighted by the distance of the pixel to its border so that the seams are smoothed out.
+///
+/// # Arguments
+///
+/// * `img1` - The reference image.
+/// * `img2` - The image to warp into the frame of the reference image.
+/// * `m` - The 3x3 perspective transformation matrix img2 -> img1.
+///
+/// # Returns
+///
+/// A new image large enough to contain both images. The pixels not covered by any of the
+/// images are set to zero.
+///
+/// # Errors
+///
+/// Returns [`ImageError::InvalidSize`] if an image is empty, or
+/// [`ImageError::CannotComputeDeterminant`] if the transformation matrix is not invertible.
+/// Returns [`ImageError::InvalidImageSize`] with the size of the first image and the size of
+/// the panorama if the transformation is degenerate, i.e. it maps a corner of the second image
+/// to infinity or behind the camera, or the panorama is more than four times larger than both
+/// images side by side.
+///
+/// # Example
+///
+/// ```
+/// use kornia_image::{Image, ImageSize};
+/// use kornia_image::allocator::CpuAllocator;
+/// use kornia_imgproc::warp::stitch;
+///
+/// let size = ImageSize { width: 4, height: 3 };
+/// let img1 = Image::<u8, 3, _>::from_size_val(size, 10, CpuAllocator).unwrap();
+/// let img2 = Image::<u8, 3, _>::from_size_val(size, 20, CpuAllocator).unwrap();
+///
+/// // the second image starts two pixels to the right of the first one
+/// let m = [1.0, 0.0, 2.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0];
+/// let panorama = stitch(&img1, &img2, &m).unwrap();
+/// assert_eq!(panorama.width(), 6);
+/// assert_eq!(panorama.height(), 3);
+/// ```
+pub fn stitch<A1: ImageAllocator, A2: ImageAllocator>(
+    img1: &Image<u8, 3, A1>,
+    img2: &Image<u8, 3, A2>,
+    m: &[f32; 9],
+) -> Result<Image<u8, 3, CpuAllocator>, ImageError> {
+    for (cols, rows) in [(img1.cols(), img1.rows()), (img2.cols(), img2.rows())] {
+        if cols == 0 || rows == 0 {
+            return Err(ImageError::InvalidSize(cols, rows));
+        }
+    }
+
+    let inv_m = inverse_perspective_matrix(m)?;
+
+    // bounding box of both images in the frame of the first one
+    let (w1, h1) = (img1.cols() as f32, img1.rows() as f32);
+    let (w2, h2) = (img2.cols() as f32, img2.rows() as f32);
+    let corners = [
+        (0.0, 0.0),
+        (w2 - 1.0, 0.0),
+        (0.0, h2 - 1.0),
+        (w2 - 1.0, h2 - 1.0),
+    ];
+    // a corner on or behind the line at infinity has no finite position in the panorama
+    let in_front = corners
+        .iter()
+        .all(|&(x, y)| m[6] * x + m[7] * y + m[8] > 0.0);
+    let corners = corners.map(|(x, y)| transform_point(x, y, m));
+
+    let min_x = corners.iter().fold(0.0f32, |acc, p| acc.min(p.0)).floor();
+    let min_y = corners.iter().fold(0.0f32, |acc, p| acc.min(p.1)).floor();
+    let max_x = corners.iter().fold(w1 - 1.0, |acc, p| acc.max(p.0)).ceil();
+    let max_y = corners.iter().fold(h1 - 1.0, |acc, p| acc.max(p.1)).ceil();
+
+    let (width, height) = (max_x - min_x + 1.0, max_y - min_y + 1.0);
+    let max_width = MAX_CANVAS_SCALE * (w1 + w2);
+    let max_height = MAX_CANVAS_SCALE * (h1 + h2);
+    // the comparisons are false for NaN, so the non-finite sizes are rejected too
+    if !(in_front && width <= max_width && height <= max_height) {
+        return Err(ImageError::InvalidImageSize(
+            img1.cols(),
+            img1.rows(),
+            width as usize,
+            height as usize,
+        ));
+    }
+
+    let size = ImageSize {
+        width: width as usize,
+        height: height as usize,
+    };
+    let mut dst = Image::from_size_val(size, 0u8, CpuAllocator)?;
+
+    dst.as_slice_mut()
+        .par_chunks_exact_mut(size.width * 3)
+        .enumerate()
+        .for_each(|(row, dst_row)| {
+            dst_row
+                .chunks_exact_mut(3)
+                .enumerate()
+                .for_each(|(col, dst_pixel)| {
+                    let (x1, y1) = (col as f32 + min_x, row as f32 + min_y);
+                    let (x2, y2) = transform_point(x1, y1, &inv_m);
+
+                    let mut acc = [0.0f32; 3];
+                    let mut weight_sum = 0.0;
+
+                    for (img_data, (x, y), (w, h)) in [
+                        (img1.as_slice(), (x1, y1), (w1, h1)),
+                        (img2.as_slice(), (x2, y2), (w2, h2)),
+                    ] {
+                        let Some(pixel) =
+                            sample_bilinear_rgb8(img_data, h as usize, w as usize, x, y)
+                        else {
+                            continue;
+                        };
+
+                        // distance to the closest border of the image
+                        let weight = x.min(w - 1.0 - x).min(y).min(h - 1.0 - y) + 1.0;
+                        acc.iter_mut()
+                            .zip(pixel.iter())
+                            .for_each(|(a, &p)| *a += weight * p);
+                        weight_sum += weight;
+                    }
+
+                    if weight_sum > 0.0 {
+                        dst_pixel.iter_mut().zip(acc.iter()).for_each(|(d, &a)| {
+                            *d = (a / weight_sum).round().clamp(0.0, 255.0) as u8;
+                        });
+                    }
+                });
+        });
+
+    Ok(dst)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crop::crop_image;
+
+    #[test]
+    fn test_stitch_split_image() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 20,
+            height: 10,
+        };
+        let data = (0..size.width * size.height * 3)
+            .map(|i| ((i * 37) % 256) as u8)
+            .collect();
+        let image = Image::<u8, 3, _>::new(size, data, CpuAllocator)?;
+
+        // two halves overlapping in the columns [8, 12)
+        let half_size = ImageSize {
+            width: 12,
+            height: 10,
+        };
+        let mut left = Image::<u8, 3, _>::from_size_val(half_size, 0, CpuAllocator)?;
+        let mut right = Image::<u8, 3, _>::from_size_val(half_size, 0, CpuAllocator)?;
+        crop_image(&image, &mut left, 0, 0)?;
+        crop_image(&image, &mut right, 8, 0)?;
+
+        let m = [1.0, 0.0, 8.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0];
+        let panorama = stitch(&left, &right, &m)?;
+        assert_eq!(panorama.size(), size);
+        assert_eq!(panorama.as_slice(), image.as_slice());
+
+        // swapping the images extends the canvas to the left
+        let m = [1.0, 0.0, -8.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0];
+        let panorama = stitch(&right, &left, &m)?;
+        assert_eq!(panorama.size(), size);
+        assert_eq!(panorama.as_slice(), image.as_slice());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_stitch_feathering() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 5,
+            height: 9,
+        };
+        let img1 = Image::<u8, 3, _>::from_size_val(size, 0, CpuAllocator)?;
+        let img2 = Image::<u8, 3, _>::from_size_val(size, 200, CpuAllocator)?;
+
+        // overlap of four columns with the second image shifted by one pixel
+        let m = [1.0, 0.0, 1.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0];
+        let panorama = stitch(&img1, &img2, &m)?;
+        assert_eq!(panorama.width(), 6);
+        assert_eq!(panorama.height(), 9);
+
+        // the middle row goes smoothly from the first to the second image
+        let row = (0..panorama.width())
+            .map(|x| panorama.get_pixel(x, 4, 0).copied())
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(row, vec![0, 67, 80, 120, 133, 200]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_stitch_invalid() -> Result<(), ImageError> {
+        let size = ImageSize {
+            width: 4,
+            height: 3,
+        };
+        let image = Image::<u8, 3, _>::from_size_val(size, 10, CpuAllocator)?;
+        let identity = [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0];
+
+        let empty = Image::<u8, 3, _>::new(
+            ImageSize {
+                width: 0,
+                height: 3,
+            },
+            vec![],
+            CpuAllocator,
+        )?;
+        assert!(matches!(
+            stitch(&image, &empty, &identity),
+            Err(ImageError::InvalidSize(0, 3))
+        ));
+        assert!(matches!(
+            stitch(&empty, &image, &identity),
+            Err(ImageError::InvalidSize(0, 3))
+        ));
+
+        // a corner of the second image is sent to infinity
+        let m = [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, -1.0 / 3.0, 0.0, 1.0];
+        assert!(matches!(
+            stitch(&image, &image, &m),
+            Err(ImageError::InvalidImageSize(4, 3, _, _))
+        ));
+
+        // a huge translation would allocate a huge panorama
+        let m = [1.0, 0.0, 1e6, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0];
+        assert!(matches!(
+            stitch(&image, &image, &m),
+            Err(ImageError::InvalidImageSize(4, 3, _, _))
+        ));
+
+        Ok(())
+    }
+}
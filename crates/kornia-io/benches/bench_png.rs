@@ -0,0 +1,61 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use kornia_image::{allocator::CpuAllocator, Image, ImageSize};
+use kornia_io::png::{
+    encode_image_png_rgb16, encode_image_png_rgb16_pipelined, encode_image_png_rgb8,
+    encode_image_png_rgb8_pipelined,
+};
+use std::hint::black_box;
+
+fn bench_encode_png(c: &mut Criterion) {
+    let mut group = c.benchmark_group("PngEncoder");
+    group.sample_size(10);
+
+    // 4K frame with a smooth gradient plus some texture
+    let size = ImageSize {
+        width: 3840,
+        height: 2160,
+    };
+    let data = (0..size.height)
+        .flat_map(|y| (0..size.width).flat_map(move |x| [x as u16, y as u16, (x ^ y) as u16]))
+        .collect::<Vec<_>>();
+
+    let image_u8 =
+        Image::<u8, 3, _>::new(size, data.iter().map(|&v| v as u8).collect(), CpuAllocator)
+            .unwrap();
+    let image_u16 = Image::<u16, 3, _>::new(size, data, CpuAllocator).unwrap();
+
+    let mut buffer = Vec::new();
+
+    group.bench_function("rgb8_serial", |b| {
+        b.iter(|| {
+            buffer.clear();
+            encode_image_png_rgb8(black_box(&image_u8), &mut buffer).unwrap()
+        })
+    });
+
+    group.bench_function("rgb8_pipelined", |b| {
+        b.iter(|| {
+            buffer.clear();
+            encode_image_png_rgb8_pipelined(black_box(&image_u8), &mut buffer).unwrap()
+        })
+    });
+
+    group.bench_function("rgb16_serial", |b| {
+        b.iter(|| {
+            buffer.clear();
+            encode_image_png_rgb16(black_box(&image_u16), &mut buffer).unwrap()
+        })
+    });
+
+    group.bench_function("rgb16_pipelined", |b| {
+        b.iter(|| {
+            buffer.clear();
+            encode_image_png_rgb16_pipelined(black_box(&image_u16), &mut buffer).unwrap()
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_encode_png);
+criterion_main!(benches);
@@ -2,12 +2,27 @@ use super::GstAllocator;
 use crate::stream::error::StreamCaptureError;
 use circular_buffer::CircularBuffer;
 use gstreamer::prelude::*;
-use kornia_image::{Image, ImageSize};
-use std::sync::{Arc, Mutex};
+use kornia_image::{
+    allocator::{CpuAllocator, ImageAllocator},
+    Image, ImageSize,
+};
+use kornia_tensor::{storage::TensorStorage, view::TensorView};
+use std::{
+    marker::PhantomData,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant},
+};
+
+/// The number of frames kept in the capture buffer.
+const FRAME_BUFFER_CAPACITY: usize = 5;
 
 // utility struct to store the frame buffer
 struct FrameBuffer {
     buffer: gstreamer::Buffer,
+    caps: gstreamer::Caps,
     width: i32,
     height: i32,
 }
@@ -38,11 +53,210 @@ impl From<gstreamer::State> for StreamerState {
     }
 }
 
+/// Statistics about the frames received by a [`StreamCapture`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CaptureStats {
+    /// The total number of frames received from the pipeline.
+    pub frames_received: u64,
+    /// The number of frames overwritten in the buffer before being grabbed.
+    pub frames_dropped: u64,
+    /// The number of frames currently waiting in the buffer.
+    pub buffer_len: usize,
+    /// The maximum number of frames kept in the buffer.
+    pub buffer_capacity: usize,
+    /// The average time between two received frames, if at least two were received.
+    pub mean_frame_interval: Option<Duration>,
+    /// The number of times the pipeline was rebuilt after an error or an end of stream.
+    pub reconnects: u64,
+}
+
+// utility struct to accumulate the capture statistics in the appsink callback
+#[derive(Default)]
+struct CaptureCounters {
+    frames_received: u64,
+    frames_dropped: u64,
+    first_frame: Option<Instant>,
+    last_frame: Option<Instant>,
+    reconnects: u64,
+}
+
+/// The format of the frames negotiated between the pipeline and its appsink.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CaptureCaps {
+    /// The width of the frames in pixels.
+    pub width: usize,
+    /// The height of the frames in pixels.
+    pub height: usize,
+    /// The GStreamer name of the pixel format, e.g. `RGB` or `NV12`.
+    pub format: String,
+    /// The number of frames per second, if the caps define a framerate.
+    pub framerate: Option<f64>,
+}
+
+/// The policy to rebuild the pipeline of a [`StreamCapture`] after an error or an end of stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// The maximum number of consecutive attempts to rebuild the pipeline without a frame.
+    pub max_retries: u32,
+    /// The time to wait before each attempt.
+    pub backoff: Duration,
+}
+
+/// A frame in the NV12 format as delivered by the pipeline, without color conversion.
+///
+/// NV12 has a full resolution Y plane and a plane of interleaved U and V samples subsampled
+/// by two in both directions. The planes keep the offsets and the row strides of the
+/// GStreamer buffer, so the rows can be padded. Use
+/// `kornia_imgproc::color::convert_nv12_to_rgb_u8` to convert the frame to RGB.
+pub struct Nv12Frame {
+    y: TensorStorage<u8, GstAllocator>,
+    uv: TensorStorage<u8, GstAllocator>,
+    size: ImageSize,
+    y_stride: usize,
+    uv_stride: usize,
+}
+
+impl Nv12Frame {
+    /// Gets the size of the frame in pixels.
+    pub fn size(&self) -> ImageSize {
+        self.size
+    }
+
+    /// Gets the number of bytes between two rows of the Y plane.
+    pub fn y_stride(&self) -> usize {
+        self.y_stride
+    }
+
+    /// Gets the number of bytes between two rows of the UV plane.
+    pub fn uv_stride(&self) -> usize {
+        self.uv_stride
+    }
+
+    /// Gets the Y plane as a view with shape (H, W).
+    pub fn y_plane(&self) -> TensorView<'_, u8, 2, GstAllocator> {
+        // the plane was checked against the buffer when the frame was grabbed
+        TensorView::from_storage(
+            &self.y,
+            [self.size.height, self.size.width],
+            [self.y_stride, 1],
+            0,
+        )
+        .expect("Failed to create a view of the Y plane")
+    }
+
+    /// Gets the interleaved UV plane as a view with shape (H / 2, W / 2, 2).
+    pub fn uv_plane(&self) -> TensorView<'_, u8, 3, GstAllocator> {
+        TensorView::from_storage(
+            &self.uv,
+            [self.size.height.div_ceil(2), self.size.width.div_ceil(2), 2],
+            [self.uv_stride, 2, 1],
+            0,
+        )
+        .expect("Failed to create a view of the UV plane")
+    }
+}
+
+/// A frame in the RGB format that keeps the row stride of the GStreamer buffer.
+///
+/// GStreamer aligns the rows of RGB frames to 4 bytes, so the rows of a frame whose width is
+/// not a multiple of 4 are padded. The frame reports its layout with the numpy conventions, a
+/// shape (H, W, 3) in row-major order and strides in bytes, so the buffer can be wrapped in an
+/// array without copying, e.g. with `numpy.lib.stride_tricks.as_strided`.
+pub struct RgbFrame {
+    data: TensorStorage<u8, GstAllocator>,
+    size: ImageSize,
+    stride: usize,
+}
+
+impl RgbFrame {
+    /// Gets the size of the frame in pixels.
+    pub fn size(&self) -> ImageSize {
+        self.size
+    }
+
+    /// Gets the number of bytes between two rows, including the padding.
+    pub fn stride(&self) -> usize {
+        self.stride
+    }
+
+    /// Gets the shape of the frame as (H, W, 3).
+    pub fn shape(&self) -> [usize; 3] {
+        [self.size.height, self.size.width, 3]
+    }
+
+    /// Gets the strides of the frame in bytes for each dimension of [`RgbFrame::shape`].
+    pub fn strides(&self) -> [usize; 3] {
+        [self.stride, 3, 1]
+    }
+
+    /// Checks whether the rows are adjacent in memory, i.e. not padded.
+    pub fn is_contiguous(&self) -> bool {
+        self.stride == self.size.width * 3
+    }
+
+    /// Gets a pointer to the first pixel of the frame.
+    pub fn as_ptr(&self) -> *const u8 {
+        self.data.as_ptr()
+    }
+
+    /// Gets the frame as a view with shape (H, W, 3).
+    pub fn view(&self) -> TensorView<'_, u8, 3, GstAllocator> {
+        // the rows were checked against the buffer when the frame was grabbed
+        TensorView::from_storage(&self.data, self.shape(), self.strides(), 0)
+            .expect("Failed to create a view of the frame")
+    }
+
+    /// Copies the frame to a contiguous image, dropping the row padding.
+    pub fn to_image(&self) -> Result<Image<u8, 3, CpuAllocator>, StreamCaptureError> {
+        let row_len = self.size.width * 3;
+        let mut data = Vec::with_capacity(row_len * self.size.height);
+        for row in 0..self.size.height {
+            let start = row * self.stride;
+            data.extend_from_slice(&self.data.as_slice()[start..start + row_len]);
+        }
+        Image::new(self.size, data, CpuAllocator).map_err(StreamCaptureError::ImageError)
+    }
+}
+
+/// A frame borrowed from a [`StreamCapture`] without copying or wrapping its buffer.
+///
+/// The frame dereferences to an [`Image`] that reads the mapped GStreamer buffer directly.
+/// It borrows the capture mutably, so it cannot be kept past the next grab, which unmaps the
+/// buffer:
+///
+/// ```compile_fail,E0499
+/// use kornia_io::gstreamer::StreamCapture;
+///
+/// let mut capture = StreamCapture::new("videotestsrc ! appsink name=sink").unwrap();
+/// let frame = capture.grab_borrowed().unwrap();
+/// let next = capture.grab_borrowed().unwrap();
+/// drop(frame);
+/// ```
+pub struct FrameRef<'a> {
+    image: Image<u8, 3, GstAllocator>,
+    _capture: PhantomData<&'a mut StreamCapture>,
+}
+
+impl std::ops::Deref for FrameRef<'_> {
+    type Target = Image<u8, 3, GstAllocator>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.image
+    }
+}
+
 /// Represents a stream capture pipeline using GStreamer.
 pub struct StreamCapture {
     pub(crate) pipeline: gstreamer::Pipeline,
-    circular_buffer: Arc<Mutex<CircularBuffer<5, FrameBuffer>>>,
+    circular_buffer: Arc<Mutex<CircularBuffer<FRAME_BUFFER_CAPACITY, FrameBuffer>>>,
     fps: Arc<Mutex<gstreamer::Fraction>>,
+    counters: Arc<Mutex<CaptureCounters>>,
+    pipeline_desc: String,
+    retry_policy: Option<RetryPolicy>,
+    retries: u32,
+    closed: AtomicBool,
+    // the buffer of the last frame grabbed with `grab_borrowed`, mapped until the next one
+    borrowed_frame: Option<gstreamer::buffer::MappedBuffer<gstreamer::buffer::Readable>>,
 }
 
 impl StreamCapture {
@@ -60,49 +274,50 @@ impl StreamCapture {
             gstreamer::init()?;
         }
 
-        let pipeline = gstreamer::parse::launch(pipeline_desc)?
-            .dynamic_cast::<gstreamer::Pipeline>()
-            .map_err(StreamCaptureError::DowncastPipelineError)?;
-
-        let appsink = pipeline
-            .by_name("sink")
-            .ok_or_else(|| StreamCaptureError::GetElementByNameError)?
-            .dynamic_cast::<gstreamer_app::AppSink>()
-            .map_err(StreamCaptureError::DowncastPipelineError)?;
-
         let circular_buffer = Arc::new(Mutex::new(CircularBuffer::new()));
         let fps = Arc::new(Mutex::new(gstreamer::Fraction::new(1, 1)));
+        let counters = Arc::new(Mutex::new(CaptureCounters::default()));
 
-        appsink.set_callbacks(
-            gstreamer_app::AppSinkCallbacks::builder()
-                .new_sample({
-                    let circular_buffer = circular_buffer.clone();
-                    let fps = fps.clone();
-
-                    move |sink| {
-                        Self::extract_frame_buffer(sink)
-                            .map_err(|_| gstreamer::FlowError::Eos)
-                            .and_then(|(frame_buffer, fps_fraction)| {
-                                circular_buffer
-                                    .lock()
-                                    .map_err(|_| gstreamer::FlowError::Error)?
-                                    .push_back(frame_buffer);
-                                *fps.lock().map_err(|_| gstreamer::FlowError::Error)? =
-                                    fps_fraction;
-                                Ok(gstreamer::FlowSuccess::Ok)
-                            })
-                    }
-                })
-                .build(),
-        );
+        let pipeline = Self::build_pipeline(pipeline_desc, &circular_buffer, &fps, &counters)?;
 
         Ok(Self {
             pipeline,
             circular_buffer,
             fps,
+            counters,
+            pipeline_desc: pipeline_desc.to_string(),
+            retry_policy: None,
+            retries: 0,
+            closed: AtomicBool::new(false),
+            borrowed_frame: None,
         })
     }
 
+    /// Creates a new StreamCapture instance that rebuilds its pipeline when it fails.
+    ///
+    /// When the pipeline posts an error or reaches the end of the stream, e.g. because a
+    /// network camera dropped the connection, the pipeline is torn down and rebuilt from
+    /// `pipeline_desc` according to `retry_policy`. The check runs when grabbing a frame, so
+    /// the grab calls may block for the backoff time while reconnecting. Frames already in
+    /// the buffer are kept. A pipeline stopped with [`StreamCapture::close`] is not rebuilt.
+    ///
+    /// # Arguments
+    ///
+    /// * `pipeline_desc` - A string describing the GStreamer pipeline.
+    /// * `retry_policy` - The policy to rebuild the pipeline.
+    ///
+    /// # Returns
+    ///
+    /// A Result containing the StreamCapture instance or a StreamCaptureError.
+    pub fn with_reconnect(
+        pipeline_desc: &str,
+        retry_policy: RetryPolicy,
+    ) -> Result<Self, StreamCaptureError> {
+        let mut capture = Self::new(pipeline_desc)?;
+        capture.retry_policy = Some(retry_policy);
+        Ok(capture)
+    }
+
     /// Gets the current fps of the stream
     pub fn get_fps(&self) -> Option<f64> {
         self.fps
@@ -116,12 +331,80 @@ impl StreamCapture {
         self.pipeline.current_state().into()
     }
 
+    /// Gets the format of the frames negotiated by the appsink.
+    ///
+    /// This allows allocating the destination images before grabbing the first frame.
+    ///
+    /// # Returns
+    ///
+    /// The negotiated caps, or None if the pipeline has not negotiated them yet, e.g. before
+    /// [`StreamCapture::start`] or while the pipeline is prerolling.
+    pub fn caps(&self) -> Option<CaptureCaps> {
+        let caps = self
+            .pipeline
+            .by_name("sink")?
+            .static_pad("sink")?
+            .current_caps()?;
+        let structure = caps.structure(0)?;
+
+        let framerate = structure
+            .get::<gstreamer::Fraction>("framerate")
+            .ok()
+            .filter(|fps| fps.denom() != 0)
+            .map(|fps| fps.numer() as f64 / fps.denom() as f64);
+
+        Some(CaptureCaps {
+            width: structure.get::<i32>("width").ok()? as usize,
+            height: structure.get::<i32>("height").ok()? as usize,
+            format: structure.get::<String>("format").ok()?,
+            framerate,
+        })
+    }
+
+    /// Gets the statistics of the frames received since the stream was started.
+    ///
+    /// A growing number of dropped frames means that the frames are not grabbed as fast
+    /// as they are produced by the pipeline.
+    pub fn stats(&self) -> Result<CaptureStats, StreamCaptureError> {
+        let buffer_len = self
+            .circular_buffer
+            .lock()
+            .map_err(|_| StreamCaptureError::MutexPoisonError)?
+            .len();
+
+        let counters = self
+            .counters
+            .lock()
+            .map_err(|_| StreamCaptureError::MutexPoisonError)?;
+
+        let mean_frame_interval = match (counters.first_frame, counters.last_frame) {
+            (Some(first), Some(last)) if counters.frames_received > 1 => {
+                Some((last - first) / (counters.frames_received - 1) as u32)
+            }
+            _ => None,
+        };
+
+        Ok(CaptureStats {
+            frames_received: counters.frames_received,
+            frames_dropped: counters.frames_dropped,
+            buffer_len,
+            buffer_capacity: FRAME_BUFFER_CAPACITY,
+            mean_frame_interval,
+            reconnects: counters.reconnects,
+        })
+    }
+
     /// Starts the stream capture pipeline and processes messages on the bus.
     pub fn start(&self) -> Result<(), StreamCaptureError> {
         self.circular_buffer
             .lock()
             .map_err(|_| StreamCaptureError::MutexPoisonError)?
             .clear();
+        *self
+            .counters
+            .lock()
+            .map_err(|_| StreamCaptureError::MutexPoisonError)? = CaptureCounters::default();
+        self.closed.store(false, Ordering::Relaxed);
         self.pipeline.set_state(gstreamer::State::Playing)?;
         Ok(())
     }
@@ -135,6 +418,8 @@ impl StreamCapture {
     ///
     /// An Option containing the last captured Image or None if no image has been captured yet.
     pub fn grab_rgb8(&mut self) -> Result<Option<Image<u8, 3, GstAllocator>>, StreamCaptureError> {
+        self.reconnect_if_needed()?;
+
         let mut circular_buffer = self
             .circular_buffer
             .lock()
@@ -143,6 +428,7 @@ impl StreamCapture {
         let Some(frame_buffer) = circular_buffer.pop_front() else {
             return Ok(None);
         };
+        self.retries = 0;
 
         // unpack the frame buffer
         let width = frame_buffer.width;
@@ -178,8 +464,304 @@ impl StreamCapture {
         Ok(Some(image))
     }
 
+    /// Grabs the last captured image frame as a borrowed view of the GStreamer buffer.
+    ///
+    /// This is the cheapest way to read the frames with a single consumer: the buffer is
+    /// mapped and viewed in place, and stays mapped until the next call. The returned frame
+    /// borrows the capture, so the borrow checker prevents using it after the next grab.
+    ///
+    /// # Returns
+    ///
+    /// An Option containing the frame or None if no image has been captured yet.
+    pub fn grab_borrowed(&mut self) -> Result<Option<FrameRef<'_>>, StreamCaptureError> {
+        self.reconnect_if_needed()?;
+
+        // unmap the previous frame before taking the next one
+        self.borrowed_frame = None;
+
+        let Some(frame_buffer) = self
+            .circular_buffer
+            .lock()
+            .map_err(|_| StreamCaptureError::MutexPoisonError)?
+            .pop_front()
+        else {
+            return Ok(None);
+        };
+        self.retries = 0;
+
+        let size = ImageSize {
+            width: frame_buffer.width as usize,
+            height: frame_buffer.height as usize,
+        };
+
+        let mapped_buffer = self.borrowed_frame.insert(
+            frame_buffer
+                .buffer
+                .into_mapped_buffer_readable()
+                .map_err(|_| StreamCaptureError::GetBufferError)?,
+        );
+
+        // the mapped buffer is owned by the capture and outlives the frame, so the image
+        // does not need to keep a reference to the gstreamer buffer
+        let image = unsafe {
+            Image::from_raw_parts(
+                size,
+                mapped_buffer.as_ptr(),
+                mapped_buffer.len(),
+                GstAllocator::default(),
+            )
+            .map_err(StreamCaptureError::ImageError)
+        }?;
+
+        Ok(Some(FrameRef {
+            image,
+            _capture: PhantomData,
+        }))
+    }
+
+    /// Grabs the last captured image frame into a preallocated image.
+    ///
+    /// Unlike [`StreamCapture::grab_rgb8`], the frame data is copied into `dst`, which allows
+    /// reusing the same image across frames without allocating in tight loops.
+    ///
+    /// # Arguments
+    ///
+    /// * `dst` - The destination image with the same size as the captured frames.
+    ///
+    /// # Returns
+    ///
+    /// `true` if a frame was copied into `dst`, or `false` if no frame has been captured yet.
+    ///
+    /// # Errors
+    ///
+    /// If the size of `dst` does not match the size of the frame, or the frame cannot be read,
+    /// an error is returned and the frame is kept in the buffer.
+    pub fn grab_into<A: ImageAllocator>(
+        &mut self,
+        dst: &mut Image<u8, 3, A>,
+    ) -> Result<bool, StreamCaptureError> {
+        self.reconnect_if_needed()?;
+
+        let mut circular_buffer = self
+            .circular_buffer
+            .lock()
+            .map_err(|_| StreamCaptureError::MutexPoisonError)?;
+
+        let Some(frame_buffer) = circular_buffer.front() else {
+            return Ok(false);
+        };
+
+        // check the size before consuming the frame so that the caller can retry
+        let frame_size = ImageSize {
+            width: frame_buffer.width as usize,
+            height: frame_buffer.height as usize,
+        };
+
+        if frame_size != dst.size() {
+            return Err(StreamCaptureError::ImageSizeMismatch(
+                frame_size,
+                dst.size(),
+            ));
+        }
+
+        let mapped_buffer = frame_buffer
+            .buffer
+            .map_readable()
+            .map_err(|_| StreamCaptureError::GetBufferError)?;
+
+        let dst_data = dst.as_slice_mut();
+        if mapped_buffer.len() != dst_data.len() {
+            return Err(StreamCaptureError::InvalidImageFormat(format!(
+                "Expected {} bytes, got {}",
+                dst_data.len(),
+                mapped_buffer.len()
+            )));
+        }
+
+        dst_data.copy_from_slice(mapped_buffer.as_slice());
+        drop(mapped_buffer);
+
+        // the frame is consumed only once it has been copied
+        circular_buffer.pop_front();
+        self.retries = 0;
+
+        Ok(true)
+    }
+
+    /// Grabs the last captured frame in the NV12 format without copying it.
+    ///
+    /// The pipeline must deliver NV12 frames to the appsink, e.g. ending with
+    /// `video/x-raw,format=NV12 ! appsink name=sink`. The plane offsets and strides are
+    /// taken from the video meta of the buffer if present, or from the caps otherwise.
+    ///
+    /// # Returns
+    ///
+    /// An Option containing the last captured frame or None if no frame has been captured yet.
+    ///
+    /// # Errors
+    ///
+    /// If the frame is not in the NV12 format or the buffer is too small for its planes,
+    /// an error is returned.
+    pub fn grab_nv12(&mut self) -> Result<Option<Nv12Frame>, StreamCaptureError> {
+        self.reconnect_if_needed()?;
+
+        let mut circular_buffer = self
+            .circular_buffer
+            .lock()
+            .map_err(|_| StreamCaptureError::MutexPoisonError)?;
+
+        let Some(frame_buffer) = circular_buffer.pop_front() else {
+            return Ok(None);
+        };
+        self.retries = 0;
+
+        let video_info = gstreamer_video::VideoInfo::from_caps(&frame_buffer.caps)
+            .map_err(|e| StreamCaptureError::GetCapsError(e.to_string()))?;
+
+        if video_info.format() != gstreamer_video::VideoFormat::Nv12 {
+            return Err(StreamCaptureError::InvalidImageFormat(format!(
+                "Expected NV12 frames, got {:?}",
+                video_info.format()
+            )));
+        }
+
+        // the video meta describes the actual layout when the producer pads the planes
+        let (offsets, strides) = match frame_buffer.buffer.meta::<gstreamer_video::VideoMeta>() {
+            Some(meta) => (meta.offset().to_vec(), meta.stride().to_vec()),
+            None => (video_info.offset().to_vec(), video_info.stride().to_vec()),
+        };
+
+        let size = ImageSize {
+            width: frame_buffer.width as usize,
+            height: frame_buffer.height as usize,
+        };
+        let (y_offset, uv_offset) = (offsets[0], offsets[1]);
+        let (y_stride, uv_stride) = (strides[0] as usize, strides[1] as usize);
+
+        let mapped_buffer = frame_buffer
+            .buffer
+            .into_mapped_buffer_readable()
+            .map_err(|_| StreamCaptureError::GetBufferError)?;
+
+        let data_ptr = mapped_buffer.as_ptr();
+        let data_len = mapped_buffer.len();
+
+        // the last row of each plane does not need to be padded
+        let y_len = (size.height - 1) * y_stride + size.width;
+        let uv_len = (size.height.div_ceil(2) - 1) * uv_stride + size.width.div_ceil(2) * 2;
+        if y_offset + y_len > data_len || uv_offset + uv_len > data_len {
+            return Err(StreamCaptureError::InvalidImageFormat(format!(
+                "Buffer of {data_len} bytes is too small for the NV12 planes"
+            )));
+        }
+
+        // both planes keep the gstreamer buffer alive, see `grab_rgb8`
+        let buffer = mapped_buffer.into_buffer();
+        let (y, uv) = unsafe {
+            (
+                TensorStorage::from_raw_parts(
+                    data_ptr.add(y_offset),
+                    data_len - y_offset,
+                    GstAllocator(buffer.clone()),
+                ),
+                TensorStorage::from_raw_parts(
+                    data_ptr.add(uv_offset),
+                    data_len - uv_offset,
+                    GstAllocator(buffer),
+                ),
+            )
+        };
+
+        Ok(Some(Nv12Frame {
+            y,
+            uv,
+            size,
+            y_stride,
+            uv_stride,
+        }))
+    }
+
+    /// Grabs the last captured frame in the RGB format without copying it, keeping the
+    /// padding of its rows.
+    ///
+    /// Unlike [`StreamCapture::grab_rgb8`], the frame can have padded rows, e.g. when its
+    /// width is not a multiple of 4. The row stride is taken from the video meta of the buffer
+    /// if present, or from the caps otherwise.
+    ///
+    /// # Returns
+    ///
+    /// An Option containing the last captured frame or None if no frame has been captured yet.
+    ///
+    /// # Errors
+    ///
+    /// If the frame is not in the RGB format or the buffer is too small for its rows, an
+    /// error is returned.
+    pub fn grab_rgb8_strided(&mut self) -> Result<Option<RgbFrame>, StreamCaptureError> {
+        self.reconnect_if_needed()?;
+
+        let mut circular_buffer = self
+            .circular_buffer
+            .lock()
+            .map_err(|_| StreamCaptureError::MutexPoisonError)?;
+
+        let Some(frame_buffer) = circular_buffer.pop_front() else {
+            return Ok(None);
+        };
+        self.retries = 0;
+
+        let video_info = gstreamer_video::VideoInfo::from_caps(&frame_buffer.caps)
+            .map_err(|e| StreamCaptureError::GetCapsError(e.to_string()))?;
+
+        if video_info.format() != gstreamer_video::VideoFormat::Rgb {
+            return Err(StreamCaptureError::InvalidImageFormat(format!(
+                "Expected RGB frames, got {:?}",
+                video_info.format()
+            )));
+        }
+
+        // the video meta describes the actual layout when the producer pads the rows
+        let (offset, stride) = match frame_buffer.buffer.meta::<gstreamer_video::VideoMeta>() {
+            Some(meta) => (meta.offset()[0], meta.stride()[0] as usize),
+            None => (video_info.offset()[0], video_info.stride()[0] as usize),
+        };
+
+        let size = ImageSize {
+            width: frame_buffer.width as usize,
+            height: frame_buffer.height as usize,
+        };
+
+        let mapped_buffer = frame_buffer
+            .buffer
+            .into_mapped_buffer_readable()
+            .map_err(|_| StreamCaptureError::GetBufferError)?;
+
+        let data_ptr = mapped_buffer.as_ptr();
+        let data_len = mapped_buffer.len();
+
+        // the last row does not need to be padded
+        let len = size.height.saturating_sub(1) * stride + size.width * 3;
+        if offset + len > data_len {
+            return Err(StreamCaptureError::InvalidImageFormat(format!(
+                "Buffer of {data_len} bytes is too small for the RGB rows"
+            )));
+        }
+
+        // the storage keeps the gstreamer buffer alive, see `grab_rgb8`
+        let data = unsafe {
+            TensorStorage::from_raw_parts(
+                data_ptr.add(offset),
+                data_len - offset,
+                GstAllocator(mapped_buffer.into_buffer()),
+            )
+        };
+
+        Ok(Some(RgbFrame { data, size, stride }))
+    }
+
     /// Closes the stream capture pipeline.
     pub fn close(&self) -> Result<(), StreamCaptureError> {
+        // the end of stream sent below must not trigger a reconnection
+        self.closed.store(true, Ordering::Relaxed);
         let res = self.pipeline.send_event(gstreamer::event::Eos::new());
         if !res {
             return Err(StreamCaptureError::SendEosError);
@@ -192,6 +774,109 @@ impl StreamCapture {
         Ok(())
     }
 
+    /// Creates the pipeline and connects its appsink to the shared frame buffer.
+    fn build_pipeline(
+        pipeline_desc: &str,
+        circular_buffer: &Arc<Mutex<CircularBuffer<FRAME_BUFFER_CAPACITY, FrameBuffer>>>,
+        fps: &Arc<Mutex<gstreamer::Fraction>>,
+        counters: &Arc<Mutex<CaptureCounters>>,
+    ) -> Result<gstreamer::Pipeline, StreamCaptureError> {
+        let pipeline = gstreamer::parse::launch(pipeline_desc)?
+            .dynamic_cast::<gstreamer::Pipeline>()
+            .map_err(StreamCaptureError::DowncastPipelineError)?;
+
+        let appsink = pipeline
+            .by_name("sink")
+            .ok_or_else(|| StreamCaptureError::GetElementByNameError)?
+            .dynamic_cast::<gstreamer_app::AppSink>()
+            .map_err(StreamCaptureError::DowncastPipelineError)?;
+
+        appsink.set_callbacks(
+            gstreamer_app::AppSinkCallbacks::builder()
+                .new_sample({
+                    let circular_buffer = circular_buffer.clone();
+                    let fps = fps.clone();
+                    let counters = counters.clone();
+
+                    move |sink| {
+                        Self::extract_frame_buffer(sink)
+                            .map_err(|_| gstreamer::FlowError::Eos)
+                            .and_then(|(frame_buffer, fps_fraction)| {
+                                let overwritten = circular_buffer
+                                    .lock()
+                                    .map_err(|_| gstreamer::FlowError::Error)?
+                                    .push_back(frame_buffer)
+                                    .is_some();
+
+                                let mut counters =
+                                    counters.lock().map_err(|_| gstreamer::FlowError::Error)?;
+                                let now = Instant::now();
+                                counters.frames_received += 1;
+                                counters.frames_dropped += overwritten as u64;
+                                counters.first_frame.get_or_insert(now);
+                                counters.last_frame = Some(now);
+
+                                *fps.lock().map_err(|_| gstreamer::FlowError::Error)? =
+                                    fps_fraction;
+                                Ok(gstreamer::FlowSuccess::Ok)
+                            })
+                    }
+                })
+                .build(),
+        );
+
+        Ok(pipeline)
+    }
+
+    /// Rebuilds the pipeline if it failed and the capture has a retry policy.
+    fn reconnect_if_needed(&mut self) -> Result<(), StreamCaptureError> {
+        let Some(retry_policy) = self.retry_policy else {
+            return Ok(());
+        };
+
+        if self.closed.load(Ordering::Relaxed) {
+            return Ok(());
+        }
+
+        let bus = self.pipeline.bus().ok_or(StreamCaptureError::BusError)?;
+        if bus
+            .pop_filtered(&[gstreamer::MessageType::Error, gstreamer::MessageType::Eos])
+            .is_none()
+        {
+            return Ok(());
+        }
+
+        loop {
+            if self.retries >= retry_policy.max_retries {
+                return Err(StreamCaptureError::ReconnectError(self.retries));
+            }
+            self.retries += 1;
+
+            std::thread::sleep(retry_policy.backoff);
+
+            // the failed pipeline must release the source before building a new one
+            let _ = self.pipeline.set_state(gstreamer::State::Null);
+
+            let Ok(pipeline) = Self::build_pipeline(
+                &self.pipeline_desc,
+                &self.circular_buffer,
+                &self.fps,
+                &self.counters,
+            ) else {
+                continue;
+            };
+
+            self.pipeline = pipeline;
+            if self.pipeline.set_state(gstreamer::State::Playing).is_ok() {
+                self.counters
+                    .lock()
+                    .map_err(|_| StreamCaptureError::MutexPoisonError)?
+                    .reconnects += 1;
+                return Ok(());
+            }
+        }
+    }
+
     /// Extracts a frame buffer from the AppSink.
     ///
     /// # Arguments
@@ -232,6 +917,7 @@ impl StreamCapture {
 
         let frame_buffer = FrameBuffer {
             buffer,
+            caps: caps.to_owned(),
             width,
             height,
         };
@@ -246,3 +932,321 @@ impl Drop for StreamCapture {
         self.close().expect("Failed to close StreamCapture");
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{CaptureCaps, RetryPolicy, StreamCapture, FRAME_BUFFER_CAPACITY};
+    use crate::gstreamer::StreamCaptureError;
+    use kornia_image::{allocator::CpuAllocator, Image, ImageSize};
+
+    const PIPELINE: &str = "videotestsrc num-buffers=5 \
+        ! video/x-raw,format=RGB,width=8,height=4,framerate=30/1 \
+        ! appsink name=sink sync=false";
+
+    // wait until the pipeline produces the first frame
+    fn wait_for_frame(capture: &StreamCapture) {
+        for _ in 0..100 {
+            if capture
+                .circular_buffer
+                .lock()
+                .is_ok_and(|buffer| !buffer.is_empty())
+            {
+                return;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+    }
+
+    #[ignore = "need gstreamer in CI"]
+    #[test]
+    fn grab_into_reuses_buffer() -> Result<(), Box<dyn std::error::Error>> {
+        let mut capture = StreamCapture::new(PIPELINE)?;
+
+        let size = ImageSize {
+            width: 8,
+            height: 4,
+        };
+        let mut img = Image::<u8, 3, _>::from_size_val(size, 0, CpuAllocator)?;
+        let ptr = img.as_ptr();
+
+        // nothing has been captured before starting the pipeline
+        assert!(!capture.grab_into(&mut img)?);
+
+        capture.start()?;
+        wait_for_frame(&capture);
+
+        assert!(capture.grab_into(&mut img)?);
+        assert_eq!(img.as_ptr(), ptr);
+        assert_eq!(img.size(), size);
+
+        capture.close()?;
+
+        Ok(())
+    }
+
+    #[ignore = "need gstreamer in CI"]
+    #[test]
+    fn grab_into_size_mismatch() -> Result<(), Box<dyn std::error::Error>> {
+        let mut capture = StreamCapture::new(PIPELINE)?;
+        capture.start()?;
+        wait_for_frame(&capture);
+
+        let size = ImageSize {
+            width: 4,
+            height: 4,
+        };
+        let mut img = Image::<u8, 3, _>::from_size_val(size, 0, CpuAllocator)?;
+
+        let front_pts = |capture: &StreamCapture| {
+            capture
+                .circular_buffer
+                .lock()
+                .ok()
+                .and_then(|buffer| buffer.front().map(|frame| frame.buffer.pts()))
+        };
+        let pts = front_pts(&capture);
+        assert!(pts.is_some());
+
+        let res = capture.grab_into(&mut img);
+        assert!(matches!(
+            res,
+            Err(StreamCaptureError::ImageSizeMismatch(expected, actual))
+                if expected == ImageSize { width: 8, height: 4 } && actual == size
+        ));
+
+        // the frame is kept and returned by the next grab
+        assert_eq!(front_pts(&capture), pts);
+        let mut img = Image::<u8, 3, _>::from_size_val([8, 4].into(), 0, CpuAllocator)?;
+        assert!(capture.grab_into(&mut img)?);
+        assert_ne!(front_pts(&capture), pts);
+
+        capture.close()?;
+
+        Ok(())
+    }
+
+    #[ignore = "need gstreamer in CI"]
+    #[test]
+    fn stats_slow_consumer_drops_frames() -> Result<(), Box<dyn std::error::Error>> {
+        let num_buffers = 12;
+        let mut capture = StreamCapture::new(&format!(
+            "videotestsrc num-buffers={num_buffers} \
+            ! video/x-raw,format=RGB,width=8,height=4,framerate=30/1 \
+            ! appsink name=sink sync=false"
+        ))?;
+
+        let stats = capture.stats()?;
+        assert_eq!(stats.frames_received, 0);
+        assert_eq!(stats.mean_frame_interval, None);
+
+        capture.start()?;
+
+        // do not consume any frame until the stream is over
+        for _ in 0..200 {
+            if capture.stats()?.frames_received == num_buffers {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+
+        let stats = capture.stats()?;
+        assert_eq!(stats.frames_received, num_buffers);
+        assert_eq!(stats.buffer_len, FRAME_BUFFER_CAPACITY);
+        assert_eq!(stats.buffer_capacity, FRAME_BUFFER_CAPACITY);
+        assert_eq!(
+            stats.frames_dropped,
+            num_buffers - FRAME_BUFFER_CAPACITY as u64
+        );
+        assert!(stats.mean_frame_interval.is_some());
+
+        // grabbing a frame frees a slot in the buffer
+        assert!(capture.grab_rgb8()?.is_some());
+        assert_eq!(capture.stats()?.buffer_len, FRAME_BUFFER_CAPACITY - 1);
+
+        capture.close()?;
+
+        Ok(())
+    }
+
+    #[ignore = "need gstreamer in CI"]
+    #[test]
+    fn grab_nv12_planes() -> Result<(), Box<dyn std::error::Error>> {
+        let mut capture = StreamCapture::new(
+            "videotestsrc pattern=black num-buffers=5 \
+            ! video/x-raw,format=NV12,width=6,height=4,framerate=30/1 \
+            ! appsink name=sink sync=false",
+        )?;
+        capture.start()?;
+        wait_for_frame(&capture);
+
+        let frame = capture.grab_nv12()?.expect("no frame captured");
+        assert_eq!(
+            frame.size(),
+            ImageSize {
+                width: 6,
+                height: 4
+            }
+        );
+        assert!(frame.y_stride() >= 6);
+
+        let y_plane = frame.y_plane();
+        assert_eq!(y_plane.shape, [4, 6]);
+        assert_eq!(*y_plane.get_unchecked([3, 5]), 16);
+
+        let uv_plane = frame.uv_plane();
+        assert_eq!(uv_plane.shape, [2, 3, 2]);
+        assert_eq!(*uv_plane.get_unchecked([1, 2, 1]), 128);
+
+        capture.close()?;
+
+        Ok(())
+    }
+
+    #[ignore = "need gstreamer in CI"]
+    #[test]
+    fn grab_rgb8_strided_padded_rows() -> Result<(), Box<dyn std::error::Error>> {
+        // 6 RGB pixels take 18 bytes, padded to 20 by gstreamer
+        let mut capture = StreamCapture::new(
+            "videotestsrc pattern=white num-buffers=5 \
+            ! video/x-raw,format=RGB,width=6,height=4,framerate=30/1 \
+            ! appsink name=sink sync=false",
+        )?;
+        capture.start()?;
+        wait_for_frame(&capture);
+
+        let frame = capture.grab_rgb8_strided()?.expect("no frame captured");
+        assert_eq!(frame.shape(), [4, 6, 3]);
+        assert_eq!(frame.strides(), [20, 3, 1]);
+        assert!(!frame.is_contiguous());
+
+        // the view reads the pixels through the strides, skipping the padding
+        let view = frame.view();
+        assert_eq!(*view.get_unchecked([3, 5, 2]), 255);
+
+        let img = frame.to_image()?;
+        assert_eq!(
+            img.size(),
+            ImageSize {
+                width: 6,
+                height: 4
+            }
+        );
+        assert!(img.as_slice().iter().all(|&v| v == 255));
+
+        capture.close()?;
+
+        Ok(())
+    }
+
+    #[ignore = "need gstreamer in CI"]
+    #[test]
+    fn reconnect_after_end_of_stream() -> Result<(), Box<dyn std::error::Error>> {
+        // the source ends after 3 frames, as a camera dropping the connection would
+        let mut capture = StreamCapture::with_reconnect(
+            "videotestsrc num-buffers=3 \
+            ! video/x-raw,format=RGB,width=8,height=4,framerate=30/1 \
+            ! appsink name=sink sync=false",
+            RetryPolicy {
+                max_retries: 3,
+                backoff: std::time::Duration::from_millis(10),
+            },
+        )?;
+        capture.start()?;
+
+        let mut num_frames = 0;
+        for _ in 0..500 {
+            if capture.grab_rgb8()?.is_some() {
+                num_frames += 1;
+            }
+            if num_frames > 6 {
+                break;
+            }
+            std::thread::sleep(std::time::Duration::from_millis(5));
+        }
+
+        // the frames keep coming after the end of the first stream
+        assert!(num_frames > 6);
+        assert!(capture.stats()?.reconnects >= 2);
+
+        // an intentional close does not rebuild the pipeline
+        capture.close()?;
+        let reconnects = capture.stats()?.reconnects;
+        std::thread::sleep(std::time::Duration::from_millis(50));
+        assert!(capture.grab_rgb8()?.is_none());
+        assert_eq!(capture.stats()?.reconnects, reconnects);
+
+        Ok(())
+    }
+
+    #[ignore = "need gstreamer in CI"]
+    #[test]
+    fn caps_after_start() -> Result<(), Box<dyn std::error::Error>> {
+        let mut capture = StreamCapture::new(
+            "videotestsrc num-buffers=5 \
+            ! video/x-raw,format=RGB,width=1024,height=688,framerate=30/1 \
+            ! appsink name=sink sync=false",
+        )?;
+
+        // the caps are not negotiated before the pipeline is playing
+        assert_eq!(capture.caps(), None);
+
+        capture.start()?;
+        wait_for_frame(&capture);
+
+        assert_eq!(
+            capture.caps(),
+            Some(CaptureCaps {
+                width: 1024,
+                height: 688,
+                format: "RGB".to_string(),
+                framerate: Some(30.0),
+            })
+        );
+
+        // the caps match the grabbed frames
+        let img = capture.grab_rgb8()?.expect("no frame captured");
+        assert_eq!(img.width(), 1024);
+        assert_eq!(img.height(), 688);
+
+        capture.close()?;
+
+        Ok(())
+    }
+
+    #[ignore = "need gstreamer in CI"]
+    #[test]
+    fn grab_borrowed_reads_frame() -> Result<(), Box<dyn std::error::Error>> {
+        let mut capture = StreamCapture::new(
+            "videotestsrc pattern=white num-buffers=5 \
+            ! video/x-raw,format=RGB,width=8,height=4,framerate=30/1 \
+            ! appsink name=sink sync=false",
+        )?;
+
+        // nothing has been captured before starting the pipeline
+        assert!(capture.grab_borrowed()?.is_none());
+
+        capture.start()?;
+        wait_for_frame(&capture);
+
+        {
+            let frame = capture.grab_borrowed()?.expect("no frame captured");
+            assert_eq!(
+                frame.size(),
+                ImageSize {
+                    width: 8,
+                    height: 4
+                }
+            );
+            assert_eq!(frame.as_slice().len(), 8 * 4 * 3);
+            assert!(frame.as_slice().iter().all(|&v| v == 255));
+        }
+
+        // the previous frame is released by the next grab
+        wait_for_frame(&capture);
+        assert!(capture.grab_borrowed()?.is_some());
+
+        capture.close()?;
+
+        Ok(())
+    }
+}
@@ -1,3 +1,5 @@
+use kornia_image::ImageSize;
+
 /// An error type for the stream module.
 #[derive(thiserror::Error, Debug)]
 pub enum StreamCaptureError {
@@ -73,6 +75,14 @@ pub enum StreamCaptureError {
     /// An error occurred when the image is not valid.
     #[error(transparent)]
     ImageError(#[from] kornia_image::ImageError),
+
+    /// The pipeline could not be rebuilt within the retry policy.
+    #[error("Failed to reconnect the pipeline after {0} retries")]
+    ReconnectError(u32),
+
+    /// The destination image size does not match the size of the captured frame.
+    #[error("Destination image size mismatch: expected {0}, got {1}")]
+    ImageSizeMismatch(ImageSize, ImageSize),
 }
 
 /// Error type for video reader
@@ -8,7 +8,13 @@ use kornia_image::{
     Image, ImageSize,
 };
 use png::{BitDepth, ColorType, Decoder, Encoder};
-use std::{fs, fs::File, path::Path};
+use std::{fs, fs::File, io::Write, path::Path, sync::mpsc, thread};
+
+/// Number of image rows handed to the PNG encoder at once.
+const PNG_STRIP_ROWS: usize = 64;
+
+/// Number of converted strips that can be queued ahead of the PNG encoder.
+const PNG_PIPELINE_DEPTH: usize = 4;
 
 /// Read a PNG image as grayscale (Gray8).
 ///
@@ -377,6 +383,175 @@ pub fn write_image_png_gray16<A: ImageAllocator>(
     )
 }
 
+/// Encodes the given RGB8 image to PNG bytes (in-memory) using a provided buffer.
+///
+/// # Arguments
+///
+/// - `image` - The RGB8 image to encode.
+/// - `buffer` - A mutable buffer to write the PNG bytes into.
+///
+/// # Note
+///
+/// The encoded data will be appended to any existing content in the buffer.
+pub fn encode_image_png_rgb8<A: ImageAllocator>(
+    image: &Image<u8, 3, A>,
+    buffer: &mut Vec<u8>,
+) -> Result<(), IoError> {
+    encode_png_impl(
+        buffer,
+        image.as_slice(),
+        image.size(),
+        BitDepth::Eight,
+        ColorType::Rgb,
+        false,
+    )
+}
+
+/// Encodes the given RGB8 image to PNG bytes (in-memory), copying the rows to the encoder
+/// from a separate thread.
+///
+/// The rows are prepared in strips on a producer thread while the encoder compresses the
+/// previous strips. The output is byte-identical to [`encode_image_png_rgb8`].
+///
+/// # Arguments
+///
+/// - `image` - The RGB8 image to encode.
+/// - `buffer` - A mutable buffer to write the PNG bytes into.
+pub fn encode_image_png_rgb8_pipelined<A: ImageAllocator>(
+    image: &Image<u8, 3, A>,
+    buffer: &mut Vec<u8>,
+) -> Result<(), IoError> {
+    encode_png_impl(
+        buffer,
+        image.as_slice(),
+        image.size(),
+        BitDepth::Eight,
+        ColorType::Rgb,
+        true,
+    )
+}
+
+/// Encodes the given RGB16 image to PNG bytes (in-memory) using a provided buffer.
+///
+/// # Arguments
+///
+/// - `image` - The RGB16 image to encode.
+/// - `buffer` - A mutable buffer to write the PNG bytes into.
+///
+/// # Note
+///
+/// The encoded data will be appended to any existing content in the buffer.
+pub fn encode_image_png_rgb16<A: ImageAllocator>(
+    image: &Image<u16, 3, A>,
+    buffer: &mut Vec<u8>,
+) -> Result<(), IoError> {
+    encode_png_impl(
+        buffer,
+        image.as_slice(),
+        image.size(),
+        BitDepth::Sixteen,
+        ColorType::Rgb,
+        false,
+    )
+}
+
+/// Encodes the given RGB16 image to PNG bytes (in-memory), converting the samples to big
+/// endian on a separate thread.
+///
+/// The conversion of each strip of rows overlaps with the compression of the previous
+/// strips. The output is byte-identical to [`encode_image_png_rgb16`].
+///
+/// # Arguments
+///
+/// - `image` - The RGB16 image to encode.
+/// - `buffer` - A mutable buffer to write the PNG bytes into.
+pub fn encode_image_png_rgb16_pipelined<A: ImageAllocator>(
+    image: &Image<u16, 3, A>,
+    buffer: &mut Vec<u8>,
+) -> Result<(), IoError> {
+    encode_png_impl(
+        buffer,
+        image.as_slice(),
+        image.size(),
+        BitDepth::Sixteen,
+        ColorType::Rgb,
+        true,
+    )
+}
+
+/// Samples that can be serialized into the byte layout expected by the PNG encoder.
+trait PngSample: Copy + Sync {
+    fn extend_bytes(samples: &[Self], bytes: &mut Vec<u8>);
+}
+
+impl PngSample for u8 {
+    fn extend_bytes(samples: &[Self], bytes: &mut Vec<u8>) {
+        bytes.extend_from_slice(samples);
+    }
+}
+
+impl PngSample for u16 {
+    fn extend_bytes(samples: &[Self], bytes: &mut Vec<u8>) {
+        bytes.extend(samples.iter().flat_map(|v| v.to_be_bytes()));
+    }
+}
+
+// NOTE: both the serial and the pipelined paths feed the encoder with the same strips in
+// the same order, which guarantees the same compressed output.
+fn encode_png_impl<T: PngSample>(
+    buffer: &mut Vec<u8>,
+    image_data: &[T],
+    image_size: ImageSize,
+    // Make sure you set `depth` correctly
+    depth: BitDepth,
+    color_type: ColorType,
+    pipelined: bool,
+) -> Result<(), IoError> {
+    let mut encoder = Encoder::new(buffer, image_size.width as u32, image_size.height as u32);
+    encoder.set_color(color_type);
+    encoder.set_depth(depth);
+
+    let mut writer = encoder
+        .write_header()
+        .map_err(|e| IoError::PngEncodingError(e.to_string()))?;
+    let mut stream = writer
+        .stream_writer()
+        .map_err(|e| IoError::PngEncodingError(e.to_string()))?;
+
+    let row_len = image_data.len() / image_size.height.max(1);
+    let strip_len = (row_len * PNG_STRIP_ROWS).max(1);
+
+    if pipelined {
+        thread::scope(|s| {
+            let (tx, rx) = mpsc::sync_channel::<Vec<u8>>(PNG_PIPELINE_DEPTH);
+            s.spawn(move || {
+                for strip in image_data.chunks(strip_len) {
+                    let mut bytes = Vec::with_capacity(std::mem::size_of_val(strip));
+                    T::extend_bytes(strip, &mut bytes);
+                    // the receiver is gone only if the encoder failed
+                    if tx.send(bytes).is_err() {
+                        break;
+                    }
+                }
+            });
+            rx.iter().try_for_each(|bytes| stream.write_all(&bytes))
+        })?;
+    } else {
+        let mut bytes = Vec::with_capacity(strip_len * std::mem::size_of::<T>());
+        for strip in image_data.chunks(strip_len) {
+            bytes.clear();
+            T::extend_bytes(strip, &mut bytes);
+            stream.write_all(&bytes)?;
+        }
+    }
+
+    stream
+        .finish()
+        .map_err(|e| IoError::PngEncodingError(e.to_string()))?;
+
+    Ok(())
+}
+
 fn write_png_impl(
     file_path: impl AsRef<Path>,
     image_data: &[u8],
@@ -464,4 +639,40 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn encode_png_pipelined_identical() -> Result<(), IoError> {
+        let size = ImageSize {
+            width: 301,
+            height: 150,
+        };
+        let data = (0..size.width * size.height * 3)
+            .map(|i| ((i * 31) ^ (i / 7)) as u16)
+            .collect::<Vec<_>>();
+
+        // 8-bit
+        let data_u8 = data.iter().map(|&v| v as u8).collect();
+        let image = Rgb8::from_size_vec(size, data_u8, CpuAllocator)?;
+        let (mut serial, mut pipelined) = (Vec::new(), Vec::new());
+        encode_image_png_rgb8(&image, &mut serial)?;
+        encode_image_png_rgb8_pipelined(&image, &mut pipelined)?;
+        assert_eq!(serial, pipelined);
+
+        let mut decoded = Rgb8::from_size_val(size, 0, CpuAllocator)?;
+        decode_image_png_rgb8(&serial, &mut decoded)?;
+        assert_eq!(decoded.as_slice(), image.as_slice());
+
+        // 16-bit
+        let image = Rgb16::from_size_vec(size, data, CpuAllocator)?;
+        let (mut serial, mut pipelined) = (Vec::new(), Vec::new());
+        encode_image_png_rgb16(&image, &mut serial)?;
+        encode_image_png_rgb16_pipelined(&image, &mut pipelined)?;
+        assert_eq!(serial, pipelined);
+
+        let mut decoded = Rgb16::from_size_val(size, 0, CpuAllocator)?;
+        decode_image_png_rgb16(&serial, &mut decoded)?;
+        assert_eq!(decoded.as_slice(), image.as_slice());
+
+        Ok(())
+    }
 }
@@ -118,6 +118,9 @@ pub fn read_image_tiff_mono16(
 
 /// Read a TIFF image and return it as single precision floating point grayscale image.
 ///
+/// The image must be stored with 32 bits per sample and `SampleFormat=IEEEFP`. The values
+/// are returned as stored, without any normalization, so the full float range is preserved.
+///
 /// # Arguments
 ///
 /// * `file_path` - The path to the TIFF image.
@@ -249,6 +252,9 @@ pub fn write_image_tiff_mono16<A: ImageAllocator>(
 
 /// Write a TIFF image with a single precision as one channel image.
 ///
+/// The samples are written with 32 bits and `SampleFormat=IEEEFP`, preserving the values
+/// exactly, e.g. for depth maps or HDR data outside the [0, 1] range.
+///
 /// # Arguments
 ///
 /// * `file_path` - The path to the TIFF image.
@@ -470,4 +476,49 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn synthetic_write_tiff_monof32_full_range() -> Result<(), IoError> {
+        let tmp_dir = tempfile::tempdir()?;
+        create_dir_all(tmp_dir.path())?;
+
+        let data = vec![
+            -1.5,
+            0.0,
+            1.0 + f32::EPSILON,
+            65536.25,
+            1e-30,
+            -3.4e38,
+            f32::MAX,
+            f32::MIN_POSITIVE,
+        ];
+
+        let img_mono32f = Grayf32::from_size_vec(
+            ImageSize {
+                width: 4,
+                height: 2,
+            },
+            data,
+            CpuAllocator,
+        )?;
+
+        let file_path = tmp_dir.path().join("depth.tiff");
+        write_image_tiff_mono32f(&file_path, &img_mono32f)?;
+
+        // the samples are stored as IEEE floating point
+        let mut decoder = tiff::decoder::Decoder::new(fs::File::open(&file_path)?)?;
+        assert_eq!(
+            decoder.get_tag_unsigned::<u16>(tiff::tags::Tag::SampleFormat)?,
+            tiff::tags::SampleFormat::IEEEFP.to_u16()
+        );
+
+        let img_mono32f_back = read_image_tiff_mono32f(&file_path)?;
+        assert_eq!(img_mono32f_back.size(), img_mono32f.size());
+        assert_eq!(img_mono32f_back.as_slice(), img_mono32f.as_slice());
+
+        // reading a float image as an integer image is rejected
+        assert!(read_image_tiff_mono16(&file_path).is_err());
+
+        Ok(())
+    }
 }
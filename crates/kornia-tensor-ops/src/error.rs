@@ -16,6 +16,14 @@ pub enum TensorOpsError {
     #[error("Shape mismatch: {0:?} != {1:?}")]
     ShapeMismatch(Vec<usize>, Vec<usize>),
 
+    /// The number of requested components is not valid for the number of features.
+    #[error("Invalid number of components {0}, expected a value in [1, {1}]")]
+    InvalidNumComponents(usize, usize),
+
+    /// Not enough samples to perform the operation.
+    #[error("Not enough samples: got {0}, expected at least {1}")]
+    NotEnoughSamples(usize, usize),
+
     /// Tensor error
     #[error("Error with the tensor: {0}")]
     TensorError(#[from] TensorError),
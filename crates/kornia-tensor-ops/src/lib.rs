@@ -10,5 +10,8 @@ pub mod kernels;
 /// module containing ops implementations.
 pub mod ops;
 
+/// module containing the principal component analysis.
+pub mod pca;
+
 pub use error::TensorOpsError;
 pub use ops::TensorOps;
@@ -1,5 +1,9 @@
 use crate::kernels::{cosine_similarity_float_kernel, dot_product1_kernel};
-use kornia_tensor::{storage::TensorStorage, CpuAllocator, Tensor, TensorAllocator, TensorError};
+use kornia_tensor::{
+    storage::TensorStorage,
+    tensor::{gemm, get_strides_from_shape},
+    CpuAllocator, Tensor, TensorAllocator, TensorError,
+};
 use num_traits::{Float, Zero};
 
 use crate::error::TensorOpsError;
@@ -46,13 +50,8 @@ where
     let mut out_shape = tensor.shape;
     out_shape[dim] = 1;
 
-    let mut out_strides = tensor.strides;
-    if dim > 0 {
-        out_strides
-            .iter_mut()
-            .take(dim)
-            .for_each(|s| *s /= tensor.shape[dim]);
-    }
+    // NOTE: computed from the output shape so that zero-sized dimensions are supported
+    let out_strides = get_strides_from_shape(out_shape);
 
     let numel: usize = out_shape.iter().product();
     let mut data = vec![T::zero(); numel];
@@ -91,7 +90,7 @@ where
     T: Float + Clone,
     A: TensorAllocator + Clone + 'static,
 {
-    tensor.map(|&x| x * n)
+    tensor.map(|x| x * n)
 }
 
 /// Raise the pixel data to the power of a float.
@@ -110,6 +109,50 @@ where
 {
     tensor.map(|x| x.powf(n))
 }
+
+/// Compute the exponential of the pixel data.
+///
+/// # Returns
+///
+/// A new image with the exponential of the pixel data.
+fn exp<T, const N: usize, A>(tensor: &Tensor<T, N, A>) -> Tensor<T, N, A>
+where
+    T: Float + Clone,
+    A: TensorAllocator + Clone + 'static,
+{
+    tensor.map(|x| x.exp())
+}
+
+/// Compute the natural logarithm of the pixel data.
+///
+/// NOTE: the logarithm of zero is negative infinity and of a negative value is NaN.
+///
+/// # Returns
+///
+/// A new image with the natural logarithm of the pixel data.
+fn ln<T, const N: usize, A>(tensor: &Tensor<T, N, A>) -> Tensor<T, N, A>
+where
+    T: Float + Clone,
+    A: TensorAllocator + Clone + 'static,
+{
+    tensor.map(|x| x.ln())
+}
+
+/// Compute the square root of the pixel data.
+///
+/// NOTE: the square root of a negative value is NaN.
+///
+/// # Returns
+///
+/// A new image with the square root of the pixel data.
+fn sqrt<T, const N: usize, A>(tensor: &Tensor<T, N, A>) -> Tensor<T, N, A>
+where
+    T: Float + Clone,
+    A: TensorAllocator + Clone + 'static,
+{
+    tensor.map(|x| x.sqrt())
+}
+
 /// Perform an element-wise minimum operation on two tensors.
 ///
 /// # Arguments
@@ -446,6 +489,86 @@ where
     Ok(T::one() - similarity)
 }
 
+/// Compute the batched matrix product of two 3D tensors.
+///
+/// The matrices are multiplied over the two trailing dimensions for each index of the leading
+/// batch dimension. A batch of size 1 is broadcast to the batch size of the other tensor.
+///
+/// # Arguments
+///
+/// * `a` - The left matrices with shape (B, M, K) or (1, M, K).
+/// * `b` - The right matrices with shape (B, K, N) or (1, K, N).
+///
+/// # Returns
+///
+/// A new tensor with shape (B, M, N).
+///
+/// # Errors
+///
+/// If the inner dimensions of the matrices don't match or the batch sizes can't be
+/// broadcast, an error is returned.
+///
+/// Example:
+/// ```
+/// use kornia_tensor::{Tensor, CpuAllocator};
+/// use kornia_tensor_ops::TensorOps;
+///
+/// let a = Tensor::<i32, 3, CpuAllocator>::from_shape_slice([2, 1, 2], &[1, 2, 3, 4], CpuAllocator).unwrap();
+/// let b = Tensor::<i32, 3, CpuAllocator>::from_shape_slice([1, 2, 1], &[1, 1], CpuAllocator).unwrap();
+/// let c = Tensor::<i32, 3, CpuAllocator>::bmm(&a, &b).unwrap();
+/// assert_eq!(c.shape, [2, 1, 1]);
+/// assert_eq!(c.as_slice(), &[3, 7]);
+/// ```
+fn bmm<T, A>(a: &Tensor<T, 3, A>, b: &Tensor<T, 3, A>) -> Result<Tensor<T, 3, A>, TensorOpsError>
+where
+    T: Zero + Clone + std::ops::Add<Output = T> + std::ops::Mul<Output = T> + Copy,
+    A: TensorAllocator + Clone + 'static,
+{
+    let [batch_a, m, k] = a.shape;
+    let [batch_b, k2, n] = b.shape;
+    let batch = if batch_a == batch_b || batch_b == 1 {
+        Some(batch_a)
+    } else if batch_a == 1 {
+        Some(batch_b)
+    } else {
+        None
+    };
+    let Some(batch) = batch.filter(|_| k == k2) else {
+        return Err(TensorOpsError::ShapeMismatch(
+            a.shape.to_vec(),
+            b.shape.to_vec(),
+        ));
+    };
+
+    // a batch of size 1 is repeated by not moving along the batch dimension
+    let a_batch_stride = if batch_a == 1 { 0 } else { a.strides[0] };
+    let b_batch_stride = if batch_b == 1 { 0 } else { b.strides[0] };
+
+    let mut data = vec![T::zero(); batch * m * n];
+    if m * n > 0 {
+        for (i, out) in data.chunks_exact_mut(m * n).enumerate() {
+            gemm(
+                (
+                    &a.as_slice()[i * a_batch_stride..],
+                    [a.strides[1], a.strides[2]],
+                ),
+                (
+                    &b.as_slice()[i * b_batch_stride..],
+                    [b.strides[1], b.strides[2]],
+                ),
+                out,
+                [m, k, n],
+            );
+        }
+    }
+
+    Ok(Tensor::from_shape_vec(
+        [batch, m, n],
+        data,
+        a.storage.alloc().clone(),
+    )?)
+}
+
 /// Trait providing tensor operations for CPU-based tensors.
 ///
 /// This trait defines a collection of mathematical operations that can be performed on tensors.
@@ -498,6 +621,22 @@ pub trait TensorOps<T, const N: usize> {
     fn powf(&self, n: T) -> Tensor<T, N, CpuAllocator>
     where
         T: Float + Clone;
+
+    /// Compute the exponential of the pixel data.
+    fn exp(&self) -> Tensor<T, N, CpuAllocator>
+    where
+        T: Float + Clone;
+
+    /// Compute the natural logarithm of the pixel data, NaN for negative values.
+    fn ln(&self) -> Tensor<T, N, CpuAllocator>
+    where
+        T: Float + Clone;
+
+    /// Compute the square root of the pixel data, NaN for negative values.
+    fn sqrt(&self) -> Tensor<T, N, CpuAllocator>
+    where
+        T: Float + Clone;
+
     /// Perform an element-wise minimum operation on two tensors.
     fn min(
         &self,
@@ -561,6 +700,22 @@ pub trait TensorOps<T, const N: usize> {
     where
         T: Zero + Clone + std::ops::Add<Output = T> + std::ops::Mul<Output = T> + Copy;
 
+    /// Compute the matrix product of two 2D tensors with [`Tensor::matmul`]
+    fn matmul2(
+        a: &Tensor<T, 2, CpuAllocator>,
+        b: &Tensor<T, 2, CpuAllocator>,
+    ) -> Result<Tensor<T, 2, CpuAllocator>, TensorOpsError>
+    where
+        T: Zero + Clone + std::ops::Add<Output = T> + std::ops::Mul<Output = T> + Copy;
+
+    /// Compute the batched matrix product of two 3D tensors
+    fn bmm(
+        a: &Tensor<T, 3, CpuAllocator>,
+        b: &Tensor<T, 3, CpuAllocator>,
+    ) -> Result<Tensor<T, 3, CpuAllocator>, TensorOpsError>
+    where
+        T: Zero + Clone + std::ops::Add<Output = T> + std::ops::Mul<Output = T> + Copy;
+
     /// Compute the cosine similarity between two tensors
     fn cosine_similarity(
         a: &Tensor<T, N, CpuAllocator>,
@@ -602,6 +757,27 @@ impl<T, const N: usize> TensorOps<T, N> for Tensor<T, N, CpuAllocator> {
         powf(self, n)
     }
 
+    fn exp(&self) -> Tensor<T, N, CpuAllocator>
+    where
+        T: Float + Clone,
+    {
+        exp(self)
+    }
+
+    fn ln(&self) -> Tensor<T, N, CpuAllocator>
+    where
+        T: Float + Clone,
+    {
+        ln(self)
+    }
+
+    fn sqrt(&self) -> Tensor<T, N, CpuAllocator>
+    where
+        T: Float + Clone,
+    {
+        sqrt(self)
+    }
+
     fn min(
         &self,
         other: &Tensor<T, N, CpuAllocator>,
@@ -683,6 +859,26 @@ impl<T, const N: usize> TensorOps<T, N> for Tensor<T, N, CpuAllocator> {
         dot_product1(a, b)
     }
 
+    fn matmul2(
+        a: &Tensor<T, 2, CpuAllocator>,
+        b: &Tensor<T, 2, CpuAllocator>,
+    ) -> Result<Tensor<T, 2, CpuAllocator>, TensorOpsError>
+    where
+        T: Zero + Clone + std::ops::Add<Output = T> + std::ops::Mul<Output = T> + Copy,
+    {
+        Ok(a.matmul(b)?)
+    }
+
+    fn bmm(
+        a: &Tensor<T, 3, CpuAllocator>,
+        b: &Tensor<T, 3, CpuAllocator>,
+    ) -> Result<Tensor<T, 3, CpuAllocator>, TensorOpsError>
+    where
+        T: Zero + Clone + std::ops::Add<Output = T> + std::ops::Mul<Output = T> + Copy,
+    {
+        bmm(a, b)
+    }
+
     fn cosine_similarity(
         a: &Tensor<T, N, CpuAllocator>,
         b: &Tensor<T, N, CpuAllocator>,
@@ -717,6 +913,20 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_sum_zero_sized() -> Result<(), TensorOpsError> {
+        let t = Tensor::<f32, 2, CpuAllocator>::from_shape_vec([0, 3], vec![], CpuAllocator)?;
+
+        let agg = sum_elements(&t, 0)?;
+        assert_eq!(agg.shape, [1, 3]);
+        assert_eq!(agg.as_slice(), [0.0; 3]);
+
+        let agg = sum_elements(&t, 1)?;
+        assert_eq!(agg.shape, [0, 1]);
+        assert_eq!(agg.numel(), 0);
+        Ok(())
+    }
+
     #[test]
     fn test_dot_product_shape_mismatch() {
         let a = Tensor::<i32, 1, CpuAllocator>::from_shape_slice([3], &[1, 2, 3], CpuAllocator)
@@ -814,6 +1024,39 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn exp_ln_sqrt() -> Result<(), TensorError> {
+        let data: Vec<f32> = vec![0.5, 1.0, 2.0, 3.0, 4.5, 6.0];
+        let t = Tensor::<f32, 2, _>::from_shape_vec([2, 3], data, CpuAllocator)?;
+
+        let roundtrip = ln(&exp(&t));
+        for (a, b) in roundtrip.as_slice().iter().zip(t.as_slice()) {
+            assert!((a - b).abs() < 1e-5);
+        }
+
+        let recovered = sqrt(&powf(&t, 2.0));
+        for (a, b) in recovered.as_slice().iter().zip(t.as_slice()) {
+            assert!((a - b).abs() < 1e-5);
+        }
+
+        // values out of the domain
+        let t = Tensor::<f32, 1, _>::from_shape_vec([2], vec![-1.0, 0.0], CpuAllocator)?;
+        assert!(ln(&t).as_slice()[0].is_nan());
+        assert_eq!(ln(&t).as_slice()[1], f32::NEG_INFINITY);
+        assert!(sqrt(&t).as_slice()[0].is_nan());
+
+        // a transposed tensor is read following its strides
+        let data: Vec<f32> = vec![1.0, 4.0, 9.0, 16.0, 25.0, 36.0];
+        let mut t = Tensor::<f32, 2, _>::from_shape_vec([2, 3], data, CpuAllocator)?;
+        t.shape = [3, 2];
+        t.strides = [1, 3];
+        let t_sqrt = sqrt(&t);
+        assert_eq!(t_sqrt.strides, [2, 1]);
+        assert_eq!(t_sqrt.as_slice(), &[1.0, 4.0, 2.0, 5.0, 3.0, 6.0]);
+
+        Ok(())
+    }
+
     #[test]
     fn test_min_f32() -> Result<(), TensorError> {
         let data_a: [f32; 5] = [3.0, 1.0, 4.0, 1.0, 5.0];
@@ -1008,6 +1251,52 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn matmul2_and_bmm() -> Result<(), TensorOpsError> {
+        let a = Tensor::<i32, 2, _>::from_shape_vec([2, 3], vec![1, 2, 3, 4, 5, 6], CpuAllocator)?;
+        let b = Tensor::<i32, 2, _>::from_shape_vec([3, 1], vec![1, 0, -1], CpuAllocator)?;
+        let c = Tensor::<i32, 2, CpuAllocator>::matmul2(&a, &b)?;
+        assert_eq!(c.shape, [2, 1]);
+        assert_eq!(c.as_slice(), &[-2, -2]);
+        assert!(Tensor::<i32, 2, CpuAllocator>::matmul2(&b, &b).is_err());
+
+        // two independent 2x2 products
+        #[rustfmt::skip]
+        let a = Tensor::<i32, 3, _>::from_shape_vec(
+            [2, 2, 2],
+            vec![
+                1, 2, 3, 4,
+                0, 1, 1, 0,
+            ],
+            CpuAllocator,
+        )?;
+        #[rustfmt::skip]
+        let b = Tensor::<i32, 3, _>::from_shape_vec(
+            [2, 2, 2],
+            vec![
+                5, 6, 7, 8,
+                2, 3, 4, 5,
+            ],
+            CpuAllocator,
+        )?;
+        let c = bmm(&a, &b)?;
+        assert_eq!(c.shape, [2, 2, 2]);
+        assert_eq!(c.as_slice(), &[19, 22, 43, 50, 4, 5, 2, 3]);
+
+        // the batch of size 1 is broadcast
+        let identity =
+            Tensor::<i32, 3, _>::from_shape_vec([1, 2, 2], vec![1, 0, 0, 1], CpuAllocator)?;
+        assert_eq!(bmm(&identity, &b)?.as_slice(), b.as_slice());
+        assert_eq!(bmm(&a, &identity)?.as_slice(), a.as_slice());
+
+        let three = Tensor::<i32, 3, _>::from_shape_vec([3, 2, 2], vec![0; 12], CpuAllocator)?;
+        assert!(bmm(&a, &three).is_err());
+        let wide = Tensor::<i32, 3, _>::from_shape_vec([2, 3, 2], vec![0; 12], CpuAllocator)?;
+        assert!(bmm(&a, &wide).is_err());
+
+        Ok(())
+    }
+
     #[test]
     fn test_cosine_distance() -> Result<(), TensorOpsError> {
         let a =
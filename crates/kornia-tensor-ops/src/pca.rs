@@ -0,0 +1,236 @@
+use kornia_tensor::{Tensor1, Tensor2, TensorAllocator};
+
+use crate::error::TensorOpsError;
+
+/// Maximum number of Jacobi sweeps before giving up on convergence.
+const JACOBI_MAX_SWEEPS: usize = 100;
+
+/// The principal components, one per row, and their variances in decreasing order.
+pub type PcaResult<A> = Result<(Tensor2<f32, A>, Tensor1<f32, A>), TensorOpsError>;
+
+/// Compute the principal components of a set of samples.
+///
+/// The data is centered around its mean and the sample covariance matrix is decomposed
+/// with the cyclic Jacobi eigenvalue algorithm. The mean and covariance are accumulated
+/// in `f64` to reduce the loss of precision on large or offset datasets.
+///
+/// # Arguments
+///
+/// * `data` - The samples as a matrix of shape `[num_samples, num_features]`.
+/// * `n_components` - The number of principal components to keep.
+///
+/// # Returns
+///
+/// A tuple with the principal component vectors as a matrix of shape
+/// `[n_components, num_features]`, sorted by decreasing explained variance, and the
+/// explained variance of each component as a vector of shape `[n_components]`.
+///
+/// # Errors
+///
+/// If `n_components` is zero or greater than the number of features, or if there are less
+/// than two samples, an error is returned.
+///
+/// # Example
+///
+/// ```
+/// use kornia_tensor::{Tensor, CpuAllocator};
+/// use kornia_tensor_ops::pca::pca;
+///
+/// let data = Tensor::<f32, 2, _>::from_shape_vec(
+///     [4, 2],
+///     vec![0.0, 0.0, 1.0, 1.0, 2.0, 2.0, 3.0, 3.0],
+///     CpuAllocator,
+/// ).unwrap();
+///
+/// let (components, variances) = pca(&data, 1).unwrap();
+/// assert_eq!(components.shape, [1, 2]);
+/// assert_eq!(variances.shape, [1]);
+/// ```
+pub fn pca<A>(data: &Tensor2<f32, A>, n_components: usize) -> PcaResult<A>
+where
+    A: TensorAllocator + 'static,
+{
+    let [num_samples, num_features] = data.shape;
+
+    if n_components == 0 || n_components > num_features {
+        return Err(TensorOpsError::InvalidNumComponents(
+            n_components,
+            num_features,
+        ));
+    }
+
+    if num_samples < 2 {
+        return Err(TensorOpsError::NotEnoughSamples(num_samples, 2));
+    }
+
+    // compute the mean of each feature
+    let mut mean = vec![0.0f64; num_features];
+    for i in 0..num_samples {
+        for (j, m) in mean.iter_mut().enumerate() {
+            *m += *data.get_unchecked([i, j]) as f64;
+        }
+    }
+    mean.iter_mut().for_each(|m| *m /= num_samples as f64);
+
+    // compute the upper triangle of the covariance matrix from the centered samples
+    let mut cov = vec![0.0f64; num_features * num_features];
+    let mut centered = vec![0.0f64; num_features];
+    for i in 0..num_samples {
+        for (j, c) in centered.iter_mut().enumerate() {
+            *c = *data.get_unchecked([i, j]) as f64 - mean[j];
+        }
+        for p in 0..num_features {
+            for q in p..num_features {
+                cov[p * num_features + q] += centered[p] * centered[q];
+            }
+        }
+    }
+
+    let norm = (num_samples - 1) as f64;
+    for p in 0..num_features {
+        for q in p..num_features {
+            let v = cov[p * num_features + q] / norm;
+            cov[p * num_features + q] = v;
+            cov[q * num_features + p] = v;
+        }
+    }
+
+    let eigenvectors = symmetric_eigen_jacobi(&mut cov, num_features);
+
+    // sort the eigenpairs by decreasing eigenvalue
+    let mut order = (0..num_features).collect::<Vec<_>>();
+    order.sort_by(|&a, &b| {
+        let (ea, eb) = (cov[a * num_features + a], cov[b * num_features + b]);
+        eb.total_cmp(&ea)
+    });
+
+    let mut components = Vec::with_capacity(n_components * num_features);
+    let mut variances = Vec::with_capacity(n_components);
+    for &k in order.iter().take(n_components) {
+        let column = (0..num_features)
+            .map(|j| eigenvectors[j * num_features + k])
+            .collect::<Vec<_>>();
+
+        // fix the sign so that the largest coefficient is positive
+        let pivot = column
+            .iter()
+            .copied()
+            .fold(0.0f64, |acc, v| if v.abs() > acc.abs() { v } else { acc });
+        let sign = if pivot < 0.0 { -1.0 } else { 1.0 };
+
+        components.extend(column.iter().map(|&v| (sign * v) as f32));
+        variances.push(cov[k * num_features + k].max(0.0) as f32);
+    }
+
+    let alloc = data.storage.alloc();
+    let components =
+        Tensor2::from_shape_vec([n_components, num_features], components, alloc.clone())?;
+    let variances = Tensor1::from_shape_vec([n_components], variances, alloc.clone())?;
+
+    Ok((components, variances))
+}
+
+/// Diagonalize a symmetric matrix in place using the cyclic Jacobi eigenvalue algorithm.
+///
+/// On return the diagonal of `a` holds the eigenvalues and the columns of the returned
+/// row-major matrix hold the corresponding unit eigenvectors.
+fn symmetric_eigen_jacobi(a: &mut [f64], n: usize) -> Vec<f64> {
+    let mut v = vec![0.0f64; n * n];
+    for i in 0..n {
+        v[i * n + i] = 1.0;
+    }
+
+    let scale = a
+        .iter()
+        .fold(0.0f64, |acc, x| acc + x * x)
+        .max(f64::MIN_POSITIVE);
+
+    for _ in 0..JACOBI_MAX_SWEEPS {
+        let mut off_diagonal = 0.0;
+        for p in 0..n {
+            for q in (p + 1)..n {
+                off_diagonal += a[p * n + q] * a[p * n + q];
+            }
+        }
+        if off_diagonal <= f64::EPSILON * f64::EPSILON * scale {
+            break;
+        }
+
+        for p in 0..n {
+            for q in (p + 1)..n {
+                let apq = a[p * n + q];
+                if apq == 0.0 {
+                    continue;
+                }
+
+                let theta = (a[q * n + q] - a[p * n + p]) / (2.0 * apq);
+                let t = theta.signum() / (theta.abs() + (theta * theta + 1.0).sqrt());
+                let c = 1.0 / (t * t + 1.0).sqrt();
+                let s = t * c;
+
+                for k in 0..n {
+                    let (akp, akq) = (a[k * n + p], a[k * n + q]);
+                    a[k * n + p] = c * akp - s * akq;
+                    a[k * n + q] = s * akp + c * akq;
+                }
+                for k in 0..n {
+                    let (apk, aqk) = (a[p * n + k], a[q * n + k]);
+                    a[p * n + k] = c * apk - s * aqk;
+                    a[q * n + k] = s * apk + c * aqk;
+                }
+                for k in 0..n {
+                    let (vkp, vkq) = (v[k * n + p], v[k * n + q]);
+                    v[k * n + p] = c * vkp - s * vkq;
+                    v[k * n + q] = s * vkp + c * vkq;
+                }
+            }
+        }
+    }
+
+    v
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use kornia_tensor::{CpuAllocator, Tensor};
+
+    #[test]
+    fn test_pca_line() -> Result<(), TensorOpsError> {
+        // samples along the direction (3, 4) / 5 with a constant offset
+        let data = (0..20)
+            .flat_map(|i| {
+                let s = i as f32 - 10.0;
+                [100.0 + 0.6 * s, -50.0 + 0.8 * s]
+            })
+            .collect::<Vec<_>>();
+        let data = Tensor::<f32, 2, _>::from_shape_vec([20, 2], data, CpuAllocator)?;
+
+        let (components, variances) = pca(&data, 2)?;
+        assert_eq!(components.shape, [2, 2]);
+        assert_eq!(variances.shape, [2]);
+
+        let first = components.as_slice();
+        assert!((first[0] - 0.6).abs() < 1e-5);
+        assert!((first[1] - 0.8).abs() < 1e-5);
+
+        // variance of the integers -10..10 is 35
+        assert!((variances.as_slice()[0] - 35.0).abs() < 1e-3);
+        assert!(variances.as_slice()[1].abs() < 1e-4);
+        Ok(())
+    }
+
+    #[test]
+    fn test_pca_invalid_components() -> Result<(), TensorOpsError> {
+        let data = Tensor::<f32, 2, _>::zeros([4, 3], CpuAllocator);
+        assert_eq!(
+            pca(&data, 4).err(),
+            Some(TensorOpsError::InvalidNumComponents(4, 3))
+        );
+        assert_eq!(
+            pca(&data, 0).err(),
+            Some(TensorOpsError::InvalidNumComponents(0, 3))
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,56 @@
+use criterion::{criterion_group, criterion_main, Criterion};
+use kornia_tensor::{CpuAllocator, Tensor};
+use std::hint::black_box;
+
+/// A 4K RGB image as a float tensor.
+fn tensor_4k() -> Tensor<f32, 3, CpuAllocator> {
+    Tensor::from_shape_fn([2160, 3840, 3], CpuAllocator, |[y, x, c]| {
+        ((y * 31 + x * 7 + c * 13) % 256) as f32
+    })
+}
+
+fn bench_map(c: &mut Criterion) {
+    let mut group = c.benchmark_group("map_3840x2160x3");
+    let t = tensor_4k();
+
+    group.bench_function("serial", |b| {
+        b.iter(|| black_box(t.map(|x| x / 255.0 * 2.0 - 1.0)))
+    });
+    group.bench_function("rayon", |b| {
+        b.iter(|| black_box(t.par_map(|x| x / 255.0 * 2.0 - 1.0)))
+    });
+
+    group.finish();
+}
+
+fn bench_sum_axis(c: &mut Criterion) {
+    let mut group = c.benchmark_group("sum_axis_3840x2160x3");
+    let t = tensor_4k();
+
+    for axis in [0, 2] {
+        group.bench_function(format!("serial_axis_{axis}"), |b| {
+            b.iter(|| black_box(t.sum_axis::<2>(axis).unwrap()))
+        });
+        group.bench_function(format!("rayon_axis_{axis}"), |b| {
+            b.iter(|| black_box(t.par_sum_axis::<2>(axis).unwrap()))
+        });
+    }
+
+    group.finish();
+}
+
+fn bench_elementwise(c: &mut Criterion) {
+    let mut group = c.benchmark_group("mul_3840x2160x3");
+    let t = tensor_4k();
+    let other = t.map(|x| x * 0.5);
+
+    group.bench_function("serial", |b| b.iter(|| black_box(t.mul(&other).unwrap())));
+    group.bench_function("rayon", |b| {
+        b.iter(|| black_box(t.par_mul(&other).unwrap()))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_map, bench_sum_axis, bench_elementwise);
+criterion_main!(benches);
@@ -0,0 +1,41 @@
+use std::{env, path::PathBuf};
+
+/// The directories of the CUDA toolkit searched for the runtime library.
+const CUDA_LIB_DIRS: [&str; 3] = ["lib64", "lib", "lib/x64"];
+
+fn main() {
+    println!("cargo:rustc-check-cfg=cfg(kornia_cudart)");
+    println!("cargo:rerun-if-env-changed=CUDA_PATH");
+    println!("cargo:rerun-if-env-changed=CUDA_HOME");
+
+    if env::var_os("CARGO_FEATURE_CUDA").is_none() {
+        return;
+    }
+
+    // link the CUDA runtime only if the toolkit is installed, so that the `cuda` feature builds
+    // without it; the runtime calls then fail at run time instead
+    let roots = ["CUDA_PATH", "CUDA_HOME"]
+        .into_iter()
+        .filter_map(env::var_os)
+        .map(PathBuf::from)
+        .chain(std::iter::once(PathBuf::from("/usr/local/cuda")));
+
+    for root in roots {
+        for dir in CUDA_LIB_DIRS.iter().map(|lib| root.join(lib)) {
+            if ["libcudart.so", "libcudart.dylib", "cudart.lib"]
+                .iter()
+                .any(|name| dir.join(name).exists())
+            {
+                println!("cargo:rustc-link-search=native={}", dir.display());
+                println!("cargo:rustc-link-lib=cudart");
+                println!("cargo:rustc-cfg=kornia_cudart");
+                return;
+            }
+        }
+    }
+
+    println!(
+        "cargo:warning=the CUDA runtime was not found, set CUDA_PATH to the CUDA toolkit to use \
+         the `cuda` feature"
+    );
+}
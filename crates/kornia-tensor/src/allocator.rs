@@ -3,6 +3,9 @@ use std::alloc::Layout;
 
 use thiserror::Error;
 
+#[cfg(feature = "cuda")]
+pub use crate::cuda::CudaAllocator;
+
 /// Error type for tensor memory allocation operations.
 ///
 /// This enum represents all possible errors that can occur during tensor memory
@@ -21,6 +24,13 @@ pub enum TensorAllocatorError {
     /// This typically indicates an out-of-memory condition or other allocation failure.
     #[error("Null pointer")]
     NullPointer,
+
+    /// The alignment of the layout is below the alignment of the allocator.
+    ///
+    /// This error occurs when an [`AlignedCpuAllocator`] is asked for memory with a layout
+    /// which is not aligned as the allocator requires.
+    #[error("Layout alignment {0} is below the allocator alignment {1}")]
+    AlignmentError(usize, usize),
 }
 
 /// Trait for custom tensor memory allocators.
@@ -195,6 +205,67 @@ impl TensorAllocator for CpuAllocator {
     }
 }
 
+/// CPU memory allocator with a minimum alignment of `ALIGN` bytes.
+///
+/// The memory is aligned to at least `ALIGN` bytes, e.g. 32 for AVX loads, so that SIMD kernels
+/// can use aligned loads from the base pointer of a tensor. `ALIGN` must be a power of two.
+///
+/// Only the tensors allocated through the allocator are aligned, e.g. with
+/// [`Tensor::from_size_val_aligned`](crate::Tensor::from_size_val_aligned). The constructors
+/// taking a vector keep the memory, and the alignment, of the vector.
+///
+/// # Examples
+///
+/// ```rust
+/// use kornia_tensor::{AlignedCpuAllocator, Tensor};
+///
+/// let tensor = Tensor::<f32, 2, AlignedCpuAllocator<32>>::from_size_val_aligned([3, 5], 0.0)
+///     .unwrap();
+/// assert_eq!(tensor.as_ptr() as usize % 32, 0);
+/// ```
+#[derive(Clone, Default)]
+pub struct AlignedCpuAllocator<const ALIGN: usize>;
+
+impl<const ALIGN: usize> AlignedCpuAllocator<ALIGN> {
+    /// Returns the layout with the alignment raised to at least `ALIGN` bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TensorAllocatorError::LayoutError`] if `ALIGN` is not a power of two or the
+    /// size overflows when rounded up to the alignment.
+    pub fn aligned_layout(layout: Layout) -> Result<Layout, TensorAllocatorError> {
+        Layout::from_size_align(layout.size(), layout.align().max(ALIGN))
+            .map_err(TensorAllocatorError::LayoutError)
+    }
+}
+
+/// Implements [`TensorAllocator`] using the Rust global allocator with a minimum alignment.
+impl<const ALIGN: usize> TensorAllocator for AlignedCpuAllocator<ALIGN> {
+    /// Allocates memory with a layout aligned to at least `ALIGN` bytes.
+    ///
+    /// The layout is used as is, so that the memory is deallocated with the same layout. Raise
+    /// the alignment of a layout with [`AlignedCpuAllocator::aligned_layout`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TensorAllocatorError::AlignmentError`] if the alignment of the layout is below
+    /// `ALIGN`, or [`TensorAllocatorError::NullPointer`] if the allocation fails.
+    fn alloc(&self, layout: Layout) -> Result<*mut u8, TensorAllocatorError> {
+        if layout.align() < ALIGN {
+            return Err(TensorAllocatorError::AlignmentError(layout.align(), ALIGN));
+        }
+        CpuAllocator.alloc(layout)
+    }
+
+    /// Deallocates memory with the layout it was allocated with.
+    ///
+    /// The storages adopting the memory of a vector are released with the layout of the vector.
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        CpuAllocator.dealloc(ptr, layout)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -207,4 +278,38 @@ mod tests {
         allocator.dealloc(ptr, layout);
         Ok(())
     }
+
+    #[test]
+    fn test_aligned_cpu_allocator() -> Result<(), TensorAllocatorError> {
+        let allocator = AlignedCpuAllocator::<64>;
+        let layout = Layout::array::<u8>(100).map_err(TensorAllocatorError::LayoutError)?;
+        let aligned = AlignedCpuAllocator::<64>::aligned_layout(layout)?;
+        assert_eq!(aligned.align(), 64);
+        assert_eq!(aligned.size(), 100);
+
+        let ptr = allocator.alloc(aligned)?;
+        assert_eq!(ptr as usize % 64, 0);
+        allocator.dealloc(ptr, aligned);
+
+        // the alignment must be a power of two
+        assert!(AlignedCpuAllocator::<24>::aligned_layout(layout).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_aligned_cpu_allocator_under_aligned() -> Result<(), TensorAllocatorError> {
+        let allocator = AlignedCpuAllocator::<32>;
+        let layout = Layout::from_size_align(64, 8).map_err(TensorAllocatorError::LayoutError)?;
+        assert_eq!(
+            allocator.alloc(layout),
+            Err(TensorAllocatorError::AlignmentError(8, 32))
+        );
+
+        // a layout with a larger alignment is allocated and deallocated as is
+        let layout = Layout::from_size_align(64, 128).map_err(TensorAllocatorError::LayoutError)?;
+        let ptr = allocator.alloc(layout)?;
+        assert_eq!(ptr as usize % 128, 0);
+        allocator.dealloc(ptr, layout);
+        Ok(())
+    }
 }
@@ -1,9 +1,34 @@
+use std::{
+    borrow::Cow,
+    io::{Read, Write},
+};
+
 use crate::{
     allocator::{CpuAllocator, TensorAllocator},
+    bytes::ByteElement,
+    rle::RleTensor,
     storage::TensorStorage,
-    Tensor,
+    Tensor, TensorError,
 };
 
+/// The magic bytes at the start of the framed bincode format.
+const MAGIC: &[u8; 4] = b"KTNS";
+
+/// The header flag for an uncompressed payload.
+const FLAG_RAW: u8 = 0;
+
+/// The header flag for a zstd compressed payload.
+const FLAG_ZSTD: u8 = 1;
+
+/// The length of the header: the magic bytes followed by the compression flag.
+const HEADER_LEN: usize = MAGIC.len() + 1;
+
+/// The magic bytes at the start of the streamed format.
+const STREAM_MAGIC: &[u8; 4] = b"KTSR";
+
+/// The number of bytes written or read at once by the streamed format.
+const STREAM_CHUNK_LEN: usize = 1 << 16;
+
 impl<T, const N: usize, A: TensorAllocator + 'static> bincode::enc::Encode for Tensor<T, N, A>
 where
     T: bincode::enc::Encode,
@@ -37,6 +62,274 @@ where
     }
 }
 
+impl<T, const N: usize> bincode::enc::Encode for RleTensor<T, N>
+where
+    T: bincode::enc::Encode,
+{
+    fn encode<E: bincode::enc::Encoder>(
+        &self,
+        encoder: &mut E,
+    ) -> Result<(), bincode::error::EncodeError> {
+        bincode::Encode::encode(&self.shape, encoder)?;
+        bincode::Encode::encode(&self.values, encoder)?;
+        bincode::Encode::encode(&self.lengths, encoder)?;
+        Ok(())
+    }
+}
+
+impl<T, const N: usize, C> bincode::de::Decode<C> for RleTensor<T, N>
+where
+    T: bincode::de::Decode<C>,
+{
+    fn decode<D: bincode::de::Decoder<Context = C>>(
+        decoder: &mut D,
+    ) -> Result<Self, bincode::error::DecodeError> {
+        let shape: [usize; N] = bincode::Decode::decode(decoder)?;
+        let values: Vec<T> = bincode::Decode::decode(decoder)?;
+        let lengths: Vec<usize> = bincode::Decode::decode(decoder)?;
+        if values.len() != lengths.len() {
+            return Err(bincode::error::DecodeError::Other(
+                "the number of run values and lengths differ",
+            ));
+        }
+        RleTensor::from_runs(shape, values.into_iter().zip(lengths).collect())
+            .map_err(|e| bincode::error::DecodeError::OtherString(e.to_string()))
+    }
+}
+
+impl<T, const N: usize, A: TensorAllocator + 'static> Tensor<T, N, A>
+where
+    T: bincode::enc::Encode,
+{
+    /// Encode the tensor with bincode, prefixed by a header flagging the payload as uncompressed.
+    ///
+    /// The output can be read back with [`Tensor::from_bincode_compressed`].
+    ///
+    /// # Returns
+    ///
+    /// The header followed by the encoded tensor.
+    pub fn to_bincode(&self) -> Result<Vec<u8>, TensorError> {
+        let mut bytes = frame_header(FLAG_RAW);
+        bincode::encode_into_std_write(self, &mut bytes, bincode::config::standard())
+            .map_err(|e| TensorError::SerializationError(e.to_string()))?;
+        Ok(bytes)
+    }
+
+    /// Encode the tensor with bincode and compress the payload with zstd.
+    ///
+    /// The header flags the payload as compressed, so that [`Tensor::from_bincode_compressed`]
+    /// detects it when reading.
+    ///
+    /// # Arguments
+    ///
+    /// * `level` - The zstd compression level. Use 0 for the zstd default.
+    ///
+    /// # Returns
+    ///
+    /// The header followed by the compressed tensor.
+    #[cfg(feature = "zstd")]
+    pub fn to_bincode_compressed(&self, level: i32) -> Result<Vec<u8>, TensorError> {
+        let encoded = bincode::encode_to_vec(self, bincode::config::standard())
+            .map_err(|e| TensorError::SerializationError(e.to_string()))?;
+        // the single-shot compression stores the uncompressed length in the zstd frame
+        let compressed = zstd::bulk::compress(&encoded, level)
+            .map_err(|e| TensorError::SerializationError(e.to_string()))?;
+        let mut bytes = frame_header(FLAG_ZSTD);
+        bytes.extend_from_slice(&compressed);
+        Ok(bytes)
+    }
+}
+
+impl<T, const N: usize> Tensor<T, N, CpuAllocator>
+where
+    T: bincode::de::Decode<()>,
+{
+    /// Decode a tensor written by [`Tensor::to_bincode`] or `Tensor::to_bincode_compressed`.
+    ///
+    /// The compression is detected from the header flag. Reading a compressed payload
+    /// requires the `zstd` feature.
+    ///
+    /// # Arguments
+    ///
+    /// * `bytes` - The header followed by the encoded tensor.
+    ///
+    /// # Returns
+    ///
+    /// The decoded tensor.
+    pub fn from_bincode_compressed(bytes: &[u8]) -> Result<Self, TensorError> {
+        if bytes.len() < HEADER_LEN || &bytes[..MAGIC.len()] != MAGIC {
+            return Err(TensorError::SerializationError(
+                "missing tensor header".to_string(),
+            ));
+        }
+
+        let payload = &bytes[HEADER_LEN..];
+        let encoded: Cow<[u8]> = match bytes[MAGIC.len()] {
+            FLAG_RAW => Cow::Borrowed(payload),
+            #[cfg(feature = "zstd")]
+            FLAG_ZSTD => Cow::Owned(decompress(payload)?),
+            #[cfg(not(feature = "zstd"))]
+            FLAG_ZSTD => {
+                return Err(TensorError::SerializationError(
+                    "compressed payload requires the zstd feature".to_string(),
+                ))
+            }
+            flag => {
+                return Err(TensorError::SerializationError(format!(
+                    "unknown compression flag {flag}"
+                )))
+            }
+        };
+
+        let (tensor, _) = bincode::decode_from_slice(&encoded, bincode::config::standard())
+            .map_err(|e| TensorError::SerializationError(e.to_string()))?;
+        Ok(tensor)
+    }
+}
+
+/// Decompress a zstd payload, preallocating the buffer from the length stored in the frame.
+///
+/// The length is not trusted: the preallocation is capped to a chunk and the buffer grows with
+/// the decoded data.
+#[cfg(feature = "zstd")]
+fn decompress(payload: &[u8]) -> Result<Vec<u8>, TensorError> {
+    let len = zstd::zstd_safe::get_frame_content_size(payload)
+        .map_err(|_| TensorError::SerializationError("invalid zstd frame".to_string()))?
+        .unwrap_or(0);
+    let len = usize::try_from(len).unwrap_or(usize::MAX);
+    let mut decoded = Vec::with_capacity(len.min(STREAM_CHUNK_LEN));
+    zstd::stream::copy_decode(payload, &mut decoded)
+        .map_err(|e| TensorError::SerializationError(e.to_string()))?;
+    Ok(decoded)
+}
+
+impl<T: ByteElement, const N: usize, A: TensorAllocator> Tensor<T, N, A> {
+    /// Serialize the tensor to a writer without building the whole payload in memory.
+    ///
+    /// The output is a header with the magic bytes, the element type tag, the number of
+    /// dimensions, the shape, the strides and the storage length, as little-endian `u64`s,
+    /// followed by the little-endian bytes of the storage elements, written in chunks.
+    ///
+    /// # Arguments
+    ///
+    /// * `w` - The writer, e.g. a buffered file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    pub fn serialize_to_writer<W: Write>(&self, mut w: W) -> Result<(), TensorError> {
+        let data = self.storage.as_slice();
+
+        let mut header = Vec::with_capacity(STREAM_MAGIC.len() + 2 + (2 * N + 1) * 8);
+        header.extend_from_slice(STREAM_MAGIC);
+        header.push(T::TAG);
+        header.push(N as u8);
+        for v in self.shape.iter().chain(&self.strides).chain([&data.len()]) {
+            header.extend_from_slice(&(*v as u64).to_le_bytes());
+        }
+        w.write_all(&header).map_err(io_error)?;
+
+        let mut chunk = Vec::with_capacity(STREAM_CHUNK_LEN);
+        for elements in data.chunks((STREAM_CHUNK_LEN / T::SIZE).max(1)) {
+            chunk.clear();
+            elements.iter().for_each(|v| v.write_le(&mut chunk));
+            w.write_all(&chunk).map_err(io_error)?;
+        }
+        w.flush().map_err(io_error)
+    }
+}
+
+impl<T: ByteElement, const N: usize> Tensor<T, N, CpuAllocator> {
+    /// Deserialize a tensor written by [`Tensor::serialize_to_writer`] from a reader.
+    ///
+    /// The elements are read in chunks into the storage of the tensor, so the only large
+    /// allocation is the tensor itself.
+    ///
+    /// # Arguments
+    ///
+    /// * `r` - The reader, e.g. a buffered file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading fails, the header is invalid, or the element type or the
+    /// number of dimensions differ from the serialized tensor.
+    pub fn deserialize_from_reader<R: Read>(mut r: R) -> Result<Self, TensorError> {
+        let mut prefix = [0u8; 6];
+        r.read_exact(&mut prefix).map_err(io_error)?;
+        if &prefix[..STREAM_MAGIC.len()] != STREAM_MAGIC {
+            return Err(TensorError::SerializationError(
+                "missing tensor stream header".to_string(),
+            ));
+        }
+        if prefix[4] != T::TAG {
+            return Err(TensorError::SerializationError(format!(
+                "element type tag {} does not match the expected {}",
+                prefix[4],
+                T::TAG
+            )));
+        }
+        if prefix[5] as usize != N {
+            return Err(TensorError::SerializationError(format!(
+                "expected {N} dimensions, got {}",
+                prefix[5]
+            )));
+        }
+
+        let mut read_usize = || -> Result<usize, TensorError> {
+            let mut buf = [0u8; 8];
+            r.read_exact(&mut buf).map_err(io_error)?;
+            usize::try_from(u64::from_le_bytes(buf))
+                .map_err(|e| TensorError::SerializationError(e.to_string()))
+        };
+        let mut shape = [0; N];
+        let mut strides = [0; N];
+        for v in shape.iter_mut().chain(strides.iter_mut()) {
+            *v = read_usize()?;
+        }
+        let len = read_usize()?;
+
+        // the strides must stay in the storage, as the accessors do not check the bounds
+        let numel = shape.iter().product::<usize>();
+        let extent = shape
+            .iter()
+            .zip(&strides)
+            .map(|(&d, &s)| d.saturating_sub(1) * s)
+            .sum::<usize>();
+        if numel > 0 && extent >= len {
+            return Err(TensorError::SerializationError(format!(
+                "the shape {shape:?} and strides {strides:?} exceed the {len} stored elements"
+            )));
+        }
+
+        // the length is not trusted, so the storage grows with the elements actually read
+        let mut data = Vec::with_capacity(len.min(STREAM_CHUNK_LEN / T::SIZE));
+        let mut chunk = vec![0u8; (STREAM_CHUNK_LEN / T::SIZE).max(1) * T::SIZE];
+        while data.len() < len {
+            let count = (len - data.len()).min(chunk.len() / T::SIZE);
+            let bytes = &mut chunk[..count * T::SIZE];
+            r.read_exact(bytes).map_err(io_error)?;
+            data.extend(bytes.chunks_exact(T::SIZE).map(T::read_le));
+        }
+
+        Ok(Self {
+            storage: TensorStorage::from_vec(data, CpuAllocator),
+            shape,
+            strides,
+        })
+    }
+}
+
+fn io_error(e: std::io::Error) -> TensorError {
+    TensorError::SerializationError(e.to_string())
+}
+
+fn frame_header(flag: u8) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(HEADER_LEN);
+    bytes.extend_from_slice(MAGIC);
+    bytes.push(flag);
+    bytes
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -56,4 +349,127 @@ mod tests {
         assert_eq!(tensor.as_slice(), deserialized.0.as_slice());
         Ok(())
     }
+
+    #[test]
+    fn test_bincode_framed() -> Result<(), Box<dyn std::error::Error>> {
+        let tensor = Tensor::<u8, 2, CpuAllocator>::from_shape_vec(
+            [2, 3],
+            vec![1, 2, 3, 4, 5, 6],
+            CpuAllocator,
+        )?;
+        let bytes = tensor.to_bincode()?;
+        let decoded = Tensor::<u8, 2, CpuAllocator>::from_bincode_compressed(&bytes)?;
+        assert_eq!(decoded.shape, tensor.shape);
+        assert_eq!(decoded.as_slice(), tensor.as_slice());
+
+        assert!(Tensor::<u8, 2, CpuAllocator>::from_bincode_compressed(&bytes[1..]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_bincode_rle() -> Result<(), Box<dyn std::error::Error>> {
+        let mask = Tensor::<u8, 2, _>::from_shape_fn([64, 64], CpuAllocator, |[y, x]| {
+            ((10..20).contains(&y) && (30..40).contains(&x)) as u8
+        });
+        let rle = RleTensor::from_dense(&mask);
+
+        let config = bincode::config::standard();
+        let dense_bytes = bincode::encode_to_vec(&mask, config)?;
+        let rle_bytes = bincode::encode_to_vec(&rle, config)?;
+        assert!(rle_bytes.len() * 20 < dense_bytes.len());
+
+        let (decoded, _): (RleTensor<u8, 2>, usize) =
+            bincode::decode_from_slice(&rle_bytes, config)?;
+        assert_eq!(decoded, rle);
+        assert_eq!(decoded.to_dense(CpuAllocator)?.as_slice(), mask.as_slice());
+        Ok(())
+    }
+
+    #[test]
+    fn test_stream_serialization() -> Result<(), Box<dyn std::error::Error>> {
+        // larger than a chunk so that the elements are streamed in several writes
+        let data = (0..100_000).map(|i| i as f32 * 0.25).collect::<Vec<_>>();
+        let tensor = Tensor::<f32, 2, _>::from_shape_vec([250, 400], data, CpuAllocator)?;
+
+        let mut bytes = Vec::new();
+        tensor.serialize_to_writer(&mut bytes)?;
+        assert_eq!(bytes.len(), 6 + 5 * 8 + 100_000 * 4);
+
+        let decoded = Tensor::<f32, 2, _>::deserialize_from_reader(bytes.as_slice())?;
+        assert_eq!(decoded.shape, tensor.shape);
+        assert_eq!(decoded.strides, tensor.strides);
+        assert_eq!(decoded.as_slice(), tensor.as_slice());
+
+        // the strides of a transposed tensor are kept
+        let mut transposed =
+            Tensor::<u16, 2, _>::from_shape_vec([2, 3], vec![1, 2, 3, 4, 5, 6], CpuAllocator)?;
+        transposed.shape = [3, 2];
+        transposed.strides = [1, 3];
+        let mut bytes = Vec::new();
+        transposed.serialize_to_writer(&mut bytes)?;
+        let decoded = Tensor::<u16, 2, _>::deserialize_from_reader(bytes.as_slice())?;
+        assert_eq!(decoded.strides, [1, 3]);
+        assert_eq!(*decoded.get_unchecked([2, 1]), 6);
+
+        // a different element type, rank or a truncated stream is rejected
+        assert!(Tensor::<i16, 2, _>::deserialize_from_reader(bytes.as_slice()).is_err());
+        assert!(Tensor::<u16, 3, _>::deserialize_from_reader(bytes.as_slice()).is_err());
+        assert!(Tensor::<u16, 2, _>::deserialize_from_reader(&bytes[..bytes.len() - 1]).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_stream_forged_length() -> Result<(), Box<dyn std::error::Error>> {
+        // a header announcing far more elements than the stream holds is not preallocated
+        let mut bytes = STREAM_MAGIC.to_vec();
+        bytes.extend_from_slice(&[f32::TAG, 1]);
+        for v in [1u64, 1, 1 << 60] {
+            bytes.extend_from_slice(&v.to_le_bytes());
+        }
+        bytes.extend_from_slice(&1.0f32.to_le_bytes());
+
+        let res = Tensor::<f32, 1, _>::deserialize_from_reader(bytes.as_slice());
+        assert!(matches!(res, Err(TensorError::SerializationError(_))));
+        Ok(())
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn test_bincode_compressed() -> Result<(), Box<dyn std::error::Error>> {
+        // a mostly constant image compresses well
+        let data = (0..64 * 64)
+            .map(|i| if i % 64 < 32 { 10.0 } else { 200.0 })
+            .collect();
+        let tensor = Tensor::<f32, 2, CpuAllocator>::from_shape_vec([64, 64], data, CpuAllocator)?;
+
+        let raw = tensor.to_bincode()?;
+        let compressed = tensor.to_bincode_compressed(0)?;
+        assert!(compressed.len() < raw.len());
+
+        let decoded = Tensor::<f32, 2, CpuAllocator>::from_bincode_compressed(&compressed)?;
+        assert_eq!(decoded.shape, tensor.shape);
+        assert_eq!(decoded.strides, tensor.strides);
+        assert_eq!(decoded.as_slice(), tensor.as_slice());
+
+        // a truncated header or payload is rejected
+        assert!(Tensor::<f32, 2, CpuAllocator>::from_bincode_compressed(&compressed[..4]).is_err());
+        let mut truncated = compressed.clone();
+        truncated.pop();
+        assert!(Tensor::<f32, 2, CpuAllocator>::from_bincode_compressed(&truncated).is_err());
+        Ok(())
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn test_bincode_compressed_forged_length() {
+        // a zstd frame announcing 2^62 bytes of content but holding an empty raw block
+        let mut bytes = frame_header(FLAG_ZSTD);
+        bytes.extend_from_slice(&0xFD2F_B528u32.to_le_bytes());
+        bytes.push(0xE0);
+        bytes.extend_from_slice(&(1u64 << 62).to_le_bytes());
+        bytes.extend_from_slice(&[1, 0, 0]);
+
+        let res = Tensor::<f32, 2, CpuAllocator>::from_bincode_compressed(&bytes);
+        assert!(matches!(res, Err(TensorError::SerializationError(_))));
+    }
 }
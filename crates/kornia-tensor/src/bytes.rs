@@ -0,0 +1,268 @@
+use crate::{allocator::TensorAllocator, tensor::row_major_indices, Tensor, TensorError};
+
+/// An element type with a fixed size byte representation.
+///
+/// The implementations follow the `to_le_bytes`/`to_be_bytes` methods of the primitive types,
+/// so the multi-byte elements are swapped when the requested byte order is not the native one.
+pub trait ByteElement: Copy {
+    /// The number of bytes of an element.
+    const SIZE: usize;
+
+    /// A tag identifying the element type in the serialized headers.
+    const TAG: u8;
+
+    /// Append the little-endian bytes of the element.
+    fn write_le(&self, bytes: &mut Vec<u8>);
+
+    /// Append the big-endian bytes of the element.
+    fn write_be(&self, bytes: &mut Vec<u8>);
+
+    /// Read an element from its little-endian bytes.
+    ///
+    /// PRECONDITION: `bytes` has exactly `SIZE` bytes.
+    fn read_le(bytes: &[u8]) -> Self;
+
+    /// Read an element from its big-endian bytes.
+    ///
+    /// PRECONDITION: `bytes` has exactly `SIZE` bytes.
+    fn read_be(bytes: &[u8]) -> Self;
+
+    /// Reverse the bytes of the element, the identity for single-byte elements.
+    fn swap_bytes(self) -> Self;
+}
+
+macro_rules! impl_byte_element {
+    ($($t:ty => $tag:expr),*) => {
+        $(
+            impl ByteElement for $t {
+                const SIZE: usize = std::mem::size_of::<$t>();
+
+                const TAG: u8 = $tag;
+
+                fn write_le(&self, bytes: &mut Vec<u8>) {
+                    bytes.extend_from_slice(&self.to_le_bytes());
+                }
+
+                fn write_be(&self, bytes: &mut Vec<u8>) {
+                    bytes.extend_from_slice(&self.to_be_bytes());
+                }
+
+                fn read_le(bytes: &[u8]) -> Self {
+                    let mut buf = [0; std::mem::size_of::<$t>()];
+                    buf.copy_from_slice(bytes);
+                    <$t>::from_le_bytes(buf)
+                }
+
+                fn read_be(bytes: &[u8]) -> Self {
+                    let mut buf = [0; std::mem::size_of::<$t>()];
+                    buf.copy_from_slice(bytes);
+                    <$t>::from_be_bytes(buf)
+                }
+
+                fn swap_bytes(self) -> Self {
+                    let mut buf = self.to_ne_bytes();
+                    buf.reverse();
+                    <$t>::from_ne_bytes(buf)
+                }
+            }
+        )*
+    };
+}
+
+impl_byte_element!(
+    u8 => 0,
+    i8 => 1,
+    u16 => 2,
+    i16 => 3,
+    u32 => 4,
+    i32 => 5,
+    u64 => 6,
+    i64 => 7,
+    f32 => 8,
+    f64 => 9
+);
+
+impl<T: ByteElement, const N: usize, A: TensorAllocator> Tensor<T, N, A> {
+    /// Serialize the elements of the tensor as little-endian bytes.
+    ///
+    /// The elements are written in row-major order, so the bytes of a non-contiguous tensor
+    /// follow its logical layout. The shape is not included.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{CpuAllocator, Tensor};
+    ///
+    /// let t = Tensor::<u16, 1, _>::from_shape_vec([2], vec![1, 0x0203], CpuAllocator).unwrap();
+    /// assert_eq!(t.to_bytes_le(), vec![1, 0, 3, 2]);
+    /// ```
+    pub fn to_bytes_le(&self) -> Vec<u8> {
+        self.to_bytes_with(T::write_le)
+    }
+
+    /// Serialize the elements of the tensor as big-endian bytes.
+    ///
+    /// The elements are written in row-major order, so the bytes of a non-contiguous tensor
+    /// follow its logical layout. The shape is not included.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{CpuAllocator, Tensor};
+    ///
+    /// let t = Tensor::<u16, 1, _>::from_shape_vec([2], vec![1, 0x0203], CpuAllocator).unwrap();
+    /// assert_eq!(t.to_bytes_be(), vec![0, 1, 2, 3]);
+    /// ```
+    pub fn to_bytes_be(&self) -> Vec<u8> {
+        self.to_bytes_with(T::write_be)
+    }
+
+    /// Create a tensor from the little-endian bytes of its elements in row-major order.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape` - The shape of the tensor.
+    /// * `bytes` - The bytes of the elements, e.g. from [`Tensor::to_bytes_le`].
+    /// * `alloc` - The allocator of the tensor.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the number of bytes does not match the shape.
+    pub fn from_bytes_le(shape: [usize; N], bytes: &[u8], alloc: A) -> Result<Self, TensorError> {
+        Self::from_bytes_with(shape, bytes, alloc, T::read_le)
+    }
+
+    /// Create a tensor from the big-endian bytes of its elements in row-major order.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape` - The shape of the tensor.
+    /// * `bytes` - The bytes of the elements, e.g. from [`Tensor::to_bytes_be`].
+    /// * `alloc` - The allocator of the tensor.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the number of bytes does not match the shape.
+    pub fn from_bytes_be(shape: [usize; N], bytes: &[u8], alloc: A) -> Result<Self, TensorError> {
+        Self::from_bytes_with(shape, bytes, alloc, T::read_be)
+    }
+
+    /// Reverse the byte order of each element in place.
+    ///
+    /// This fixes the elements of a buffer written with the other byte order, e.g. after
+    /// wrapping foreign data with [`Tensor::from_raw_parts`]. The whole storage is swapped,
+    /// including the elements skipped by the strides. Single-byte elements are left as is.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{CpuAllocator, Tensor};
+    ///
+    /// let mut t = Tensor::<u16, 1, _>::from_shape_vec([2], vec![0x0102, 0xff00], CpuAllocator).unwrap();
+    /// t.swap_bytes_inplace();
+    /// assert_eq!(t.as_slice(), &[0x0201, 0x00ff]);
+    /// ```
+    pub fn swap_bytes_inplace(&mut self) {
+        if T::SIZE > 1 {
+            self.storage
+                .as_mut_slice()
+                .iter_mut()
+                .for_each(|v| *v = v.swap_bytes());
+        }
+    }
+
+    fn to_bytes_with(&self, write: impl Fn(&T, &mut Vec<u8>)) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(self.numel() * T::SIZE);
+        if self.is_standard_layout() {
+            self.as_slice().iter().for_each(|v| write(v, &mut bytes));
+        } else {
+            row_major_indices(self.shape)
+                .for_each(|index| write(self.get_unchecked(index), &mut bytes));
+        }
+        bytes
+    }
+
+    fn from_bytes_with(
+        shape: [usize; N],
+        bytes: &[u8],
+        alloc: A,
+        read: impl Fn(&[u8]) -> T,
+    ) -> Result<Self, TensorError> {
+        let numel = shape.iter().product::<usize>();
+        if bytes.len() != numel * T::SIZE {
+            return Err(TensorError::SerializationError(format!(
+                "expected {} bytes for {} elements, got {}",
+                numel * T::SIZE,
+                numel,
+                bytes.len()
+            )));
+        }
+        let data = bytes.chunks_exact(T::SIZE).map(read).collect();
+        Tensor::from_shape_vec(shape, data, alloc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CpuAllocator, Tensor, TensorError};
+
+    #[test]
+    fn bytes_endianness() -> Result<(), TensorError> {
+        let t = Tensor::<u32, 2, _>::from_shape_vec(
+            [2, 2],
+            vec![0x01020304, 0xdeadbeef, 0, u32::MAX],
+            CpuAllocator,
+        )?;
+
+        // the big-endian bytes are the same on any host
+        let be = t.to_bytes_be();
+        assert_eq!(&be[..8], &[1, 2, 3, 4, 0xde, 0xad, 0xbe, 0xef]);
+        let from_be = Tensor::<u32, 2, _>::from_bytes_be([2, 2], &be, CpuAllocator)?;
+        assert_eq!(from_be.as_slice(), t.as_slice());
+
+        // reading with the other byte order swaps the elements
+        let swapped = Tensor::<u32, 2, _>::from_bytes_le([2, 2], &be, CpuAllocator)?;
+        assert_eq!(swapped.as_slice(), &[0x04030201, 0xefbeadde, 0, u32::MAX]);
+
+        let f = Tensor::<f32, 1, _>::from_shape_vec([3], vec![1.5, -0.25, 1e9], CpuAllocator)?;
+        let from_le = Tensor::<f32, 1, _>::from_bytes_le([3], &f.to_bytes_le(), CpuAllocator)?;
+        assert_eq!(from_le.as_slice(), f.as_slice());
+        assert_eq!(&f.to_bytes_be()[..4], &1.5f32.to_be_bytes());
+
+        // a transposed tensor is written in its logical order
+        let mut transposed =
+            Tensor::<u16, 2, _>::from_shape_vec([2, 2], vec![1, 2, 3, 4], CpuAllocator)?;
+        transposed.strides = [1, 2];
+        assert_eq!(transposed.to_bytes_le(), vec![1, 0, 3, 0, 2, 0, 4, 0]);
+
+        assert!(matches!(
+            Tensor::<u32, 2, _>::from_bytes_be([2, 2], &be[..15], CpuAllocator),
+            Err(TensorError::SerializationError(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn swap_bytes_inplace() -> Result<(), TensorError> {
+        let data = vec![0x0102, 0xabcd, 0, u16::MAX];
+        let mut t = Tensor::<u16, 2, _>::from_shape_vec([2, 2], data.clone(), CpuAllocator)?;
+
+        t.swap_bytes_inplace();
+        assert_eq!(t.as_slice(), &[0x0201, 0xcdab, 0, u16::MAX]);
+        t.swap_bytes_inplace();
+        assert_eq!(t.as_slice(), data.as_slice());
+
+        // swapping matches reading the bytes with the other byte order
+        let f = Tensor::<f64, 1, _>::from_shape_vec([2], vec![1.5, -3.25], CpuAllocator)?;
+        let mut swapped = Tensor::<f64, 1, _>::from_bytes_le([2], &f.to_bytes_be(), CpuAllocator)?;
+        swapped.swap_bytes_inplace();
+        assert_eq!(swapped.as_slice(), f.as_slice());
+
+        let mut bytes = Tensor::<u8, 1, _>::from_shape_vec([3], vec![1, 2, 3], CpuAllocator)?;
+        bytes.swap_bytes_inplace();
+        assert_eq!(bytes.as_slice(), &[1, 2, 3]);
+
+        Ok(())
+    }
+}
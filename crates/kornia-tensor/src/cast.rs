@@ -0,0 +1,127 @@
+/// Convert a value from another element type with saturation.
+///
+/// The conversions never wrap around:
+///
+/// - float to integer rounds to the nearest integer, with ties away from zero, and clamps to
+///   the range of the integer type. NaN maps to 0.
+/// - integer to integer clamps to the range of the output type.
+/// - integer to float and float to float follow `as`, i.e. round to the nearest float.
+///
+/// New element types are supported by implementing this trait for the pairs of types.
+///
+/// # Example
+///
+/// ```
+/// use kornia_tensor::cast::CastFrom;
+///
+/// assert_eq!(u8::cast_from(255.6f32), 255);
+/// assert_eq!(u8::cast_from(-3i32), 0);
+/// assert_eq!(i16::cast_from(f32::NAN), 0);
+/// assert_eq!(f32::cast_from(200u8), 200.0);
+/// ```
+pub trait CastFrom<T> {
+    /// Convert the value to this type.
+    fn cast_from(value: T) -> Self;
+}
+
+macro_rules! impl_cast_int_from_int {
+    ($src:ty => $($dst:ty),*) => {
+        $(
+            impl CastFrom<$src> for $dst {
+                #[inline]
+                fn cast_from(value: $src) -> Self {
+                    (value as i128).clamp(<$dst>::MIN as i128, <$dst>::MAX as i128) as $dst
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_cast_int_from_float {
+    ($src:ty => $($dst:ty),*) => {
+        $(
+            impl CastFrom<$src> for $dst {
+                #[inline]
+                fn cast_from(value: $src) -> Self {
+                    // `as` saturates at the bounds of the integer type and maps NaN to 0
+                    value.round() as $dst
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_cast_float_from {
+    ($src:ty => $($dst:ty),*) => {
+        $(
+            impl CastFrom<$src> for $dst {
+                #[inline]
+                fn cast_from(value: $src) -> Self {
+                    value as $dst
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_cast_from_int {
+    ($($src:ty),*) => {
+        $(
+            impl_cast_int_from_int!($src => u8, i8, u16, i16, u32, i32, u64, i64);
+            impl_cast_float_from!($src => f32, f64);
+        )*
+    };
+}
+
+macro_rules! impl_cast_from_float {
+    ($($src:ty),*) => {
+        $(
+            impl_cast_int_from_float!($src => u8, i8, u16, i16, u32, i32, u64, i64);
+            impl_cast_float_from!($src => f32, f64);
+        )*
+    };
+}
+
+impl_cast_from_int!(u8, i8, u16, i16, u32, i32, u64, i64);
+impl_cast_from_float!(f32, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::CastFrom;
+    use crate::{CpuAllocator, Tensor, TensorError};
+
+    #[test]
+    fn cast_saturating() -> Result<(), TensorError> {
+        let heatmap = Tensor::<f32, 1, _>::from_shape_vec(
+            [8],
+            vec![-1.0, 0.4, 0.5, 127.5, 254.6, 256.0, f32::NAN, f32::INFINITY],
+            CpuAllocator,
+        )?;
+        assert_eq!(
+            heatmap.cast::<u8>().as_slice(),
+            &[0, 0, 1, 128, 255, 255, 0, 255]
+        );
+        assert_eq!(
+            heatmap.cast::<i8>().as_slice(),
+            &[-1, 0, 1, 127, 127, 127, 0, 127]
+        );
+
+        // negative integers clamp to zero when cast to unsigned types
+        let ints =
+            Tensor::<i32, 1, _>::from_shape_vec([4], vec![-70000, -1, 300, 70000], CpuAllocator)?;
+        assert_eq!(ints.cast::<u8>().as_slice(), &[0, 0, 255, 255]);
+        assert_eq!(ints.cast::<u16>().as_slice(), &[0, 0, 300, 65535]);
+        assert_eq!(ints.cast::<i16>().as_slice(), &[-32768, -1, 300, 32767]);
+        assert_eq!(
+            ints.cast::<f32>().as_slice(),
+            &[-70000.0, -1.0, 300.0, 70000.0]
+        );
+
+        assert_eq!(u64::cast_from(-0.5f64), 0);
+        assert_eq!(u64::cast_from(u64::MAX), u64::MAX);
+        assert_eq!(i64::cast_from(u64::MAX), i64::MAX);
+        assert_eq!(f64::cast_from(0.1f32), 0.1f32 as f64);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,332 @@
+use std::{
+    alloc::Layout,
+    any::Any,
+    ffi::{c_int, c_void},
+    ptr::NonNull,
+    sync::Arc,
+};
+
+use thiserror::Error;
+
+use crate::{
+    allocator::{CpuAllocator, TensorAllocator, TensorAllocatorError},
+    storage::TensorStorage,
+    Tensor,
+};
+
+#[cfg(kornia_cudart)]
+use runtime::*;
+
+/// The CUDA runtime calls, linked by the build script when the CUDA toolkit is found.
+#[cfg(kornia_cudart)]
+mod runtime {
+    use std::ffi::{c_int, c_void};
+
+    #[link(name = "cudart")]
+    extern "C" {
+        pub fn cudaSetDevice(device: c_int) -> c_int;
+        pub fn cudaMalloc(ptr: *mut *mut c_void, size: usize) -> c_int;
+        pub fn cudaFree(ptr: *mut c_void) -> c_int;
+        pub fn cudaMemcpy(dst: *mut c_void, src: *const c_void, count: usize, kind: c_int)
+            -> c_int;
+    }
+}
+
+#[cfg(not(kornia_cudart))]
+use no_runtime::*;
+
+/// The CUDA runtime calls of a build without the CUDA toolkit, which all fail with
+/// `cudaErrorNoDevice`.
+#[cfg(not(kornia_cudart))]
+#[allow(non_snake_case)]
+mod no_runtime {
+    use std::ffi::{c_int, c_void};
+
+    const NO_DEVICE: c_int = 100;
+
+    pub unsafe fn cudaSetDevice(_device: c_int) -> c_int {
+        NO_DEVICE
+    }
+
+    pub unsafe fn cudaMalloc(_ptr: *mut *mut c_void, _size: usize) -> c_int {
+        NO_DEVICE
+    }
+
+    pub unsafe fn cudaFree(_ptr: *mut c_void) -> c_int {
+        NO_DEVICE
+    }
+
+    pub unsafe fn cudaMemcpy(
+        _dst: *mut c_void,
+        _src: *const c_void,
+        _count: usize,
+        _kind: c_int,
+    ) -> c_int {
+        NO_DEVICE
+    }
+}
+
+/// The `cudaMemcpyHostToDevice` kind of copy.
+const MEMCPY_HOST_TO_DEVICE: c_int = 1;
+
+/// The `cudaMemcpyDeviceToHost` kind of copy.
+const MEMCPY_DEVICE_TO_HOST: c_int = 2;
+
+/// An error type for the CUDA operations.
+#[derive(Debug, Error)]
+pub enum CudaError {
+    /// A CUDA runtime call failed.
+    #[error("CUDA call {0} failed with error code {1}")]
+    Runtime(&'static str, i32),
+
+    /// The layout of the device memory is invalid.
+    #[error(transparent)]
+    Alloc(#[from] TensorAllocatorError),
+}
+
+/// Check the status code returned by a CUDA runtime call.
+fn check(call: &'static str, status: c_int) -> Result<(), CudaError> {
+    match status {
+        0 => Ok(()),
+        code => Err(CudaError::Runtime(call, code)),
+    }
+}
+
+/// A [`TensorAllocator`] for the memory of a CUDA device.
+///
+/// The memory is allocated with `cudaMalloc` and released with `cudaFree`, so that a
+/// `Tensor<T, N, CudaAllocator>` keeps its data on the device. Create a device tensor with
+/// [`Tensor::to_cuda`] and copy it back to the host with [`Tensor::to_cpu`].
+///
+/// The data of a device tensor cannot be read from the host: the accessors of the host memory,
+/// e.g. [`Tensor::as_slice`] or `clone`, must not be used on it. Only the device pointer from
+/// [`Tensor::as_ptr`] is meant to be passed to the CUDA kernels.
+///
+/// An allocator with a parent wraps device memory owned by the parent, e.g. a buffer shared by
+/// another library: the memory is kept alive by the parent and is not freed by the allocator.
+///
+/// The CUDA runtime is linked when the toolkit is found at build time, in `CUDA_PATH`,
+/// `CUDA_HOME` or `/usr/local/cuda`. Otherwise every allocation and copy fails.
+#[derive(Clone)]
+pub struct CudaAllocator {
+    device: i32,
+    parent: Option<Arc<dyn Any + Send + Sync>>,
+}
+
+impl CudaAllocator {
+    /// Create an allocator on the given CUDA device.
+    ///
+    /// # Arguments
+    ///
+    /// * `device` - The index of the CUDA device.
+    pub fn new(device: i32) -> Self {
+        Self {
+            device,
+            parent: None,
+        }
+    }
+
+    /// Set the owner of the device memory, so that the allocator does not free it.
+    ///
+    /// # Arguments
+    ///
+    /// * `parent` - The owner of the memory, kept alive as long as the allocator.
+    pub fn with_parent(mut self, parent: Arc<dyn Any + Send + Sync>) -> Self {
+        self.parent = Some(parent);
+        self
+    }
+
+    /// The index of the CUDA device.
+    pub fn device(&self) -> i32 {
+        self.device
+    }
+
+    /// Whether the device memory is owned by a parent.
+    pub fn has_parent(&self) -> bool {
+        self.parent.is_some()
+    }
+
+    /// Allocate device memory, keeping the status of the CUDA runtime on failure.
+    fn malloc(&self, layout: Layout) -> Result<*mut u8, CudaError> {
+        let mut ptr: *mut c_void = std::ptr::null_mut();
+        // SAFETY: the pointer is written by the runtime and checked below.
+        unsafe {
+            check("cudaSetDevice", cudaSetDevice(self.device))?;
+            check("cudaMalloc", cudaMalloc(&mut ptr, layout.size()))?;
+        }
+        if ptr.is_null() {
+            return Err(TensorAllocatorError::NullPointer.into());
+        }
+        Ok(ptr as *mut u8)
+    }
+}
+
+impl Default for CudaAllocator {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+/// Implements [`TensorAllocator`] using the CUDA runtime.
+impl TensorAllocator for CudaAllocator {
+    /// Allocates device memory with `cudaMalloc`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TensorAllocatorError::NullPointer`] if the device cannot be selected or the
+    /// memory cannot be allocated.
+    fn alloc(&self, layout: Layout) -> Result<*mut u8, TensorAllocatorError> {
+        self.malloc(layout)
+            .map_err(|_| TensorAllocatorError::NullPointer)
+    }
+
+    /// Frees device memory with `cudaFree`.
+    ///
+    /// This is a no-op if the memory is owned by a parent.
+    #[allow(clippy::not_unsafe_ptr_arg_deref)]
+    fn dealloc(&self, ptr: *mut u8, _layout: Layout) {
+        if self.parent.is_some() || ptr.is_null() {
+            return;
+        }
+        // the memory must be freed on the device which allocated it; the errors cannot be
+        // reported from a destructor
+        // SAFETY: the pointer was returned by `cudaMalloc` on this device.
+        unsafe {
+            if cudaSetDevice(self.device) == 0 {
+                cudaFree(ptr as *mut c_void);
+            }
+        }
+    }
+}
+
+impl<T: Copy, const N: usize> Tensor<T, N, CudaAllocator> {
+    /// Copy the tensor from the CUDA device to the host memory.
+    ///
+    /// The whole storage is copied, so the host tensor keeps the shape and the strides of the
+    /// device tensor.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the copy fails.
+    pub fn to_cpu(&self) -> Result<Tensor<T, N, CpuAllocator>, CudaError> {
+        let len = self.storage.len();
+        let numel = len / std::mem::size_of::<T>().max(1);
+        let mut data = Vec::<T>::with_capacity(numel);
+        if len > 0 {
+            // SAFETY: the device buffer and the vector are valid for `len` bytes.
+            unsafe {
+                check("cudaSetDevice", cudaSetDevice(self.storage.alloc().device))?;
+                let status = cudaMemcpy(
+                    data.as_mut_ptr() as *mut c_void,
+                    self.as_ptr() as *const c_void,
+                    len,
+                    MEMCPY_DEVICE_TO_HOST,
+                );
+                check("cudaMemcpy", status)?;
+            }
+        }
+        // SAFETY: the elements were initialized by the copy.
+        unsafe { data.set_len(numel) };
+
+        Ok(Tensor {
+            storage: TensorStorage::from_vec(data, CpuAllocator),
+            shape: self.shape,
+            strides: self.strides,
+        })
+    }
+}
+
+impl<T: Copy, const N: usize> Tensor<T, N, CpuAllocator> {
+    /// Copy the tensor to the memory of a CUDA device.
+    ///
+    /// Non-contiguous tensors are copied in their logical order, so the device tensor always
+    /// has a standard layout.
+    ///
+    /// # Arguments
+    ///
+    /// * `alloc` - The allocator of the device memory.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the device memory cannot be allocated or the copy fails.
+    pub fn to_cuda(&self, alloc: CudaAllocator) -> Result<Tensor<T, N, CudaAllocator>, CudaError> {
+        let host;
+        let data = if self.is_standard_layout() {
+            self.as_slice()
+        } else {
+            host = self.view().to_contiguous();
+            host.as_slice()
+        };
+
+        let len = std::mem::size_of_val(data);
+        let layout = Layout::array::<T>(data.len()).map_err(TensorAllocatorError::LayoutError)?;
+        let ptr = if len == 0 {
+            NonNull::dangling()
+        } else {
+            let ptr = alloc.malloc(layout)?;
+            // SAFETY: both buffers are valid for `len` bytes.
+            let status = unsafe {
+                cudaMemcpy(
+                    ptr as *mut c_void,
+                    data.as_ptr() as *const c_void,
+                    len,
+                    MEMCPY_HOST_TO_DEVICE,
+                )
+            };
+            if let Err(e) = check("cudaMemcpy", status) {
+                alloc.dealloc(ptr, layout);
+                return Err(e);
+            }
+            // SAFETY: the allocation succeeded, so the pointer is not null.
+            unsafe { NonNull::new_unchecked(ptr as *mut T) }
+        };
+
+        Ok(Tensor {
+            storage: TensorStorage {
+                ptr,
+                len,
+                layout,
+                alloc,
+            },
+            shape: self.shape,
+            strides: crate::get_strides_from_shape(self.shape),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[ignore = "need a CUDA device in CI"]
+    fn test_cuda_roundtrip() -> Result<(), Box<dyn std::error::Error>> {
+        let data = (0..24).map(|i| i as f32 * 0.5).collect::<Vec<_>>();
+        let tensor = Tensor::<f32, 3, _>::from_shape_vec([2, 3, 4], data.clone(), CpuAllocator)?;
+
+        let device: Tensor<f32, 3, CudaAllocator> = tensor.to_cuda(CudaAllocator::default())?;
+        assert_eq!(device.shape, [2, 3, 4]);
+        assert_eq!(device.storage.alloc().device(), 0);
+
+        let host = device.to_cpu()?;
+        assert_eq!(host.as_slice(), data.as_slice());
+
+        // a transposed tensor is uploaded in its logical order
+        let mut transposed =
+            Tensor::<u16, 2, _>::from_shape_vec([2, 2], vec![1, 2, 3, 4], CpuAllocator)?;
+        transposed.strides = [1, 2];
+        let host = transposed.to_cuda(CudaAllocator::default())?.to_cpu()?;
+        assert_eq!(host.as_slice(), &[1, 3, 2, 4]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn parent_skips_dealloc() {
+        // the allocator never touches the memory owned by a parent
+        let alloc = CudaAllocator::new(1).with_parent(Arc::new(()));
+        assert!(alloc.has_parent());
+        assert!(alloc.clone().has_parent());
+        alloc.dealloc(NonNull::<u8>::dangling().as_ptr(), Layout::new::<u8>());
+    }
+}
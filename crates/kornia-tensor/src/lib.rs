@@ -86,6 +86,7 @@
 //! - [`Tensor3`]: Three-dimensional tensor
 //! - [`Tensor4`]: Four-dimensional tensor
 //! - [`CpuTensor2`]: Two-dimensional CPU tensor (most common)
+//! - [`CpuTensorAligned32`]: CPU tensor with its memory aligned to 32 bytes
 
 /// Allocator module containing memory management utilities.
 ///
@@ -96,10 +97,61 @@ pub mod allocator;
 /// Bincode module for binary serialization and deserialization.
 ///
 /// This module provides efficient binary serialization support for tensors when the
-/// `bincode` feature is enabled.
+/// `bincode` feature is enabled. The payload can be compressed with zstd when the `zstd`
+/// feature is enabled.
 #[cfg(feature = "bincode")]
 pub mod bincode;
 
+/// Bytes module for the serialization of tensors with an explicit byte order.
+///
+/// This module provides the [`bytes::ByteElement`] trait for the element types that can be
+/// written and read as little-endian or big-endian bytes.
+pub mod bytes;
+
+/// Cast module for the saturating conversions between element types.
+///
+/// This module provides the [`cast::CastFrom`] trait used by [`Tensor::cast`].
+pub mod cast;
+
+/// Cuda module containing the CUDA device allocator and tensors.
+///
+/// This module provides [`cuda::CudaAllocator`] to keep the tensor data in the memory of a CUDA
+/// device when the `cuda` feature is enabled.
+#[cfg(feature = "cuda")]
+pub mod cuda;
+
+/// Mmap module for tensors backed by memory-mapped files.
+///
+/// This module provides [`mmap::MmapParentDeallocator`] and [`Tensor::from_mmap`] to read
+/// large tensors from disk without loading them in memory when the `memmap2` feature is enabled.
+#[cfg(feature = "memmap2")]
+pub mod mmap;
+
+/// Ndarray module for conversions between tensors and `ndarray` arrays.
+///
+/// This module provides zero-copy views of tensors as `ndarray` arrays and the conversion
+/// of arrays to tensors when the `ndarray` feature is enabled.
+#[cfg(feature = "ndarray")]
+pub mod ndarray;
+
+/// Pad module for growing tensors with selectable border modes.
+///
+/// This module provides [`pad::PadMode`] and [`Tensor::pad`] to pad the axes of a tensor, e.g.
+/// before a convolution.
+pub mod pad;
+
+/// Parallel module for the element-wise operations and reductions of large tensors.
+///
+/// This module provides the `par_*` variants of the tensor operations, e.g. [`Tensor::par_map`],
+/// which split the data across the threads of the rayon pool when the `rayon` feature is enabled.
+#[cfg(feature = "rayon")]
+pub mod parallel;
+
+/// Run-length encoding module for tensors with large uniform regions.
+///
+/// This module provides [`rle::RleTensor`] to store masks and label maps compactly.
+pub mod rle;
+
 /// Serde module for JSON/other format serialization and deserialization.
 ///
 /// This module provides flexible serialization support for tensors when the
@@ -118,15 +170,22 @@ pub mod storage;
 /// This module provides the core [`tensor::Tensor`] struct and related functionality.
 pub mod tensor;
 
+/// Wgpu module containing the GPU allocator.
+///
+/// This module provides [`wgpu::WgpuAllocator`] to keep a copy of the tensor data in a GPU
+/// buffer when the `wgpu` feature is enabled.
+#[cfg(feature = "wgpu")]
+pub mod wgpu;
+
 /// View module containing non-owning tensor view implementations.
 ///
 /// This module provides [`view::TensorView`] for creating efficient, zero-copy views
 /// into existing tensor data.
 pub mod view;
 
-pub use crate::allocator::{CpuAllocator, TensorAllocator};
+pub use crate::allocator::{AlignedCpuAllocator, CpuAllocator, TensorAllocator};
 pub(crate) use crate::tensor::get_strides_from_shape;
-pub use crate::tensor::{Tensor, TensorError};
+pub use crate::tensor::{Tensor, TensorError, TensorLayout};
 
 /// Type alias for a 1-dimensional tensor.
 pub type Tensor1<T, A> = Tensor<T, 1, A>;
@@ -142,3 +201,6 @@ pub type Tensor4<T, A> = Tensor<T, 4, A>;
 
 /// Type alias for a 2-dimensional tensor with CPU allocator.
 pub type CpuTensor2<T> = Tensor2<T, CpuAllocator>;
+
+/// Type alias for a tensor with CPU memory aligned to 32 bytes.
+pub type CpuTensorAligned32<T, const N: usize> = Tensor<T, N, AlignedCpuAllocator<32>>;
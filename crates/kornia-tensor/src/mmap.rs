@@ -0,0 +1,152 @@
+use std::{alloc::Layout, fs::File, path::Path, sync::Arc};
+
+use memmap2::{MmapMut, MmapOptions};
+use thiserror::Error;
+
+use crate::{
+    allocator::{CpuAllocator, TensorAllocator, TensorAllocatorError},
+    Tensor, TensorError,
+};
+
+/// An error type for the memory-mapped tensors.
+#[derive(Debug, Error)]
+pub enum MmapError {
+    /// The file could not be opened or mapped.
+    #[error(transparent)]
+    Io(#[from] std::io::Error),
+
+    /// The size of the file does not match the shape of the tensor.
+    ///
+    /// The size of the tensor is `usize::MAX` if it overflows.
+    #[error("The file has {0} bytes but the tensor needs {1} bytes")]
+    SizeMismatch(u64, usize),
+
+    /// The tensor could not be created from the mapping.
+    #[error(transparent)]
+    Tensor(#[from] TensorError),
+}
+
+/// A [`TensorAllocator`] for tensors whose memory is a memory-mapped file.
+///
+/// The allocator is the parent of the mapping: it keeps the mapping alive as long as the tensor
+/// storage, and the file is unmapped when the storage and its allocator are dropped. The memory
+/// of the mapping is never released through [`TensorAllocator::dealloc`], while the memory of
+/// the storages copied from the tensor, e.g. by `clone`, is released with the system allocator.
+#[derive(Clone)]
+pub struct MmapParentDeallocator(Arc<MmapMut>);
+
+impl MmapParentDeallocator {
+    /// Whether the pointer points into the mapping.
+    fn contains(&self, ptr: *mut u8) -> bool {
+        let start = self.0.as_ptr() as usize;
+        (start..start + self.0.len()).contains(&(ptr as usize))
+    }
+}
+
+impl TensorAllocator for MmapParentDeallocator {
+    fn alloc(&self, layout: Layout) -> Result<*mut u8, TensorAllocatorError> {
+        CpuAllocator.alloc(layout)
+    }
+
+    fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        // the mapping is released by the parent when the last tensor using it is dropped
+        if !self.contains(ptr) {
+            CpuAllocator.dealloc(ptr, layout)
+        }
+    }
+}
+
+impl<T: Clone, const N: usize> Tensor<T, N, MmapParentDeallocator> {
+    /// Create a tensor backed by a memory-mapped file.
+    ///
+    /// The file holds the elements of the tensor in row-major order with the native byte order,
+    /// e.g. as written from [`Tensor::as_slice`]. The pages are read from the file on access, so
+    /// the tensor can be larger than the available memory.
+    ///
+    /// The file is mapped copy-on-write: the file is never modified, and writes to the tensor
+    /// are only visible to the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file.
+    /// * `shape` - The shape of the tensor.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the file cannot be mapped, or if its size is not the number of
+    /// elements of `shape` times `size_of::<T>()`.
+    ///
+    /// # Safety
+    ///
+    /// The file must not be modified by another process while it is mapped, and every byte
+    /// pattern of the file must be a valid `T`.
+    pub unsafe fn from_mmap(path: impl AsRef<Path>, shape: [usize; N]) -> Result<Self, MmapError> {
+        let file = File::open(path)?;
+
+        // a shape too large for the address space needs more bytes than any file
+        let len = if shape.contains(&0) {
+            Some(0)
+        } else {
+            shape
+                .iter()
+                .try_fold(std::mem::size_of::<T>(), |acc, &dim| acc.checked_mul(dim))
+        };
+        let file_len = file.metadata()?.len();
+        let len = match len {
+            Some(len) if file_len == len as u64 => len,
+            _ => return Err(MmapError::SizeMismatch(file_len, len.unwrap_or(usize::MAX))),
+        };
+
+        let mut mmap = MmapOptions::new().map_copy(&file)?;
+        let data = mmap.as_mut_ptr() as *const T;
+
+        // the mapping is page aligned, so the pointer is aligned for any element type
+        Ok(Tensor::from_raw_parts(
+            shape,
+            data,
+            len,
+            MmapParentDeallocator(Arc::new(mmap)),
+        )?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+
+    #[test]
+    fn from_mmap() -> Result<(), Box<dyn std::error::Error>> {
+        let data = (0..12).map(|i| i as f32 * 0.5).collect::<Vec<_>>();
+        let mut file = tempfile::NamedTempFile::new()?;
+        for v in &data {
+            file.write_all(&v.to_ne_bytes())?;
+        }
+        file.flush()?;
+
+        let mut tensor = unsafe { Tensor::<f32, 2, _>::from_mmap(file.path(), [3, 4])? };
+        assert_eq!(tensor.shape, [3, 4]);
+        assert_eq!(tensor.as_slice(), data.as_slice());
+
+        // a copy is released by the system allocator, and writes stay in memory
+        let copy = tensor.clone();
+        tensor.as_slice_mut()[0] = 42.0;
+        assert_eq!(copy.as_slice(), data.as_slice());
+        drop(tensor);
+        assert_eq!(std::fs::read(file.path())?[..4], 0f32.to_ne_bytes());
+
+        assert!(matches!(
+            unsafe { Tensor::<f32, 2, MmapParentDeallocator>::from_mmap(file.path(), [4, 4]) },
+            Err(MmapError::SizeMismatch(48, 64))
+        ));
+        assert!(matches!(
+            unsafe {
+                Tensor::<f32, 2, MmapParentDeallocator>::from_mmap(file.path(), [usize::MAX, 2])
+            },
+            Err(MmapError::SizeMismatch(48, usize::MAX))
+        ));
+
+        Ok(())
+    }
+}
@@ -0,0 +1,166 @@
+use ndarray::{ArrayBase, ArrayView, Data, Dimension, IxDyn, ShapeBuilder};
+
+use crate::{allocator::TensorAllocator, Tensor, TensorError};
+
+impl<T, const N: usize, A: TensorAllocator> Tensor<T, N, A> {
+    /// View the tensor as a dynamic-dimensional `ndarray` array without copying the data.
+    ///
+    /// The view borrows the tensor storage and has the same shape and strides, so it can
+    /// view non-contiguous tensors as well.
+    ///
+    /// # Returns
+    ///
+    /// An `ArrayView` over the tensor elements.
+    ///
+    /// # Errors
+    ///
+    /// If the strides of the tensor reach outside of its storage, an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let data: Vec<u8> = vec![1, 2, 3, 4, 5, 6];
+    /// let t = Tensor::<u8, 2, _>::from_shape_vec([2, 3], data, CpuAllocator).unwrap();
+    ///
+    /// let view = t.as_ndarray_view().unwrap();
+    /// assert_eq!(view.shape(), &[2, 3]);
+    /// assert_eq!(view[[1, 2]], 6);
+    /// assert_eq!(view.as_ptr(), t.as_ptr());
+    /// ```
+    pub fn as_ndarray_view(&self) -> Result<ArrayView<'_, T, IxDyn>, TensorError> {
+        let shape = IxDyn(&self.shape).strides(IxDyn(&self.strides));
+        ArrayView::from_shape(shape, self.as_slice())
+            .map_err(|e| TensorError::UnsupportedOperation(e.to_string()))
+    }
+
+    /// Create a tensor from an `ndarray` array.
+    ///
+    /// The data of an owned array with a standard layout is moved to the tensor without
+    /// copying. Other arrays, e.g. views or transposed arrays, are copied in row-major order.
+    /// Arrays with less than `N` dimensions get leading dimensions of size 1.
+    ///
+    /// # Arguments
+    ///
+    /// * `arr` - The array to convert.
+    /// * `alloc` - The allocator of the tensor.
+    ///
+    /// # Returns
+    ///
+    /// A new `Tensor` with a standard layout.
+    ///
+    /// # Errors
+    ///
+    /// If the array has more than `N` dimensions, an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let arr = ndarray::Array2::<u8>::from_shape_fn((2, 3), |(i, j)| (i * 3 + j) as u8);
+    ///
+    /// let t = Tensor::<u8, 2, _>::from_ndarray(arr.t(), CpuAllocator).unwrap();
+    /// assert_eq!(t.shape, [3, 2]);
+    /// assert_eq!(t.as_slice(), &[0, 3, 1, 4, 2, 5]);
+    /// ```
+    pub fn from_ndarray<S, D>(arr: ArrayBase<S, D>, alloc: A) -> Result<Self, TensorError>
+    where
+        T: Clone,
+        S: Data<Elem = T>,
+        D: Dimension,
+    {
+        let ndim = arr.ndim();
+        if ndim > N {
+            return Err(TensorError::DimensionMismatch(format!(
+                "Cannot convert an array with {ndim} dimensions to a {N}D tensor"
+            )));
+        }
+
+        let mut shape = [1; N];
+        shape[N - ndim..].copy_from_slice(arr.shape());
+
+        // a no-op for owned arrays
+        let arr = arr.into_owned();
+        let data = if arr.is_standard_layout() {
+            // the elements of a sliced array start at an offset in its buffer
+            let numel = arr.len();
+            let (mut data, offset) = arr.into_raw_vec_and_offset();
+            let offset = offset.unwrap_or(0);
+            data.truncate(offset + numel);
+            data.drain(..offset);
+            data
+        } else {
+            arr.iter().cloned().collect()
+        };
+
+        Tensor::from_shape_vec(shape, data, alloc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use ndarray::{s, Array, Array3};
+
+    use crate::{CpuAllocator, Tensor, TensorError};
+
+    #[test]
+    fn ndarray_view() -> Result<(), TensorError> {
+        let t = Tensor::<u8, 3, _>::from_shape_fn([2, 3, 4], CpuAllocator, |[i, j, k]| {
+            (i * 12 + j * 4 + k) as u8
+        });
+
+        let view = t.as_ndarray_view()?;
+        assert_eq!(view.shape(), &[2, 3, 4]);
+        assert_eq!(view.as_ptr(), t.as_ptr());
+        assert_eq!(view.iter().copied().collect::<Vec<_>>(), t.as_slice());
+
+        // a transposed tensor keeps its strides
+        let mut transposed = t.clone();
+        transposed.shape = [4, 3, 2];
+        transposed.strides = [1, 4, 12];
+        let view = transposed.as_ndarray_view()?;
+        assert_eq!(view.strides(), &[1, 4, 12]);
+        assert_eq!(view[[3, 1, 0]], 7);
+
+        // strides reaching outside of the storage
+        transposed.strides = [1, 4, 24];
+        assert!(transposed.as_ndarray_view().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_ndarray() -> Result<(), TensorError> {
+        // an owned standard layout array is moved
+        let arr = Array3::<f32>::from_shape_fn((2, 3, 4), |(i, j, k)| (i * 12 + j * 4 + k) as f32);
+        let ptr = arr.as_ptr();
+        let t = Tensor::<f32, 3, _>::from_ndarray(arr.clone(), CpuAllocator)?;
+        assert_eq!(t.shape, [2, 3, 4]);
+        assert_eq!(t.as_slice(), arr.as_slice().unwrap());
+
+        let moved = Tensor::<f32, 3, _>::from_ndarray(arr, CpuAllocator)?;
+        assert_eq!(moved.as_ptr(), ptr);
+
+        // a sliced owned array starts at an offset
+        let sliced = Array::from_iter(0..12u8)
+            .into_shape_with_order((4, 3))
+            .unwrap()
+            .slice_move(s![1..3, ..]);
+        let t = Tensor::<u8, 2, _>::from_ndarray(sliced, CpuAllocator)?;
+        assert_eq!(t.shape, [2, 3]);
+        assert_eq!(t.as_slice(), &[3, 4, 5, 6, 7, 8]);
+
+        // views are copied and missing dimensions are added in front
+        let arr = Array::from_iter(0..6u8);
+        let t = Tensor::<u8, 3, _>::from_ndarray(arr.view(), CpuAllocator)?;
+        assert_eq!(t.shape, [1, 1, 6]);
+        assert_eq!(t.as_slice(), &[0, 1, 2, 3, 4, 5]);
+
+        let arr = arr.into_shape_with_order((2, 3)).unwrap();
+        assert!(Tensor::<u8, 1, _>::from_ndarray(arr, CpuAllocator).is_err());
+
+        Ok(())
+    }
+}
@@ -0,0 +1,227 @@
+use crate::{
+    allocator::TensorAllocator, get_strides_from_shape, storage::TensorStorage,
+    tensor::row_major_indices, Tensor, TensorError,
+};
+
+/// The mode to fill the padding of a tensor.
+///
+/// The examples show how a row `abcd` is padded with 3 elements on both sides.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PadMode<T> {
+    /// The padding is a constant value: `vvv|abcd|vvv`.
+    Constant(T),
+    /// The tensor is mirrored around the border element, as OpenCV's `BORDER_REFLECT_101`
+    /// and NumPy's `reflect` do: `dcb|abcd|cba`.
+    Reflect,
+    /// The border element is repeated: `aaa|abcd|ddd`.
+    Replicate,
+    /// The tensor is repeated periodically: `bcd|abcd|abc`.
+    Wrap,
+}
+
+impl<T> PadMode<T> {
+    /// Map a possibly out of bounds coordinate of an axis to an index in `[0, len)`.
+    ///
+    /// Returns `None` for the coordinates in the padding of [`PadMode::Constant`].
+    /// PRECONDITION: `len` is positive.
+    fn source_index(&self, i: isize, len: usize) -> Option<usize> {
+        let n = len as isize;
+        if (0..n).contains(&i) {
+            return Some(i as usize);
+        }
+
+        let idx = match self {
+            PadMode::Constant(_) => return None,
+            PadMode::Replicate => i.clamp(0, n - 1),
+            PadMode::Wrap => i.rem_euclid(n),
+            PadMode::Reflect if n == 1 => 0,
+            PadMode::Reflect => {
+                // the reflection has a period of 2 * (n - 1), so it covers any pad width
+                let i = i.rem_euclid(2 * (n - 1));
+                if i < n {
+                    i
+                } else {
+                    2 * (n - 1) - i
+                }
+            }
+        };
+
+        Some(idx as usize)
+    }
+}
+
+impl<T: Clone, const N: usize, A: TensorAllocator> Tensor<T, N, A> {
+    /// Pad the tensor along each axis.
+    ///
+    /// The padding can be wider than the tensor: the reflection and the repetition continue
+    /// over the padded elements.
+    ///
+    /// # Arguments
+    ///
+    /// * `widths` - The number of elements to add before and after each axis.
+    /// * `mode` - How to fill the padding, see [`PadMode`].
+    ///
+    /// # Returns
+    ///
+    /// A new tensor with each axis grown by its pad widths and a standard layout.
+    ///
+    /// # Errors
+    ///
+    /// If an empty axis is padded with a mode other than [`PadMode::Constant`], an error is
+    /// returned, as there is no element to repeat.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{pad::PadMode, CpuAllocator, Tensor};
+    ///
+    /// let t = Tensor::<u8, 1, _>::from_shape_vec([4], vec![1, 2, 3, 4], CpuAllocator).unwrap();
+    ///
+    /// let padded = t.pad([(2, 1)], PadMode::Reflect).unwrap();
+    /// assert_eq!(padded.as_slice(), &[3, 2, 1, 2, 3, 4, 3]);
+    /// ```
+    pub fn pad(&self, widths: [(usize, usize); N], mode: PadMode<T>) -> Result<Self, TensorError> {
+        let mut shape = self.shape;
+        for (dim, &(before, after)) in widths.iter().enumerate() {
+            let constant = matches!(mode, PadMode::Constant(_));
+            if self.shape[dim] == 0 && before + after > 0 && !constant {
+                return Err(TensorError::UnsupportedOperation(format!(
+                    "Cannot pad the empty axis {dim} of a tensor with shape {:?} by repeating it",
+                    self.shape
+                )));
+            }
+            shape[dim] += before + after;
+        }
+
+        let data = row_major_indices(shape)
+            .map(|index| {
+                let src_index = (0..N).try_fold([0; N], |mut src_index, dim| {
+                    let i = index[dim] as isize - widths[dim].0 as isize;
+                    src_index[dim] = mode.source_index(i, self.shape[dim])?;
+                    Some(src_index)
+                });
+                match (src_index, &mode) {
+                    (Some(src_index), _) => self.get_unchecked(src_index).clone(),
+                    (None, PadMode::Constant(value)) => value.clone(),
+                    // the other modes map every coordinate into the tensor
+                    (None, _) => unreachable!(),
+                }
+            })
+            .collect();
+
+        Ok(Tensor {
+            storage: TensorStorage::from_vec(data, self.storage.alloc().clone()),
+            shape,
+            strides: get_strides_from_shape(shape),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PadMode;
+    use crate::{CpuAllocator, Tensor, TensorError};
+
+    #[test]
+    fn pad_1d() -> Result<(), TensorError> {
+        let t = Tensor::<i32, 1, _>::from_shape_vec([4], vec![1, 2, 3, 4], CpuAllocator)?;
+
+        let padded = t.pad([(3, 3)], PadMode::Constant(0))?;
+        assert_eq!(padded.shape, [10]);
+        assert_eq!(padded.as_slice(), &[0, 0, 0, 1, 2, 3, 4, 0, 0, 0]);
+
+        // the reflection does not repeat the border element, as BORDER_REFLECT_101
+        let padded = t.pad([(3, 3)], PadMode::Reflect)?;
+        assert_eq!(padded.as_slice(), &[4, 3, 2, 1, 2, 3, 4, 3, 2, 1]);
+
+        let padded = t.pad([(3, 3)], PadMode::Replicate)?;
+        assert_eq!(padded.as_slice(), &[1, 1, 1, 1, 2, 3, 4, 4, 4, 4]);
+
+        let padded = t.pad([(3, 3)], PadMode::Wrap)?;
+        assert_eq!(padded.as_slice(), &[2, 3, 4, 1, 2, 3, 4, 1, 2, 3]);
+
+        // the padding can be wider than the tensor
+        let padded = t.pad([(0, 7)], PadMode::Reflect)?;
+        assert_eq!(&padded.as_slice()[4..], &[3, 2, 1, 2, 3, 4, 3]);
+        let single = Tensor::<i32, 1, _>::from_shape_vec([1], vec![7], CpuAllocator)?;
+        assert_eq!(single.pad([(2, 1)], PadMode::Reflect)?.as_slice(), &[7; 4]);
+
+        let empty = Tensor::<i32, 1, _>::from_shape_vec([0], vec![], CpuAllocator)?;
+        assert_eq!(
+            empty.pad([(1, 1)], PadMode::Constant(5))?.as_slice(),
+            &[5, 5]
+        );
+        assert!(matches!(
+            empty.pad([(1, 1)], PadMode::Wrap),
+            Err(TensorError::UnsupportedOperation(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn pad_2d() -> Result<(), TensorError> {
+        // [[1, 2, 3],
+        //  [4, 5, 6]]
+        let t = Tensor::<u8, 2, _>::from_shape_vec([2, 3], vec![1, 2, 3, 4, 5, 6], CpuAllocator)?;
+        let widths = [(1, 1), (2, 0)];
+
+        let padded = t.pad(widths, PadMode::Constant(9))?;
+        assert_eq!(padded.shape, [4, 5]);
+        #[rustfmt::skip]
+        assert_eq!(
+            padded.as_slice(),
+            &[
+                9, 9, 9, 9, 9,
+                9, 9, 1, 2, 3,
+                9, 9, 4, 5, 6,
+                9, 9, 9, 9, 9,
+            ]
+        );
+
+        let padded = t.pad(widths, PadMode::Reflect)?;
+        #[rustfmt::skip]
+        assert_eq!(
+            padded.as_slice(),
+            &[
+                6, 5, 4, 5, 6,
+                3, 2, 1, 2, 3,
+                6, 5, 4, 5, 6,
+                3, 2, 1, 2, 3,
+            ]
+        );
+
+        let padded = t.pad(widths, PadMode::Replicate)?;
+        #[rustfmt::skip]
+        assert_eq!(
+            padded.as_slice(),
+            &[
+                1, 1, 1, 2, 3,
+                1, 1, 1, 2, 3,
+                4, 4, 4, 5, 6,
+                4, 4, 4, 5, 6,
+            ]
+        );
+
+        let padded = t.pad(widths, PadMode::Wrap)?;
+        #[rustfmt::skip]
+        assert_eq!(
+            padded.as_slice(),
+            &[
+                5, 6, 4, 5, 6,
+                2, 3, 1, 2, 3,
+                5, 6, 4, 5, 6,
+                2, 3, 1, 2, 3,
+            ]
+        );
+
+        // a transposed tensor is padded in its logical order
+        let mut transposed = t.clone();
+        transposed.shape = [3, 2];
+        transposed.strides = [1, 3];
+        let padded = transposed.pad([(0, 1), (0, 0)], PadMode::Replicate)?;
+        assert_eq!(padded.as_slice(), &[1, 4, 2, 5, 3, 6, 3, 6]);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,296 @@
+use rayon::prelude::*;
+
+use crate::{
+    allocator::TensorAllocator, get_strides_from_shape, storage::TensorStorage, Tensor, TensorError,
+};
+
+/// The minimum number of elements processed by a rayon task, to amortize the scheduling.
+const PAR_CHUNK_LEN: usize = 1 << 14;
+
+impl<T, const N: usize, A: TensorAllocator> Tensor<T, N, A>
+where
+    T: Copy + Send + Sync,
+{
+    /// Apply a function to each element of the tensor in parallel.
+    ///
+    /// This is the parallel version of [`Tensor::map`]. The contiguous data is split across
+    /// the threads of the rayon pool, the tensors without a standard layout are mapped serially.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The function to apply to each element.
+    ///
+    /// # Returns
+    ///
+    /// A new tensor with the same shape and a standard layout.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let t = Tensor::<u8, 1, _>::from_shape_vec([4], vec![0, 51, 102, 255], CpuAllocator).unwrap();
+    ///
+    /// let t2 = t.par_map(|x| x as f32 / 255.0);
+    /// assert_eq!(t2.as_slice(), &[0.0, 0.2, 0.4, 1.0]);
+    /// ```
+    pub fn par_map<U, F>(&self, f: F) -> Tensor<U, N, A>
+    where
+        U: Send,
+        F: Fn(T) -> U + Send + Sync,
+    {
+        if !self.is_standard_layout() {
+            return self.map(f);
+        }
+
+        let mut data = Vec::with_capacity(self.numel());
+        self.as_slice()
+            .par_iter()
+            .with_min_len(PAR_CHUNK_LEN)
+            .map(|&x| f(x))
+            .collect_into_vec(&mut data);
+
+        Tensor {
+            storage: TensorStorage::from_vec(data, self.storage.alloc().clone()),
+            shape: self.shape,
+            strides: get_strides_from_shape(self.shape),
+        }
+    }
+
+    /// Add two tensors with the same shape element-wise in parallel.
+    ///
+    /// This is the parallel version of [`Tensor::add`] without broadcasting.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The tensor to add, with the same shape as `self`.
+    ///
+    /// # Errors
+    ///
+    /// If the shapes differ, an error is returned.
+    pub fn par_add<A2: TensorAllocator>(
+        &self,
+        other: &Tensor<T, N, A2>,
+    ) -> Result<Tensor<T, N, A>, TensorError>
+    where
+        T: std::ops::Add<Output = T>,
+    {
+        self.par_zip_op(other, |a, b| a + b)
+    }
+
+    /// Subtract two tensors with the same shape element-wise in parallel.
+    ///
+    /// This is the parallel version of [`Tensor::sub`] without broadcasting.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The tensor to subtract, with the same shape as `self`.
+    ///
+    /// # Errors
+    ///
+    /// If the shapes differ, an error is returned.
+    pub fn par_sub<A2: TensorAllocator>(
+        &self,
+        other: &Tensor<T, N, A2>,
+    ) -> Result<Tensor<T, N, A>, TensorError>
+    where
+        T: std::ops::Sub<Output = T>,
+    {
+        self.par_zip_op(other, |a, b| a - b)
+    }
+
+    /// Multiply two tensors with the same shape element-wise in parallel.
+    ///
+    /// This is the parallel version of [`Tensor::mul`] without broadcasting.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The tensor to multiply by, with the same shape as `self`.
+    ///
+    /// # Errors
+    ///
+    /// If the shapes differ, an error is returned.
+    pub fn par_mul<A2: TensorAllocator>(
+        &self,
+        other: &Tensor<T, N, A2>,
+    ) -> Result<Tensor<T, N, A>, TensorError>
+    where
+        T: std::ops::Mul<Output = T>,
+    {
+        self.par_zip_op(other, |a, b| a * b)
+    }
+
+    /// Divide two tensors with the same shape element-wise in parallel.
+    ///
+    /// This is the parallel version of [`Tensor::div`] without broadcasting.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The tensor to divide by, with the same shape as `self`.
+    ///
+    /// # Errors
+    ///
+    /// If the shapes differ, an error is returned.
+    pub fn par_div<A2: TensorAllocator>(
+        &self,
+        other: &Tensor<T, N, A2>,
+    ) -> Result<Tensor<T, N, A>, TensorError>
+    where
+        T: std::ops::Div<Output = T>,
+    {
+        self.par_zip_op(other, |a, b| a / b)
+    }
+
+    /// Sum the elements along the given axis in parallel, dropping it.
+    ///
+    /// This is the parallel version of [`Tensor::sum_axis`], and the elements are summed in the
+    /// same order, so the floating point results are identical.
+    ///
+    /// # Arguments
+    ///
+    /// * `axis` - The axis to reduce.
+    ///
+    /// # Errors
+    ///
+    /// If the axis is out of bounds or `M` is not `N - 1`, an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let data: Vec<u32> = vec![1, 2, 3, 4, 5, 6];
+    /// let t = Tensor::<u32, 2, CpuAllocator>::from_shape_vec([2, 3], data, CpuAllocator).unwrap();
+    ///
+    /// let cols = t.par_sum_axis::<1>(0).unwrap();
+    /// assert_eq!(cols.as_slice(), &[5, 7, 9]);
+    /// ```
+    pub fn par_sum_axis<const M: usize>(&self, axis: usize) -> Result<Tensor<T, M, A>, TensorError>
+    where
+        T: std::ops::Add<Output = T> + num_traits::Zero,
+    {
+        if axis >= N {
+            return Err(TensorError::DimOutOfBounds(axis, N));
+        }
+
+        let mut shape = self.shape;
+        shape[axis] = 1;
+        let numel = shape.iter().product::<usize>();
+        if !self.is_standard_layout() || numel == 0 {
+            return self.sum_axis(axis);
+        }
+
+        // each block of `inner` outputs sums `len` contiguous rows of the source
+        let len = self.shape[axis];
+        let inner = self.shape[axis + 1..].iter().product::<usize>();
+        let src = self.as_slice();
+
+        let mut data = vec![T::zero(); numel];
+        data.par_chunks_mut(inner)
+            .with_min_len(PAR_CHUNK_LEN.div_ceil(inner))
+            .enumerate()
+            .for_each(|(outer, acc)| {
+                for k in 0..len {
+                    let row = &src[(outer * len + k) * inner..][..inner];
+                    acc.iter_mut().zip(row).for_each(|(a, &b)| *a = *a + b);
+                }
+            });
+
+        Tensor {
+            storage: TensorStorage::from_vec(data, self.storage.alloc().clone()),
+            shape,
+            strides: get_strides_from_shape(shape),
+        }
+        .squeeze(axis)
+    }
+
+    fn par_zip_op<A2: TensorAllocator>(
+        &self,
+        other: &Tensor<T, N, A2>,
+        op: impl Fn(T, T) -> T + Send + Sync,
+    ) -> Result<Tensor<T, N, A>, TensorError> {
+        if self.shape != other.shape {
+            return Err(TensorError::DimensionMismatch(format!(
+                "Shapes {:?} and {:?} are not compatible for element-wise operations",
+                self.shape, other.shape
+            )));
+        }
+
+        if !self.is_standard_layout() || !other.is_standard_layout() {
+            return self.broadcast_op(other, op);
+        }
+
+        let mut data = self.as_slice().to_vec();
+        data.par_chunks_mut(PAR_CHUNK_LEN)
+            .zip(other.as_slice().par_chunks(PAR_CHUNK_LEN))
+            .for_each(|(lhs, rhs)| {
+                lhs.iter_mut().zip(rhs).for_each(|(a, &b)| *a = op(*a, b));
+            });
+
+        Ok(Tensor {
+            storage: TensorStorage::from_vec(data, self.storage.alloc().clone()),
+            shape: self.shape,
+            strides: get_strides_from_shape(self.shape),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{CpuAllocator, Tensor, TensorError};
+
+    #[test]
+    fn par_matches_serial() -> Result<(), TensorError> {
+        // large enough to be split across several tasks
+        let shape = [64, 48, 3];
+        let t = Tensor::<f32, 3, _>::from_shape_fn(shape, CpuAllocator, |[y, x, c]| {
+            ((y * 31 + x * 7 + c * 13) % 101) as f32 * 0.37
+        });
+        let other = t.map(|x| x * 0.5 + 1.0);
+
+        let f = |x: f32| x.sqrt() * 2.0 - 1.0;
+        assert_eq!(t.par_map(f).as_slice(), t.map(f).as_slice());
+
+        assert_eq!(t.par_add(&other)?.as_slice(), t.add(&other)?.as_slice());
+        assert_eq!(t.par_sub(&other)?.as_slice(), t.sub(&other)?.as_slice());
+        assert_eq!(t.par_mul(&other)?.as_slice(), t.mul(&other)?.as_slice());
+        assert_eq!(t.par_div(&other)?.as_slice(), t.div(&other)?.as_slice());
+
+        for axis in 0..3 {
+            let par = t.par_sum_axis::<2>(axis)?;
+            let serial = t.sum_axis::<2>(axis)?;
+            assert_eq!(par.shape, serial.shape);
+            assert_eq!(par.as_slice(), serial.as_slice());
+        }
+
+        // the tensors without a standard layout take the serial path
+        let mut transposed = t.clone();
+        transposed.shape = [48, 64, 3];
+        transposed.strides = [3, 144, 1];
+        assert_eq!(
+            transposed.par_map(f).as_slice(),
+            transposed.map(f).as_slice()
+        );
+        let contiguous = transposed.map(|x| x);
+        assert_eq!(
+            transposed.par_add(&contiguous)?.as_slice(),
+            contiguous.add(&contiguous)?.as_slice()
+        );
+        assert_eq!(
+            transposed.par_sum_axis::<2>(1)?.as_slice(),
+            transposed.sum_axis::<2>(1)?.as_slice()
+        );
+
+        let wrong = Tensor::<f32, 3, _>::zeros([64, 48, 1], CpuAllocator);
+        assert!(matches!(
+            t.par_add(&wrong),
+            Err(TensorError::DimensionMismatch(_))
+        ));
+        assert!(matches!(
+            t.par_sum_axis::<2>(3),
+            Err(TensorError::DimOutOfBounds(3, 3))
+        ));
+
+        Ok(())
+    }
+}
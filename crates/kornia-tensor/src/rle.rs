@@ -0,0 +1,184 @@
+use crate::{allocator::TensorAllocator, Tensor, TensorError};
+
+/// A run-length encoded tensor.
+///
+/// The elements are stored as runs of repeated values in row-major order, which is compact
+/// for tensors made of large uniform regions such as segmentation masks and label maps.
+///
+/// # Example
+///
+/// ```
+/// use kornia_tensor::{rle::RleTensor, CpuAllocator, Tensor};
+///
+/// let mask = Tensor::<u8, 2, _>::from_shape_vec([2, 3], vec![0, 0, 1, 1, 1, 0], CpuAllocator)
+///     .unwrap();
+///
+/// let rle = RleTensor::from_dense(&mask);
+/// assert_eq!(rle.runs().collect::<Vec<_>>(), vec![(&0, 2), (&1, 3), (&0, 1)]);
+///
+/// let dense = rle.to_dense(CpuAllocator).unwrap();
+/// assert_eq!(dense.as_slice(), mask.as_slice());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct RleTensor<T, const N: usize> {
+    /// The shape of the dense tensor.
+    pub shape: [usize; N],
+    /// The value of each run.
+    pub(crate) values: Vec<T>,
+    /// The number of elements of each run.
+    pub(crate) lengths: Vec<usize>,
+}
+
+impl<T, const N: usize> RleTensor<T, N>
+where
+    T: Copy + PartialEq,
+{
+    /// Encode a dense tensor, visiting its elements in row-major order.
+    ///
+    /// # Arguments
+    ///
+    /// * `tensor` - The dense tensor. It does not need to be contiguous.
+    ///
+    /// # Returns
+    ///
+    /// The run-length encoded tensor.
+    pub fn from_dense<A: TensorAllocator>(tensor: &Tensor<T, N, A>) -> Self {
+        let mut rle = Self {
+            shape: tensor.shape,
+            values: Vec::new(),
+            lengths: Vec::new(),
+        };
+
+        if tensor.is_standard_layout() {
+            tensor.as_slice().iter().for_each(|&v| rle.push(v));
+        } else {
+            let mut index = [0; N];
+            for _ in 0..tensor.numel() {
+                rle.push(*tensor.get_unchecked(index));
+                // advance the index in row-major order
+                for dim in (0..N).rev() {
+                    index[dim] += 1;
+                    if index[dim] < tensor.shape[dim] {
+                        break;
+                    }
+                    index[dim] = 0;
+                }
+            }
+        }
+
+        rle
+    }
+
+    /// Decode the runs into a dense tensor with a standard layout.
+    ///
+    /// # Arguments
+    ///
+    /// * `alloc` - The allocator of the dense tensor.
+    ///
+    /// # Returns
+    ///
+    /// The dense tensor.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the runs do not cover the shape of the tensor.
+    pub fn to_dense<A: TensorAllocator>(&self, alloc: A) -> Result<Tensor<T, N, A>, TensorError> {
+        let mut data = Vec::with_capacity(self.numel());
+        for (&value, length) in self.runs() {
+            data.resize(data.len() + length, value);
+        }
+        Tensor::from_shape_vec(self.shape, data, alloc)
+    }
+
+    fn push(&mut self, value: T) {
+        match (self.values.last(), self.lengths.last_mut()) {
+            (Some(last), Some(length)) if *last == value => *length += 1,
+            _ => {
+                self.values.push(value);
+                self.lengths.push(1);
+            }
+        }
+    }
+}
+
+impl<T, const N: usize> RleTensor<T, N> {
+    /// Create a run-length encoded tensor from its runs.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape` - The shape of the dense tensor.
+    /// * `runs` - The value and the number of elements of each run in row-major order.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the runs do not cover the shape of the tensor.
+    pub fn from_runs(shape: [usize; N], runs: Vec<(T, usize)>) -> Result<Self, TensorError> {
+        let (values, lengths): (Vec<_>, Vec<_>) = runs.into_iter().unzip();
+        let numel = lengths.iter().sum::<usize>();
+        if numel != shape.iter().product::<usize>() {
+            return Err(TensorError::InvalidShape(numel));
+        }
+        Ok(Self {
+            shape,
+            values,
+            lengths,
+        })
+    }
+
+    /// Iterate over the runs as pairs of value and number of elements.
+    pub fn runs(&self) -> impl Iterator<Item = (&T, usize)> + '_ {
+        self.values.iter().zip(self.lengths.iter().copied())
+    }
+
+    /// The number of runs.
+    pub fn num_runs(&self) -> usize {
+        self.values.len()
+    }
+
+    /// The number of elements of the dense tensor.
+    pub fn numel(&self) -> usize {
+        self.shape.iter().product()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RleTensor;
+    use crate::{CpuAllocator, Tensor, TensorError};
+
+    #[test]
+    fn rle_roundtrip() -> Result<(), TensorError> {
+        // a mostly zero mask with a 10x10 square of ones
+        let mask = Tensor::<u8, 2, _>::from_shape_fn([64, 48], CpuAllocator, |[y, x]| {
+            ((20..30).contains(&y) && (5..15).contains(&x)) as u8
+        });
+
+        let rle = RleTensor::from_dense(&mask);
+        // one run before the square, two per square row, and the runs between them merge
+        assert_eq!(rle.num_runs(), 21);
+        assert_eq!(rle.runs().map(|(_, n)| n).sum::<usize>(), 64 * 48);
+        assert_eq!(rle.runs().next(), Some((&0, 20 * 48 + 5)));
+
+        let dense = rle.to_dense(CpuAllocator)?;
+        assert_eq!(dense.shape, mask.shape);
+        assert_eq!(dense.as_slice(), mask.as_slice());
+
+        // a transposed tensor is encoded in its logical order
+        let mut transposed =
+            Tensor::<u8, 2, _>::from_shape_vec([2, 3], vec![1, 1, 2, 2, 3, 3], CpuAllocator)?;
+        transposed.shape = [3, 2];
+        transposed.strides = [1, 3];
+        let rle = RleTensor::from_dense(&transposed);
+        assert_eq!(rle.to_dense(CpuAllocator)?.as_slice(), &[1, 2, 1, 3, 2, 3]);
+
+        assert!(RleTensor::from_runs([2, 2], vec![(0u8, 3)]).is_err());
+        assert_eq!(
+            RleTensor::from_runs([2, 2], vec![(0u8, 3), (1, 1)])?
+                .to_dense(CpuAllocator)?
+                .as_slice(),
+            &[0, 0, 0, 1]
+        );
+
+        Ok(())
+    }
+}
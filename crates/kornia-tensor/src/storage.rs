@@ -237,6 +237,19 @@ impl<T, A: TensorAllocator> TensorStorage<T, A> {
         let ptr = self.ptr;
         let vec_len = length / std::mem::size_of::<T>();
 
+        // memory with a raised alignment, e.g. from `AlignedCpuAllocator`, cannot be released
+        // by a vector, so move the elements to a new vector and release the memory on drop
+        if self.layout.align() != std::mem::align_of::<T>() {
+            let mut vec = Vec::with_capacity(vec_len);
+            // SAFETY: the elements are moved out, and the drop of the storage only releases
+            // the memory without dropping them.
+            unsafe {
+                std::ptr::copy_nonoverlapping(ptr.as_ptr(), vec.as_mut_ptr(), vec_len);
+                vec.set_len(vec_len);
+            }
+            return vec;
+        }
+
         // Safety
         std::mem::forget(self);
         unsafe { Vec::from_raw_parts(ptr.as_ptr(), vec_len, vec_capacity) }
@@ -252,8 +265,12 @@ unsafe impl<T, A: TensorAllocator> Sync for TensorStorage<T, A> {}
 impl<T, A: TensorAllocator> Drop for TensorStorage<T, A> {
     /// Automatically deallocates the storage's memory when dropped.
     ///
-    /// This uses the storage's allocator to properly free the memory.
+    /// This uses the storage's allocator to properly free the memory. Zero-sized storages
+    /// hold a dangling pointer that was never allocated, so they are not deallocated.
     fn drop(&mut self) {
+        if self.layout.size() == 0 {
+            return;
+        }
         self.alloc
             .dealloc(self.ptr.as_ptr() as *mut u8, self.layout);
     }
@@ -1,7 +1,10 @@
+use std::{alloc::Layout, ptr::NonNull};
+
 use thiserror::Error;
 
 use super::{
-    allocator::{CpuAllocator, TensorAllocator, TensorAllocatorError},
+    allocator::{AlignedCpuAllocator, CpuAllocator, TensorAllocator, TensorAllocatorError},
+    cast::CastFrom,
     storage::TensorStorage,
     view::TensorView,
 };
@@ -21,6 +24,15 @@ pub enum TensorError {
     #[error("Index out of bounds. The index {0} is out of bounds.")]
     IndexOutOfBounds(usize),
 
+    /// The axis is not smaller than the number of dimensions of the tensor.
+    #[error("Axis {0} is out of bounds for a tensor with {1} dimensions")]
+    DimOutOfBounds(usize, usize),
+
+    /// Two sizes that must agree do not match, e.g. the number of elements when reshaping or
+    /// the inner dimensions of a matrix product.
+    #[error("Incompatible shapes: size {0} does not match size {1}")]
+    IncompatibleShape(usize, usize),
+
     /// Error with the tensor storage.
     #[error("Error with the tensor storage: {0}")]
     StorageError(#[from] TensorAllocatorError),
@@ -32,6 +44,14 @@ pub enum TensorError {
     /// Unsupported operation for the given data type or tensor configuration.
     #[error("Unsupported operation: {0}")]
     UnsupportedOperation(String),
+
+    /// The tensor data is not laid out contiguously in row-major order.
+    #[error("Tensor with shape {0:?} and strides {1:?} is not contiguous")]
+    NotContiguous(Vec<usize>, Vec<usize>),
+
+    /// Error when encoding or decoding the tensor data.
+    #[error("Serialization error: {0}")]
+    SerializationError(String),
 }
 
 /// Computes the strides for a row-major (C-contiguous) tensor layout.
@@ -71,6 +91,83 @@ pub fn get_strides_from_shape<const N: usize>(shape: [usize; N]) -> [usize; N] {
     strides
 }
 
+/// Check if the given strides describe a contiguous row-major layout of the shape.
+pub(crate) fn check_contiguous<const N: usize>(
+    shape: [usize; N],
+    strides: [usize; N],
+) -> Result<(), TensorError> {
+    let mut expected_stride: usize = 1;
+    for (&dim, &stride) in shape.iter().rev().zip(strides.iter().rev()) {
+        if stride != expected_stride {
+            return Err(TensorError::NotContiguous(shape.to_vec(), strides.to_vec()));
+        }
+        expected_stride = expected_stride.saturating_mul(dim);
+    }
+    Ok(())
+}
+
+/// Iterate over the indices of the shape in row-major order.
+pub(crate) fn row_major_indices<const N: usize>(
+    shape: [usize; N],
+) -> impl Iterator<Item = [usize; N]> {
+    let numel = shape.iter().product::<usize>();
+    let mut index = [0; N];
+    (0..numel).map(move |_| {
+        let current = index;
+        // increment index
+        for dim in (0..N).rev() {
+            index[dim] += 1;
+            if index[dim] < shape[dim] {
+                break;
+            }
+            index[dim] = 0;
+        }
+        current
+    })
+}
+
+/// Check that `min` is not greater than `max` for clamping.
+pub(crate) fn check_clamp_range<T: PartialOrd>(min: T, max: T) -> Result<(), TensorError> {
+    // also rejects the bounds that are not comparable, e.g. NaN
+    match min.partial_cmp(&max) {
+        Some(std::cmp::Ordering::Less | std::cmp::Ordering::Equal) => Ok(()),
+        _ => Err(TensorError::UnsupportedOperation(
+            "clamp requires min to be less than or equal to max".to_string(),
+        )),
+    }
+}
+
+/// Clamp a value into `[min, max]`, letting the values that are not comparable pass through.
+#[inline]
+pub(crate) fn clamp_value<T: PartialOrd>(value: T, min: T, max: T) -> T {
+    if value < min {
+        min
+    } else if value > max {
+        max
+    } else {
+        value
+    }
+}
+
+/// The memory layout of a tensor, as seen by external code reading its raw pointer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TensorLayout<const N: usize> {
+    /// The shape of the tensor.
+    pub shape: [usize; N],
+    /// The strides of the tensor in number of elements.
+    pub strides: [usize; N],
+    /// The strides of the tensor in bytes.
+    pub byte_strides: [usize; N],
+    /// The offset in bytes of the first element from the start of the storage.
+    pub byte_offset: usize,
+    /// The size in bytes of one element.
+    pub element_size: usize,
+    /// The largest power of two the data pointer is aligned to.
+    pub alignment: usize,
+    /// Whether the data is contiguous in row-major order.
+    pub is_contiguous: bool,
+}
+
 /// A multi-dimensional array (tensor) with owned data.
 ///
 /// `Tensor` is the core data structure for storing and manipulating multi-dimensional arrays.
@@ -102,6 +199,12 @@ pub fn get_strides_from_shape<const N: usize>(shape: [usize; N]) -> [usize; N] {
 /// Tensors are `Send` and `Sync` when using thread-safe allocators, allowing safe sharing
 /// across thread boundaries.
 ///
+/// # Zero-sized Tensors
+///
+/// A shape may contain zero-sized dimensions (e.g. `[0, 3]`). Such tensors are valid but empty:
+/// [`numel`](Self::numel) returns `0`, no memory is allocated for the data, element access
+/// returns `None` and reductions return their identity value (e.g. `0` for a sum).
+///
 /// # Examples
 ///
 /// Creating a tensor from data:
@@ -534,6 +637,158 @@ impl<T, const N: usize, A: TensorAllocator> Tensor<T, N, A> {
             .and_then(|i| self.storage.as_slice().get(i))
     }
 
+    /// Iterate over the elements of the tensor together with their index.
+    ///
+    /// The elements are visited in row-major order of their logical index, following the
+    /// strides, so the order is the same for a tensor with a permuted or strided layout.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let mut t = Tensor::<u8, 2, _>::from_shape_vec([2, 2], vec![1, 2, 3, 4], CpuAllocator).unwrap();
+    /// t.strides = [1, 2];
+    ///
+    /// let items = t.indexed_iter().collect::<Vec<_>>();
+    /// assert_eq!(items, vec![([0, 0], &1), ([0, 1], &3), ([1, 0], &2), ([1, 1], &4)]);
+    /// ```
+    pub fn indexed_iter(&self) -> impl Iterator<Item = ([usize; N], &T)> + '_ {
+        row_major_indices(self.shape).map(move |index| (index, self.get_unchecked(index)))
+    }
+
+    /// Iterate mutably over the elements of the tensor together with their index.
+    ///
+    /// The elements are visited in row-major order of their logical index, following the
+    /// strides, as in [`Tensor::indexed_iter`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the strides map two indices to the same element, e.g. with a zero stride, or
+    /// if they point past the end of the storage.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let mut t = Tensor::<usize, 2, _>::from_shape_val([2, 3], 0, CpuAllocator);
+    /// t.indexed_iter_mut().for_each(|([r, c], v)| *v = 10 * r + c);
+    /// assert_eq!(t.as_slice(), &[0, 1, 2, 10, 11, 12]);
+    /// ```
+    pub fn indexed_iter_mut(&mut self) -> impl Iterator<Item = ([usize; N], &mut T)> + '_ {
+        assert!(
+            self.has_unique_offsets(),
+            "strides {:?} do not map the shape {:?} to distinct elements of the storage",
+            self.strides,
+            self.shape
+        );
+
+        let strides = self.strides;
+        let data = self.storage.as_mut_slice().as_mut_ptr();
+        row_major_indices(self.shape).map(move |index| {
+            let offset = index.iter().zip(strides).map(|(i, s)| i * s).sum::<usize>();
+            // SAFETY: the offsets are in bounds and distinct, so each element is borrowed once.
+            (index, unsafe { &mut *data.add(offset) })
+        })
+    }
+
+    /// Whether every index maps to a distinct element inside the storage.
+    fn has_unique_offsets(&self) -> bool {
+        if self.shape.contains(&0) {
+            return true;
+        }
+
+        // visit the dimensions from the smallest stride: each stride must skip past all the
+        // elements reachable with the smaller ones
+        let mut dims = [0; N];
+        dims.iter_mut().enumerate().for_each(|(i, d)| *d = i);
+        dims.sort_by_key(|&d| self.strides[d]);
+
+        let mut max_offset = 0;
+        for d in dims {
+            if self.shape[d] > 1 && self.strides[d] <= max_offset {
+                return false;
+            }
+            max_offset += self.strides[d] * (self.shape[d] - 1);
+        }
+        max_offset < self.storage.as_slice().len()
+    }
+
+    /// Get the maximum value of the tensor together with its index.
+    ///
+    /// The tensor is traversed once in row-major order and ties are resolved in favour of the
+    /// first occurrence. Values that are not comparable (e.g. `NaN`) are skipped unless they
+    /// are the first element.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the maximum value and its multidimensional index, or `None` if the
+    /// tensor is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let data: Vec<u8> = vec![1, 7, 3, 7, 5, 6];
+    /// let t = Tensor::<u8, 2, CpuAllocator>::from_shape_vec([2, 3], data, CpuAllocator).unwrap();
+    /// assert_eq!(t.max_with_index(), Some((7, [0, 1])));
+    /// ```
+    pub fn max_with_index(&self) -> Option<(T, [usize; N])>
+    where
+        T: PartialOrd + Clone,
+    {
+        self.extreme_with_index(|candidate, current| candidate > current)
+    }
+
+    /// Get the minimum value of the tensor together with its index.
+    ///
+    /// The tensor is traversed once in row-major order and ties are resolved in favour of the
+    /// first occurrence. Values that are not comparable (e.g. `NaN`) are skipped unless they
+    /// are the first element.
+    ///
+    /// # Returns
+    ///
+    /// A tuple with the minimum value and its multidimensional index, or `None` if the
+    /// tensor is empty.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let data: Vec<u8> = vec![4, 7, 1, 7, 1, 6];
+    /// let t = Tensor::<u8, 2, CpuAllocator>::from_shape_vec([2, 3], data, CpuAllocator).unwrap();
+    /// assert_eq!(t.min_with_index(), Some((1, [0, 2])));
+    /// ```
+    pub fn min_with_index(&self) -> Option<(T, [usize; N])>
+    where
+        T: PartialOrd + Clone,
+    {
+        self.extreme_with_index(|candidate, current| candidate < current)
+    }
+
+    /// Find the first element in row-major order that is not beaten by any other element.
+    fn extreme_with_index<F>(&self, is_better: F) -> Option<(T, [usize; N])>
+    where
+        T: Clone,
+        F: Fn(&T, &T) -> bool,
+    {
+        let slice = self.storage.as_slice();
+
+        let mut best: Option<(&T, [usize; N])> = None;
+        for index in row_major_indices(self.shape) {
+            let value = &slice[self.get_iter_offset_unchecked(index)];
+            match best {
+                Some((current, _)) if !is_better(value, current) => {}
+                _ => best = Some((value, index)),
+            }
+        }
+
+        best.map(|(value, index)| (value.clone(), index))
+    }
+
     /// Reshape the tensor to a new shape.
     ///
     /// # Arguments
@@ -580,240 +835,346 @@ impl<T, const N: usize, A: TensorAllocator> Tensor<T, N, A> {
             storage: &self.storage,
             shape,
             strides,
+            offset: 0,
         })
     }
 
-    /// Permutes (reorders) the dimensions of the tensor.
+    /// Reshape the tensor to a new shape, taking ownership of its data.
     ///
-    /// This is a zero-copy operation that returns a view with reordered dimensions.
-    /// The underlying data is not moved; only the shape and strides are adjusted.
-    /// Common use cases include matrix transposition and changing data layout.
+    /// Unlike [`Tensor::reshape`], which returns a view borrowing the tensor, this returns a new
+    /// tensor. If the tensor has a standard layout its storage is moved without copying,
+    /// otherwise the elements are copied in row-major order to a new storage.
     ///
     /// # Arguments
     ///
-    /// * `axes` - An array specifying the new dimension order. `axes[i]` indicates which
-    ///   source dimension becomes the i-th dimension in the output.
+    /// * `shape` - The new shape of the tensor.
     ///
     /// # Returns
     ///
-    /// A [`TensorView`] with permuted dimensions.
-    ///
-    /// # Examples
-    ///
-    /// Transposing a 2D tensor (matrix):
-    ///
-    /// ```rust
-    /// use kornia_tensor::{Tensor, CpuAllocator};
+    /// A new `Tensor` with the given shape and a standard layout.
     ///
-    /// let data = vec![1, 2, 3, 4, 5, 6];
-    /// let tensor = Tensor::<i32, 2, _>::from_shape_vec([2, 3], data, CpuAllocator).unwrap();
+    /// # Errors
     ///
-    /// // Original: [[1, 2, 3],
-    /// //            [4, 5, 6]]
+    /// If the number of elements in the new shape does not match the number of elements in
+    /// the tensor, an error is returned.
     ///
-    /// // Transpose by swapping dimensions
-    /// let transposed = tensor.permute_axes([1, 0]);
-    /// assert_eq!(transposed.shape, [3, 2]);
+    /// # Example
     ///
-    /// // Result: [[1, 4],
-    /// //          [2, 5],
-    /// //          [3, 6]]
-    /// assert_eq!(*transposed.get_unchecked([0, 0]), 1);
-    /// assert_eq!(*transposed.get_unchecked([0, 1]), 4);
-    /// assert_eq!(*transposed.get_unchecked([1, 0]), 2);
     /// ```
-    ///
-    /// Reordering dimensions of a 3D tensor (e.g., changing channel order):
-    ///
-    /// ```rust
     /// use kornia_tensor::{Tensor, CpuAllocator};
     ///
-    /// // Create a 2x3x4 tensor (batch x height x width)
-    /// let tensor = Tensor::<i32, 3, _>::from_shape_val([2, 3, 4], 1, CpuAllocator);
+    /// let data: Vec<u8> = vec![1, 2, 3, 4, 5, 6];
+    /// let t = Tensor::<u8, 2, CpuAllocator>::from_shape_vec([2, 3], data, CpuAllocator).unwrap();
+    /// let ptr = t.as_ptr();
     ///
-    /// // Reorder to (batch x width x height)
-    /// let permuted = tensor.permute_axes([0, 2, 1]);
-    /// assert_eq!(permuted.shape, [2, 4, 3]);
+    /// let t2 = t.into_shape([3, 2, 1]).unwrap();
+    /// assert_eq!(t2.shape, [3, 2, 1]);
+    /// assert_eq!(t2.strides, [2, 1, 1]);
+    /// assert_eq!(t2.as_ptr(), ptr);
     /// ```
-    pub fn permute_axes(&self, axes: [usize; N]) -> TensorView<'_, T, N, A> {
-        let mut new_shape = [0; N];
-        let mut new_strides = [0; N];
-        for (i, &axis) in axes.iter().enumerate() {
-            new_shape[i] = self.shape[axis];
-            new_strides[i] = self.strides[axis];
+    pub fn into_shape<const M: usize>(
+        self,
+        shape: [usize; M],
+    ) -> Result<Tensor<T, M, A>, TensorError>
+    where
+        T: Clone,
+    {
+        let numel = shape.iter().product::<usize>();
+        if numel != self.numel() {
+            return Err(TensorError::IncompatibleShape(self.numel(), numel));
         }
 
-        TensorView {
-            storage: &self.storage,
-            shape: new_shape,
-            strides: new_strides,
+        if self.is_standard_layout() {
+            return Ok(Tensor {
+                storage: self.storage,
+                shape,
+                strides: get_strides_from_shape(shape),
+            });
         }
-    }
 
-    /// Return a view of the tensor.
-    ///
-    /// The view is a reference to the tensor storage with a different shape and strides.
-    ///
-    /// # Returns
-    ///
-    /// A `TensorView` instance.
-    pub fn view(&self) -> TensorView<'_, T, N, A> {
-        TensorView {
-            storage: &self.storage,
-            shape: self.shape,
-            strides: self.strides,
-        }
+        // copy the elements of the view in row-major order
+        let slice = self.as_slice();
+        let data = row_major_indices(self.shape)
+            .map(|index| slice[self.get_iter_offset_unchecked(index)].clone())
+            .collect();
+
+        Ok(Tensor {
+            storage: TensorStorage::from_vec(data, self.storage.alloc().clone()),
+            shape,
+            strides: get_strides_from_shape(shape),
+        })
     }
 
-    /// Create a new tensor with all elements set to zero.
+    /// Remove an axis of size 1 without copying the data.
+    ///
+    /// Only the shape and the strides change, so the storage is reused as is, also for
+    /// tensors without a standard layout.
     ///
     /// # Arguments
     ///
-    /// * `shape` - The shape of the tensor.
-    /// * `alloc` - The allocator to use.
+    /// * `axis` - The axis to remove, which must have size 1.
     ///
     /// # Returns
-    pub fn zeros(shape: [usize; N], alloc: A) -> Tensor<T, N, A>
-    where
-        T: Clone + num_traits::Zero,
-    {
-        // TODO: add allocator parameter
-        Self::from_shape_val(shape, T::zero(), alloc)
-    }
-
-    /// Apply a function to each element of the tensor.
     ///
-    /// # Arguments
-    ///
-    /// * `f` - The function to apply to each element.
+    /// A new `Tensor` with the shape of the tensor without the axis.
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// A new `Tensor` instance.
+    /// If the axis is out of bounds, does not have size 1, or `M` is not `N - 1`, an error
+    /// is returned.
     ///
     /// # Example
     ///
     /// ```
     /// use kornia_tensor::{Tensor, CpuAllocator};
     ///
-    /// let data: Vec<u8> = vec![1, 2, 3, 4];
-    /// let t = Tensor::<u8, 1, CpuAllocator>::from_shape_vec([4], data, CpuAllocator).unwrap();
+    /// let t = Tensor::<u8, 3, _>::from_shape_vec([2, 1, 3], vec![1, 2, 3, 4, 5, 6], CpuAllocator).unwrap();
     ///
-    /// let t2 = t.map(|x| *x + 1);
-    /// assert_eq!(t2.as_slice(), vec![2, 3, 4, 5]);
+    /// let t = t.squeeze::<2>(1).unwrap();
+    /// assert_eq!(t.shape, [2, 3]);
+    /// assert_eq!(t.strides, [3, 1]);
     /// ```
-    pub fn map<U, F>(&self, f: F) -> Tensor<U, N, A>
-    where
-        F: Fn(&T) -> U,
-    {
-        let data: Vec<U> = self.as_slice().iter().map(f).collect();
-        let storage = TensorStorage::from_vec(data, self.storage.alloc().clone());
+    pub fn squeeze<const M: usize>(self, axis: usize) -> Result<Tensor<T, M, A>, TensorError> {
+        if M + 1 != N {
+            return Err(TensorError::DimensionMismatch(format!(
+                "Cannot drop an axis of a {N}D tensor to get a {M}D tensor"
+            )));
+        }
+        if axis >= N {
+            return Err(TensorError::DimOutOfBounds(axis, N));
+        }
+        if self.shape[axis] != 1 {
+            return Err(TensorError::DimensionMismatch(format!(
+                "Cannot squeeze axis {axis} of size {}",
+                self.shape[axis]
+            )));
+        }
 
-        Tensor {
-            storage,
-            shape: self.shape,
-            strides: self.strides,
+        let mut shape = [0; M];
+        let mut strides = [0; M];
+        for (dst, src) in (0..N).filter(|&i| i != axis).enumerate() {
+            shape[dst] = self.shape[src];
+            strides[dst] = self.strides[src];
         }
+
+        Ok(Tensor {
+            storage: self.storage,
+            shape,
+            strides,
+        })
     }
 
-    /// Checks if the tensor has a standard contiguous (row-major) memory layout.
+    /// Remove all the axes of size 1 without copying the data.
     ///
-    /// A standard layout means the tensor's data is stored contiguously in memory
-    /// following row-major order, where the rightmost dimension varies fastest.
-    /// This is important for performance in many operations and for interoperability
-    /// with other libraries.
+    /// This is handy to clean up the outputs of a network, e.g. a `[1, 1, H, W]` mask.
     ///
     /// # Returns
     ///
-    /// `true` if the tensor has contiguous row-major layout, `false` otherwise.
-    ///
-    /// # When is a layout non-standard?
+    /// A new `Tensor` with the axes of size larger than 1.
     ///
-    /// - After dimension permutation (e.g., transpose)
-    /// - After certain slicing operations
-    /// - When strides have been manually modified
-    /// - When the tensor is a non-contiguous view
+    /// # Errors
     ///
-    /// # Examples
+    /// If `M` is not the number of axes of size larger than 1, an error is returned.
     ///
-    /// Standard layout:
+    /// # Example
     ///
-    /// ```rust
+    /// ```
     /// use kornia_tensor::{Tensor, CpuAllocator};
     ///
-    /// let tensor = Tensor::<i32, 2, _>::from_shape_val([3, 4], 0, CpuAllocator);
-    /// assert!(tensor.is_standard_layout());
+    /// let t = Tensor::<f32, 4, _>::from_shape_val([1, 1, 2, 3], 0.5, CpuAllocator);
+    ///
+    /// let t = t.squeeze_all::<2>().unwrap();
+    /// assert_eq!(t.shape, [2, 3]);
     /// ```
+    pub fn squeeze_all<const M: usize>(self) -> Result<Tensor<T, M, A>, TensorError> {
+        let kept = self.shape.iter().filter(|&&dim| dim != 1).count();
+        if kept != M {
+            return Err(TensorError::DimensionMismatch(format!(
+                "Cannot squeeze the shape {:?} with {kept} axes larger than 1 to a {M}D tensor",
+                self.shape
+            )));
+        }
+
+        let mut shape = [0; M];
+        let mut strides = [0; M];
+        for (dst, src) in (0..N).filter(|&i| self.shape[i] != 1).enumerate() {
+            shape[dst] = self.shape[src];
+            strides[dst] = self.strides[src];
+        }
+
+        Ok(Tensor {
+            storage: self.storage,
+            shape,
+            strides,
+        })
+    }
+
+    /// Insert an axis of size 1 without copying the data.
     ///
-    /// Non-standard layout after transpose:
+    /// The new axis gets the stride that keeps a standard layout standard.
     ///
-    /// ```rust
-    /// use kornia_tensor::{Tensor, CpuAllocator};
+    /// # Arguments
     ///
-    /// let data = vec![1, 2, 3, 4];
-    /// let tensor = Tensor::<i32, 2, _>::from_shape_vec([2, 2], data, CpuAllocator).unwrap();
+    /// * `axis` - The position of the new axis, from 0 to `N` included.
     ///
-    /// // After permutation, the memory layout is no longer contiguous
-    /// let mut transposed = tensor.permute_axes([1, 0]).as_contiguous();
-    /// transposed.strides = [1, 2]; // Transpose stride pattern
+    /// # Returns
     ///
-    /// // This would need to_standard_layout() to become contiguous again
-    /// ```
+    /// A new `Tensor` with the new axis at the given position.
     ///
-    /// Detecting non-standard layout:
+    /// # Errors
     ///
-    /// ```rust
+    /// If the axis is larger than `N` or `M` is not `N + 1`, an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
     /// use kornia_tensor::{Tensor, CpuAllocator};
     ///
-    /// let data = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
-    /// let mut tensor = Tensor::<i32, 3, _>::from_shape_vec([2, 2, 3], data, CpuAllocator).unwrap();
+    /// let t = Tensor::<u8, 2, _>::from_shape_vec([2, 3], vec![1, 2, 3, 4, 5, 6], CpuAllocator).unwrap();
     ///
-    /// // Manually corrupt the strides
-    /// tensor.strides = [10, 5, 1];
-    /// assert!(!tensor.is_standard_layout());
+    /// let batch = t.unsqueeze::<3>(0).unwrap();
+    /// assert_eq!(batch.shape, [1, 2, 3]);
+    /// assert_eq!(batch.strides, [6, 3, 1]);
     /// ```
-    pub fn is_standard_layout(&self) -> bool {
-        let mut expected_stride: usize = 1;
-        for (&dim, &stride) in self.shape.iter().rev().zip(self.strides.iter().rev()) {
-            if stride != expected_stride {
-                return false;
-            }
-            expected_stride = expected_stride.saturating_mul(dim);
+    pub fn unsqueeze<const M: usize>(self, axis: usize) -> Result<Tensor<T, M, A>, TensorError> {
+        if M != N + 1 {
+            return Err(TensorError::DimensionMismatch(format!(
+                "Cannot add an axis to a {N}D tensor to get a {M}D tensor"
+            )));
+        }
+        if axis > N {
+            return Err(TensorError::DimOutOfBounds(axis, M));
+        }
+
+        let mut shape = [1; M];
+        let mut strides = [0; M];
+        for (src, dst) in (0..M).filter(|&i| i != axis).enumerate() {
+            shape[dst] = self.shape[src];
+            strides[dst] = self.strides[src];
         }
-        true
+        strides[axis] = match axis {
+            axis if axis < N => self.shape[axis] * self.strides[axis],
+            _ => 1,
+        };
+
+        Ok(Tensor {
+            storage: self.storage,
+            shape,
+            strides,
+        })
     }
 
-    /// Converts the tensor to standard contiguous (row-major) memory layout.
+    /// Reinterpret the tensor as a 2D matrix without copying the data.
     ///
-    /// If the tensor already has standard layout, returns a clone. Otherwise, creates a new
-    /// tensor with contiguous memory by copying elements in the correct order according to
-    /// the current shape and strides.
+    /// All the leading dimensions are collapsed into rows and the last dimension is kept as
+    /// columns, i.e. a tensor of shape `[H, W, C]` is viewed as a matrix of shape `[H * W, C]`.
+    /// This is [`Tensor::flatten_from`] the last axis.
     ///
-    /// This is essential for:
-    /// - Interfacing with libraries expecting contiguous memory
-    /// - Optimizing performance of certain operations
-    /// - Ensuring consistent memory layout after transformations
+    /// # Returns
+    ///
+    /// A new `TensorView` instance with shape `[product_of_leading, last_dim]` sharing the
+    /// tensor storage.
+    ///
+    /// # Errors
+    ///
+    /// If the tensor does not have a standard contiguous layout or has no dimensions, an error
+    /// is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let t = Tensor::<u8, 3, CpuAllocator>::from_shape_fn([2, 2, 3], CpuAllocator, |[y, x, c]| {
+    ///     (y * 6 + x * 3 + c) as u8
+    /// });
+    /// let m = t.as_2d().unwrap();
+    /// assert_eq!(m.shape, [4, 3]);
+    /// assert_eq!(m.strides, [3, 1]);
+    /// assert_eq!(*m.get_unchecked([3, 2]), 11);
+    /// ```
+    pub fn as_2d(&self) -> Result<TensorView<'_, T, 2, A>, TensorError> {
+        if N == 0 {
+            return Err(TensorError::DimensionMismatch(
+                "Cannot view a 0-dimensional tensor as a 2D matrix".to_string(),
+            ));
+        }
+
+        self.flatten_from(N - 1)
+    }
+
+    /// Flatten the axes from `start_axis` into the columns of a 2D matrix without copying the
+    /// data.
+    ///
+    /// The axes before `start_axis` are collapsed into the rows, so with a batch axis first
+    /// and `start_axis = 1` each row holds one item of the batch, as PyTorch's
+    /// `flatten(start_dim=1)`. A tensor of shape `[B, C, H, W]` is viewed as a matrix of shape
+    /// `[B, C * H * W]`.
     ///
     /// # Arguments
     ///
-    /// * `alloc` - The allocator to use for the new tensor if reallocation is needed
+    /// * `start_axis` - The first axis collapsed into the columns, at most `N`.
     ///
     /// # Returns
     ///
-    /// A new tensor with standard layout on success.
+    /// A new `TensorView` instance with shape `[product_of_leading, product_of_trailing]`
+    /// sharing the tensor storage.
     ///
     /// # Errors
     ///
-    /// Returns [`TensorError::DimensionMismatch`] if the tensor's shape and data are inconsistent.
+    /// If `start_axis` is greater than `N`, or the tensor does not have a standard contiguous
+    /// layout, an error is returned.
     ///
-    /// # Performance
+    /// # Example
     ///
-    /// This operation is O(1) if already standard layout (just clones), O(n) otherwise where
-    /// n is the number of elements.
+    /// ```
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let t = Tensor::<u8, 3, CpuAllocator>::from_shape_fn([2, 3, 4], CpuAllocator, |[b, y, x]| {
+    ///     (b * 12 + y * 4 + x) as u8
+    /// });
+    /// let m = t.flatten_from(1).unwrap();
+    /// assert_eq!(m.shape, [2, 12]);
+    /// assert_eq!(*m.get_unchecked([1, 0]), 12);
+    /// ```
+    pub fn flatten_from(&self, start_axis: usize) -> Result<TensorView<'_, T, 2, A>, TensorError> {
+        if start_axis > N {
+            return Err(TensorError::DimOutOfBounds(start_axis, N));
+        }
+        check_contiguous(self.shape, self.strides)?;
+
+        let rows = self.shape[..start_axis].iter().product::<usize>();
+        let cols = self.shape[start_axis..].iter().product::<usize>();
+
+        let shape = [rows, cols];
+        let strides = get_strides_from_shape(shape);
+
+        Ok(TensorView {
+            storage: &self.storage,
+            shape,
+            strides,
+            offset: 0,
+        })
+    }
+
+    /// Permutes (reorders) the dimensions of the tensor.
+    ///
+    /// This is a zero-copy operation that returns a view with reordered dimensions.
+    /// The underlying data is not moved; only the shape and strides are adjusted.
+    /// Common use cases include matrix transposition and changing data layout.
+    ///
+    /// # Arguments
+    ///
+    /// * `axes` - An array specifying the new dimension order. `axes[i]` indicates which
+    ///   source dimension becomes the i-th dimension in the output.
+    ///
+    /// # Returns
+    ///
+    /// A [`TensorView`] with permuted dimensions.
     ///
     /// # Examples
     ///
-    /// Converting after permutation:
+    /// Transposing a 2D tensor (matrix):
     ///
     /// ```rust
     /// use kornia_tensor::{Tensor, CpuAllocator};
@@ -821,848 +1182,4127 @@ impl<T, const N: usize, A: TensorAllocator> Tensor<T, N, A> {
     /// let data = vec![1, 2, 3, 4, 5, 6];
     /// let tensor = Tensor::<i32, 2, _>::from_shape_vec([2, 3], data, CpuAllocator).unwrap();
     ///
-    /// // Transpose creates non-contiguous layout
+    /// // Original: [[1, 2, 3],
+    /// //            [4, 5, 6]]
+    ///
+    /// // Transpose by swapping dimensions
     /// let transposed = tensor.permute_axes([1, 0]);
-    /// let owned = transposed.as_contiguous(); // Now we have an owned tensor
+    /// assert_eq!(transposed.shape, [3, 2]);
     ///
-    /// // Convert to standard layout
-    /// let standard = owned.to_standard_layout(CpuAllocator).unwrap();
-    /// assert!(standard.is_standard_layout());
+    /// // Result: [[1, 4],
+    /// //          [2, 5],
+    /// //          [3, 6]]
+    /// assert_eq!(*transposed.get_unchecked([0, 0]), 1);
+    /// assert_eq!(*transposed.get_unchecked([0, 1]), 4);
+    /// assert_eq!(*transposed.get_unchecked([1, 0]), 2);
     /// ```
     ///
-    /// Fixing corrupted strides:
+    /// Reordering dimensions of a 3D tensor (e.g., changing channel order):
     ///
     /// ```rust
     /// use kornia_tensor::{Tensor, CpuAllocator};
     ///
-    /// let data = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
-    /// let mut tensor = Tensor::<i32, 3, _>::from_shape_vec([2, 2, 3], data, CpuAllocator).unwrap();
-    ///
-    /// // Simulate non-standard strides
-    /// tensor.strides = [1, 6, 2];
-    /// assert!(!tensor.is_standard_layout());
+    /// // Create a 2x3x4 tensor (batch x height x width)
+    /// let tensor = Tensor::<i32, 3, _>::from_shape_val([2, 3, 4], 1, CpuAllocator);
     ///
-    /// // Restore standard layout
-    /// let fixed = tensor.to_standard_layout(CpuAllocator).unwrap();
-    /// assert!(fixed.is_standard_layout());
-    /// assert_eq!(fixed.strides, [6, 3, 1]);
+    /// // Reorder to (batch x width x height)
+    /// let permuted = tensor.permute_axes([0, 2, 1]);
+    /// assert_eq!(permuted.shape, [2, 4, 3]);
     /// ```
+    pub fn permute_axes(&self, axes: [usize; N]) -> TensorView<'_, T, N, A> {
+        let mut new_shape = [0; N];
+        let mut new_strides = [0; N];
+        for (i, &axis) in axes.iter().enumerate() {
+            new_shape[i] = self.shape[axis];
+            new_strides[i] = self.strides[axis];
+        }
+
+        TensorView {
+            storage: &self.storage,
+            shape: new_shape,
+            strides: new_strides,
+            offset: 0,
+        }
+    }
+
+    /// Permute the axes of the tensor, checking that `axes` is a permutation.
     ///
-    /// No-op for already standard tensors:
+    /// This is the checked version of [`Tensor::permute_axes`]. The view shares the tensor
+    /// storage, use [`TensorView::to_contiguous`] to get a tensor with a standard layout.
     ///
-    /// ```rust
+    /// # Arguments
+    ///
+    /// * `axes` - The axis of the tensor for each axis of the view.
+    ///
+    /// # Returns
+    ///
+    /// A new `TensorView` instance with the permuted shape and strides.
+    ///
+    /// # Errors
+    ///
+    /// If `axes` is not a permutation of `0..N`, an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
     /// use kornia_tensor::{Tensor, CpuAllocator};
     ///
-    /// let tensor = Tensor::<i32, 2, _>::from_shape_val([3, 4], 42, CpuAllocator);
-    /// assert!(tensor.is_standard_layout());
+    /// // an image with shape (H, W, C) viewed as (C, H, W)
+    /// let hwc = Tensor::<u8, 3, _>::from_shape_fn([2, 4, 3], CpuAllocator, |[_, _, c]| c as u8);
+    /// let chw = hwc.permute([2, 0, 1]).unwrap();
+    /// assert_eq!(chw.shape, [3, 2, 4]);
+    /// assert_eq!(chw.strides, [1, 12, 3]);
+    /// assert_eq!(*chw.get_unchecked([2, 1, 3]), 2);
     ///
-    /// // This will just clone since it's already standard
-    /// let standard = tensor.to_standard_layout(CpuAllocator).unwrap();
-    /// assert!(standard.is_standard_layout());
+    /// assert!(hwc.permute([0, 0, 1]).is_err());
     /// ```
-    pub fn to_standard_layout(&self, alloc: A) -> Result<Self, TensorError>
-    where
-        T: Clone + std::fmt::Debug,
-    {
-        if self.is_standard_layout() {
-            return Ok(self.clone());
-        }
-
-        let total_elems: usize = self.shape.iter().product();
-        let mut flat = Vec::with_capacity(total_elems);
-        let mut idx = [0; N];
-        let slice = self.storage.as_slice();
-
-        for _ in 0..total_elems {
-            let offset = idx
-                .iter()
-                .zip(self.strides.iter())
-                .map(|(&i, &s)| i * s)
-                .sum::<usize>();
-
-            flat.push(slice[offset].clone());
-
-            // increment index
-            for dim in (0..N).rev() {
-                idx[dim] += 1;
-                if idx[dim] < self.shape[dim] {
-                    break;
-                } else {
-                    idx[dim] = 0;
-                }
+    pub fn permute(&self, axes: [usize; N]) -> Result<TensorView<'_, T, N, A>, TensorError> {
+        let mut used = [false; N];
+        for &axis in axes.iter() {
+            if axis >= N || used[axis] {
+                return Err(TensorError::DimensionMismatch(format!(
+                    "Axes {axes:?} are not a permutation of 0..{N}"
+                )));
             }
+            used[axis] = true;
         }
 
-        Tensor::from_shape_vec(self.shape, flat, alloc).map_err(|_| {
-            TensorError::DimensionMismatch(format!(
-                "Cannot construct tensor of shape {:?} with {:?} elements",
-                self.shape, total_elems,
-            ))
-        })
+        Ok(self.permute_axes(axes))
     }
 
-    /// Cast the tensor to a new type.
+    /// Swap two axes of the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `dim0` - The first axis.
+    /// * `dim1` - The second axis.
     ///
     /// # Returns
     ///
-    /// A new `Tensor` instance.
+    /// A new `TensorView` instance with the two axes swapped.
+    ///
+    /// # Errors
+    ///
+    /// If an axis is out of bounds, an error is returned.
     ///
     /// # Example
     ///
     /// ```
     /// use kornia_tensor::{Tensor, CpuAllocator};
     ///
-    /// let data: Vec<u8> = vec![1, 2, 3, 4];
-    /// let t = Tensor::<u8, 1, CpuAllocator>::from_shape_vec([4], data, CpuAllocator).unwrap();
+    /// let data: Vec<u8> = vec![1, 2, 3, 4, 5, 6];
+    /// let t = Tensor::<u8, 2, _>::from_shape_vec([2, 3], data, CpuAllocator).unwrap();
     ///
-    /// let t2 = t.cast::<f32>();
-    /// assert_eq!(t2.as_slice(), vec![1.0, 2.0, 3.0, 4.0]);
+    /// let t2 = t.transpose(0, 1).unwrap();
+    /// assert_eq!(t2.shape, [3, 2]);
+    /// assert!(!t2.is_contiguous());
+    /// assert_eq!(t2.to_contiguous().as_slice(), vec![1, 4, 2, 5, 3, 6]);
     /// ```
-    pub fn cast<U>(&self) -> Tensor<U, N, CpuAllocator>
-    where
-        U: From<T>,
-        T: Clone,
-    {
-        let mut data: Vec<U> = Vec::with_capacity(self.storage.len());
-        self.as_slice().iter().for_each(|x| {
-            data.push(U::from(x.clone()));
-        });
-        let storage = TensorStorage::from_vec(data, CpuAllocator);
-        Tensor {
-            storage,
-            shape: self.shape,
-            strides: self.strides,
+    pub fn transpose(
+        &self,
+        dim0: usize,
+        dim1: usize,
+    ) -> Result<TensorView<'_, T, N, A>, TensorError> {
+        if let Some(&dim) = [dim0, dim1].iter().find(|&&dim| dim >= N) {
+            return Err(TensorError::DimOutOfBounds(dim, N));
         }
+
+        let mut axes: [usize; N] = std::array::from_fn(|i| i);
+        axes.swap(dim0, dim1);
+
+        Ok(self.permute_axes(axes))
     }
 
-    /// Perform an element-wise operation on two tensors.
+    /// Extract a sub-region of the tensor without copying the data.
+    ///
+    /// The view shares the tensor storage and starts at an offset within it. For example, an
+    /// image with shape (H, W, C) is cropped with `ranges = [y0..y1, x0..x1, 0..C]`.
     ///
     /// # Arguments
     ///
-    /// * `other` - The other tensor to perform the operation with.
-    /// * `op` - The operation to perform.
+    /// * `ranges` - The range of indices to keep along each axis. An empty range gives an
+    ///   axis of size zero.
     ///
     /// # Returns
     ///
-    /// A new `Tensor` instance.
+    /// A new `TensorView` instance with the shape of the ranges and the tensor strides.
+    ///
+    /// # Errors
+    ///
+    /// If a range goes beyond the size of its axis, an error is returned.
     ///
     /// # Example
     ///
     /// ```
     /// use kornia_tensor::{Tensor, CpuAllocator};
     ///
-    /// let data1: Vec<u8> = vec![1, 2, 3, 4];
-    /// let t1 = Tensor::<u8, 1, CpuAllocator>::from_shape_vec([4], data1, CpuAllocator).unwrap();
+    /// let t = Tensor::<u8, 2, _>::from_shape_fn([4, 4], CpuAllocator, |[y, x]| (y * 4 + x) as u8);
     ///
-    /// let data2: Vec<u8> = vec![1, 2, 3, 4];
-    /// let t2 = Tensor::<u8, 1, CpuAllocator>::from_shape_vec([4], data2, CpuAllocator).unwrap();
+    /// let rows = t.slice([1..3, 0..4]).unwrap();
+    /// assert_eq!(rows.shape, [2, 4]);
+    /// assert_eq!(rows.as_slice(), &[4, 5, 6, 7, 8, 9, 10, 11]);
     ///
-    /// let t3 = t1.element_wise_op(&t2, |a, b| *a + *b).unwrap();
-    /// assert_eq!(t3.as_slice(), vec![2, 4, 6, 8]);
+    /// let crop = t.slice([1..3, 1..3]).unwrap();
+    /// assert_eq!(crop.to_contiguous().as_slice(), &[5, 6, 9, 10]);
+    /// ```
+    pub fn slice(
+        &self,
+        ranges: [std::ops::Range<usize>; N],
+    ) -> Result<TensorView<'_, T, N, A>, TensorError> {
+        let mut shape = [0; N];
+        let mut offset = 0;
+        for (dim, range) in ranges.iter().enumerate() {
+            if range.start > self.shape[dim] || range.end > self.shape[dim] {
+                return Err(TensorError::IndexOutOfBounds(range.start.max(range.end)));
+            }
+            shape[dim] = range.len();
+            offset += range.start * self.strides[dim];
+        }
+
+        // an empty view may start past the end of the storage
+        if shape.contains(&0) {
+            offset = 0;
+        }
+
+        Ok(TensorView {
+            storage: &self.storage,
+            shape,
+            strides: self.strides,
+            offset,
+        })
+    }
+
+    /// Select the given indices along an axis into a new tensor.
     ///
-    /// let t4 = t1.element_wise_op(&t2, |a, b| *a - *b).unwrap();
-    /// assert_eq!(t4.as_slice(), vec![0, 0, 0, 0]);
+    /// The indices are gathered in the given order and may repeat, e.g. to reorder or
+    /// duplicate the channels of an image.
     ///
-    /// let t5 = t1.element_wise_op(&t2, |a, b| *a * *b).unwrap();
-    /// assert_eq!(t5.as_slice(), vec![1, 4, 9, 16]);
+    /// # Arguments
+    ///
+    /// * `axis` - The axis to select along.
+    /// * `indices` - The indices to keep along the axis.
+    ///
+    /// # Returns
+    ///
+    /// A new tensor with `indices.len()` elements along the axis and a standard layout.
+    ///
+    /// # Errors
+    ///
+    /// If the axis or any of the indices is out of bounds, an error is returned.
+    ///
+    /// # Example
     ///
-    /// let t6 = t1.element_wise_op(&t2, |a, b| *a / *b).unwrap();
-    /// assert_eq!(t6.as_slice(), vec![1, 1, 1, 1]);
     /// ```
-    pub fn element_wise_op<F>(
-        &self,
-        other: &Tensor<T, N, CpuAllocator>,
-        op: F,
-    ) -> Result<Tensor<T, N, CpuAllocator>, TensorError>
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let t = Tensor::<u8, 2, _>::from_shape_vec([3, 2], vec![1, 2, 3, 4, 5, 6], CpuAllocator).unwrap();
+    ///
+    /// let rows = t.index_select(0, &[2, 0]).unwrap();
+    /// assert_eq!(rows.shape, [2, 2]);
+    /// assert_eq!(rows.as_slice(), &[5, 6, 1, 2]);
+    /// ```
+    pub fn index_select(&self, axis: usize, indices: &[usize]) -> Result<Self, TensorError>
     where
-        F: Fn(&T, &T) -> T,
+        T: Clone,
     {
-        if self.shape != other.shape {
-            return Err(TensorError::DimensionMismatch(format!(
-                "Shapes {:?} and {:?} are not compatible for element-wise operations",
-                self.shape, other.shape
-            )));
+        if axis >= N {
+            return Err(TensorError::DimOutOfBounds(axis, N));
+        }
+        if let Some(&index) = indices.iter().find(|&&i| i >= self.shape[axis]) {
+            return Err(TensorError::IndexOutOfBounds(index));
         }
 
-        let data = self
-            .as_slice()
-            .iter()
-            .zip(other.as_slice().iter())
-            .map(|(a, b)| op(a, b))
+        let mut shape = self.shape;
+        shape[axis] = indices.len();
+
+        let data = row_major_indices(shape)
+            .map(|mut index| {
+                index[axis] = indices[index[axis]];
+                self.get_unchecked(index).clone()
+            })
             .collect();
 
-        let storage = TensorStorage::from_vec(data, CpuAllocator);
+        Ok(Tensor {
+            storage: TensorStorage::from_vec(data, self.storage.alloc().clone()),
+            shape,
+            strides: get_strides_from_shape(shape),
+        })
+    }
+
+    /// Reverse the order of the elements along the given axes.
+    ///
+    /// The elements are read through the strides, so the tensor does not need to be
+    /// contiguous. An empty list of axes copies the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `axes` - The axes to reverse.
+    ///
+    /// # Returns
+    ///
+    /// A new tensor with the same shape and a standard layout.
+    ///
+    /// # Errors
+    ///
+    /// If an axis is out of bounds or listed twice, an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let t = Tensor::<u8, 2, _>::from_shape_vec([2, 3], vec![1, 2, 3, 4, 5, 6], CpuAllocator).unwrap();
+    ///
+    /// assert_eq!(t.flip(&[1]).unwrap().as_slice(), &[3, 2, 1, 6, 5, 4]);
+    /// assert_eq!(t.flip(&[0, 1]).unwrap().as_slice(), &[6, 5, 4, 3, 2, 1]);
+    /// ```
+    pub fn flip(&self, axes: &[usize]) -> Result<Self, TensorError>
+    where
+        T: Clone,
+    {
+        let mut flipped = [false; N];
+        for &axis in axes {
+            if axis >= N {
+                return Err(TensorError::DimOutOfBounds(axis, N));
+            }
+            if flipped[axis] {
+                return Err(TensorError::DimensionMismatch(format!(
+                    "Axis {axis} is repeated in the axes {axes:?} to flip"
+                )));
+            }
+            flipped[axis] = true;
+        }
+
+        let data = row_major_indices(self.shape)
+            .map(|mut index| {
+                for dim in 0..N {
+                    if flipped[dim] {
+                        index[dim] = self.shape[dim] - 1 - index[dim];
+                    }
+                }
+                self.get_unchecked(index).clone()
+            })
+            .collect();
 
         Ok(Tensor {
-            storage,
+            storage: TensorStorage::from_vec(data, self.storage.alloc().clone()),
             shape: self.shape,
-            strides: self.strides,
+            strides: get_strides_from_shape(self.shape),
         })
     }
-}
 
-impl<T, const N: usize, A> Clone for Tensor<T, N, A>
-where
-    T: Clone,
-    A: TensorAllocator + Clone,
-{
-    fn clone(&self) -> Self {
-        Self {
-            storage: self.storage.clone(),
+    /// Shift the elements circularly along an axis.
+    ///
+    /// The element at index `i` moves to index `(i + shift) mod len`, so the elements shifted
+    /// past the end wrap around to the start, and a negative shift rolls the other way. Shifts
+    /// larger than the axis are taken modulo its length, e.g. to center the zero frequency of a
+    /// spectrum with a shift of `len / 2`.
+    ///
+    /// # Arguments
+    ///
+    /// * `shift` - The number of positions to shift the elements by.
+    /// * `axis` - The axis to roll.
+    ///
+    /// # Returns
+    ///
+    /// A new tensor with the same shape and a standard layout.
+    ///
+    /// # Errors
+    ///
+    /// If the axis is out of bounds, an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let t = Tensor::<u8, 1, _>::from_shape_vec([4], vec![1, 2, 3, 4], CpuAllocator).unwrap();
+    ///
+    /// assert_eq!(t.roll(1, 0).unwrap().as_slice(), &[4, 1, 2, 3]);
+    /// assert_eq!(t.roll(-1, 0).unwrap().as_slice(), &[2, 3, 4, 1]);
+    /// ```
+    pub fn roll(&self, shift: isize, axis: usize) -> Result<Self, TensorError>
+    where
+        T: Clone,
+    {
+        if axis >= N {
+            return Err(TensorError::DimOutOfBounds(axis, N));
+        }
+
+        let len = self.shape[axis];
+        // the output index i reads the input index (i - shift) mod len
+        let offset = if len == 0 {
+            0
+        } else {
+            len - shift.rem_euclid(len as isize) as usize
+        };
+
+        let data = row_major_indices(self.shape)
+            .map(|mut index| {
+                index[axis] = (index[axis] + offset) % len;
+                self.get_unchecked(index).clone()
+            })
+            .collect();
+
+        Ok(Tensor {
+            storage: TensorStorage::from_vec(data, self.storage.alloc().clone()),
+            shape: self.shape,
+            strides: get_strides_from_shape(self.shape),
+        })
+    }
+
+    /// Broadcast the tensor to a larger shape without copying the data, as NumPy does.
+    ///
+    /// The shapes are aligned on their last dimension. Each dimension of the tensor must be
+    /// either 1 or equal to the target dimension, and the leading dimensions missing from the
+    /// tensor are added. The broadcast dimensions get a stride of 0, so all their indices read
+    /// the same element.
+    ///
+    /// The elements of the view alias each other, so the view is read-only: [`TensorView`]
+    /// has no mutable accessors. Use [`TensorView::to_contiguous`] to materialize it.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape` - The target shape, with at least the number of dimensions of the tensor.
+    ///
+    /// # Returns
+    ///
+    /// A view with the target shape.
+    ///
+    /// # Errors
+    ///
+    /// If the target shape has fewer dimensions than the tensor, or a dimension is neither 1
+    /// nor the target dimension, an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let bias = Tensor::<f32, 1, _>::from_shape_vec([3], vec![0.1, 0.2, 0.3], CpuAllocator).unwrap();
+    ///
+    /// let view = bias.broadcast_to([2, 4, 3]).unwrap();
+    /// assert_eq!(view.shape, [2, 4, 3]);
+    /// assert_eq!(view.strides, [0, 0, 1]);
+    /// assert_eq!(*view.get_unchecked([1, 3, 2]), 0.3);
+    /// ```
+    pub fn broadcast_to<const M: usize>(
+        &self,
+        shape: [usize; M],
+    ) -> Result<TensorView<'_, T, M, A>, TensorError> {
+        if M < N {
+            return Err(TensorError::DimensionMismatch(format!(
+                "cannot broadcast a tensor with {N} dimensions to {M} dimensions"
+            )));
+        }
+
+        // the leading dimensions missing from the tensor are broadcast
+        let mut strides = [0; M];
+        for (dim, (&size, &stride)) in self.shape.iter().zip(&self.strides).enumerate() {
+            let target = shape[M - N + dim];
+            strides[M - N + dim] = match size {
+                size if size == target => stride,
+                1 => 0,
+                _ => return Err(TensorError::IncompatibleShape(size, target)),
+            };
+        }
+
+        Ok(TensorView {
+            storage: &self.storage,
+            shape,
+            strides,
+            offset: 0,
+        })
+    }
+
+    /// Return a view of the tensor.
+    ///
+    /// The view is a reference to the tensor storage with a different shape and strides.
+    ///
+    /// # Returns
+    ///
+    /// A `TensorView` instance.
+    pub fn view(&self) -> TensorView<'_, T, N, A> {
+        TensorView {
+            storage: &self.storage,
             shape: self.shape,
             strides: self.strides,
+            offset: 0,
         }
     }
-}
 
-impl<T, const N: usize, A> std::fmt::Display for Tensor<T, N, A>
-where
-    T: std::fmt::Display + std::fmt::LowerExp,
-    A: TensorAllocator,
-{
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let width = self
-            .storage
-            .as_slice()
-            .iter()
-            .map(|v| format!("{v:.4}").len())
-            .max()
-            .unwrap();
+    /// Iterate over the windows of the tensor as zero-copy views.
+    ///
+    /// The windows are visited in row-major order of their origin, which moves by `stride`
+    /// along each dimension. Along a dimension of size `n` there are
+    /// `(n - window) / stride + 1` windows, or none if the window is larger than `n`, so the
+    /// windows never read past the bounds of the tensor.
+    ///
+    /// # Arguments
+    ///
+    /// * `window` - The shape of the windows.
+    /// * `stride` - The step between the origins of two consecutive windows per dimension.
+    ///
+    /// # Returns
+    ///
+    /// An iterator of [`TensorView`] with the shape of the window.
+    ///
+    /// # Errors
+    ///
+    /// If any window size or stride is zero, an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let data: Vec<u8> = vec![1, 2, 3, 4, 5, 6];
+    /// let t = Tensor::<u8, 1, CpuAllocator>::from_shape_vec([6], data, CpuAllocator).unwrap();
+    ///
+    /// let windows = t
+    ///     .windows([3], [2])
+    ///     .unwrap()
+    ///     .map(|w| w.as_contiguous().into_vec())
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(windows, vec![vec![1, 2, 3], vec![3, 4, 5]]);
+    /// ```
+    pub fn windows(
+        &self,
+        window: [usize; N],
+        stride: [usize; N],
+    ) -> Result<impl Iterator<Item = TensorView<'_, T, N, A>> + '_, TensorError> {
+        if window.contains(&0) || stride.contains(&0) {
+            return Err(TensorError::UnsupportedOperation(format!(
+                "window {window:?} and stride {stride:?} must be non-zero"
+            )));
+        }
 
-        let scientific = width > 8;
+        let mut num_windows = [0; N];
+        for (dim, num) in num_windows.iter_mut().enumerate() {
+            if window[dim] <= self.shape[dim] {
+                *num = (self.shape[dim] - window[dim]) / stride[dim] + 1;
+            }
+        }
 
-        let should_mask: [bool; N] = self.shape.map(|s| s > 8);
-        let mut skip_until = 0;
+        // the number of windows is zero if any dimension has none
+        let total = num_windows.iter().product::<usize>();
 
-        for (i, v) in self.storage.as_slice().iter().enumerate() {
-            if i < skip_until {
-                continue;
-            }
-            let mut value = String::new();
-            let mut prefix = String::new();
-            let mut suffix = String::new();
-            let mut separator = ",".to_string();
-            let mut last_size = 1;
-            for (dim, (&size, maskable)) in self.shape.iter().zip(should_mask).enumerate().rev() {
-                let prod = size * last_size;
-                if i % prod == (3 * last_size) && maskable {
-                    let pad = if dim == (N - 1) { 0 } else { dim + 1 };
-                    value = format!("{}...", " ".repeat(pad));
-                    skip_until = i + (size - 4) * last_size;
-                    prefix = "".to_string();
-                    if dim != (N - 1) {
-                        separator = "\n".repeat(N - 1 - dim);
-                    }
-                    break;
-                } else if i % prod == 0 {
-                    prefix.push('[');
-                } else if (i + 1) % prod == 0 {
-                    suffix.push(']');
-                    separator.push('\n');
-                    if dim == 0 {
-                        separator = "".to_string();
-                    }
-                } else {
-                    break;
-                }
-                last_size = prod;
+        Ok((0..total).map(move |mut i| {
+            let mut offset = 0;
+            for dim in (0..N).rev() {
+                offset += (i % num_windows[dim]) * stride[dim] * self.strides[dim];
+                i /= num_windows[dim];
             }
-            if !prefix.is_empty() {
-                prefix = format!("{prefix:>N$}");
+
+            TensorView {
+                storage: &self.storage,
+                shape: window,
+                strides: self.strides,
+                offset,
             }
+        }))
+    }
 
-            if value.is_empty() {
-                value = if scientific {
-                    let num = format!("{v:.4e}");
-                    let (before, after) = num.split_once('e').unwrap();
-                    let after = if let Some(stripped) = after.strip_prefix('-') {
-                        format!("-{:0>2}", &stripped)
-                    } else {
-                        format!("+{:0>2}", &after)
-                    };
-                    format!("{before}e{after}")
-                } else {
-                    let rounded = format!("{v:.4}");
-                    format!("{rounded:>width$}")
-                }
-            };
-            write!(f, "{prefix}{value}{suffix}{separator}",)?;
-        }
+    /// Create a new tensor with all elements set to zero.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape` - The shape of the tensor.
+    /// * `alloc` - The allocator to use.
+    ///
+    /// # Returns
+    pub fn zeros(shape: [usize; N], alloc: A) -> Tensor<T, N, A>
+    where
+        T: Clone + num_traits::Zero,
+    {
+        // TODO: add allocator parameter
+        Self::from_shape_val(shape, T::zero(), alloc)
+    }
+
+    /// Apply a function to each element of the tensor.
+    ///
+    /// The elements are visited in logical row-major order through the strides, so this also
+    /// works on non-contiguous tensors, e.g. after transposing.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The function to apply to each element.
+    ///
+    /// # Returns
+    ///
+    /// A new tensor with the same shape and a standard layout.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let data: Vec<u8> = vec![0, 51, 102, 255];
+    /// let t = Tensor::<u8, 1, CpuAllocator>::from_shape_vec([4], data, CpuAllocator).unwrap();
+    ///
+    /// let t2 = t.map(|x| x as f32 / 255.0);
+    /// assert_eq!(t2.as_slice(), vec![0.0, 0.2, 0.4, 1.0]);
+    /// ```
+    pub fn map<U, F>(&self, f: F) -> Tensor<U, N, A>
+    where
+        T: Copy,
+        F: Fn(T) -> U,
+    {
+        self.view().map(f)
+    }
+
+    /// Apply a function to each element of the tensor, writing into an existing tensor.
+    ///
+    /// Both tensors are visited in logical row-major order through their strides.
+    ///
+    /// # Arguments
+    ///
+    /// * `dst` - The output tensor with the same shape.
+    /// * `f` - The function to apply to each element.
+    ///
+    /// # Errors
+    ///
+    /// If the shapes differ, an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let t = Tensor::<u8, 1, _>::from_shape_vec([3], vec![1, 2, 3], CpuAllocator).unwrap();
+    /// let mut dst = Tensor::<f32, 1, _>::zeros([3], CpuAllocator);
+    ///
+    /// t.map_into(&mut dst, |x| x as f32 * 0.5).unwrap();
+    /// assert_eq!(dst.as_slice(), &[0.5, 1.0, 1.5]);
+    /// ```
+    pub fn map_into<U, A2: TensorAllocator, F>(
+        &self,
+        dst: &mut Tensor<U, N, A2>,
+        f: F,
+    ) -> Result<(), TensorError>
+    where
+        T: Copy,
+        F: Fn(T) -> U,
+    {
+        if self.shape != dst.shape {
+            return Err(TensorError::DimensionMismatch(format!(
+                "Shapes {:?} and {:?} are not compatible for element-wise operations",
+                self.shape, dst.shape
+            )));
+        }
+
+        if self.strides == dst.strides {
+            dst.as_slice_mut()
+                .iter_mut()
+                .zip(self.as_slice())
+                .for_each(|(d, &s)| *d = f(s));
+            return Ok(());
+        }
+
+        for index in row_major_indices(self.shape) {
+            let i = self.get_iter_offset_unchecked(index);
+            let j = dst.get_iter_offset_unchecked(index);
+            dst.as_slice_mut()[j] = f(self.as_slice()[i]);
+        }
+
+        Ok(())
+    }
+
+    /// Checks if the tensor has a standard contiguous (row-major) memory layout.
+    ///
+    /// A standard layout means the tensor's data is stored contiguously in memory
+    /// following row-major order, where the rightmost dimension varies fastest.
+    /// This is important for performance in many operations and for interoperability
+    /// with other libraries.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the tensor has contiguous row-major layout, `false` otherwise.
+    ///
+    /// # When is a layout non-standard?
+    ///
+    /// - After dimension permutation (e.g., transpose)
+    /// - After certain slicing operations
+    /// - When strides have been manually modified
+    /// - When the tensor is a non-contiguous view
+    ///
+    /// # Examples
+    ///
+    /// Standard layout:
+    ///
+    /// ```rust
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let tensor = Tensor::<i32, 2, _>::from_shape_val([3, 4], 0, CpuAllocator);
+    /// assert!(tensor.is_standard_layout());
+    /// ```
+    ///
+    /// Non-standard layout after transpose:
+    ///
+    /// ```rust
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let data = vec![1, 2, 3, 4];
+    /// let tensor = Tensor::<i32, 2, _>::from_shape_vec([2, 2], data, CpuAllocator).unwrap();
+    ///
+    /// // After permutation, the memory layout is no longer contiguous
+    /// let mut transposed = tensor.permute_axes([1, 0]).as_contiguous();
+    /// transposed.strides = [1, 2]; // Transpose stride pattern
+    ///
+    /// // This would need to_standard_layout() to become contiguous again
+    /// ```
+    ///
+    /// Detecting non-standard layout:
+    ///
+    /// ```rust
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let data = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+    /// let mut tensor = Tensor::<i32, 3, _>::from_shape_vec([2, 2, 3], data, CpuAllocator).unwrap();
+    ///
+    /// // Manually corrupt the strides
+    /// tensor.strides = [10, 5, 1];
+    /// assert!(!tensor.is_standard_layout());
+    /// ```
+    pub fn is_standard_layout(&self) -> bool {
+        check_contiguous(self.shape, self.strides).is_ok()
+    }
+
+    /// Check that the tensor is contiguous before handing its pointer to external code.
+    ///
+    /// # Returns
+    ///
+    /// The tensor itself, so the check can be chained with the pointer accessors.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TensorError::NotContiguous`] with the shape and strides if the tensor is
+    /// not laid out contiguously in row-major order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let mut t = Tensor::<u8, 2, _>::from_shape_val([2, 3], 0, CpuAllocator);
+    /// let ptr = t.assert_contiguous().unwrap().as_ptr();
+    /// assert!(!ptr.is_null());
+    ///
+    /// t.strides = [1, 2];
+    /// assert!(t.assert_contiguous().is_err());
+    /// ```
+    pub fn assert_contiguous(&self) -> Result<&Self, TensorError> {
+        check_contiguous(self.shape, self.strides)?;
+        Ok(self)
+    }
+
+    /// Get the memory layout of the tensor to validate the assumptions of external code.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let t = Tensor::<u16, 2, _>::from_shape_val([2, 3], 0, CpuAllocator);
+    /// let layout = t.layout_info();
+    /// assert_eq!(layout.byte_strides, [6, 2]);
+    /// assert_eq!(layout.element_size, 2);
+    /// assert!(layout.alignment >= 2);
+    /// assert!(layout.is_contiguous);
+    /// ```
+    pub fn layout_info(&self) -> TensorLayout<N> {
+        let element_size = std::mem::size_of::<T>();
+        let address = self.as_ptr() as usize;
+        TensorLayout {
+            shape: self.shape,
+            strides: self.strides,
+            byte_strides: self.strides.map(|s| s * element_size),
+            // owned tensors always start at the beginning of their storage
+            byte_offset: 0,
+            element_size,
+            alignment: address & address.wrapping_neg(),
+            is_contiguous: self.is_standard_layout(),
+        }
+    }
+
+    /// Converts the tensor to standard contiguous (row-major) memory layout.
+    ///
+    /// If the tensor already has standard layout, returns a clone. Otherwise, creates a new
+    /// tensor with contiguous memory by copying elements in the correct order according to
+    /// the current shape and strides.
+    ///
+    /// This is essential for:
+    /// - Interfacing with libraries expecting contiguous memory
+    /// - Optimizing performance of certain operations
+    /// - Ensuring consistent memory layout after transformations
+    ///
+    /// # Arguments
+    ///
+    /// * `alloc` - The allocator to use for the new tensor if reallocation is needed
+    ///
+    /// # Returns
+    ///
+    /// A new tensor with standard layout on success.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TensorError::DimensionMismatch`] if the tensor's shape and data are inconsistent.
+    ///
+    /// # Performance
+    ///
+    /// This operation is O(1) if already standard layout (just clones), O(n) otherwise where
+    /// n is the number of elements.
+    ///
+    /// # Examples
+    ///
+    /// Converting after permutation:
+    ///
+    /// ```rust
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let data = vec![1, 2, 3, 4, 5, 6];
+    /// let tensor = Tensor::<i32, 2, _>::from_shape_vec([2, 3], data, CpuAllocator).unwrap();
+    ///
+    /// // Transpose creates non-contiguous layout
+    /// let transposed = tensor.permute_axes([1, 0]);
+    /// let owned = transposed.as_contiguous(); // Now we have an owned tensor
+    ///
+    /// // Convert to standard layout
+    /// let standard = owned.to_standard_layout(CpuAllocator).unwrap();
+    /// assert!(standard.is_standard_layout());
+    /// ```
+    ///
+    /// Fixing corrupted strides:
+    ///
+    /// ```rust
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let data = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+    /// let mut tensor = Tensor::<i32, 3, _>::from_shape_vec([2, 2, 3], data, CpuAllocator).unwrap();
+    ///
+    /// // Simulate non-standard strides
+    /// tensor.strides = [1, 6, 2];
+    /// assert!(!tensor.is_standard_layout());
+    ///
+    /// // Restore standard layout
+    /// let fixed = tensor.to_standard_layout(CpuAllocator).unwrap();
+    /// assert!(fixed.is_standard_layout());
+    /// assert_eq!(fixed.strides, [6, 3, 1]);
+    /// ```
+    ///
+    /// No-op for already standard tensors:
+    ///
+    /// ```rust
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let tensor = Tensor::<i32, 2, _>::from_shape_val([3, 4], 42, CpuAllocator);
+    /// assert!(tensor.is_standard_layout());
+    ///
+    /// // This will just clone since it's already standard
+    /// let standard = tensor.to_standard_layout(CpuAllocator).unwrap();
+    /// assert!(standard.is_standard_layout());
+    /// ```
+    pub fn to_standard_layout(&self, alloc: A) -> Result<Self, TensorError>
+    where
+        T: Clone + std::fmt::Debug,
+    {
+        if self.is_standard_layout() {
+            return Ok(self.clone());
+        }
+
+        let total_elems: usize = self.shape.iter().product();
+        let mut flat = Vec::with_capacity(total_elems);
+        let mut idx = [0; N];
+        let slice = self.storage.as_slice();
+
+        for _ in 0..total_elems {
+            let offset = idx
+                .iter()
+                .zip(self.strides.iter())
+                .map(|(&i, &s)| i * s)
+                .sum::<usize>();
+
+            flat.push(slice[offset].clone());
+
+            // increment index
+            for dim in (0..N).rev() {
+                idx[dim] += 1;
+                if idx[dim] < self.shape[dim] {
+                    break;
+                } else {
+                    idx[dim] = 0;
+                }
+            }
+        }
+
+        Tensor::from_shape_vec(self.shape, flat, alloc).map_err(|_| {
+            TensorError::DimensionMismatch(format!(
+                "Cannot construct tensor of shape {:?} with {:?} elements",
+                self.shape, total_elems,
+            ))
+        })
+    }
+
+    /// Cast the tensor to a new type.
+    ///
+    /// The elements are converted with [`CastFrom`]: floats are rounded to the nearest integer
+    /// and clamped to the range of integer types, with NaN mapped to 0, and integers are
+    /// clamped to the range of the new type instead of wrapping around.
+    ///
+    /// # Returns
+    ///
+    /// A new `Tensor` instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let data: Vec<u8> = vec![1, 2, 3, 4];
+    /// let t = Tensor::<u8, 1, CpuAllocator>::from_shape_vec([4], data, CpuAllocator).unwrap();
+    ///
+    /// let t2 = t.cast::<f32>();
+    /// assert_eq!(t2.as_slice(), vec![1.0, 2.0, 3.0, 4.0]);
+    ///
+    /// let data: Vec<f32> = vec![-1.0, 127.6, 256.0];
+    /// let heatmap = Tensor::<f32, 1, _>::from_shape_vec([3], data, CpuAllocator).unwrap();
+    /// assert_eq!(heatmap.cast::<u8>().as_slice(), vec![0, 128, 255]);
+    /// ```
+    pub fn cast<U>(&self) -> Tensor<U, N, CpuAllocator>
+    where
+        U: CastFrom<T>,
+        T: Copy,
+    {
+        let mut data: Vec<U> = Vec::with_capacity(self.storage.len());
+        self.as_slice().iter().for_each(|&x| {
+            data.push(U::cast_from(x));
+        });
+        let storage = TensorStorage::from_vec(data, CpuAllocator);
+        Tensor {
+            storage,
+            shape: self.shape,
+            strides: self.strides,
+        }
+    }
+
+    /// Perform an element-wise operation on two tensors.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The other tensor to perform the operation with.
+    /// * `op` - The operation to perform.
+    ///
+    /// # Returns
+    ///
+    /// A new `Tensor` instance.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let data1: Vec<u8> = vec![1, 2, 3, 4];
+    /// let t1 = Tensor::<u8, 1, CpuAllocator>::from_shape_vec([4], data1, CpuAllocator).unwrap();
+    ///
+    /// let data2: Vec<u8> = vec![1, 2, 3, 4];
+    /// let t2 = Tensor::<u8, 1, CpuAllocator>::from_shape_vec([4], data2, CpuAllocator).unwrap();
+    ///
+    /// let t3 = t1.element_wise_op(&t2, |a, b| *a + *b).unwrap();
+    /// assert_eq!(t3.as_slice(), vec![2, 4, 6, 8]);
+    ///
+    /// let t4 = t1.element_wise_op(&t2, |a, b| *a - *b).unwrap();
+    /// assert_eq!(t4.as_slice(), vec![0, 0, 0, 0]);
+    ///
+    /// let t5 = t1.element_wise_op(&t2, |a, b| *a * *b).unwrap();
+    /// assert_eq!(t5.as_slice(), vec![1, 4, 9, 16]);
+    ///
+    /// let t6 = t1.element_wise_op(&t2, |a, b| *a / *b).unwrap();
+    /// assert_eq!(t6.as_slice(), vec![1, 1, 1, 1]);
+    /// ```
+    pub fn element_wise_op<F>(
+        &self,
+        other: &Tensor<T, N, CpuAllocator>,
+        op: F,
+    ) -> Result<Tensor<T, N, CpuAllocator>, TensorError>
+    where
+        F: Fn(&T, &T) -> T,
+    {
+        if self.shape != other.shape {
+            return Err(TensorError::DimensionMismatch(format!(
+                "Shapes {:?} and {:?} are not compatible for element-wise operations",
+                self.shape, other.shape
+            )));
+        }
+
+        let data = self
+            .as_slice()
+            .iter()
+            .zip(other.as_slice().iter())
+            .map(|(a, b)| op(a, b))
+            .collect();
+
+        let storage = TensorStorage::from_vec(data, CpuAllocator);
+
+        Ok(Tensor {
+            storage,
+            shape: self.shape,
+            strides: self.strides,
+        })
+    }
+}
+
+impl<T, const N: usize, A: TensorAllocator> Tensor<T, N, A>
+where
+    T: Copy + std::ops::Mul<Output = T> + std::ops::Add<Output = T>,
+{
+    /// Compute `scale * x + shift` with a scale and a shift per channel.
+    ///
+    /// The scale and the shift are broadcast along all the dimensions except `axis`, which is
+    /// the channel axis, e.g. the last axis of an HWC image or the first axis of a CHW tensor.
+    /// This is how a batch normalization layer is applied at inference time.
+    ///
+    /// # Arguments
+    ///
+    /// * `scale` - The scale for each channel.
+    /// * `shift` - The shift for each channel.
+    /// * `axis` - The channel axis.
+    ///
+    /// # Returns
+    ///
+    /// A new `Tensor` with the same shape and a standard layout.
+    ///
+    /// # Errors
+    ///
+    /// If `axis` is out of bounds or the length of `scale` or `shift` differs from the number
+    /// of channels, an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let data: Vec<f32> = vec![1.0, 2.0, 3.0, 4.0];
+    /// let t = Tensor::<f32, 2, CpuAllocator>::from_shape_vec([2, 2], data, CpuAllocator).unwrap();
+    ///
+    /// let scale = Tensor::from_shape_vec([2], vec![2.0, 10.0], CpuAllocator).unwrap();
+    /// let shift = Tensor::from_shape_vec([2], vec![1.0, 0.0], CpuAllocator).unwrap();
+    ///
+    /// let t2 = t.affine_transform(&scale, &shift, 1).unwrap();
+    /// assert_eq!(t2.as_slice(), vec![3.0, 20.0, 7.0, 40.0]);
+    /// ```
+    pub fn affine_transform(
+        &self,
+        scale: &Tensor<T, 1, A>,
+        shift: &Tensor<T, 1, A>,
+        axis: usize,
+    ) -> Result<Tensor<T, N, A>, TensorError> {
+        if axis >= N {
+            return Err(TensorError::DimOutOfBounds(axis, N));
+        }
+
+        let channels = self.shape[axis];
+        if scale.shape[0] != channels || shift.shape[0] != channels {
+            return Err(TensorError::DimensionMismatch(format!(
+                "Scale {:?} and shift {:?} do not match the {channels} channels of axis {axis}",
+                scale.shape, shift.shape
+            )));
+        }
+
+        let (scale, shift) = (scale.as_slice(), shift.as_slice());
+        let data = row_major_indices(self.shape)
+            .map(|index| {
+                let c = index[axis];
+                scale[c] * *self.get_unchecked(index) + shift[c]
+            })
+            .collect();
+
+        Ok(Tensor {
+            storage: TensorStorage::from_vec(data, self.storage.alloc().clone()),
+            shape: self.shape,
+            strides: get_strides_from_shape(self.shape),
+        })
+    }
+}
+
+impl<T: Copy, const N: usize, A: TensorAllocator> Tensor<T, N, A> {
+    /// Add two tensors element-wise with NumPy-style broadcasting.
+    ///
+    /// The shape of `other` is aligned with the trailing dimensions of `self`, and the
+    /// dimensions of size 1 of either tensor are repeated to match the other one.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The tensor to add, with at most as many dimensions as `self`.
+    ///
+    /// # Returns
+    ///
+    /// A new `Tensor` with the broadcast shape, allocated with the allocator of `self`.
+    ///
+    /// # Errors
+    ///
+    /// If the shapes are not broadcast-compatible, an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let image = Tensor::<f32, 3, CpuAllocator>::zeros([1, 2, 3], CpuAllocator);
+    /// let bias = Tensor::<f32, 1, CpuAllocator>::from_shape_vec([3], vec![1.0, 2.0, 3.0], CpuAllocator).unwrap();
+    ///
+    /// let t = image.add(&bias).unwrap();
+    /// assert_eq!(t.shape, [1, 2, 3]);
+    /// assert_eq!(t.as_slice(), vec![1.0, 2.0, 3.0, 1.0, 2.0, 3.0]);
+    /// ```
+    pub fn add<const M: usize, A2: TensorAllocator>(
+        &self,
+        other: &Tensor<T, M, A2>,
+    ) -> Result<Tensor<T, N, A>, TensorError>
+    where
+        T: std::ops::Add<Output = T>,
+    {
+        self.broadcast_op(other, |a, b| a + b)
+    }
+
+    /// Subtract two tensors element-wise with NumPy-style broadcasting.
+    ///
+    /// See [`Tensor::add`] for the broadcasting rules.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The tensor to subtract, with at most as many dimensions as `self`.
+    ///
+    /// # Errors
+    ///
+    /// If the shapes are not broadcast-compatible, an error is returned.
+    pub fn sub<const M: usize, A2: TensorAllocator>(
+        &self,
+        other: &Tensor<T, M, A2>,
+    ) -> Result<Tensor<T, N, A>, TensorError>
+    where
+        T: std::ops::Sub<Output = T>,
+    {
+        self.broadcast_op(other, |a, b| a - b)
+    }
+
+    /// Multiply two tensors element-wise with NumPy-style broadcasting.
+    ///
+    /// See [`Tensor::add`] for the broadcasting rules.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The tensor to multiply by, with at most as many dimensions as `self`.
+    ///
+    /// # Errors
+    ///
+    /// If the shapes are not broadcast-compatible, an error is returned.
+    pub fn mul<const M: usize, A2: TensorAllocator>(
+        &self,
+        other: &Tensor<T, M, A2>,
+    ) -> Result<Tensor<T, N, A>, TensorError>
+    where
+        T: std::ops::Mul<Output = T>,
+    {
+        self.broadcast_op(other, |a, b| a * b)
+    }
+
+    /// Divide two tensors element-wise with NumPy-style broadcasting.
+    ///
+    /// See [`Tensor::add`] for the broadcasting rules.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The tensor to divide by, with at most as many dimensions as `self`.
+    ///
+    /// # Errors
+    ///
+    /// If the shapes are not broadcast-compatible, an error is returned.
+    pub fn div<const M: usize, A2: TensorAllocator>(
+        &self,
+        other: &Tensor<T, M, A2>,
+    ) -> Result<Tensor<T, N, A>, TensorError>
+    where
+        T: std::ops::Div<Output = T>,
+    {
+        self.broadcast_op(other, |a, b| a / b)
+    }
+
+    /// Add a tensor with the same shape in place.
+    ///
+    /// # Errors
+    ///
+    /// If the shapes differ, an error is returned.
+    pub fn add_assign<A2: TensorAllocator>(
+        &mut self,
+        other: &Tensor<T, N, A2>,
+    ) -> Result<(), TensorError>
+    where
+        T: std::ops::Add<Output = T>,
+    {
+        self.assign_op(other, |a, b| a + b)
+    }
+
+    /// Subtract a tensor with the same shape in place.
+    ///
+    /// # Errors
+    ///
+    /// If the shapes differ, an error is returned.
+    pub fn sub_assign<A2: TensorAllocator>(
+        &mut self,
+        other: &Tensor<T, N, A2>,
+    ) -> Result<(), TensorError>
+    where
+        T: std::ops::Sub<Output = T>,
+    {
+        self.assign_op(other, |a, b| a - b)
+    }
+
+    /// Multiply by a tensor with the same shape in place.
+    ///
+    /// # Errors
+    ///
+    /// If the shapes differ, an error is returned.
+    pub fn mul_assign<A2: TensorAllocator>(
+        &mut self,
+        other: &Tensor<T, N, A2>,
+    ) -> Result<(), TensorError>
+    where
+        T: std::ops::Mul<Output = T>,
+    {
+        self.assign_op(other, |a, b| a * b)
+    }
+
+    /// Divide by a tensor with the same shape in place.
+    ///
+    /// # Errors
+    ///
+    /// If the shapes differ, an error is returned.
+    pub fn div_assign<A2: TensorAllocator>(
+        &mut self,
+        other: &Tensor<T, N, A2>,
+    ) -> Result<(), TensorError>
+    where
+        T: std::ops::Div<Output = T>,
+    {
+        self.assign_op(other, |a, b| a / b)
+    }
+
+    /// Compare each element with a value, checking if it is greater.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to compare with.
+    ///
+    /// # Returns
+    ///
+    /// A boolean mask with the same shape.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let t = Tensor::<u8, 1, _>::from_shape_vec([3], vec![1, 5, 9], CpuAllocator).unwrap();
+    /// assert_eq!(t.gt(5).as_slice(), &[false, false, true]);
+    /// ```
+    pub fn gt(&self, value: T) -> Tensor<bool, N, A>
+    where
+        T: PartialOrd,
+    {
+        self.map(|x| x > value)
+    }
+
+    /// Compare each element with a value, checking if it is less.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value to compare with.
+    ///
+    /// # Returns
+    ///
+    /// A boolean mask with the same shape.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let t = Tensor::<u8, 1, _>::from_shape_vec([3], vec![1, 5, 9], CpuAllocator).unwrap();
+    /// assert_eq!(t.lt(5).as_slice(), &[true, false, false]);
+    /// ```
+    pub fn lt(&self, value: T) -> Tensor<bool, N, A>
+    where
+        T: PartialOrd,
+    {
+        self.map(|x| x < value)
+    }
+
+    /// Clamp each element of the tensor into the range `[min, max]`.
+    ///
+    /// The elements are visited through the strides, so this also works on non-contiguous
+    /// tensors. NaN elements stay NaN, as in [`f32::clamp`].
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - The lower bound of the range.
+    /// * `max` - The upper bound of the range.
+    ///
+    /// # Returns
+    ///
+    /// A new tensor with the same shape and a standard layout.
+    ///
+    /// # Errors
+    ///
+    /// If `min` is greater than `max`, or they are not comparable, an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let t = Tensor::<f32, 1, _>::from_shape_vec([3], vec![-0.5, 0.5, 1.5], CpuAllocator).unwrap();
+    /// assert_eq!(t.clamp(0.0, 1.0).unwrap().as_slice(), &[0.0, 0.5, 1.0]);
+    /// ```
+    pub fn clamp(&self, min: T, max: T) -> Result<Tensor<T, N, A>, TensorError>
+    where
+        T: PartialOrd,
+    {
+        self.view().clamp(min, max)
+    }
+
+    /// Clamp each element of the tensor into the range `[min, max]` in place.
+    ///
+    /// See [`Tensor::clamp`] for the handling of NaN.
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - The lower bound of the range.
+    /// * `max` - The upper bound of the range.
+    ///
+    /// # Errors
+    ///
+    /// If `min` is greater than `max`, or they are not comparable, an error is returned.
+    pub fn clamp_mut(&mut self, min: T, max: T) -> Result<(), TensorError>
+    where
+        T: PartialOrd,
+    {
+        check_clamp_range(min, max)?;
+        self.indexed_iter_mut()
+            .for_each(|(_, v)| *v = clamp_value(*v, min, max));
+        Ok(())
+    }
+
+    /// Compute the absolute value of each element of the tensor.
+    ///
+    /// # Returns
+    ///
+    /// A new tensor with the same shape and a standard layout.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let t = Tensor::<i16, 1, _>::from_shape_vec([3], vec![-3, 0, 2], CpuAllocator).unwrap();
+    /// assert_eq!(t.abs().as_slice(), &[3, 0, 2]);
+    /// ```
+    pub fn abs(&self) -> Tensor<T, N, A>
+    where
+        T: num_traits::Signed,
+    {
+        self.view().abs()
+    }
+
+    /// Select between two values depending on a boolean mask.
+    ///
+    /// This is a cheaper alternative to selecting between two full tensors when the branches
+    /// are constant, e.g. to binarize an image.
+    ///
+    /// # Arguments
+    ///
+    /// * `cond` - The mask. It does not need to be contiguous.
+    /// * `if_true` - The value where the mask is `true`.
+    /// * `if_false` - The value where the mask is `false`.
+    ///
+    /// # Returns
+    ///
+    /// A new `Tensor` with the shape of the mask and a standard layout.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let gray = Tensor::<u8, 2, _>::from_shape_vec([2, 2], vec![10, 200, 130, 50], CpuAllocator)
+    ///     .unwrap();
+    ///
+    /// let binary = Tensor::where_scalar(&gray.gt(128), 255u8, 0);
+    /// assert_eq!(binary.as_slice(), &[0, 255, 255, 0]);
+    /// ```
+    pub fn where_scalar(cond: &Tensor<bool, N, A>, if_true: T, if_false: T) -> Tensor<T, N, A> {
+        let select = |c: bool| if c { if_true } else { if_false };
+
+        let data = if cond.is_standard_layout() {
+            cond.as_slice().iter().map(|&c| select(c)).collect()
+        } else {
+            let slice = cond.as_slice();
+            row_major_indices(cond.shape)
+                .map(|index| select(slice[cond.get_iter_offset_unchecked(index)]))
+                .collect()
+        };
+
+        Tensor {
+            storage: TensorStorage::from_vec(data, cond.storage.alloc().clone()),
+            shape: cond.shape,
+            strides: get_strides_from_shape(cond.shape),
+        }
+    }
+
+    /// Sum the elements along the given axis, keeping it with size 1.
+    ///
+    /// Keeping the reduced axis allows broadcasting the result against the input, e.g. to
+    /// subtract the sum of each row. Use [`Tensor::sum_axis`] to drop the axis instead.
+    ///
+    /// # Arguments
+    ///
+    /// * `axis` - The axis to reduce.
+    ///
+    /// # Returns
+    ///
+    /// A new `Tensor` with the same shape except for the reduced axis, which has size 1.
+    ///
+    /// # Errors
+    ///
+    /// If the axis is out of bounds, an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let data: Vec<u32> = vec![1, 2, 3, 4, 5, 6];
+    /// let t = Tensor::<u32, 2, CpuAllocator>::from_shape_vec([2, 3], data, CpuAllocator).unwrap();
+    ///
+    /// let rows = t.sum_axis_keepdim(1).unwrap();
+    /// assert_eq!(rows.shape, [2, 1]);
+    /// assert_eq!(rows.as_slice(), vec![6, 15]);
+    /// ```
+    pub fn sum_axis_keepdim(&self, axis: usize) -> Result<Tensor<T, N, A>, TensorError>
+    where
+        T: std::ops::Add<Output = T> + num_traits::Zero,
+    {
+        self.reduce_axis(axis, Some(T::zero()), |acc, x| acc + x)
+    }
+
+    /// Sum the elements along the given axis, dropping it.
+    ///
+    /// The rank `M` of the result must be `N - 1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `axis` - The axis to reduce.
+    ///
+    /// # Returns
+    ///
+    /// A new `Tensor` with the shape of the tensor without the reduced axis.
+    ///
+    /// # Errors
+    ///
+    /// If the axis is out of bounds or `M` is not `N - 1`, an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let data: Vec<u32> = vec![1, 2, 3, 4, 5, 6];
+    /// let t = Tensor::<u32, 2, CpuAllocator>::from_shape_vec([2, 3], data, CpuAllocator).unwrap();
+    ///
+    /// let rows = t.sum_axis::<1>(1).unwrap();
+    /// assert_eq!(rows.shape, [2]);
+    /// assert_eq!(rows.as_slice(), vec![6, 15]);
+    /// ```
+    pub fn sum_axis<const M: usize>(&self, axis: usize) -> Result<Tensor<T, M, A>, TensorError>
+    where
+        T: std::ops::Add<Output = T> + num_traits::Zero,
+    {
+        self.sum_axis_keepdim(axis)?.squeeze(axis)
+    }
+
+    /// Average the elements along the given axis, keeping it with size 1.
+    ///
+    /// Only floating point tensors are supported, cast integer tensors first with
+    /// [`Tensor::cast`]. The mean of an empty axis is `NaN`.
+    ///
+    /// # Arguments
+    ///
+    /// * `axis` - The axis to reduce.
+    ///
+    /// # Returns
+    ///
+    /// A new `Tensor` with the same shape except for the reduced axis, which has size 1.
+    ///
+    /// # Errors
+    ///
+    /// If the axis is out of bounds, an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let data: Vec<f32> = vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0];
+    /// let t = Tensor::<f32, 2, CpuAllocator>::from_shape_vec([2, 3], data, CpuAllocator).unwrap();
+    ///
+    /// let cols = t.mean_axis_keepdim(0).unwrap();
+    /// assert_eq!(cols.shape, [1, 3]);
+    /// assert_eq!(cols.as_slice(), vec![2.5, 3.5, 4.5]);
+    /// ```
+    pub fn mean_axis_keepdim(&self, axis: usize) -> Result<Tensor<T, N, A>, TensorError>
+    where
+        T: num_traits::Float,
+    {
+        let mut sum = self.sum_axis_keepdim(axis)?;
+        let len =
+            <T as num_traits::NumCast>::from(self.shape[axis]).ok_or(TensorError::CastError)?;
+        sum.as_slice_mut().iter_mut().for_each(|x| *x = *x / len);
+        Ok(sum)
+    }
+
+    /// Average the elements along the given axis, dropping it.
+    ///
+    /// See [`Tensor::mean_axis_keepdim`] for the supported types. The rank `M` of the result
+    /// must be `N - 1`.
+    ///
+    /// # Errors
+    ///
+    /// If the axis is out of bounds or `M` is not `N - 1`, an error is returned.
+    pub fn mean_axis<const M: usize>(&self, axis: usize) -> Result<Tensor<T, M, A>, TensorError>
+    where
+        T: num_traits::Float,
+    {
+        self.mean_axis_keepdim(axis)?.squeeze(axis)
+    }
+
+    /// Get the maximum of the elements along the given axis, keeping it with size 1.
+    ///
+    /// Values that are not comparable (e.g. `NaN`) are skipped unless they are the first
+    /// element of the axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `axis` - The axis to reduce.
+    ///
+    /// # Returns
+    ///
+    /// A new `Tensor` with the same shape except for the reduced axis, which has size 1.
+    ///
+    /// # Errors
+    ///
+    /// If the axis is out of bounds or empty, an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let data: Vec<u8> = vec![1, 7, 3, 9, 5, 6];
+    /// let t = Tensor::<u8, 2, CpuAllocator>::from_shape_vec([2, 3], data, CpuAllocator).unwrap();
+    ///
+    /// assert_eq!(t.max_axis_keepdim(1).unwrap().shape, [2, 1]);
+    /// assert_eq!(t.max_axis::<1>(1).unwrap().as_slice(), vec![7, 9]);
+    /// ```
+    pub fn max_axis_keepdim(&self, axis: usize) -> Result<Tensor<T, N, A>, TensorError>
+    where
+        T: PartialOrd,
+    {
+        self.reduce_axis(axis, None, |acc, x| if x > acc { x } else { acc })
+    }
+
+    /// Get the maximum of the elements along the given axis, dropping it.
+    ///
+    /// See [`Tensor::max_axis_keepdim`] for the handling of incomparable values. The rank `M`
+    /// of the result must be `N - 1`.
+    ///
+    /// # Errors
+    ///
+    /// If the axis is out of bounds or empty, or `M` is not `N - 1`, an error is returned.
+    pub fn max_axis<const M: usize>(&self, axis: usize) -> Result<Tensor<T, M, A>, TensorError>
+    where
+        T: PartialOrd,
+    {
+        self.max_axis_keepdim(axis)?.squeeze(axis)
+    }
+
+    /// Get the minimum of the elements along the given axis, keeping it with size 1.
+    ///
+    /// Values that are not comparable (e.g. `NaN`) are skipped unless they are the first
+    /// element of the axis.
+    ///
+    /// # Arguments
+    ///
+    /// * `axis` - The axis to reduce.
+    ///
+    /// # Returns
+    ///
+    /// A new `Tensor` with the same shape except for the reduced axis, which has size 1.
+    ///
+    /// # Errors
+    ///
+    /// If the axis is out of bounds or empty, an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let data: Vec<u8> = vec![1, 7, 3, 9, 5, 6];
+    /// let t = Tensor::<u8, 2, CpuAllocator>::from_shape_vec([2, 3], data, CpuAllocator).unwrap();
+    ///
+    /// assert_eq!(t.min_axis_keepdim(0).unwrap().shape, [1, 3]);
+    /// assert_eq!(t.min_axis::<1>(0).unwrap().as_slice(), vec![1, 5, 3]);
+    /// ```
+    pub fn min_axis_keepdim(&self, axis: usize) -> Result<Tensor<T, N, A>, TensorError>
+    where
+        T: PartialOrd,
+    {
+        self.reduce_axis(axis, None, |acc, x| if x < acc { x } else { acc })
+    }
+
+    /// Get the minimum of the elements along the given axis, dropping it.
+    ///
+    /// See [`Tensor::min_axis_keepdim`] for the handling of incomparable values. The rank `M`
+    /// of the result must be `N - 1`.
+    ///
+    /// # Errors
+    ///
+    /// If the axis is out of bounds or empty, or `M` is not `N - 1`, an error is returned.
+    pub fn min_axis<const M: usize>(&self, axis: usize) -> Result<Tensor<T, M, A>, TensorError>
+    where
+        T: PartialOrd,
+    {
+        self.min_axis_keepdim(axis)?.squeeze(axis)
+    }
+
+    /// Get the index of the maximum along the given axis, dropping it.
+    ///
+    /// Ties resolve to the lowest index. Values that are not comparable (e.g. `NaN`) are
+    /// skipped unless they are the first element of the axis, as in [`Tensor::max_axis`]. The
+    /// rank `M` of the result must be `N - 1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `axis` - The axis to reduce.
+    ///
+    /// # Returns
+    ///
+    /// A new `Tensor` with the indices along the axis of the maximum of each reduced slice.
+    ///
+    /// # Errors
+    ///
+    /// If the axis is out of bounds or empty, or `M` is not `N - 1`, an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let data: Vec<f32> = vec![0.1, 0.7, 0.2, 0.5, 0.5, 0.0];
+    /// let logits = Tensor::<f32, 2, CpuAllocator>::from_shape_vec([2, 3], data, CpuAllocator).unwrap();
+    ///
+    /// assert_eq!(logits.argmax_axis::<1>(1).unwrap().as_slice(), &[1, 0]);
+    /// ```
+    pub fn argmax_axis<const M: usize>(
+        &self,
+        axis: usize,
+    ) -> Result<Tensor<usize, M, A>, TensorError>
+    where
+        T: PartialOrd,
+    {
+        self.arg_reduce_axis(axis, |candidate, current| candidate > current)?
+            .squeeze(axis)
+    }
+
+    /// Get the index of the minimum along the given axis, dropping it.
+    ///
+    /// Ties resolve to the lowest index. Values that are not comparable (e.g. `NaN`) are
+    /// skipped unless they are the first element of the axis, as in [`Tensor::min_axis`]. The
+    /// rank `M` of the result must be `N - 1`.
+    ///
+    /// # Arguments
+    ///
+    /// * `axis` - The axis to reduce.
+    ///
+    /// # Returns
+    ///
+    /// A new `Tensor` with the indices along the axis of the minimum of each reduced slice.
+    ///
+    /// # Errors
+    ///
+    /// If the axis is out of bounds or empty, or `M` is not `N - 1`, an error is returned.
+    pub fn argmin_axis<const M: usize>(
+        &self,
+        axis: usize,
+    ) -> Result<Tensor<usize, M, A>, TensorError>
+    where
+        T: PartialOrd,
+    {
+        self.arg_reduce_axis(axis, |candidate, current| candidate < current)?
+            .squeeze(axis)
+    }
+
+    /// Find the first index along `axis` that is not beaten by a later element.
+    fn arg_reduce_axis<F>(
+        &self,
+        axis: usize,
+        is_better: F,
+    ) -> Result<Tensor<usize, N, A>, TensorError>
+    where
+        F: Fn(&T, &T) -> bool,
+    {
+        if axis >= N {
+            return Err(TensorError::DimOutOfBounds(axis, N));
+        }
+
+        let len = self.shape[axis];
+        if len == 0 {
+            return Err(TensorError::UnsupportedOperation(format!(
+                "Cannot reduce the empty axis {axis} of a tensor with shape {:?}",
+                self.shape
+            )));
+        }
+
+        // iterate over all the indices with a zero coordinate along the axis
+        let mut shape = self.shape;
+        shape[axis] = 1;
+
+        let data = row_major_indices(shape)
+            .map(|mut index| {
+                let mut best = (0, self.get_unchecked(index));
+                for i in 1..len {
+                    index[axis] = i;
+                    let candidate = self.get_unchecked(index);
+                    if is_better(candidate, best.1) {
+                        best = (i, candidate);
+                    }
+                }
+                best.0
+            })
+            .collect();
+
+        Ok(Tensor {
+            storage: TensorStorage::from_vec(data, self.storage.alloc().clone()),
+            shape,
+            strides: get_strides_from_shape(shape),
+        })
+    }
+
+    /// Fold the elements along `axis` starting from `init`, or from the first element if `None`.
+    fn reduce_axis<F>(
+        &self,
+        axis: usize,
+        init: Option<T>,
+        f: F,
+    ) -> Result<Tensor<T, N, A>, TensorError>
+    where
+        F: Fn(T, T) -> T,
+    {
+        if axis >= N {
+            return Err(TensorError::DimOutOfBounds(axis, N));
+        }
+
+        let (len, stride) = (self.shape[axis], self.strides[axis]);
+        if len == 0 && init.is_none() {
+            return Err(TensorError::UnsupportedOperation(format!(
+                "Cannot reduce the empty axis {axis} of a tensor with shape {:?}",
+                self.shape
+            )));
+        }
+
+        // iterate over all the indices with a zero coordinate along the axis
+        let mut shape = self.shape;
+        shape[axis] = 1;
+
+        let slice = self.as_slice();
+        let data = row_major_indices(shape)
+            .map(|index| {
+                let base = self.get_iter_offset_unchecked(index);
+                let (mut acc, start) = match init {
+                    Some(init) => (init, 0),
+                    None => (slice[base], 1),
+                };
+                for i in start..len {
+                    acc = f(acc, slice[base + i * stride]);
+                }
+                acc
+            })
+            .collect();
+
+        Ok(Tensor {
+            storage: TensorStorage::from_vec(data, self.storage.alloc().clone()),
+            shape,
+            strides: get_strides_from_shape(shape),
+        })
+    }
+
+    pub(crate) fn broadcast_op<const M: usize, A2: TensorAllocator>(
+        &self,
+        other: &Tensor<T, M, A2>,
+        op: impl Fn(T, T) -> T,
+    ) -> Result<Tensor<T, N, A>, TensorError> {
+        let incompatible = || {
+            TensorError::DimensionMismatch(format!(
+                "Shapes {:?} and {:?} are not broadcast-compatible",
+                self.shape, other.shape
+            ))
+        };
+        if M > N {
+            return Err(incompatible());
+        }
+
+        // align the dimensions of `other` to the right, the missing ones have size 1
+        let lead = N - M;
+        let mut other_shape = [1; N];
+        let mut other_strides = [0; N];
+        other_shape[lead..].copy_from_slice(&other.shape);
+        other_strides[lead..].copy_from_slice(&other.strides);
+
+        let mut shape = self.shape;
+        for (dim, &other_dim) in shape.iter_mut().zip(other_shape.iter()) {
+            if *dim == 1 {
+                *dim = other_dim;
+            } else if other_dim != 1 && other_dim != *dim {
+                return Err(incompatible());
+            }
+        }
+
+        // the broadcast dimensions do not move along the data
+        let broadcast_strides = |shape_in: &[usize; N], strides: &[usize; N]| {
+            let mut out = *strides;
+            for (s, (&dim_in, &dim)) in out.iter_mut().zip(shape_in.iter().zip(shape.iter())) {
+                if dim_in != dim {
+                    *s = 0;
+                }
+            }
+            out
+        };
+        let self_strides = broadcast_strides(&self.shape, &self.strides);
+        let other_strides = broadcast_strides(&other_shape, &other_strides);
+
+        let (lhs, rhs) = (self.as_slice(), other.as_slice());
+        let data = row_major_indices(shape)
+            .map(|index| {
+                let offset = |strides: &[usize; N]| -> usize {
+                    index.iter().zip(strides.iter()).map(|(i, s)| i * s).sum()
+                };
+                op(lhs[offset(&self_strides)], rhs[offset(&other_strides)])
+            })
+            .collect();
+
+        Ok(Tensor {
+            storage: TensorStorage::from_vec(data, self.storage.alloc().clone()),
+            shape,
+            strides: get_strides_from_shape(shape),
+        })
+    }
+
+    fn assign_op<A2: TensorAllocator>(
+        &mut self,
+        other: &Tensor<T, N, A2>,
+        op: impl Fn(T, T) -> T,
+    ) -> Result<(), TensorError> {
+        if self.shape != other.shape {
+            return Err(TensorError::DimensionMismatch(format!(
+                "Shapes {:?} and {:?} are not compatible for element-wise operations",
+                self.shape, other.shape
+            )));
+        }
+
+        if self.strides == other.strides {
+            self.as_slice_mut()
+                .iter_mut()
+                .zip(other.as_slice())
+                .for_each(|(a, &b)| *a = op(*a, b));
+            return Ok(());
+        }
+
+        for index in row_major_indices(self.shape) {
+            let i = self.get_iter_offset_unchecked(index);
+            let j = other.get_iter_offset_unchecked(index);
+            let rhs = other.as_slice()[j];
+            let lhs = &mut self.as_slice_mut()[i];
+            *lhs = op(*lhs, rhs);
+        }
+
+        Ok(())
+    }
+}
+
+impl<T: num_traits::Float, const N: usize, A: TensorAllocator> Tensor<T, N, A> {
+    /// Check whether two tensors are equal within a tolerance, as NumPy's `allclose`.
+    ///
+    /// The elements `a` of `self` and `b` of `other` are close when
+    /// `|a - b| <= atol + rtol * |b|`, so the relative tolerance is scaled by `other`. The
+    /// infinities are close to the infinities of the same sign only, and NaN is never close
+    /// to anything; see [`Tensor::allclose_checked`] to compare NaN as equal.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The tensor to compare with, e.g. the expected values.
+    /// * `rtol` - The relative tolerance.
+    /// * `atol` - The absolute tolerance.
+    ///
+    /// # Returns
+    ///
+    /// Whether all the elements are close, or `false` if the shapes differ.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let a = Tensor::<f32, 1, _>::from_shape_vec([2], vec![1.0, 100.0], CpuAllocator).unwrap();
+    /// let b = Tensor::<f32, 1, _>::from_shape_vec([2], vec![1.0 + 1e-6, 100.0001], CpuAllocator).unwrap();
+    ///
+    /// assert!(a.allclose(&b, 1e-5, 1e-8));
+    /// assert!(!a.allclose(&b, 0.0, 1e-8));
+    /// ```
+    pub fn allclose<A2: TensorAllocator>(
+        &self,
+        other: &Tensor<T, N, A2>,
+        rtol: T,
+        atol: T,
+    ) -> bool {
+        self.allclose_checked(other, rtol, atol, false)
+            .unwrap_or(false)
+    }
+
+    /// Check whether two tensors are equal within a tolerance, failing if the shapes differ.
+    ///
+    /// See [`Tensor::allclose`] for the tolerance. The elements are compared in logical
+    /// order, so the tensors may have different strides.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The tensor to compare with, e.g. the expected values.
+    /// * `rtol` - The relative tolerance.
+    /// * `atol` - The absolute tolerance.
+    /// * `equal_nan` - Whether NaN elements at the same position are close.
+    ///
+    /// # Returns
+    ///
+    /// Whether all the elements are close.
+    ///
+    /// # Errors
+    ///
+    /// If the shapes of the tensors differ, an error is returned.
+    pub fn allclose_checked<A2: TensorAllocator>(
+        &self,
+        other: &Tensor<T, N, A2>,
+        rtol: T,
+        atol: T,
+        equal_nan: bool,
+    ) -> Result<bool, TensorError> {
+        if self.shape != other.shape {
+            return Err(TensorError::DimensionMismatch(format!(
+                "Cannot compare a tensor with shape {:?} to a tensor with shape {:?}",
+                self.shape, other.shape
+            )));
+        }
+
+        Ok(row_major_indices(self.shape).all(|index| {
+            let (a, b) = (*self.get_unchecked(index), *other.get_unchecked(index));
+            if a.is_nan() || b.is_nan() {
+                equal_nan && a.is_nan() && b.is_nan()
+            } else if a.is_infinite() || b.is_infinite() {
+                a == b
+            } else {
+                (a - b).abs() <= atol + rtol * b.abs()
+            }
+        }))
+    }
+}
+
+impl<const N: usize, A: TensorAllocator> Tensor<f32, N, A> {
+    /// Apply the softmax function to the slices along the given axis.
+    ///
+    /// The maximum of each slice is subtracted before exponentiating, so large inputs do not
+    /// overflow.
+    ///
+    /// # Arguments
+    ///
+    /// * `axis` - The axis along which the values sum to one.
+    ///
+    /// # Returns
+    ///
+    /// A new `Tensor` with the same shape.
+    ///
+    /// # Errors
+    ///
+    /// If the axis is out of bounds, an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let data: Vec<f32> = vec![0.0, 0.0, 1000.0, 1000.0];
+    /// let t = Tensor::<f32, 2, CpuAllocator>::from_shape_vec([2, 2], data, CpuAllocator).unwrap();
+    ///
+    /// let t2 = t.softmax_axis(1).unwrap();
+    /// assert_eq!(t2.as_slice(), vec![0.5, 0.5, 0.5, 0.5]);
+    /// ```
+    pub fn softmax_axis(&self, axis: usize) -> Result<Tensor<f32, N, A>, TensorError> {
+        self.normalize_along_axis(axis, |lane| {
+            let max = lane.iter().fold(f32::NEG_INFINITY, |acc, &x| acc.max(x));
+            lane.iter_mut().for_each(|x| *x = (*x - max).exp());
+            let sum = lane.iter().sum::<f32>();
+            lane.iter_mut().for_each(|x| *x /= sum);
+        })
+    }
+
+    /// Divide the slices along the given axis by their L1 norm.
+    ///
+    /// Slices with a zero norm are left unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `axis` - The axis along which the absolute values sum to one.
+    ///
+    /// # Returns
+    ///
+    /// A new `Tensor` with the same shape.
+    ///
+    /// # Errors
+    ///
+    /// If the axis is out of bounds, an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let data: Vec<f32> = vec![1.0, 2.0, 3.0, 6.0];
+    /// let t = Tensor::<f32, 2, CpuAllocator>::from_shape_vec([2, 2], data, CpuAllocator).unwrap();
+    ///
+    /// let t2 = t.normalize_l1_axis(0).unwrap();
+    /// assert_eq!(t2.as_slice(), vec![0.25, 0.25, 0.75, 0.75]);
+    /// ```
+    pub fn normalize_l1_axis(&self, axis: usize) -> Result<Tensor<f32, N, A>, TensorError> {
+        self.normalize_along_axis(axis, |lane| {
+            let norm = lane.iter().map(|x| x.abs()).sum::<f32>();
+            if norm > 0.0 {
+                lane.iter_mut().for_each(|x| *x /= norm);
+            }
+        })
+    }
+
+    /// Divide the slices along the given axis by their L2 norm.
+    ///
+    /// Slices with a zero norm are left unchanged.
+    ///
+    /// # Arguments
+    ///
+    /// * `axis` - The axis along which the slices get unit length.
+    ///
+    /// # Returns
+    ///
+    /// A new `Tensor` with the same shape.
+    ///
+    /// # Errors
+    ///
+    /// If the axis is out of bounds, an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let data: Vec<f32> = vec![3.0, 4.0, 0.0, 0.0];
+    /// let t = Tensor::<f32, 2, CpuAllocator>::from_shape_vec([2, 2], data, CpuAllocator).unwrap();
+    ///
+    /// let t2 = t.normalize_l2_axis(1).unwrap();
+    /// assert_eq!(t2.as_slice(), vec![0.6, 0.8, 0.0, 0.0]);
+    /// ```
+    pub fn normalize_l2_axis(&self, axis: usize) -> Result<Tensor<f32, N, A>, TensorError> {
+        self.normalize_along_axis(axis, |lane| {
+            let norm = lane.iter().map(|x| x * x).sum::<f32>().sqrt();
+            if norm > 0.0 {
+                lane.iter_mut().for_each(|x| *x /= norm);
+            }
+        })
+    }
+
+    /// Apply `f` to a copy of every slice along `axis` and write the result back.
+    fn normalize_along_axis<F>(&self, axis: usize, f: F) -> Result<Tensor<f32, N, A>, TensorError>
+    where
+        F: Fn(&mut [f32]),
+    {
+        if axis >= N {
+            return Err(TensorError::DimOutOfBounds(axis, N));
+        }
+
+        let mut data = self.as_slice().to_vec();
+        let (len, stride) = (self.shape[axis], self.strides[axis]);
+
+        // iterate over all the indices with a zero coordinate along the axis, an empty axis
+        // has no lanes
+        let mut outer_shape = self.shape;
+        outer_shape[axis] = len.min(1);
+
+        let mut lane = vec![0.0; len];
+        for index in row_major_indices(outer_shape) {
+            let base = self.get_iter_offset_unchecked(index);
+            lane.iter_mut()
+                .enumerate()
+                .for_each(|(i, x)| *x = data[base + i * stride]);
+            f(&mut lane);
+            lane.iter()
+                .enumerate()
+                .for_each(|(i, &x)| data[base + i * stride] = x);
+        }
+
+        Ok(Tensor {
+            storage: TensorStorage::from_vec(data, self.storage.alloc().clone()),
+            shape: self.shape,
+            strides: self.strides,
+        })
+    }
+}
+
+impl<T, A: TensorAllocator> Tensor<T, 2, A> {
+    /// Compute the matrix product with another matrix.
+    ///
+    /// # Arguments
+    ///
+    /// * `other` - The right matrix with shape (K, N). It does not need to be contiguous.
+    ///
+    /// # Returns
+    ///
+    /// A new `Tensor` with shape (M, N), where (M, K) is the shape of `self`.
+    ///
+    /// # Errors
+    ///
+    /// If the inner dimensions of the matrices don't match, an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let a = Tensor::<i32, 2, _>::from_shape_vec([1, 2], vec![1, 2], CpuAllocator).unwrap();
+    /// let b = Tensor::<i32, 2, _>::from_shape_vec([2, 2], vec![3, 4, 5, 6], CpuAllocator).unwrap();
+    ///
+    /// let c = a.matmul(&b).unwrap();
+    /// assert_eq!(c.shape, [1, 2]);
+    /// assert_eq!(c.as_slice(), &[13, 16]);
+    /// ```
+    pub fn matmul(&self, other: &Tensor<T, 2, A>) -> Result<Tensor<T, 2, A>, TensorError>
+    where
+        T: Copy + num_traits::Zero + std::ops::Add<Output = T> + std::ops::Mul<Output = T>,
+    {
+        let [m, k] = self.shape;
+        let [k2, n] = other.shape;
+        if k != k2 {
+            return Err(TensorError::IncompatibleShape(k, k2));
+        }
+
+        let mut data = vec![T::zero(); m * n];
+        gemm(
+            (self.as_slice(), self.strides),
+            (other.as_slice(), other.strides),
+            &mut data,
+            [m, k, n],
+        );
+
+        let shape = [m, n];
+        Ok(Tensor {
+            storage: TensorStorage::from_vec(data, self.storage.alloc().clone()),
+            shape,
+            strides: get_strides_from_shape(shape),
+        })
+    }
+}
+
+impl<T: Clone, const N: usize, const ALIGN: usize> Tensor<T, N, AlignedCpuAllocator<ALIGN>> {
+    /// Create a new `Tensor` filled with a value, with its memory aligned to `ALIGN` bytes.
+    ///
+    /// Unlike [`Tensor::from_shape_val`], the memory is allocated through the allocator, so the
+    /// base pointer of the tensor is aligned to at least `ALIGN` bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `shape` - An array containing the shape of the tensor.
+    /// * `value` - The value to fill the tensor with.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the layout is invalid or the allocation fails.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::CpuTensorAligned32;
+    ///
+    /// let t = CpuTensorAligned32::<u8, 2>::from_size_val_aligned([2, 3], 7).unwrap();
+    /// assert_eq!(t.as_slice(), &[7; 6]);
+    /// assert_eq!(t.as_ptr() as usize % 32, 0);
+    /// ```
+    pub fn from_size_val_aligned(shape: [usize; N], value: T) -> Result<Self, TensorError> {
+        let numel = shape.iter().product::<usize>();
+        let alloc = AlignedCpuAllocator::<ALIGN>;
+        let layout = AlignedCpuAllocator::<ALIGN>::aligned_layout(
+            Layout::array::<T>(numel).map_err(TensorAllocatorError::LayoutError)?,
+        )?;
+
+        let ptr = if layout.size() == 0 {
+            // zero-sized storages are never deallocated, so keep the pointer aligned only
+            NonNull::new(layout.align() as *mut T).ok_or(TensorAllocatorError::NullPointer)?
+        } else {
+            let ptr = alloc.alloc(layout)? as *mut T;
+            for i in 0..numel {
+                // SAFETY: the memory was allocated for `numel` elements.
+                unsafe { ptr.add(i).write(value.clone()) };
+            }
+            // SAFETY: the allocator returns an error instead of a null pointer.
+            unsafe { NonNull::new_unchecked(ptr) }
+        };
+
+        Ok(Self {
+            storage: TensorStorage {
+                ptr,
+                len: numel * std::mem::size_of::<T>(),
+                layout,
+                alloc,
+            },
+            shape,
+            strides: get_strides_from_shape(shape),
+        })
+    }
+}
+
+/// Multiply a (M, K) and a (K, N) strided matrix into a contiguous (M, N) output.
+///
+/// This is the single kernel behind [`Tensor::matmul`] and the batched products of other
+/// crates, so an optimized or BLAS backend can replace it without changing the callers.
+///
+/// The products are accumulated into `out`, which must be zero-initialized.
+///
+/// # Arguments
+///
+/// * `a` - The data and the strides of the left matrix.
+/// * `b` - The data and the strides of the right matrix.
+/// * `out` - The row-major output with M * N elements.
+/// * `dims` - The dimensions M, K and N.
+///
+/// # Panics
+///
+/// Panics if a slice is too short for its dimensions and strides.
+pub fn gemm<T>(
+    (a, a_strides): (&[T], [usize; 2]),
+    (b, b_strides): (&[T], [usize; 2]),
+    out: &mut [T],
+    [m, k, n]: [usize; 3],
+) where
+    T: Copy + std::ops::Add<Output = T> + std::ops::Mul<Output = T>,
+{
+    for i in 0..m {
+        let out_row = &mut out[i * n..(i + 1) * n];
+        for l in 0..k {
+            let a_il = a[i * a_strides[0] + l * a_strides[1]];
+            for (j, out) in out_row.iter_mut().enumerate() {
+                *out = *out + a_il * b[l * b_strides[0] + j * b_strides[1]];
+            }
+        }
+    }
+}
+
+/// Concatenate tensors along an existing axis.
+///
+/// # Arguments
+///
+/// * `tensors` - The tensors to concatenate. They do not need to be contiguous.
+/// * `axis` - The axis along which the tensors are joined.
+///
+/// # Returns
+///
+/// A new contiguous `Tensor` allocated with the allocator of the first tensor.
+///
+/// # Errors
+///
+/// If the list is empty, the axis is out of bounds or the shapes differ in an axis other
+/// than `axis`, an error is returned.
+///
+/// # Example
+///
+/// ```
+/// use kornia_tensor::{tensor::concatenate, Tensor, CpuAllocator};
+///
+/// let a = Tensor::<u8, 2, _>::from_shape_vec([2, 2], vec![1, 2, 3, 4], CpuAllocator).unwrap();
+/// let b = Tensor::<u8, 2, _>::from_shape_vec([2, 1], vec![5, 6], CpuAllocator).unwrap();
+///
+/// let c = concatenate(&[&a, &b], 1).unwrap();
+/// assert_eq!(c.shape, [2, 3]);
+/// assert_eq!(c.as_slice(), &[1, 2, 5, 3, 4, 6]);
+/// ```
+pub fn concatenate<T, const N: usize, A>(
+    tensors: &[&Tensor<T, N, A>],
+    axis: usize,
+) -> Result<Tensor<T, N, A>, TensorError>
+where
+    T: Clone,
+    A: TensorAllocator,
+{
+    let Some(first) = tensors.first() else {
+        return Err(TensorError::UnsupportedOperation(
+            "Cannot concatenate an empty list of tensors".to_string(),
+        ));
+    };
+    if axis >= N {
+        return Err(TensorError::DimOutOfBounds(axis, N));
+    }
+
+    let mut shape = first.shape;
+    shape[axis] = 0;
+    for t in tensors {
+        let mismatch = (0..N).any(|dim| dim != axis && t.shape[dim] != first.shape[dim]);
+        if mismatch {
+            return Err(TensorError::DimensionMismatch(format!(
+                "Cannot concatenate tensors with shapes {:?} and {:?} along axis {axis}",
+                first.shape, t.shape
+            )));
+        }
+        shape[axis] += t.shape[axis];
+    }
+
+    let outer = shape[..axis].iter().product::<usize>();
+    let inner = shape[axis + 1..].iter().product::<usize>();
+    let data = interleave_chunks(tensors, |t| t.shape[axis] * inner, outer);
+
+    Ok(Tensor {
+        storage: TensorStorage::from_vec(data, first.storage.alloc().clone()),
+        shape,
+        strides: get_strides_from_shape(shape),
+    })
+}
+
+/// Stack tensors with the same shape along a new axis.
+///
+/// The rank `M` of the result must be `N + 1`.
+///
+/// # Arguments
+///
+/// * `tensors` - The tensors to stack. They do not need to be contiguous.
+/// * `axis` - The position of the new axis in the result.
+///
+/// # Returns
+///
+/// A new contiguous `Tensor` allocated with the allocator of the first tensor.
+///
+/// # Errors
+///
+/// If the list is empty, the axis is out of bounds, `M` is not `N + 1` or the shapes
+/// differ, an error is returned.
+///
+/// # Example
+///
+/// ```
+/// use kornia_tensor::{tensor::stack, Tensor, CpuAllocator};
+///
+/// // a batch of two images with shape (H, W, C)
+/// let a = Tensor::<u8, 3, _>::from_shape_val([2, 2, 3], 0, CpuAllocator);
+/// let b = Tensor::<u8, 3, _>::from_shape_val([2, 2, 3], 1, CpuAllocator);
+///
+/// let batch = stack::<_, 3, 4, _>(&[&a, &b], 0).unwrap();
+/// assert_eq!(batch.shape, [2, 2, 2, 3]);
+/// assert_eq!(&batch.as_slice()[12..], &[1; 12]);
+/// ```
+pub fn stack<T, const N: usize, const M: usize, A>(
+    tensors: &[&Tensor<T, N, A>],
+    axis: usize,
+) -> Result<Tensor<T, M, A>, TensorError>
+where
+    T: Clone,
+    A: TensorAllocator,
+{
+    let Some(first) = tensors.first() else {
+        return Err(TensorError::UnsupportedOperation(
+            "Cannot stack an empty list of tensors".to_string(),
+        ));
+    };
+    if M != N + 1 {
+        return Err(TensorError::DimensionMismatch(format!(
+            "Cannot stack {N}D tensors into a {M}D tensor"
+        )));
+    }
+    if axis > N {
+        return Err(TensorError::DimOutOfBounds(axis, M));
+    }
+    if let Some(t) = tensors.iter().find(|t| t.shape != first.shape) {
+        return Err(TensorError::DimensionMismatch(format!(
+            "Cannot stack tensors with shapes {:?} and {:?}",
+            first.shape, t.shape
+        )));
+    }
+
+    // insert the new axis in the shape
+    let mut shape = [0; M];
+    shape[..axis].copy_from_slice(&first.shape[..axis]);
+    shape[axis] = tensors.len();
+    shape[axis + 1..].copy_from_slice(&first.shape[axis..]);
+
+    let outer = first.shape[..axis].iter().product::<usize>();
+    let inner = first.shape[axis..].iter().product::<usize>();
+    let data = interleave_chunks(tensors, |_| inner, outer);
+
+    Ok(Tensor {
+        storage: TensorStorage::from_vec(data, first.storage.alloc().clone()),
+        shape,
+        strides: get_strides_from_shape(shape),
+    })
+}
+
+/// Copy `outer` consecutive chunks of each tensor in turn, in row-major order.
+fn interleave_chunks<T, const N: usize, A>(
+    tensors: &[&Tensor<T, N, A>],
+    chunk_len: impl Fn(&Tensor<T, N, A>) -> usize,
+    outer: usize,
+) -> Vec<T>
+where
+    T: Clone,
+    A: TensorAllocator,
+{
+    // the non-contiguous tensors are copied to a standard layout first
+    let contiguous = tensors
+        .iter()
+        .map(|t| (!t.is_standard_layout()).then(|| t.view().to_contiguous()))
+        .collect::<Vec<_>>();
+    let slices = tensors
+        .iter()
+        .zip(contiguous.iter())
+        .map(|(t, c)| c.as_ref().map_or(t.as_slice(), |c| c.as_slice()))
+        .collect::<Vec<_>>();
+
+    let numel = tensors.iter().map(|t| t.numel()).sum::<usize>();
+    let mut data = Vec::with_capacity(numel);
+    for o in 0..outer {
+        for (t, slice) in tensors.iter().zip(slices.iter()) {
+            let len = chunk_len(*t);
+            data.extend_from_slice(&slice[o * len..(o + 1) * len]);
+        }
+    }
+    data
+}
+
+impl<T, const N: usize, A> Clone for Tensor<T, N, A>
+where
+    T: Clone,
+    A: TensorAllocator + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            storage: self.storage.clone(),
+            shape: self.shape,
+            strides: self.strides,
+        }
+    }
+}
+
+impl<T, const N: usize, A> std::fmt::Display for Tensor<T, N, A>
+where
+    T: std::fmt::Display + std::fmt::LowerExp,
+    A: TensorAllocator,
+{
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some(width) = self
+            .storage
+            .as_slice()
+            .iter()
+            .map(|v| format!("{v:.4}").len())
+            .max()
+        else {
+            // zero-sized tensors have no elements to display
+            return write!(f, "[]");
+        };
+
+        let scientific = width > 8;
+
+        let should_mask: [bool; N] = self.shape.map(|s| s > 8);
+        let mut skip_until = 0;
+
+        for (i, v) in self.storage.as_slice().iter().enumerate() {
+            if i < skip_until {
+                continue;
+            }
+            let mut value = String::new();
+            let mut prefix = String::new();
+            let mut suffix = String::new();
+            let mut separator = ",".to_string();
+            let mut last_size = 1;
+            for (dim, (&size, maskable)) in self.shape.iter().zip(should_mask).enumerate().rev() {
+                let prod = size * last_size;
+                if i % prod == (3 * last_size) && maskable {
+                    let pad = if dim == (N - 1) { 0 } else { dim + 1 };
+                    value = format!("{}...", " ".repeat(pad));
+                    skip_until = i + (size - 4) * last_size;
+                    prefix = "".to_string();
+                    if dim != (N - 1) {
+                        separator = "\n".repeat(N - 1 - dim);
+                    }
+                    break;
+                } else if i % prod == 0 {
+                    prefix.push('[');
+                } else if (i + 1) % prod == 0 {
+                    suffix.push(']');
+                    separator.push('\n');
+                    if dim == 0 {
+                        separator = "".to_string();
+                    }
+                } else {
+                    break;
+                }
+                last_size = prod;
+            }
+            if !prefix.is_empty() {
+                prefix = format!("{prefix:>N$}");
+            }
+
+            if value.is_empty() {
+                value = if scientific {
+                    let num = format!("{v:.4e}");
+                    let (before, after) = num.split_once('e').unwrap();
+                    let after = if let Some(stripped) = after.strip_prefix('-') {
+                        format!("-{:0>2}", &stripped)
+                    } else {
+                        format!("+{:0>2}", &after)
+                    };
+                    format!("{before}e{after}")
+                } else {
+                    let rounded = format!("{v:.4}");
+                    format!("{rounded:>width$}")
+                }
+            };
+            write!(f, "{prefix}{value}{suffix}{separator}",)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::allocator::{AlignedCpuAllocator, CpuAllocator};
+    use crate::tensor::{Tensor, TensorError};
+
+    #[test]
+    fn constructor_1d() -> Result<(), TensorError> {
+        let data: Vec<u8> = vec![1];
+        let t = Tensor::<u8, 1, _>::from_shape_vec([1], data, CpuAllocator)?;
+        assert_eq!(t.shape, [1]);
+        assert_eq!(t.as_slice(), vec![1]);
+        assert_eq!(t.strides, [1]);
+        assert_eq!(t.numel(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn constructor_2d() -> Result<(), TensorError> {
+        let data: Vec<u8> = vec![1, 2];
+        let t = Tensor::<u8, 2, _>::from_shape_vec([1, 2], data, CpuAllocator)?;
+        assert_eq!(t.shape, [1, 2]);
+        assert_eq!(t.as_slice(), vec![1, 2]);
+        assert_eq!(t.strides, [2, 1]);
+        assert_eq!(t.numel(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn zero_sized_2d() -> Result<(), TensorError> {
+        let t = Tensor::<f32, 2, _>::from_shape_vec([0, 3], vec![], CpuAllocator)?;
+        assert_eq!(t.shape, [0, 3]);
+        assert_eq!(t.strides, [3, 1]);
+        assert_eq!(t.numel(), 0);
+        assert!(t.as_slice().is_empty());
+        assert!(t.get([0, 0]).is_none());
+        assert_eq!(t.to_string(), "[]");
+
+        let zeros = Tensor::<u8, 2, _>::zeros([3, 0], CpuAllocator);
+        assert_eq!(zeros.numel(), 0);
+        assert_eq!(zeros.map(|x| x + 1).numel(), 0);
+
+        let contiguous = zeros.permute_axes([1, 0]).as_contiguous();
+        assert_eq!(contiguous.shape, [0, 3]);
+        assert_eq!(contiguous.numel(), 0);
+
+        let standard = t.to_standard_layout(CpuAllocator)?;
+        assert_eq!(standard.numel(), 0);
+        Ok(())
+    }
+
+    #[test]
+    fn get_1d() -> Result<(), TensorError> {
+        let data: Vec<u8> = vec![1, 2, 3, 4];
+        let t = Tensor::<u8, 1, _>::from_shape_vec([4], data, CpuAllocator)?;
+        assert_eq!(t.get([0]), Some(&1));
+        assert_eq!(t.get([1]), Some(&2));
+        assert_eq!(t.get([2]), Some(&3));
+        assert_eq!(t.get([3]), Some(&4));
+        assert!(t.get([4]).is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn get_2d() -> Result<(), TensorError> {
+        let data: Vec<u8> = vec![1, 2, 3, 4];
+        let t = Tensor::<u8, 2, _>::from_shape_vec([2, 2], data, CpuAllocator)?;
+        assert_eq!(t.get([0, 0]), Some(&1));
+        assert_eq!(t.get([0, 1]), Some(&2));
+        assert_eq!(t.get([1, 0]), Some(&3));
+        assert_eq!(t.get([1, 1]), Some(&4));
+        assert!(t.get([2, 0]).is_none());
+        assert!(t.get([0, 2]).is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn get_3d() -> Result<(), TensorError> {
+        let data: Vec<u8> = vec![1, 2, 3, 4, 5, 6];
+        let t = Tensor::<u8, 3, _>::from_shape_vec([2, 1, 3], data, CpuAllocator)?;
+        assert_eq!(t.get([0, 0, 0]), Some(&1));
+        assert_eq!(t.get([0, 0, 1]), Some(&2));
+        assert_eq!(t.get([0, 0, 2]), Some(&3));
+        assert_eq!(t.get([1, 0, 0]), Some(&4));
+        assert_eq!(t.get([1, 0, 1]), Some(&5));
+        assert_eq!(t.get([1, 0, 2]), Some(&6));
+        assert!(t.get([2, 0, 0]).is_none());
+        assert!(t.get([0, 1, 0]).is_none());
+        assert!(t.get([0, 0, 3]).is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn get_checked_1d() -> Result<(), TensorError> {
+        let data: Vec<u8> = vec![1, 2, 3, 4];
+        let t = Tensor::<u8, 1, _>::from_shape_vec([4], data, CpuAllocator)?;
+        assert_eq!(*t.get_unchecked([0]), 1);
+        assert_eq!(*t.get_unchecked([1]), 2);
+        assert_eq!(*t.get_unchecked([2]), 3);
+        assert_eq!(*t.get_unchecked([3]), 4);
+        Ok(())
+    }
+
+    #[test]
+    fn get_checked_2d() -> Result<(), TensorError> {
+        let data: Vec<u8> = vec![1, 2, 3, 4];
+        let t = Tensor::<u8, 2, _>::from_shape_vec([2, 2], data, CpuAllocator)?;
+        assert_eq!(*t.get_unchecked([0, 0]), 1);
+        assert_eq!(*t.get_unchecked([0, 1]), 2);
+        assert_eq!(*t.get_unchecked([1, 0]), 3);
+        assert_eq!(*t.get_unchecked([1, 1]), 4);
+        Ok(())
+    }
+    #[test]
+    fn max_min_with_index_2d() -> Result<(), TensorError> {
+        let data: Vec<f32> = vec![0.5, -2.0, 3.0, 9.0, 1.0, -2.0, 9.0, 4.0, 0.0];
+        let mut t = Tensor::<f32, 2, _>::from_shape_vec([3, 3], data, CpuAllocator)?;
+        assert_eq!(t.max_with_index(), Some((9.0, [1, 0])));
+        assert_eq!(t.min_with_index(), Some((-2.0, [0, 1])));
+
+        // follows the logical order of a transposed layout
+        t.strides = [1, 3];
+        assert_eq!(t.max_with_index(), Some((9.0, [0, 1])));
+        assert_eq!(t.min_with_index(), Some((-2.0, [1, 0])));
+
+        let empty = Tensor::<f32, 2, _>::zeros([0, 3], CpuAllocator);
+        assert_eq!(empty.max_with_index(), None);
+        Ok(())
+    }
+
+    #[test]
+    fn reshape_1d() -> Result<(), TensorError> {
+        let data: Vec<u8> = vec![1, 2, 3, 4];
+        let t = Tensor::<u8, 1, _>::from_shape_vec([4], data, CpuAllocator)?;
+
+        let view = t.reshape([2, 2])?;
+
+        assert_eq!(view.shape, [2, 2]);
+        assert_eq!(view.as_slice(), vec![1, 2, 3, 4]);
+        assert_eq!(view.strides, [2, 1]);
+        assert_eq!(view.numel(), 4);
+        assert_eq!(view.as_contiguous().as_slice(), vec![1, 2, 3, 4]);
+        Ok(())
+    }
+
+    #[test]
+    fn reshape_2d() -> Result<(), TensorError> {
+        let data: Vec<u8> = vec![1, 2, 3, 4];
+        let t = Tensor::<u8, 2, _>::from_shape_vec([2, 2], data, CpuAllocator)?;
+        let t2 = t.reshape([4])?;
+
+        assert_eq!(t2.shape, [4]);
+        assert_eq!(t2.as_slice(), vec![1, 2, 3, 4]);
+        assert_eq!(t2.strides, [1]);
+        assert_eq!(t2.numel(), 4);
+        assert_eq!(t2.as_contiguous().as_slice(), vec![1, 2, 3, 4]);
+        Ok(())
+    }
+
+    #[test]
+    fn reshape_get_1d() -> Result<(), TensorError> {
+        let data: Vec<u8> = vec![1, 2, 3, 4];
+        let t = Tensor::<u8, 1, _>::from_shape_vec([4], data, CpuAllocator)?;
+        let view = t.reshape([2, 2])?;
+        assert_eq!(*view.get_unchecked([0, 0]), 1);
+        assert_eq!(*view.get_unchecked([0, 1]), 2);
+        assert_eq!(*view.get_unchecked([1, 0]), 3);
+        assert_eq!(*view.get_unchecked([1, 1]), 4);
+        assert_eq!(view.numel(), 4);
+        assert_eq!(view.as_contiguous().as_slice(), vec![1, 2, 3, 4]);
+        Ok(())
+    }
+
+    #[test]
+    fn as_2d_image() -> Result<(), TensorError> {
+        let data: Vec<u8> = (0..48).collect();
+        let t = Tensor::<u8, 3, _>::from_shape_vec([4, 4, 3], data, CpuAllocator)?;
+        let m = t.as_2d()?;
+        assert_eq!(m.shape, [16, 3]);
+        assert_eq!(m.strides, [3, 1]);
+        assert!(std::ptr::eq(m.as_ptr(), t.as_ptr()));
+        for y in 0..4 {
+            for x in 0..4 {
+                for c in 0..3 {
+                    assert_eq!(m.get_unchecked([y * 4 + x, c]), t.get_unchecked([y, x, c]));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn as_2d_non_contiguous() -> Result<(), TensorError> {
+        let data: Vec<u8> = (0..12).collect();
+        let mut t = Tensor::<u8, 3, _>::from_shape_vec([2, 2, 3], data, CpuAllocator)?;
+        t.strides = [1, 6, 2];
+        assert!(matches!(t.as_2d(), Err(TensorError::NotContiguous(_, _))));
+        Ok(())
+    }
+
+    #[test]
+    fn permute_axes_1d() -> Result<(), TensorError> {
+        let data: Vec<u8> = vec![1, 2, 3, 4];
+        let t = Tensor::<u8, 1, _>::from_shape_vec([4], data, CpuAllocator)?;
+        let t2 = t.permute_axes([0]);
+        assert_eq!(t2.shape, [4]);
+        assert_eq!(t2.as_slice(), vec![1, 2, 3, 4]);
+        assert_eq!(t2.strides, [1]);
+        assert_eq!(t2.as_contiguous().as_slice(), vec![1, 2, 3, 4]);
+        Ok(())
+    }
+
+    #[test]
+    fn permute_axes_2d() -> Result<(), TensorError> {
+        let data: Vec<u8> = vec![1, 2, 3, 4];
+        let t = Tensor::<u8, 2, _>::from_shape_vec([2, 2], data, CpuAllocator)?;
+        let view = t.permute_axes([1, 0]);
+        assert_eq!(view.shape, [2, 2]);
+        assert_eq!(*view.get_unchecked([0, 0]), 1u8);
+        assert_eq!(*view.get_unchecked([1, 0]), 2u8);
+        assert_eq!(*view.get_unchecked([0, 1]), 3u8);
+        assert_eq!(*view.get_unchecked([1, 1]), 4u8);
+        assert_eq!(view.strides, [1, 2]);
+        assert_eq!(view.as_contiguous().as_slice(), vec![1, 3, 2, 4]);
+        Ok(())
+    }
+
+    #[test]
+    fn contiguous_2d() -> Result<(), TensorError> {
+        let data: Vec<u8> = vec![1, 2, 3, 4, 5, 6];
+        let t = Tensor::<u8, 2, _>::from_shape_vec([2, 3], data, CpuAllocator)?;
+
+        let view = t.permute_axes([1, 0]);
+
+        let contiguous = view.as_contiguous();
+
+        assert_eq!(contiguous.shape, [3, 2]);
+        assert_eq!(contiguous.strides, [2, 1]);
+        assert_eq!(contiguous.as_slice(), vec![1, 4, 2, 5, 3, 6]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn zeros_1d() -> Result<(), TensorError> {
+        let t = Tensor::<u8, 1, _>::zeros([4], CpuAllocator);
+        assert_eq!(t.as_slice(), vec![0, 0, 0, 0]);
+        Ok(())
+    }
+
+    #[test]
+    fn zeros_2d() -> Result<(), TensorError> {
+        let t = Tensor::<u8, 2, _>::zeros([2, 2], CpuAllocator);
+        assert_eq!(t.as_slice(), vec![0, 0, 0, 0]);
+        Ok(())
+    }
+
+    #[test]
+    fn map_1d() -> Result<(), TensorError> {
+        let data: Vec<u8> = vec![1, 2, 3, 4];
+        let t = Tensor::<u8, 1, _>::from_shape_vec([4], data, CpuAllocator)?;
+        let t2 = t.map(|x| x + 1);
+        assert_eq!(t2.as_slice(), vec![2, 3, 4, 5]);
+        Ok(())
+    }
+
+    #[test]
+    fn map_2d() -> Result<(), TensorError> {
+        let data: Vec<u8> = vec![1, 2, 3, 4];
+        let t = Tensor::<u8, 2, _>::from_shape_vec([2, 2], data, CpuAllocator)?;
+        let t2 = t.map(|x| x + 1);
+        assert_eq!(t2.as_slice(), vec![2, 3, 4, 5]);
+        Ok(())
+    }
+
+    #[test]
+    fn map_strided() -> Result<(), TensorError> {
+        let t = Tensor::<u8, 2, _>::from_shape_vec(
+            [2, 3],
+            vec![0, 51, 102, 153, 204, 255],
+            CpuAllocator,
+        )?;
+
+        // a transposed view is mapped in its logical order
+        let transposed = t.transpose(0, 1)?.map(|v| v as f32 / 255.0);
+        assert_eq!(transposed.shape, [3, 2]);
+        assert_eq!(transposed.strides, [2, 1]);
+        assert_eq!(transposed.as_slice(), &[0.0, 0.6, 0.2, 0.8, 0.4, 1.0]);
+
+        // a tensor with permuted strides as well
+        let mut strided =
+            Tensor::<u8, 2, _>::from_shape_vec([2, 2], vec![1, 2, 3, 4], CpuAllocator)?;
+        strided.strides = [1, 2];
+        assert_eq!(strided.map(|v| v as u16 * 10).as_slice(), &[10, 30, 20, 40]);
+
+        let mut dst = Tensor::<u16, 2, _>::zeros([2, 2], CpuAllocator);
+        strided.map_into(&mut dst, |v| v as u16)?;
+        assert_eq!(dst.as_slice(), &[1, 3, 2, 4]);
+
+        let mut wrong = Tensor::<u16, 2, _>::zeros([4, 1], CpuAllocator);
+        assert!(matches!(
+            strided.map_into(&mut wrong, |v| v as u16),
+            Err(TensorError::DimensionMismatch(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn from_shape_val_1d() -> Result<(), TensorError> {
+        let t = Tensor::<u8, 1, _>::from_shape_val([4], 0, CpuAllocator);
+        assert_eq!(t.as_slice(), vec![0, 0, 0, 0]);
+        Ok(())
+    }
+
+    #[test]
+    fn from_shape_val_2d() -> Result<(), TensorError> {
+        let t = Tensor::<u8, 2, _>::from_shape_val([2, 2], 1, CpuAllocator);
+        assert_eq!(t.as_slice(), vec![1, 1, 1, 1]);
+        Ok(())
+    }
+
+    #[test]
+    fn from_shape_val_3d() -> Result<(), TensorError> {
+        let t = Tensor::<u8, 3, _>::from_shape_val([2, 1, 3], 2, CpuAllocator);
+        assert_eq!(t.as_slice(), vec![2, 2, 2, 2, 2, 2]);
+        Ok(())
+    }
+
+    #[test]
+    fn cast_1d() -> Result<(), TensorError> {
+        let data: Vec<u8> = vec![1, 2, 3, 4];
+        let t = Tensor::<u8, 1, _>::from_shape_vec([4], data, CpuAllocator)?;
+        let t2 = t.cast::<u16>();
+        assert_eq!(t2.as_slice(), vec![1, 2, 3, 4]);
+        Ok(())
+    }
+
+    #[test]
+    fn cast_2d() -> Result<(), TensorError> {
+        let data: Vec<u8> = vec![1, 2, 3, 4];
+        let t = Tensor::<u8, 2, _>::from_shape_vec([2, 2], data, CpuAllocator)?;
+        let t2 = t.cast::<u16>();
+        assert_eq!(t2.as_slice(), vec![1, 2, 3, 4]);
+        Ok(())
+    }
+
+    #[test]
+    fn from_shape_fn_1d() -> Result<(), TensorError> {
+        let alloc = CpuAllocator;
+        let t = Tensor::from_shape_fn([3, 3], alloc, |[i, j]| ((1 + i) * (1 + j)) as u8);
+        assert_eq!(t.as_slice(), vec![1, 2, 3, 2, 4, 6, 3, 6, 9]);
+        Ok(())
+    }
+
+    #[test]
+    fn from_shape_fn_2d() -> Result<(), TensorError> {
+        let alloc = CpuAllocator;
+        let t = Tensor::from_shape_fn([3, 3], alloc, |[i, j]| ((1 + i) * (1 + j)) as f32);
+        assert_eq!(
+            t.as_slice(),
+            vec![1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 3.0, 6.0, 9.0]
+        );
         Ok(())
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use crate::allocator::CpuAllocator;
-    use crate::tensor::{Tensor, TensorError};
+    #[test]
+    fn from_shape_fn_3d() -> Result<(), TensorError> {
+        let alloc = CpuAllocator;
+        let t = Tensor::from_shape_fn([2, 3, 3], alloc, |[x, y, c]| {
+            ((1 + x) * (1 + y) * (1 + c)) as i16
+        });
+        assert_eq!(
+            t.as_slice(),
+            vec![1, 2, 3, 2, 4, 6, 3, 6, 9, 2, 4, 6, 4, 8, 12, 6, 12, 18]
+        );
+        Ok(())
+    }
 
     #[test]
-    fn constructor_1d() -> Result<(), TensorError> {
-        let data: Vec<u8> = vec![1];
-        let t = Tensor::<u8, 1, _>::from_shape_vec([1], data, CpuAllocator)?;
-        assert_eq!(t.shape, [1]);
-        assert_eq!(t.as_slice(), vec![1]);
-        assert_eq!(t.strides, [1]);
-        assert_eq!(t.numel(), 1);
+    fn view_1d() -> Result<(), TensorError> {
+        let alloc = CpuAllocator;
+        let data: Vec<u8> = vec![1, 2, 3, 4];
+        let t = Tensor::<u8, 1, _>::from_shape_vec([4], data, alloc)?;
+        let view = t.view();
+
+        // check that the view has the same data
+        assert_eq!(view.as_slice(), t.as_slice());
+
+        // check that the data pointer is the same
+        assert!(std::ptr::eq(view.as_ptr(), t.as_ptr()));
+
         Ok(())
     }
 
     #[test]
-    fn constructor_2d() -> Result<(), TensorError> {
-        let data: Vec<u8> = vec![1, 2];
-        let t = Tensor::<u8, 2, _>::from_shape_vec([1, 2], data, CpuAllocator)?;
-        assert_eq!(t.shape, [1, 2]);
-        assert_eq!(t.as_slice(), vec![1, 2]);
-        assert_eq!(t.strides, [2, 1]);
-        assert_eq!(t.numel(), 2);
+    fn from_slice() -> Result<(), TensorError> {
+        let data: [u8; 4] = [1, 2, 3, 4];
+        let t = Tensor::<u8, 2, _>::from_shape_slice([2, 2], &data, CpuAllocator)?;
+
+        assert_eq!(t.shape, [2, 2]);
+        assert_eq!(t.as_slice(), &[1, 2, 3, 4]);
+
         Ok(())
     }
 
     #[test]
-    fn get_1d() -> Result<(), TensorError> {
-        let data: Vec<u8> = vec![1, 2, 3, 4];
-        let t = Tensor::<u8, 1, _>::from_shape_vec([4], data, CpuAllocator)?;
-        assert_eq!(t.get([0]), Some(&1));
-        assert_eq!(t.get([1]), Some(&2));
-        assert_eq!(t.get([2]), Some(&3));
-        assert_eq!(t.get([3]), Some(&4));
-        assert!(t.get([4]).is_none());
+    fn display_2d() -> Result<(), TensorError> {
+        let data: [u8; 4] = [1, 2, 3, 4];
+        let t = Tensor::<u8, 2, _>::from_shape_slice([2, 2], &data, CpuAllocator)?;
+        let disp = t.to_string();
+        let lines = disp.lines().collect::<Vec<_>>();
+
+        #[rustfmt::skip]
+        assert_eq!(lines.as_slice(),
+        ["[[1,2],",
+         " [3,4]]"]);
         Ok(())
     }
 
     #[test]
-    fn get_2d() -> Result<(), TensorError> {
-        let data: Vec<u8> = vec![1, 2, 3, 4];
-        let t = Tensor::<u8, 2, _>::from_shape_vec([2, 2], data, CpuAllocator)?;
-        assert_eq!(t.get([0, 0]), Some(&1));
-        assert_eq!(t.get([0, 1]), Some(&2));
-        assert_eq!(t.get([1, 0]), Some(&3));
-        assert_eq!(t.get([1, 1]), Some(&4));
-        assert!(t.get([2, 0]).is_none());
-        assert!(t.get([0, 2]).is_none());
+    fn display_3d() -> Result<(), TensorError> {
+        let data: [u8; 12] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+        let t = Tensor::<u8, 3, _>::from_shape_slice([2, 3, 2], &data, CpuAllocator)?;
+        let disp = t.to_string();
+        let lines = disp.lines().collect::<Vec<_>>();
+
+        #[rustfmt::skip]
+        assert_eq!(lines.as_slice(),
+        ["[[[ 1, 2],",
+         "  [ 3, 4],",
+         "  [ 5, 6]],",
+         "",
+         " [[ 7, 8],",
+         "  [ 9,10],",
+         "  [11,12]]]"]);
         Ok(())
     }
 
     #[test]
-    fn get_3d() -> Result<(), TensorError> {
-        let data: Vec<u8> = vec![1, 2, 3, 4, 5, 6];
-        let t = Tensor::<u8, 3, _>::from_shape_vec([2, 1, 3], data, CpuAllocator)?;
-        assert_eq!(t.get([0, 0, 0]), Some(&1));
-        assert_eq!(t.get([0, 0, 1]), Some(&2));
-        assert_eq!(t.get([0, 0, 2]), Some(&3));
-        assert_eq!(t.get([1, 0, 0]), Some(&4));
-        assert_eq!(t.get([1, 0, 1]), Some(&5));
-        assert_eq!(t.get([1, 0, 2]), Some(&6));
-        assert!(t.get([2, 0, 0]).is_none());
-        assert!(t.get([0, 1, 0]).is_none());
-        assert!(t.get([0, 0, 3]).is_none());
+    fn display_float() -> Result<(), TensorError> {
+        let data: [f32; 4] = [1.00001, 1.00009, 0.99991, 0.99999];
+        let t = Tensor::<f32, 2, _>::from_shape_slice([2, 2], &data, CpuAllocator)?;
+        let disp = t.to_string();
+        let lines = disp.lines().collect::<Vec<_>>();
+
+        #[rustfmt::skip]
+        assert_eq!(lines.as_slice(),
+        ["[[1.0000,1.0001],",
+         " [0.9999,1.0000]]"]);
         Ok(())
     }
 
     #[test]
-    fn get_checked_1d() -> Result<(), TensorError> {
-        let data: Vec<u8> = vec![1, 2, 3, 4];
-        let t = Tensor::<u8, 1, _>::from_shape_vec([4], data, CpuAllocator)?;
-        assert_eq!(*t.get_unchecked([0]), 1);
-        assert_eq!(*t.get_unchecked([1]), 2);
-        assert_eq!(*t.get_unchecked([2]), 3);
-        assert_eq!(*t.get_unchecked([3]), 4);
+    fn display_big_float() -> Result<(), TensorError> {
+        let data: [f32; 4] = [1000.00001, 1.00009, 0.99991, 0.99999];
+        let t = Tensor::<f32, 2, _>::from_shape_slice([2, 2], &data, CpuAllocator)?;
+        let disp = t.to_string();
+        let lines = disp.lines().collect::<Vec<_>>();
+
+        #[rustfmt::skip]
+        assert_eq!(lines.as_slice(),
+        ["[[1.0000e+03,1.0001e+00],",
+         " [9.9991e-01,9.9999e-01]]"]);
         Ok(())
     }
 
     #[test]
-    fn get_checked_2d() -> Result<(), TensorError> {
-        let data: Vec<u8> = vec![1, 2, 3, 4];
-        let t = Tensor::<u8, 2, _>::from_shape_vec([2, 2], data, CpuAllocator)?;
-        assert_eq!(*t.get_unchecked([0, 0]), 1);
-        assert_eq!(*t.get_unchecked([0, 1]), 2);
-        assert_eq!(*t.get_unchecked([1, 0]), 3);
-        assert_eq!(*t.get_unchecked([1, 1]), 4);
+    fn display_big_tensor() -> Result<(), TensorError> {
+        let data: [u8; 1000] = [0; 1000];
+        let t = Tensor::<u8, 3, _>::from_shape_slice([10, 10, 10], &data, CpuAllocator)?;
+        let disp = t.to_string();
+        let lines = disp.lines().collect::<Vec<_>>();
+
+        #[rustfmt::skip]
+        assert_eq!(lines.as_slice(),
+        ["[[[0,0,0,...,0],",
+         "  [0,0,0,...,0],",
+         "  [0,0,0,...,0],",
+         "  ...",
+         "  [0,0,0,...,0]],",
+         "",
+         " [[0,0,0,...,0],",
+         "  [0,0,0,...,0],",
+         "  [0,0,0,...,0],",
+         "  ...",
+         "  [0,0,0,...,0]],",
+         "",
+         " [[0,0,0,...,0],",
+         "  [0,0,0,...,0],",
+         "  [0,0,0,...,0],",
+         "  ...",
+         "  [0,0,0,...,0]],",
+         "",
+         " ...",
+         "",
+         " [[0,0,0,...,0],",
+         "  [0,0,0,...,0],",
+         "  [0,0,0,...,0],",
+         "  ...",
+         "  [0,0,0,...,0]]]"]);
         Ok(())
     }
+
     #[test]
-    fn reshape_1d() -> Result<(), TensorError> {
+    fn get_index_unchecked_1d() -> Result<(), TensorError> {
         let data: Vec<u8> = vec![1, 2, 3, 4];
-        let t = Tensor::<u8, 1, _>::from_shape_vec([4], data, CpuAllocator)?;
+        let t = Tensor::<u8, 1, CpuAllocator>::from_shape_vec([4], data, CpuAllocator)?;
+        assert_eq!(t.get_index_unchecked(0), [0]);
+        assert_eq!(t.get_index_unchecked(1), [1]);
+        assert_eq!(t.get_index_unchecked(2), [2]);
+        assert_eq!(t.get_index_unchecked(3), [3]);
+        Ok(())
+    }
 
-        let view = t.reshape([2, 2])?;
+    #[test]
+    fn get_index_unchecked_2d() -> Result<(), TensorError> {
+        let data: Vec<u8> = vec![1, 2, 3, 4];
+        let t = Tensor::<u8, 2, CpuAllocator>::from_shape_vec([2, 2], data, CpuAllocator)?;
+        assert_eq!(t.get_index_unchecked(0), [0, 0]);
+        assert_eq!(t.get_index_unchecked(1), [0, 1]);
+        assert_eq!(t.get_index_unchecked(2), [1, 0]);
+        assert_eq!(t.get_index_unchecked(3), [1, 1]);
+        Ok(())
+    }
 
-        assert_eq!(view.shape, [2, 2]);
-        assert_eq!(view.as_slice(), vec![1, 2, 3, 4]);
-        assert_eq!(view.strides, [2, 1]);
-        assert_eq!(view.numel(), 4);
-        assert_eq!(view.as_contiguous().as_slice(), vec![1, 2, 3, 4]);
+    #[test]
+    fn get_index_unchecked_3d() -> Result<(), TensorError> {
+        let data: Vec<u8> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+        let t = Tensor::<u8, 3, CpuAllocator>::from_shape_vec([2, 2, 3], data, CpuAllocator)?;
+        assert_eq!(t.get_index_unchecked(0), [0, 0, 0]);
+        assert_eq!(t.get_index_unchecked(1), [0, 0, 1]);
+        assert_eq!(t.get_index_unchecked(2), [0, 0, 2]);
+        assert_eq!(t.get_index_unchecked(3), [0, 1, 0]);
+        assert_eq!(t.get_index_unchecked(4), [0, 1, 1]);
+        assert_eq!(t.get_index_unchecked(5), [0, 1, 2]);
+        assert_eq!(t.get_index_unchecked(6), [1, 0, 0]);
+        assert_eq!(t.get_index_unchecked(7), [1, 0, 1]);
+        assert_eq!(t.get_index_unchecked(8), [1, 0, 2]);
+        assert_eq!(t.get_index_unchecked(9), [1, 1, 0]);
+        assert_eq!(t.get_index_unchecked(10), [1, 1, 1]);
+        assert_eq!(t.get_index_unchecked(11), [1, 1, 2]);
         Ok(())
     }
 
     #[test]
-    fn reshape_2d() -> Result<(), TensorError> {
-        let data: Vec<u8> = vec![1, 2, 3, 4];
-        let t = Tensor::<u8, 2, _>::from_shape_vec([2, 2], data, CpuAllocator)?;
-        let t2 = t.reshape([4])?;
+    fn get_index_to_offset_and_back() -> Result<(), TensorError> {
+        let data: Vec<u8> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+        let t = Tensor::<u8, 3, CpuAllocator>::from_shape_vec([2, 2, 3], data, CpuAllocator)?;
+        for offset in 0..12 {
+            assert_eq!(
+                t.get_iter_offset_unchecked(t.get_index_unchecked(offset)),
+                offset
+            );
+        }
+        Ok(())
+    }
 
-        assert_eq!(t2.shape, [4]);
-        assert_eq!(t2.as_slice(), vec![1, 2, 3, 4]);
-        assert_eq!(t2.strides, [1]);
-        assert_eq!(t2.numel(), 4);
-        assert_eq!(t2.as_contiguous().as_slice(), vec![1, 2, 3, 4]);
+    #[test]
+    fn get_offset_to_index_and_back() -> Result<(), TensorError> {
+        let data: Vec<u8> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+        let t = Tensor::<u8, 3, CpuAllocator>::from_shape_vec([2, 2, 3], data, CpuAllocator)?;
+        for ind in [
+            [0, 0, 0],
+            [0, 0, 1],
+            [0, 0, 2],
+            [0, 1, 0],
+            [0, 1, 1],
+            [0, 1, 2],
+            [1, 0, 0],
+            [1, 0, 1],
+            [1, 0, 2],
+            [1, 1, 0],
+            [1, 1, 1],
+            [1, 1, 2],
+        ] {
+            assert_eq!(t.get_index_unchecked(t.get_iter_offset_unchecked(ind)), ind);
+        }
         Ok(())
     }
 
     #[test]
-    fn reshape_get_1d() -> Result<(), TensorError> {
+    fn get_index_1d() -> Result<(), TensorError> {
         let data: Vec<u8> = vec![1, 2, 3, 4];
-        let t = Tensor::<u8, 1, _>::from_shape_vec([4], data, CpuAllocator)?;
-        let view = t.reshape([2, 2])?;
-        assert_eq!(*view.get_unchecked([0, 0]), 1);
-        assert_eq!(*view.get_unchecked([0, 1]), 2);
-        assert_eq!(*view.get_unchecked([1, 0]), 3);
-        assert_eq!(*view.get_unchecked([1, 1]), 4);
-        assert_eq!(view.numel(), 4);
-        assert_eq!(view.as_contiguous().as_slice(), vec![1, 2, 3, 4]);
+        let t = Tensor::<u8, 1, CpuAllocator>::from_shape_vec([4], data, CpuAllocator)?;
+        assert_eq!(t.get_index(3), Ok([3]));
+        assert!(t
+            .get_index(4)
+            .is_err_and(|x| x == TensorError::IndexOutOfBounds(4)));
         Ok(())
     }
 
     #[test]
-    fn permute_axes_1d() -> Result<(), TensorError> {
+    fn get_index_2d() -> Result<(), TensorError> {
         let data: Vec<u8> = vec![1, 2, 3, 4];
-        let t = Tensor::<u8, 1, _>::from_shape_vec([4], data, CpuAllocator)?;
-        let t2 = t.permute_axes([0]);
-        assert_eq!(t2.shape, [4]);
-        assert_eq!(t2.as_slice(), vec![1, 2, 3, 4]);
-        assert_eq!(t2.strides, [1]);
-        assert_eq!(t2.as_contiguous().as_slice(), vec![1, 2, 3, 4]);
+        let t = Tensor::<u8, 2, CpuAllocator>::from_shape_vec([2, 2], data, CpuAllocator)?;
+        assert_eq!(t.get_index_unchecked(3), [1, 1]);
+        assert!(t
+            .get_index(4)
+            .is_err_and(|x| x == TensorError::IndexOutOfBounds(4)));
         Ok(())
     }
 
     #[test]
-    fn permute_axes_2d() -> Result<(), TensorError> {
-        let data: Vec<u8> = vec![1, 2, 3, 4];
-        let t = Tensor::<u8, 2, _>::from_shape_vec([2, 2], data, CpuAllocator)?;
-        let view = t.permute_axes([1, 0]);
-        assert_eq!(view.shape, [2, 2]);
-        assert_eq!(*view.get_unchecked([0, 0]), 1u8);
-        assert_eq!(*view.get_unchecked([1, 0]), 2u8);
-        assert_eq!(*view.get_unchecked([0, 1]), 3u8);
-        assert_eq!(*view.get_unchecked([1, 1]), 4u8);
-        assert_eq!(view.strides, [1, 2]);
-        assert_eq!(view.as_contiguous().as_slice(), vec![1, 3, 2, 4]);
+    fn get_index_3d() -> Result<(), TensorError> {
+        let data: Vec<u8> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+        let t = Tensor::<u8, 3, CpuAllocator>::from_shape_vec([2, 2, 3], data, CpuAllocator)?;
+        assert_eq!(t.get_index_unchecked(11), [1, 1, 2]);
+        assert!(t
+            .get_index(12)
+            .is_err_and(|x| x == TensorError::IndexOutOfBounds(12)));
         Ok(())
     }
 
     #[test]
-    fn contiguous_2d() -> Result<(), TensorError> {
-        let data: Vec<u8> = vec![1, 2, 3, 4, 5, 6];
-        let t = Tensor::<u8, 2, _>::from_shape_vec([2, 3], data, CpuAllocator)?;
-
-        let view = t.permute_axes([1, 0]);
-
-        let contiguous = view.as_contiguous();
-
-        assert_eq!(contiguous.shape, [3, 2]);
-        assert_eq!(contiguous.strides, [2, 1]);
-        assert_eq!(contiguous.as_slice(), vec![1, 4, 2, 5, 3, 6]);
-
+    fn from_raw_parts() -> Result<(), TensorError> {
+        let data: Vec<u8> = vec![1, 2, 3, 4];
+        let t = unsafe { Tensor::from_raw_parts([2, 2], data.as_ptr(), data.len(), CpuAllocator)? };
+        std::mem::forget(data);
+        assert_eq!(t.shape, [2, 2]);
+        assert_eq!(t.as_slice(), &[1, 2, 3, 4]);
         Ok(())
     }
 
     #[test]
-    fn zeros_1d() -> Result<(), TensorError> {
-        let t = Tensor::<u8, 1, _>::zeros([4], CpuAllocator);
-        assert_eq!(t.as_slice(), vec![0, 0, 0, 0]);
+    fn contiguous_tensor_is_standard_layout_true() -> Result<(), TensorError> {
+        let data: Vec<u8> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+        let t = Tensor::<u8, 3, CpuAllocator>::from_shape_vec([2, 2, 3], data, CpuAllocator)?;
+        assert!(t.is_standard_layout());
         Ok(())
     }
 
     #[test]
-    fn zeros_2d() -> Result<(), TensorError> {
-        let t = Tensor::<u8, 2, _>::zeros([2, 2], CpuAllocator);
-        assert_eq!(t.as_slice(), vec![0, 0, 0, 0]);
+    fn broken_stride_is_standard_layout_false() -> Result<(), TensorError> {
+        let data: Vec<u8> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+        let mut t = Tensor::<u8, 3, CpuAllocator>::from_shape_vec([2, 2, 3], data, CpuAllocator)?;
+        // arbitrary incorrect stride
+        t.strides = [10, 5, 1];
+        assert!(!t.is_standard_layout());
         Ok(())
     }
 
     #[test]
-    fn map_1d() -> Result<(), TensorError> {
-        let data: Vec<u8> = vec![1, 2, 3, 4];
-        let t = Tensor::<u8, 1, _>::from_shape_vec([4], data, CpuAllocator)?;
-        let t2 = t.map(|x| *x + 1);
-        assert_eq!(t2.as_slice(), vec![2, 3, 4, 5]);
+    fn contiguous_tensor_roundtrip() -> Result<(), TensorError> {
+        let data: Vec<u8> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+        let t =
+            Tensor::<u8, 3, CpuAllocator>::from_shape_vec([2, 2, 3], data.clone(), CpuAllocator)?;
+        assert!(t.is_standard_layout());
+        match t.to_standard_layout(CpuAllocator) {
+            Ok(t2) => {
+                assert!(t2.is_standard_layout());
+                assert_eq!(t2.storage.as_slice(), data.as_slice());
+            }
+            Err(e) => return Err(e),
+        }
         Ok(())
     }
 
     #[test]
-    fn map_2d() -> Result<(), TensorError> {
-        let data: Vec<u8> = vec![1, 2, 3, 4];
-        let t = Tensor::<u8, 2, _>::from_shape_vec([2, 2], data, CpuAllocator)?;
-        let t2 = t.map(|x| *x + 1);
-        assert_eq!(t2.as_slice(), vec![2, 3, 4, 5]);
+    fn non_contiguous_to_standard_layout() -> Result<(), TensorError> {
+        let data: Vec<u8> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+        let mut t =
+            Tensor::<u8, 3, CpuAllocator>::from_shape_vec([2, 2, 3], data.clone(), CpuAllocator)?;
+        // altering strides
+        t.strides = [1, 6, 2];
+        assert!(!t.is_standard_layout());
+        match t.to_standard_layout(CpuAllocator) {
+            Ok(t2) => {
+                assert!(t2.is_standard_layout());
+            }
+            Err(e) => return Err(e),
+        }
         Ok(())
     }
 
     #[test]
-    fn from_shape_val_1d() -> Result<(), TensorError> {
-        let t = Tensor::<u8, 1, _>::from_shape_val([4], 0, CpuAllocator);
-        assert_eq!(t.as_slice(), vec![0, 0, 0, 0]);
-        Ok(())
-    }
+    fn softmax_axis() -> Result<(), TensorError> {
+        // the second row would overflow without subtracting the maximum
+        let data: Vec<f32> = vec![1.0, 2.0, 3.0, 1000.0, 1001.0, 1002.0];
+        let t = Tensor::<f32, 2, _>::from_shape_vec([2, 3], data, CpuAllocator)?;
 
-    #[test]
-    fn from_shape_val_2d() -> Result<(), TensorError> {
-        let t = Tensor::<u8, 2, _>::from_shape_val([2, 2], 1, CpuAllocator);
-        assert_eq!(t.as_slice(), vec![1, 1, 1, 1]);
-        Ok(())
-    }
+        let t2 = t.softmax_axis(1)?;
+        assert_eq!(t2.shape, [2, 3]);
+
+        let expected = [0.090_030_57, 0.244_728_47, 0.665_240_94];
+        for row in t2.as_slice().chunks_exact(3) {
+            assert!((row.iter().sum::<f32>() - 1.0).abs() < 1e-6);
+            for (x, e) in row.iter().zip(expected.iter()) {
+                assert!((x - e).abs() < 1e-6);
+            }
+        }
+
+        // along the columns every pair is dominated by the second row
+        let t3 = t.softmax_axis(0)?;
+        assert!(t3.as_slice()[..3].iter().all(|&x| x == 0.0));
+        assert!(t3.as_slice()[3..].iter().all(|&x| x == 1.0));
+
+        assert!(matches!(
+            t.softmax_axis(2),
+            Err(TensorError::DimOutOfBounds(2, 2))
+        ));
 
-    #[test]
-    fn from_shape_val_3d() -> Result<(), TensorError> {
-        let t = Tensor::<u8, 3, _>::from_shape_val([2, 1, 3], 2, CpuAllocator);
-        assert_eq!(t.as_slice(), vec![2, 2, 2, 2, 2, 2]);
         Ok(())
     }
 
     #[test]
-    fn cast_1d() -> Result<(), TensorError> {
-        let data: Vec<u8> = vec![1, 2, 3, 4];
-        let t = Tensor::<u8, 1, _>::from_shape_vec([4], data, CpuAllocator)?;
-        let t2 = t.cast::<u16>();
-        assert_eq!(t2.as_slice(), vec![1, 2, 3, 4]);
+    fn normalize_l1_l2_axis() -> Result<(), TensorError> {
+        let data: Vec<f32> = vec![3.0, -1.0, 0.0, 4.0, 1.0, 0.0];
+        let t = Tensor::<f32, 2, _>::from_shape_vec([2, 3], data, CpuAllocator)?;
+
+        let l1 = t.normalize_l1_axis(0)?;
+        assert_eq!(
+            l1.as_slice(),
+            vec![3.0 / 7.0, -0.5, 0.0, 4.0 / 7.0, 0.5, 0.0]
+        );
+
+        let l2 = t.normalize_l2_axis(0)?;
+        let expected = [0.6, -1.0 / 2f32.sqrt(), 0.0, 0.8, 1.0 / 2f32.sqrt(), 0.0];
+        for (x, e) in l2.as_slice().iter().zip(expected.iter()) {
+            assert!((x - e).abs() < 1e-6);
+        }
+
+        assert!(matches!(
+            t.normalize_l1_axis(2),
+            Err(TensorError::DimOutOfBounds(2, 2))
+        ));
+        assert!(matches!(
+            t.normalize_l2_axis(2),
+            Err(TensorError::DimOutOfBounds(2, 2))
+        ));
+
         Ok(())
     }
 
     #[test]
-    fn cast_2d() -> Result<(), TensorError> {
-        let data: Vec<u8> = vec![1, 2, 3, 4];
-        let t = Tensor::<u8, 2, _>::from_shape_vec([2, 2], data, CpuAllocator)?;
-        let t2 = t.cast::<u16>();
-        assert_eq!(t2.as_slice(), vec![1, 2, 3, 4]);
+    fn assert_contiguous() -> Result<(), TensorError> {
+        let data: Vec<f32> = (0..24).map(|x| x as f32).collect();
+        let t = Tensor::<f32, 3, _>::from_shape_vec([2, 3, 4], data, CpuAllocator)?;
+        assert!(t.assert_contiguous().is_ok());
+
+        let layout = t.layout_info();
+        assert_eq!(layout.strides, [12, 4, 1]);
+        assert_eq!(layout.byte_strides, [48, 16, 4]);
+        assert_eq!(layout.alignment % std::mem::align_of::<f32>(), 0);
+        assert!(layout.is_contiguous);
+
+        // a permuted view keeps the original strides
+        let permuted = t.permute_axes([2, 0, 1]);
+        assert_eq!(
+            permuted.assert_contiguous().err(),
+            Some(TensorError::NotContiguous(vec![4, 2, 3], vec![1, 12, 4]))
+        );
+
+        // same for an owned tensor with the permuted strides
+        let mut t2 = t.clone();
+        t2.shape = permuted.shape;
+        t2.strides = permuted.strides;
+        assert!(t2.assert_contiguous().is_err());
+        assert!(!t2.layout_info().is_contiguous);
+
         Ok(())
     }
 
     #[test]
-    fn from_shape_fn_1d() -> Result<(), TensorError> {
-        let alloc = CpuAllocator;
-        let t = Tensor::from_shape_fn([3, 3], alloc, |[i, j]| ((1 + i) * (1 + j)) as u8);
-        assert_eq!(t.as_slice(), vec![1, 2, 3, 2, 4, 6, 3, 6, 9]);
+    fn windows_2d() -> Result<(), TensorError> {
+        let data: Vec<u8> = (1..=9).collect();
+        let t = Tensor::<u8, 2, CpuAllocator>::from_shape_vec([3, 3], data, CpuAllocator)?;
+
+        let windows = t
+            .windows([2, 2], [1, 1])?
+            .map(|w| w.as_contiguous().into_vec())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            windows,
+            vec![
+                vec![1, 2, 4, 5],
+                vec![2, 3, 5, 6],
+                vec![4, 5, 7, 8],
+                vec![5, 6, 8, 9]
+            ]
+        );
+
+        // the last row and column do not fit a full window with stride 2
+        let windows = t.windows([2, 2], [2, 2])?.collect::<Vec<_>>();
+        assert_eq!(windows.len(), 1);
+        assert_eq!(*windows[0].get_unchecked([1, 1]), 5);
+
+        // a window larger than the tensor yields nothing
+        assert_eq!(t.windows([4, 1], [1, 1])?.count(), 0);
+
+        // full rows
+        let rows = t
+            .windows([1, 3], [1, 1])?
+            .map(|w| *w.get_unchecked([0, 2]))
+            .collect::<Vec<_>>();
+        assert_eq!(rows, vec![3, 6, 9]);
+
+        assert!(t.windows([0, 1], [1, 1]).is_err());
+        assert!(t.windows([1, 1], [1, 0]).is_err());
+
         Ok(())
     }
 
     #[test]
-    fn from_shape_fn_2d() -> Result<(), TensorError> {
-        let alloc = CpuAllocator;
-        let t = Tensor::from_shape_fn([3, 3], alloc, |[i, j]| ((1 + i) * (1 + j)) as f32);
+    fn affine_transform() -> Result<(), TensorError> {
+        // a 2x2 image with 3 channels
+        let data: Vec<f32> = (0..12).map(|x| x as f32).collect();
+        let t = Tensor::<f32, 3, CpuAllocator>::from_shape_vec([2, 2, 3], data, CpuAllocator)?;
+
+        let scale = Tensor::from_shape_vec([3], vec![0.5, 2.0, -1.0], CpuAllocator)?;
+        let shift = Tensor::from_shape_vec([3], vec![1.0, 0.0, 10.0], CpuAllocator)?;
+
+        let t2 = t.affine_transform(&scale, &shift, 2)?;
+        let expected = t
+            .as_slice()
+            .chunks_exact(3)
+            .flat_map(|px| [0.5 * px[0] + 1.0, 2.0 * px[1], 10.0 - px[2]])
+            .collect::<Vec<_>>();
+        assert_eq!(t2.as_slice(), expected);
+
+        // the same channels in a planar layout
+        let planar = t.permute_axes([2, 0, 1]).as_contiguous();
+        let t3 = planar.affine_transform(&scale, &shift, 0)?;
+        assert_eq!(t3.shape, [3, 2, 2]);
         assert_eq!(
-            t.as_slice(),
-            vec![1.0, 2.0, 3.0, 2.0, 4.0, 6.0, 3.0, 6.0, 9.0]
+            t3.permute_axes([1, 2, 0]).as_contiguous().as_slice(),
+            expected
         );
+
+        // the channel axis must match the length of the parameters
+        assert!(t.affine_transform(&scale, &shift, 1).is_err());
+        assert!(matches!(
+            t.affine_transform(&scale, &shift, 3),
+            Err(TensorError::DimOutOfBounds(3, 3))
+        ));
+
         Ok(())
     }
 
     #[test]
-    fn from_shape_fn_3d() -> Result<(), TensorError> {
-        let alloc = CpuAllocator;
-        let t = Tensor::from_shape_fn([2, 3, 3], alloc, |[x, y, c]| {
-            ((1 + x) * (1 + y) * (1 + c)) as i16
-        });
+    fn broadcast_arithmetic() -> Result<(), TensorError> {
+        // a 2x2 image with 3 channels and a per-channel bias
+        let data: Vec<f32> = (0..12).map(|x| x as f32).collect();
+        let t = Tensor::<f32, 3, CpuAllocator>::from_shape_vec([2, 2, 3], data, CpuAllocator)?;
+        let bias = Tensor::from_shape_vec([3], vec![1.0, 2.0, 4.0], CpuAllocator)?;
+
+        let t2 = t.add(&bias)?;
+        assert_eq!(t2.shape, [2, 2, 3]);
+        let expected = t
+            .as_slice()
+            .chunks_exact(3)
+            .flat_map(|px| [px[0] + 1.0, px[1] + 2.0, px[2] + 4.0])
+            .collect::<Vec<_>>();
+        assert_eq!(t2.as_slice(), expected);
+
+        assert_eq!(t2.sub(&bias)?.as_slice(), t.as_slice());
+        assert_eq!(t.mul(&bias)?.div(&bias)?.as_slice(), t.as_slice());
+
+        // both tensors are broadcast: [2, 1] x [3] -> [2, 3]
+        let col = Tensor::<i32, 2, _>::from_shape_vec([2, 1], vec![10, 20], CpuAllocator)?;
+        let row = Tensor::<i32, 1, _>::from_shape_vec([3], vec![1, 2, 3], CpuAllocator)?;
+        let t3 = col.add(&row)?;
+        assert_eq!(t3.shape, [2, 3]);
+        assert_eq!(t3.as_slice(), &[11, 12, 13, 21, 22, 23]);
+
+        // a transposed view is read in its logical order
+        let mut transposed =
+            Tensor::<i32, 2, _>::from_shape_vec([3, 2], vec![1, 4, 2, 5, 3, 6], CpuAllocator)?;
+        transposed.shape = [2, 3];
+        transposed.strides = [1, 2];
+        assert_eq!(transposed.mul(&row)?.as_slice(), &[1, 4, 9, 4, 10, 18]);
+
+        // incompatible shapes
+        let two = Tensor::<f32, 1, _>::from_shape_vec([2], vec![1.0, 2.0], CpuAllocator)?;
+        assert!(t.add(&two).is_err());
+        assert!(bias.add(&t).is_err());
+
+        // in place with the same shape
+        let mut t4 = t.clone();
+        t4.add_assign(&t)?;
+        t4.sub_assign(&t)?;
+        t4.mul_assign(&t)?;
         assert_eq!(
-            t.as_slice(),
-            vec![1, 2, 3, 2, 4, 6, 3, 6, 9, 2, 4, 6, 4, 8, 12, 6, 12, 18]
+            t4.as_slice(),
+            t.as_slice().iter().map(|x| x * x).collect::<Vec<_>>()
         );
+        let mut ones = Tensor::<i32, 2, _>::from_shape_val([2, 3], 1, CpuAllocator);
+        ones.add_assign(&transposed)?;
+        assert_eq!(ones.as_slice(), &[2, 3, 4, 5, 6, 7]);
+        let wrong = Tensor::<f32, 3, _>::zeros([2, 3, 2], CpuAllocator);
+        assert!(t4.div_assign(&wrong).is_err());
+
         Ok(())
     }
 
     #[test]
-    fn view_1d() -> Result<(), TensorError> {
-        let alloc = CpuAllocator;
-        let data: Vec<u8> = vec![1, 2, 3, 4];
-        let t = Tensor::<u8, 1, _>::from_shape_vec([4], data, alloc)?;
-        let view = t.view();
+    fn reduce_axis() -> Result<(), TensorError> {
+        let data: Vec<f32> = (0..24).map(|x| x as f32).collect();
+        let t = Tensor::<f32, 3, CpuAllocator>::from_shape_vec([2, 3, 4], data, CpuAllocator)?;
 
-        // check that the view has the same data
-        assert_eq!(view.as_slice(), t.as_slice());
+        let sum = t.sum_axis_keepdim(1)?;
+        assert_eq!(sum.shape, [2, 1, 4]);
+        assert_eq!(sum.strides, [4, 4, 1]);
+        assert_eq!(
+            sum.as_slice(),
+            vec![12.0, 15.0, 18.0, 21.0, 48.0, 51.0, 54.0, 57.0]
+        );
 
-        // check that the data pointer is the same
-        assert!(std::ptr::eq(view.as_ptr(), t.as_ptr()));
+        let mean = t.mean_axis_keepdim(2)?;
+        assert_eq!(mean.shape, [2, 3, 1]);
+        assert_eq!(mean.as_slice(), vec![1.5, 5.5, 9.5, 13.5, 17.5, 21.5]);
+
+        assert_eq!(t.max_axis_keepdim(0)?.as_slice(), &t.as_slice()[12..]);
+        assert_eq!(t.min_axis_keepdim(0)?.as_slice(), &t.as_slice()[..12]);
+
+        // a transposed view is reduced along its logical axes
+        let mut transposed =
+            Tensor::<i32, 2, _>::from_shape_vec([3, 2], vec![1, 4, 2, 5, 3, 9], CpuAllocator)?;
+        transposed.shape = [2, 3];
+        transposed.strides = [1, 2];
+        assert_eq!(transposed.sum_axis_keepdim(1)?.as_slice(), &[6, 18]);
+        assert_eq!(transposed.sum_axis_keepdim(0)?.as_slice(), &[5, 7, 12]);
+        assert_eq!(transposed.max_axis_keepdim(1)?.as_slice(), &[3, 9]);
+        assert_eq!(transposed.min_axis_keepdim(0)?.as_slice(), &[1, 2, 3]);
+
+        // invalid and empty axes
+        assert!(matches!(
+            t.sum_axis_keepdim(3),
+            Err(TensorError::DimOutOfBounds(3, 3))
+        ));
+        let empty = Tensor::<i32, 2, _>::from_shape_vec([2, 0], vec![], CpuAllocator)?;
+        assert_eq!(empty.sum_axis_keepdim(1)?.as_slice(), &[0, 0]);
+        assert!(empty.max_axis_keepdim(1).is_err());
 
         Ok(())
     }
 
     #[test]
-    fn from_slice() -> Result<(), TensorError> {
-        let data: [u8; 4] = [1, 2, 3, 4];
-        let t = Tensor::<u8, 2, _>::from_shape_slice([2, 2], &data, CpuAllocator)?;
+    fn reduce_axis_keepdim() -> Result<(), TensorError> {
+        let data: Vec<f32> = (0..24).map(|x| x as f32).collect();
+        let t = Tensor::<f32, 3, CpuAllocator>::from_shape_vec([2, 3, 4], data, CpuAllocator)?;
 
-        assert_eq!(t.shape, [2, 2]);
-        assert_eq!(t.as_slice(), &[1, 2, 3, 4]);
+        // the reductions drop the axis
+        let sum = t.sum_axis::<2>(1)?;
+        assert_eq!(sum.shape, [2, 4]);
+        assert_eq!(sum.as_slice(), t.sum_axis_keepdim(1)?.as_slice());
+        assert_eq!(t.mean_axis::<2>(2)?.shape, [2, 3]);
+        assert_eq!(t.max_axis::<2>(0)?.shape, [3, 4]);
+        assert_eq!(t.min_axis::<2>(0)?.as_slice(), &t.as_slice()[..12]);
+        assert!(t.sum_axis::<3>(1).is_err());
+        assert!(t.sum_axis::<2>(3).is_err());
+
+        // the kept axis broadcasts to center the rows
+        let mean = t.mean_axis_keepdim(2)?;
+        let centered = t.sub(&mean)?;
+        assert_eq!(centered.shape, [2, 3, 4]);
+        assert_eq!(
+            &centered.as_slice()[..8],
+            &[-1.5, -0.5, 0.5, 1.5, -1.5, -0.5, 0.5, 1.5]
+        );
+        assert!(centered
+            .sum_axis::<2>(2)?
+            .as_slice()
+            .iter()
+            .all(|&x| x == 0.0));
 
         Ok(())
     }
 
     #[test]
-    fn display_2d() -> Result<(), TensorError> {
-        let data: [u8; 4] = [1, 2, 3, 4];
-        let t = Tensor::<u8, 2, _>::from_shape_slice([2, 2], &data, CpuAllocator)?;
-        let disp = t.to_string();
-        let lines = disp.lines().collect::<Vec<_>>();
+    fn into_shape() -> Result<(), TensorError> {
+        let data: Vec<u8> = (0..6).collect();
+        let t = Tensor::<u8, 2, CpuAllocator>::from_shape_vec([2, 3], data, CpuAllocator)?;
+
+        // the storage is moved when contiguous
+        let ptr = t.as_ptr();
+        let t2 = t.into_shape([6])?;
+        assert_eq!(t2.shape, [6]);
+        assert_eq!(t2.strides, [1]);
+        assert_eq!(t2.as_ptr(), ptr);
+        assert_eq!(t2.as_slice(), &[0, 1, 2, 3, 4, 5]);
+
+        // a transposed view is copied in its logical order
+        let mut transposed = t2.into_shape([2, 3])?;
+        transposed.shape = [3, 2];
+        transposed.strides = [1, 3];
+        let ptr = transposed.as_ptr();
+        let t3 = transposed.into_shape([2, 3, 1])?;
+        assert_eq!(t3.strides, [3, 1, 1]);
+        assert_ne!(t3.as_ptr(), ptr);
+        assert_eq!(t3.as_slice(), &[0, 3, 1, 4, 2, 5]);
+
+        assert!(matches!(
+            t3.into_shape([4]),
+            Err(TensorError::IncompatibleShape(6, 4))
+        ));
 
-        #[rustfmt::skip]
-        assert_eq!(lines.as_slice(),
-        ["[[1,2],",
-         " [3,4]]"]);
         Ok(())
     }
 
     #[test]
-    fn display_3d() -> Result<(), TensorError> {
-        let data: [u8; 12] = [1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
-        let t = Tensor::<u8, 3, _>::from_shape_slice([2, 3, 2], &data, CpuAllocator)?;
-        let disp = t.to_string();
-        let lines = disp.lines().collect::<Vec<_>>();
+    fn permute_and_transpose() -> Result<(), TensorError> {
+        // an image with shape (H, W, C) to (C, H, W)
+        let hwc = Tensor::<u8, 3, _>::from_shape_fn([2, 2, 3], CpuAllocator, |[y, x, c]| {
+            (y * 6 + x * 3 + c) as u8
+        });
+        let chw = hwc.permute([2, 0, 1])?;
+        assert_eq!(chw.shape, [3, 2, 2]);
+        assert_eq!(chw.strides, [1, 6, 3]);
+        assert!(!chw.is_contiguous());
 
+        let chw = chw.to_contiguous();
+        assert!(chw.is_standard_layout());
         #[rustfmt::skip]
-        assert_eq!(lines.as_slice(),
-        ["[[[ 1, 2],",
-         "  [ 3, 4],",
-         "  [ 5, 6]],",
-         "",
-         " [[ 7, 8],",
-         "  [ 9,10],",
-         "  [11,12]]]"]);
-        Ok(())
-    }
+        assert_eq!(chw.as_slice(), &[
+            0, 3, 6, 9,
+            1, 4, 7, 10,
+            2, 5, 8, 11,
+        ]);
 
-    #[test]
-    fn display_float() -> Result<(), TensorError> {
-        let data: [f32; 4] = [1.00001, 1.00009, 0.99991, 0.99999];
-        let t = Tensor::<f32, 2, _>::from_shape_slice([2, 2], &data, CpuAllocator)?;
-        let disp = t.to_string();
-        let lines = disp.lines().collect::<Vec<_>>();
+        // the identity permutation keeps the layout
+        assert!(hwc.permute([0, 1, 2])?.is_contiguous());
+
+        assert!(hwc.permute([0, 1, 1]).is_err());
+        assert!(hwc.permute([0, 1, 3]).is_err());
+
+        let t = hwc.transpose(0, 2)?;
+        assert_eq!(t.shape, [3, 2, 2]);
+        assert_eq!(t.strides, [1, 3, 6]);
+        assert_eq!(*t.get_unchecked([2, 1, 0]), 5);
+        assert!(matches!(
+            hwc.transpose(0, 3),
+            Err(TensorError::DimOutOfBounds(3, 3))
+        ));
 
-        #[rustfmt::skip]
-        assert_eq!(lines.as_slice(),
-        ["[[1.0000,1.0001],",
-         " [0.9999,1.0000]]"]);
         Ok(())
     }
 
     #[test]
-    fn display_big_float() -> Result<(), TensorError> {
-        let data: [f32; 4] = [1000.00001, 1.00009, 0.99991, 0.99999];
-        let t = Tensor::<f32, 2, _>::from_shape_slice([2, 2], &data, CpuAllocator)?;
-        let disp = t.to_string();
-        let lines = disp.lines().collect::<Vec<_>>();
+    fn where_scalar() -> Result<(), TensorError> {
+        // a gray image with shape (H, W, 1)
+        let data: Vec<u8> = vec![0, 100, 128, 129, 200, 255];
+        let gray = Tensor::<u8, 3, _>::from_shape_vec([2, 3, 1], data, CpuAllocator)?;
+
+        let mask = gray.gt(128);
+        assert_eq!(mask.shape, [2, 3, 1]);
+        let binary = Tensor::where_scalar(&mask, 255u8, 0);
+        assert_eq!(binary.shape, [2, 3, 1]);
+        assert_eq!(binary.as_slice(), &[0, 0, 0, 255, 255, 255]);
+
+        let inverted = Tensor::where_scalar(&gray.lt(129), 255u8, 0);
+        assert_eq!(inverted.as_slice(), &[255, 255, 255, 0, 0, 0]);
+
+        // a transposed mask is read in its logical order
+        let mut mask = mask.into_shape([2, 3])?;
+        mask.shape = [3, 2];
+        mask.strides = [1, 3];
+        let selected = Tensor::where_scalar(&mask, 1.0f32, -1.0);
+        assert_eq!(selected.strides, [2, 1]);
+        assert_eq!(selected.as_slice(), &[-1.0, 1.0, -1.0, 1.0, -1.0, 1.0]);
 
-        #[rustfmt::skip]
-        assert_eq!(lines.as_slice(),
-        ["[[1.0000e+03,1.0001e+00],",
-         " [9.9991e-01,9.9999e-01]]"]);
         Ok(())
     }
 
     #[test]
-    fn display_big_tensor() -> Result<(), TensorError> {
-        let data: [u8; 1000] = [0; 1000];
-        let t = Tensor::<u8, 3, _>::from_shape_slice([10, 10, 10], &data, CpuAllocator)?;
-        let disp = t.to_string();
-        let lines = disp.lines().collect::<Vec<_>>();
+    fn slice_ranges() -> Result<(), TensorError> {
+        // an image with shape (H, W, C)
+        let t = Tensor::<u8, 3, _>::from_shape_fn([4, 5, 3], CpuAllocator, |[y, x, c]| {
+            (y * 15 + x * 3 + c) as u8
+        });
 
+        // full rows are contiguous
+        let rows = t.slice([1..3, 0..5, 0..3])?;
+        assert_eq!(rows.shape, [2, 5, 3]);
+        assert!(rows.is_contiguous());
+        assert_eq!(rows.as_slice(), &t.as_slice()[15..45]);
+        assert_eq!(rows.as_ptr(), t.as_slice()[15..].as_ptr());
+
+        // a crop of the image
+        let crop = t.slice([1..3, 2..4, 0..3])?;
+        assert_eq!(crop.shape, [2, 2, 3]);
+        assert_eq!(crop.strides, t.strides);
+        assert!(!crop.is_contiguous());
+        assert_eq!(*crop.get_unchecked([1, 1, 2]), 2 * 15 + 3 * 3 + 2);
         #[rustfmt::skip]
-        assert_eq!(lines.as_slice(),
-        ["[[[0,0,0,...,0],",
-         "  [0,0,0,...,0],",
-         "  [0,0,0,...,0],",
-         "  ...",
-         "  [0,0,0,...,0]],",
-         "",
-         " [[0,0,0,...,0],",
-         "  [0,0,0,...,0],",
-         "  [0,0,0,...,0],",
-         "  ...",
-         "  [0,0,0,...,0]],",
-         "",
-         " [[0,0,0,...,0],",
-         "  [0,0,0,...,0],",
-         "  [0,0,0,...,0],",
-         "  ...",
-         "  [0,0,0,...,0]],",
-         "",
-         " ...",
-         "",
-         " [[0,0,0,...,0],",
-         "  [0,0,0,...,0],",
-         "  [0,0,0,...,0],",
-         "  ...",
-         "  [0,0,0,...,0]]]"]);
+        assert_eq!(crop.to_contiguous().as_slice(), &[
+            21, 22, 23, 24, 25, 26,
+            36, 37, 38, 39, 40, 41,
+        ]);
+
+        // a single channel
+        let green = t.slice([0..4, 0..5, 1..2])?;
+        assert_eq!(green.shape, [4, 5, 1]);
+        assert_eq!(*green.get_unchecked([3, 4, 0]), 3 * 15 + 4 * 3 + 1);
+
+        // empty ranges give zero-length axes
+        let empty = t.slice([4..4, 0..5, 0..3])?;
+        assert_eq!(empty.shape, [0, 5, 3]);
+        assert_eq!(empty.numel(), 0);
+        assert!(empty.as_slice().is_empty());
+
+        assert!(matches!(
+            t.slice([0..4, 3..6, 0..3]),
+            Err(TensorError::IndexOutOfBounds(6))
+        ));
+        assert!(t.slice([5..5, 0..5, 0..3]).is_err());
+
         Ok(())
     }
 
     #[test]
-    fn get_index_unchecked_1d() -> Result<(), TensorError> {
-        let data: Vec<u8> = vec![1, 2, 3, 4];
-        let t = Tensor::<u8, 1, CpuAllocator>::from_shape_vec([4], data, CpuAllocator)?;
-        assert_eq!(t.get_index_unchecked(0), [0]);
-        assert_eq!(t.get_index_unchecked(1), [1]);
-        assert_eq!(t.get_index_unchecked(2), [2]);
-        assert_eq!(t.get_index_unchecked(3), [3]);
+    fn concatenate_and_stack() -> Result<(), TensorError> {
+        use super::{concatenate, stack};
+
+        let a = Tensor::<u8, 2, _>::from_shape_vec([2, 3], vec![1, 2, 3, 4, 5, 6], CpuAllocator)?;
+        let b = Tensor::<u8, 2, _>::from_shape_vec([1, 3], vec![7, 8, 9], CpuAllocator)?;
+
+        let c = concatenate(&[&a, &b], 0)?;
+        assert_eq!(c.shape, [3, 3]);
+        assert_eq!(c.strides, [3, 1]);
+        assert_eq!(c.as_slice(), &[1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+        // a transposed tensor is read in its logical order
+        let mut t =
+            Tensor::<u8, 2, _>::from_shape_vec([3, 2], vec![1, 4, 2, 5, 3, 6], CpuAllocator)?;
+        t.shape = [2, 3];
+        t.strides = [1, 2];
+        let c = concatenate(&[&t, &a], 1)?;
+        assert_eq!(c.shape, [2, 6]);
+        assert_eq!(c.as_slice(), &[1, 2, 3, 1, 2, 3, 4, 5, 6, 4, 5, 6]);
+
+        assert!(concatenate(&[&a, &b], 1).is_err());
+        assert!(concatenate(&[&a, &b], 2).is_err());
+        assert!(concatenate::<u8, 2, CpuAllocator>(&[], 0).is_err());
+
+        // stack along the first, middle and last axes
+        let s = stack::<_, 2, 3, _>(&[&a, &t], 0)?;
+        assert_eq!(s.shape, [2, 2, 3]);
+        assert_eq!(s.as_slice(), &[1, 2, 3, 4, 5, 6, 1, 2, 3, 4, 5, 6]);
+
+        let s = stack::<_, 2, 3, _>(&[&a, &a, &a], 1)?;
+        assert_eq!(s.shape, [2, 3, 3]);
+        assert_eq!(*s.get_unchecked([1, 2, 0]), 4);
+
+        let s = stack::<_, 2, 3, _>(&[&a, &t], 2)?;
+        assert_eq!(s.shape, [2, 3, 2]);
+        assert_eq!(&s.as_slice()[..6], &[1, 1, 2, 2, 3, 3]);
+
+        assert!(stack::<_, 2, 3, _>(&[&a, &b], 0).is_err());
+        assert!(stack::<_, 2, 3, _>(&[&a], 3).is_err());
+        assert!(stack::<_, 2, 4, _>(&[&a], 0).is_err());
+        assert!(stack::<u8, 2, 3, CpuAllocator>(&[], 0).is_err());
+
         Ok(())
     }
 
     #[test]
-    fn get_index_unchecked_2d() -> Result<(), TensorError> {
-        let data: Vec<u8> = vec![1, 2, 3, 4];
-        let t = Tensor::<u8, 2, CpuAllocator>::from_shape_vec([2, 2], data, CpuAllocator)?;
-        assert_eq!(t.get_index_unchecked(0), [0, 0]);
-        assert_eq!(t.get_index_unchecked(1), [0, 1]);
-        assert_eq!(t.get_index_unchecked(2), [1, 0]);
-        assert_eq!(t.get_index_unchecked(3), [1, 1]);
+    fn matmul_2d() -> Result<(), TensorError> {
+        // (2, 3) x (3, 2)
+        let a = Tensor::<i32, 2, _>::from_shape_vec([2, 3], vec![1, 2, 3, 4, 5, 6], CpuAllocator)?;
+        let b =
+            Tensor::<i32, 2, _>::from_shape_vec([3, 2], vec![7, 8, 9, 10, 11, 12], CpuAllocator)?;
+        let c = a.matmul(&b)?;
+        assert_eq!(c.shape, [2, 2]);
+        assert_eq!(c.as_slice(), &[58, 64, 139, 154]);
+
+        let a = Tensor::<f32, 2, _>::from_shape_vec(
+            [2, 3],
+            vec![1.0, 0.0, -1.0, 2.0, 1.0, 0.0],
+            CpuAllocator,
+        )?;
+        let b = Tensor::<f32, 2, _>::from_shape_vec(
+            [3, 2],
+            vec![1.0, 2.0, 3.0, 4.0, 5.0, 6.0],
+            CpuAllocator,
+        )?;
+        assert_eq!(a.matmul(&b)?.as_slice(), &[-4.0, -4.0, 5.0, 8.0]);
+
+        // (3, 2) x (2, 3)
+        let c = b.matmul(&a)?;
+        assert_eq!(c.shape, [3, 3]);
+        assert_eq!(&c.as_slice()[..3], &[5.0, 2.0, -1.0]);
+
+        // a transposed operand is read through its strides
+        let mut bt = Tensor::<f32, 2, _>::from_shape_vec(
+            [2, 3],
+            vec![1.0, 3.0, 5.0, 2.0, 4.0, 6.0],
+            CpuAllocator,
+        )?;
+        bt.shape = [3, 2];
+        bt.strides = [1, 3];
+        assert_eq!(a.matmul(&bt)?.as_slice(), &[-4.0, -4.0, 5.0, 8.0]);
+
+        assert!(matches!(
+            a.matmul(&a),
+            Err(TensorError::IncompatibleShape(3, 2))
+        ));
+
         Ok(())
     }
 
     #[test]
-    fn get_index_unchecked_3d() -> Result<(), TensorError> {
-        let data: Vec<u8> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
-        let t = Tensor::<u8, 3, CpuAllocator>::from_shape_vec([2, 2, 3], data, CpuAllocator)?;
-        assert_eq!(t.get_index_unchecked(0), [0, 0, 0]);
-        assert_eq!(t.get_index_unchecked(1), [0, 0, 1]);
-        assert_eq!(t.get_index_unchecked(2), [0, 0, 2]);
-        assert_eq!(t.get_index_unchecked(3), [0, 1, 0]);
-        assert_eq!(t.get_index_unchecked(4), [0, 1, 1]);
-        assert_eq!(t.get_index_unchecked(5), [0, 1, 2]);
-        assert_eq!(t.get_index_unchecked(6), [1, 0, 0]);
-        assert_eq!(t.get_index_unchecked(7), [1, 0, 1]);
-        assert_eq!(t.get_index_unchecked(8), [1, 0, 2]);
-        assert_eq!(t.get_index_unchecked(9), [1, 1, 0]);
-        assert_eq!(t.get_index_unchecked(10), [1, 1, 1]);
-        assert_eq!(t.get_index_unchecked(11), [1, 1, 2]);
+    fn from_size_val_aligned() -> Result<(), TensorError> {
+        const ALIGN: usize = 32;
+
+        // odd sizes so that the alignment does not come from the size
+        let t = crate::CpuTensorAligned32::<u8, 2>::from_size_val_aligned([3, 7], 5)?;
+        assert_eq!(t.storage.as_ptr() as usize % ALIGN, 0);
+        assert_eq!(t.storage.layout().align(), ALIGN);
+        assert_eq!(t.shape, [3, 7]);
+        assert_eq!(t.as_slice(), &[5; 21]);
+
+        let t = Tensor::<f64, 1, AlignedCpuAllocator<128>>::from_size_val_aligned([5], 1.5)?;
+        assert_eq!(t.as_ptr() as usize % 128, 0);
+        assert_eq!(t.into_vec(), vec![1.5; 5]);
+
+        let empty = crate::CpuTensorAligned32::<f32, 2>::from_size_val_aligned([0, 4], 0.0)?;
+        assert_eq!(empty.as_ptr() as usize % ALIGN, 0);
+        assert!(empty.as_slice().is_empty());
+
         Ok(())
     }
 
     #[test]
-    fn get_index_to_offset_and_back() -> Result<(), TensorError> {
-        let data: Vec<u8> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
-        let t = Tensor::<u8, 3, CpuAllocator>::from_shape_vec([2, 2, 3], data, CpuAllocator)?;
-        for offset in 0..12 {
-            assert_eq!(
-                t.get_iter_offset_unchecked(t.get_index_unchecked(offset)),
-                offset
-            );
-        }
+    fn broadcast_to() -> Result<(), TensorError> {
+        let bias = Tensor::<u8, 1, _>::from_shape_vec([3], vec![1, 2, 3], CpuAllocator)?;
+
+        // a [3] tensor read as an image with shape [H, W, 3]
+        let view = bias.broadcast_to([2, 2, 3])?;
+        assert_eq!(view.strides, [0, 0, 1]);
+        assert!(std::ptr::eq(view.storage, &bias.storage));
+        assert_eq!(view.to_contiguous().as_slice(), &[1, 2, 3].repeat(4));
+
+        // dimensions of size 1 are broadcast in place
+        let column = Tensor::<u8, 2, _>::from_shape_vec([2, 1], vec![7, 9], CpuAllocator)?;
+        let view = column.broadcast_to([2, 3])?;
+        assert_eq!(view.strides, [1, 0]);
+        assert_eq!(view.to_contiguous().as_slice(), &[7, 7, 7, 9, 9, 9]);
+
+        assert_eq!(
+            bias.broadcast_to([2, 4]).err(),
+            Some(TensorError::IncompatibleShape(3, 4))
+        );
+        assert!(matches!(
+            column.broadcast_to([6]),
+            Err(TensorError::DimensionMismatch(_))
+        ));
+
         Ok(())
     }
 
     #[test]
-    fn get_offset_to_index_and_back() -> Result<(), TensorError> {
-        let data: Vec<u8> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
-        let t = Tensor::<u8, 3, CpuAllocator>::from_shape_vec([2, 2, 3], data, CpuAllocator)?;
-        for ind in [
-            [0, 0, 0],
-            [0, 0, 1],
-            [0, 0, 2],
-            [0, 1, 0],
-            [0, 1, 1],
-            [0, 1, 2],
-            [1, 0, 0],
-            [1, 0, 1],
-            [1, 0, 2],
-            [1, 1, 0],
-            [1, 1, 1],
-            [1, 1, 2],
-        ] {
-            assert_eq!(t.get_index_unchecked(t.get_iter_offset_unchecked(ind)), ind);
+    fn indexed_iter() -> Result<(), TensorError> {
+        let data: Vec<u8> = (0..6).collect();
+        let t = Tensor::<u8, 2, _>::from_shape_vec([2, 3], data, CpuAllocator)?;
+        let items = t.indexed_iter().map(|(i, &v)| (i, v)).collect::<Vec<_>>();
+        assert_eq!(
+            items,
+            vec![
+                ([0, 0], 0),
+                ([0, 1], 1),
+                ([0, 2], 2),
+                ([1, 0], 3),
+                ([1, 1], 4),
+                ([1, 2], 5)
+            ]
+        );
+
+        // a permuted view is walked in its logical order
+        let view = t.permute_axes([1, 0]);
+        for (index, v) in view.indexed_iter() {
+            assert_eq!(*v as usize, index[1] * 3 + index[0]);
         }
+
+        // a sliced view starts at its offset
+        let sliced = t.slice([1..2, 1..3])?;
+        let items = sliced
+            .indexed_iter()
+            .map(|(i, &v)| (i, v))
+            .collect::<Vec<_>>();
+        assert_eq!(items, vec![([0, 0], 4), ([0, 1], 5)]);
+
+        // fill a transposed tensor from the coordinates
+        let mut transposed = Tensor::<usize, 2, _>::from_shape_val([2, 3], 0, CpuAllocator);
+        transposed.shape = [3, 2];
+        transposed.strides = [1, 3];
+        transposed
+            .indexed_iter_mut()
+            .for_each(|([r, c], v)| *v = 10 * r + c);
+        assert_eq!(transposed.as_slice(), &[0, 10, 20, 1, 11, 21]);
+
         Ok(())
     }
 
     #[test]
-    fn get_index_1d() -> Result<(), TensorError> {
-        let data: Vec<u8> = vec![1, 2, 3, 4];
-        let t = Tensor::<u8, 1, CpuAllocator>::from_shape_vec([4], data, CpuAllocator)?;
-        assert_eq!(t.get_index(3), Ok([3]));
-        assert!(t
-            .get_index(4)
-            .is_err_and(|x| x == TensorError::IndexOutOfBounds(4)));
+    #[should_panic]
+    fn indexed_iter_mut_aliasing() {
+        let mut t = Tensor::<u8, 2, _>::from_shape_val([2, 2], 0, CpuAllocator);
+        t.strides = [0, 1];
+        t.indexed_iter_mut().for_each(|(_, v)| *v += 1);
+    }
+
+    #[test]
+    fn clamp_abs() -> Result<(), TensorError> {
+        let t = Tensor::<f32, 1, _>::from_shape_vec(
+            [5],
+            vec![-1.5, 0.25, 2.0, f32::NAN, f32::NEG_INFINITY],
+            CpuAllocator,
+        )?;
+
+        // NaN stays NaN, as in `f32::clamp`
+        let clamped = t.clamp(0.0, 1.0)?;
+        assert_eq!(&clamped.as_slice()[..3], &[0.0, 0.25, 1.0]);
+        assert!(clamped.as_slice()[3].is_nan());
+        assert_eq!(clamped.as_slice()[4], 0.0);
+
+        let abs = t.abs();
+        assert_eq!(&abs.as_slice()[..3], &[1.5, 0.25, 2.0]);
+        assert!(abs.as_slice()[3].is_nan());
+        assert_eq!(abs.as_slice()[4], f32::INFINITY);
+
+        assert!(matches!(
+            t.clamp(1.0, 0.0),
+            Err(TensorError::UnsupportedOperation(_))
+        ));
+        assert!(t.clamp(f32::NAN, 1.0).is_err());
+
+        // the elements are visited through the strides
+        let mut transposed =
+            Tensor::<i32, 2, _>::from_shape_vec([2, 2], vec![-5, 2, 7, -1], CpuAllocator)?;
+        transposed.strides = [1, 2];
+        assert_eq!(transposed.clamp(-2, 3)?.as_slice(), &[-2, 3, 2, -1]);
+        assert_eq!(
+            transposed.permute_axes([1, 0]).abs().as_slice(),
+            &[5, 2, 7, 1]
+        );
+
+        transposed.clamp_mut(0, 5)?;
+        assert_eq!(transposed.as_slice(), &[0, 2, 5, 0]);
+        assert!(transposed.clamp_mut(5, 0).is_err());
+        assert_eq!(transposed.as_slice(), &[0, 2, 5, 0]);
+
         Ok(())
     }
 
     #[test]
-    fn get_index_2d() -> Result<(), TensorError> {
-        let data: Vec<u8> = vec![1, 2, 3, 4];
-        let t = Tensor::<u8, 2, CpuAllocator>::from_shape_vec([2, 2], data, CpuAllocator)?;
-        assert_eq!(t.get_index_unchecked(3), [1, 1]);
-        assert!(t
-            .get_index(4)
-            .is_err_and(|x| x == TensorError::IndexOutOfBounds(4)));
+    fn index_select() -> Result<(), TensorError> {
+        // a 2x2 image with 3 channels
+        let data: Vec<u8> = (0..12).collect();
+        let image = Tensor::<u8, 3, _>::from_shape_vec([2, 2, 3], data, CpuAllocator)?;
+
+        let channels = image.index_select(2, &[2, 0])?;
+        assert_eq!(channels.shape, [2, 2, 2]);
+        assert_eq!(channels.as_slice(), &[2, 0, 5, 3, 8, 6, 11, 9]);
+
+        // the indices may repeat, and are read through the strides
+        let rows = image.permute_axes([1, 0, 2]).to_contiguous();
+        let repeated = rows.index_select(0, &[1, 1])?;
+        assert_eq!(repeated.shape, [2, 2, 3]);
+        assert_eq!(&repeated.as_slice()[..6], &[3, 4, 5, 9, 10, 11]);
+        assert_eq!(repeated.as_slice()[..6], repeated.as_slice()[6..]);
+
+        let empty = image.index_select(1, &[])?;
+        assert_eq!(empty.shape, [2, 0, 3]);
+        assert_eq!(empty.numel(), 0);
+
+        assert!(matches!(
+            image.index_select(2, &[0, 3]),
+            Err(TensorError::IndexOutOfBounds(3))
+        ));
+        assert!(matches!(
+            image.index_select(3, &[0]),
+            Err(TensorError::DimOutOfBounds(3, 3))
+        ));
+
         Ok(())
     }
 
     #[test]
-    fn get_index_3d() -> Result<(), TensorError> {
-        let data: Vec<u8> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
-        let t = Tensor::<u8, 3, CpuAllocator>::from_shape_vec([2, 2, 3], data, CpuAllocator)?;
-        assert_eq!(t.get_index_unchecked(11), [1, 1, 2]);
-        assert!(t
-            .get_index(12)
-            .is_err_and(|x| x == TensorError::IndexOutOfBounds(12)));
+    fn argmax_argmin_axis() -> Result<(), TensorError> {
+        let data = vec![
+            1.0,
+            5.0,
+            5.0,
+            2.0,
+            0.0,
+            9.0,
+            f32::NAN,
+            3.0,
+            3.0,
+            -1.0,
+            -1.0,
+            4.0,
+        ];
+        let t = Tensor::<f32, 2, CpuAllocator>::from_shape_vec([3, 4], data, CpuAllocator)?;
+
+        // ties resolve to the lowest index
+        let argmax = t.argmax_axis::<1>(1)?;
+        assert_eq!(argmax.shape, [3]);
+        assert_eq!(argmax.as_slice(), &[1, 1, 3]);
+        assert_eq!(t.argmin_axis::<1>(1)?.as_slice(), &[0, 0, 1]);
+
+        // NaN is skipped when it is not the first element, as in `max_axis`
+        assert_eq!(t.argmax_axis::<1>(0)?.as_slice(), &[2, 1, 0, 2]);
+        assert_eq!(t.argmin_axis::<1>(0)?.as_slice(), &[1, 2, 2, 0]);
+
+        // the elements are read through the strides
+        let mut transposed = t.clone();
+        transposed.shape = [4, 3];
+        transposed.strides = [1, 4];
+        assert_eq!(
+            transposed.argmax_axis::<1>(0)?.as_slice(),
+            argmax.as_slice()
+        );
+
+        assert!(matches!(
+            t.argmax_axis::<1>(2),
+            Err(TensorError::DimOutOfBounds(2, 2))
+        ));
+        let empty = Tensor::<f32, 2, CpuAllocator>::from_shape_vec([2, 0], vec![], CpuAllocator)?;
+        assert!(matches!(
+            empty.argmin_axis::<1>(1),
+            Err(TensorError::UnsupportedOperation(_))
+        ));
+
         Ok(())
     }
 
     #[test]
-    fn from_raw_parts() -> Result<(), TensorError> {
-        let data: Vec<u8> = vec![1, 2, 3, 4];
-        let t = unsafe { Tensor::from_raw_parts([2, 2], data.as_ptr(), data.len(), CpuAllocator)? };
-        std::mem::forget(data);
-        assert_eq!(t.shape, [2, 2]);
-        assert_eq!(t.as_slice(), &[1, 2, 3, 4]);
+    fn flatten_from() -> Result<(), TensorError> {
+        let data: Vec<u8> = (0..24).collect();
+        let t = Tensor::<u8, 3, _>::from_shape_vec([2, 3, 4], data, CpuAllocator)?;
+
+        let m = t.flatten_from(1)?;
+        assert_eq!(m.shape, [2, 12]);
+        assert_eq!(m.strides, [12, 1]);
+        assert!(std::ptr::eq(m.as_ptr(), t.as_ptr()));
+        for b in 0..2 {
+            for i in 0..12 {
+                assert_eq!(*m.get_unchecked([b, i]), (b * 12 + i) as u8);
+            }
+        }
+
+        assert_eq!(t.flatten_from(0)?.shape, [1, 24]);
+        assert_eq!(t.flatten_from(2)?.shape, [6, 4]);
+        assert_eq!(t.flatten_from(3)?.shape, [24, 1]);
+
+        assert!(matches!(
+            t.flatten_from(4),
+            Err(TensorError::DimOutOfBounds(4, 3))
+        ));
+
+        let mut transposed = t.clone();
+        transposed.shape = [4, 3, 2];
+        transposed.strides = [1, 4, 12];
+        assert!(matches!(
+            transposed.flatten_from(1),
+            Err(TensorError::NotContiguous(_, _))
+        ));
+
         Ok(())
     }
 
     #[test]
-    fn contiguous_tensor_is_standard_layout_true() -> Result<(), TensorError> {
-        let data: Vec<u8> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
-        let t = Tensor::<u8, 3, CpuAllocator>::from_shape_vec([2, 2, 3], data, CpuAllocator)?;
-        assert!(t.is_standard_layout());
+    fn flip() -> Result<(), TensorError> {
+        let data: Vec<u8> = (0..12).collect();
+        let t = Tensor::<u8, 3, _>::from_shape_vec([2, 3, 2], data, CpuAllocator)?;
+
+        let flipped = t.flip(&[1])?;
+        assert_eq!(flipped.shape, [2, 3, 2]);
+        assert_eq!(flipped.as_slice(), &[4, 5, 2, 3, 0, 1, 10, 11, 8, 9, 6, 7]);
+
+        // flipping twice restores the tensor, and the order of the axes does not matter
+        let all = t.flip(&[2, 0, 1])?;
+        assert_eq!(all.as_slice(), &[11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0]);
+        assert_eq!(all.flip(&[0, 1, 2])?.as_slice(), t.as_slice());
+        assert_eq!(t.flip(&[])?.as_slice(), t.as_slice());
+
+        // the elements are read through the strides
+        let mut transposed =
+            Tensor::<u8, 2, _>::from_shape_vec([2, 2], vec![1, 2, 3, 4], CpuAllocator)?;
+        transposed.strides = [1, 2];
+        assert_eq!(transposed.flip(&[0])?.as_slice(), &[2, 4, 1, 3]);
+
+        assert!(matches!(
+            t.flip(&[3]),
+            Err(TensorError::DimOutOfBounds(3, 3))
+        ));
+        assert!(matches!(
+            t.flip(&[1, 0, 1]),
+            Err(TensorError::DimensionMismatch(_))
+        ));
+
         Ok(())
     }
 
     #[test]
-    fn broken_stride_is_standard_layout_false() -> Result<(), TensorError> {
-        let data: Vec<u8> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
-        let mut t = Tensor::<u8, 3, CpuAllocator>::from_shape_vec([2, 2, 3], data, CpuAllocator)?;
-        // arbitrary incorrect stride
-        t.strides = [10, 5, 1];
-        assert!(!t.is_standard_layout());
+    fn roll() -> Result<(), TensorError> {
+        let t = Tensor::<i32, 1, _>::from_shape_vec([4], vec![1, 2, 3, 4], CpuAllocator)?;
+        assert_eq!(t.roll(1, 0)?.as_slice(), &[4, 1, 2, 3]);
+        assert_eq!(t.roll(-1, 0)?.as_slice(), &[2, 3, 4, 1]);
+        assert_eq!(t.roll(0, 0)?.as_slice(), t.as_slice());
+        assert_eq!(t.roll(4, 0)?.as_slice(), t.as_slice());
+
+        // the shifts larger than the axis wrap around
+        assert_eq!(t.roll(9, 0)?.as_slice(), t.roll(1, 0)?.as_slice());
+        assert_eq!(t.roll(-6, 0)?.as_slice(), &[3, 4, 1, 2]);
+
+        // the other axes are left as is
+        let data: Vec<u8> = (0..6).collect();
+        let m = Tensor::<u8, 2, _>::from_shape_vec([2, 3], data, CpuAllocator)?;
+        assert_eq!(m.roll(1, 1)?.as_slice(), &[2, 0, 1, 5, 3, 4]);
+        assert_eq!(m.roll(1, 0)?.as_slice(), &[3, 4, 5, 0, 1, 2]);
+
+        let empty = Tensor::<u8, 2, _>::from_shape_vec([0, 3], vec![], CpuAllocator)?;
+        assert_eq!(empty.roll(2, 0)?.shape, [0, 3]);
+
+        assert!(matches!(
+            m.roll(1, 2),
+            Err(TensorError::DimOutOfBounds(2, 2))
+        ));
+
         Ok(())
     }
 
     #[test]
-    fn contiguous_tensor_roundtrip() -> Result<(), TensorError> {
-        let data: Vec<u8> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
-        let t =
-            Tensor::<u8, 3, CpuAllocator>::from_shape_vec([2, 2, 3], data.clone(), CpuAllocator)?;
-        assert!(t.is_standard_layout());
-        match t.to_standard_layout(CpuAllocator) {
-            Ok(t2) => {
-                assert!(t2.is_standard_layout());
-                assert_eq!(t2.storage.as_slice(), data.as_slice());
-            }
-            Err(e) => return Err(e),
-        }
+    fn allclose() -> Result<(), TensorError> {
+        let a = Tensor::<f32, 2, _>::from_shape_vec(
+            [2, 2],
+            vec![1.0, -2.0, 1000.0, f32::INFINITY],
+            CpuAllocator,
+        )?;
+        let b = Tensor::<f32, 2, _>::from_shape_vec(
+            [2, 2],
+            vec![1.0 + 1e-7, -2.0, 1000.005, f32::INFINITY],
+            CpuAllocator,
+        )?;
+
+        // the relative tolerance is scaled by the second tensor
+        assert!(a.allclose(&b, 1e-5, 1e-8));
+        assert!(!a.allclose(&b, 1e-6, 1e-8));
+        assert!(a.allclose(&b, 0.0, 0.02));
+
+        // the elements are compared in logical order
+        let mut transposed = b.clone();
+        transposed.strides = [1, 2];
+        assert!(!a.allclose(&transposed, 1e-5, 1e-8));
+
+        // NaN is only close to NaN when asked for
+        let nan = Tensor::<f64, 1, _>::from_shape_vec([2], vec![f64::NAN, 1.0], CpuAllocator)?;
+        assert!(!nan.allclose(&nan, 1e-5, 1e-8));
+        assert!(nan.allclose_checked(&nan, 1e-5, 1e-8, true)?);
+        let one = Tensor::<f64, 1, _>::from_shape_vec([2], vec![1.0, 1.0], CpuAllocator)?;
+        assert!(!nan.allclose_checked(&one, 1e-5, 1e-8, true)?);
+
+        // the infinities of opposite sign are not close
+        let inf = Tensor::<f64, 1, _>::from_shape_vec([1], vec![f64::INFINITY], CpuAllocator)?;
+        let neg = inf.map(|v| -v);
+        assert!(!inf.allclose(&neg, 1.0, 1.0));
+
+        // the shapes must match
+        let other =
+            Tensor::<f32, 2, _>::from_shape_vec([1, 4], b.as_slice().to_vec(), CpuAllocator)?;
+        assert!(!a.allclose(&other, 1e-5, 1e-8));
+        assert!(matches!(
+            a.allclose_checked(&other, 1e-5, 1e-8, false),
+            Err(TensorError::DimensionMismatch(_))
+        ));
+
         Ok(())
     }
 
     #[test]
-    fn non_contiguous_to_standard_layout() -> Result<(), TensorError> {
-        let data: Vec<u8> = vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
-        let mut t =
-            Tensor::<u8, 3, CpuAllocator>::from_shape_vec([2, 2, 3], data.clone(), CpuAllocator)?;
-        // altering strides
-        t.strides = [1, 6, 2];
-        assert!(!t.is_standard_layout());
-        match t.to_standard_layout(CpuAllocator) {
-            Ok(t2) => {
-                assert!(t2.is_standard_layout());
-            }
-            Err(e) => return Err(e),
+    fn squeeze_unsqueeze() -> Result<(), TensorError> {
+        let data = (0..6).collect::<Vec<u8>>();
+        let t = Tensor::<u8, 3, _>::from_shape_vec([1, 2, 3], data.clone(), CpuAllocator)?;
+        let ptr = t.as_ptr();
+
+        let t = t.squeeze::<2>(0)?;
+        assert_eq!(t.shape, [2, 3]);
+        assert_eq!(t.strides, [3, 1]);
+        assert_eq!(t.as_ptr(), ptr);
+
+        // the new axis keeps the standard layout wherever it is inserted
+        for axis in 0..3 {
+            let u = t.clone().unsqueeze::<3>(axis)?;
+            assert_eq!(u.shape.iter().product::<usize>(), 6);
+            assert_eq!(u.shape[axis], 1);
+            assert!(u.is_standard_layout());
         }
+        let t = t.unsqueeze::<3>(2)?;
+        assert_eq!(t.shape, [2, 3, 1]);
+        assert_eq!(t.as_ptr(), ptr);
+
+        // the strides of a transposed tensor are kept
+        let mut transposed = t.squeeze::<2>(2)?;
+        transposed.shape = [3, 2];
+        transposed.strides = [1, 3];
+        let u = transposed.unsqueeze::<3>(1)?;
+        assert_eq!(u.shape, [3, 1, 2]);
+        assert_eq!(*u.get([2, 0, 1]).unwrap(), 5);
+        let s = u.squeeze_all::<2>()?;
+        assert_eq!(s.strides, [1, 3]);
+
+        let t = Tensor::<u8, 4, _>::from_shape_vec([1, 2, 1, 3], data, CpuAllocator)?;
+        assert!(matches!(
+            t.clone().squeeze::<3>(1),
+            Err(TensorError::DimensionMismatch(_))
+        ));
+        assert_eq!(
+            t.clone().squeeze::<3>(4).err(),
+            Some(TensorError::DimOutOfBounds(4, 4))
+        );
+        assert!(t.clone().squeeze_all::<3>().is_err());
+        assert_eq!(t.squeeze_all::<2>()?.shape, [2, 3]);
+
         Ok(())
     }
 }
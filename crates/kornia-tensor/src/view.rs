@@ -1,5 +1,8 @@
 use crate::{
-    get_strides_from_shape, storage::TensorStorage, CpuAllocator, Tensor, TensorAllocator,
+    get_strides_from_shape,
+    storage::TensorStorage,
+    tensor::{check_clamp_range, check_contiguous, clamp_value, row_major_indices},
+    CpuAllocator, Tensor, TensorAllocator, TensorError,
 };
 
 /// A non-owning view into tensor data.
@@ -19,6 +22,13 @@ use crate::{
 /// like transposition and dimension permutation without copying data. However, some
 /// operations may require converting to a contiguous layout.
 ///
+/// # Construction
+///
+/// The view starts at an offset in the storage, so it cannot be built with a struct literal
+/// outside of this crate. Use [`TensorView::from_storage`] instead, with an offset of 0 for
+/// the views starting at the beginning of the storage, which also checks that the view fits
+/// in the storage.
+///
 /// # Examples
 ///
 /// Creating a view through reshaping:
@@ -51,6 +61,7 @@ use crate::{
 /// let contiguous = view.as_contiguous();
 /// assert_eq!(contiguous.as_slice(), &[1, 3, 2, 4]);
 /// ```
+#[non_exhaustive]
 pub struct TensorView<'a, T, const N: usize, A: TensorAllocator> {
     /// Reference to the storage held by another tensor.
     pub storage: &'a TensorStorage<T, A>,
@@ -60,31 +71,137 @@ pub struct TensorView<'a, T, const N: usize, A: TensorAllocator> {
 
     /// The strides for accessing elements in the view.
     pub strides: [usize; N],
+
+    /// The offset of the first element of the view in the storage.
+    pub(crate) offset: usize,
 }
 
-impl<T, const N: usize, A: TensorAllocator> TensorView<'_, T, N, A> {
-    /// Returns a slice view of the underlying storage.
+impl<'a, T, const N: usize, A: TensorAllocator> TensorView<'a, T, N, A> {
+    /// Create a view of the elements of a storage.
+    ///
+    /// # Arguments
+    ///
+    /// * `storage` - The storage holding the elements.
+    /// * `shape` - The shape of the view.
+    /// * `strides` - The strides of the view, in number of elements.
+    /// * `offset` - The offset of the first element of the view in the storage.
+    ///
+    /// # Errors
+    ///
+    /// If the view reaches past the end of the storage, an error is returned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{storage::TensorStorage, view::TensorView, CpuAllocator};
+    ///
+    /// let storage = TensorStorage::from_vec(vec![1u8, 2, 3, 4, 5, 6], CpuAllocator);
+    ///
+    /// // the second column of a 3x2 matrix
+    /// let view = TensorView::from_storage(&storage, [3], [2], 1).unwrap();
+    /// assert_eq!(view.as_contiguous().as_slice(), &[2, 4, 6]);
+    ///
+    /// assert!(TensorView::from_storage(&storage, [3], [2], 2).is_err());
+    /// ```
+    pub fn from_storage(
+        storage: &'a TensorStorage<T, A>,
+        shape: [usize; N],
+        strides: [usize; N],
+        offset: usize,
+    ) -> Result<Self, TensorError> {
+        if !shape.contains(&0) {
+            // the offset of the last element, checked against overflows
+            let last = shape
+                .iter()
+                .zip(strides.iter())
+                .try_fold(offset, |acc, (&dim, &stride)| {
+                    (dim - 1).checked_mul(stride)?.checked_add(acc)
+                })
+                .unwrap_or(usize::MAX);
+            if last >= storage.as_slice().len() {
+                return Err(TensorError::IndexOutOfBounds(last));
+            }
+        }
+
+        Ok(Self {
+            storage,
+            shape,
+            strides,
+            offset,
+        })
+    }
+
+    /// Returns a slice of the underlying storage spanned by the view.
     ///
-    /// Note: This returns the entire underlying storage slice, not just the elements
-    /// visible through this view's shape and strides. For element-wise access respecting
-    /// the view's layout, use [`get_unchecked`](Self::get_unchecked).
+    /// The slice starts at the first element of the view and ends at its last element. For
+    /// contiguous views, e.g. a slice of whole rows, it contains exactly the elements of the
+    /// view in row-major order. Otherwise it also contains the elements skipped by the
+    /// strides, so use [`get_unchecked`](Self::get_unchecked) for element-wise access
+    /// respecting the view's layout.
     ///
     /// # Returns
     ///
-    /// A slice containing all elements in the underlying storage.
+    /// A slice of the underlying storage, empty if the view has no elements.
     #[inline]
     pub fn as_slice(&self) -> &[T] {
-        self.storage.as_slice()
+        if self.numel() == 0 {
+            return &[];
+        }
+        let span = self
+            .shape
+            .iter()
+            .zip(self.strides.iter())
+            .fold(1, |acc, (&dim, &stride)| acc + (dim - 1) * stride);
+        &self.storage.as_slice()[self.offset..self.offset + span]
     }
 
-    /// Returns a raw pointer to the underlying storage.
+    /// Returns a raw pointer to the first element of the view.
+    ///
+    /// Together with [`shape`](Self::shape) and [`strides`](Self::strides), this describes
+    /// the strided buffer of the view for FFI. The pointer already accounts for the offset.
     ///
     /// # Returns
     ///
-    /// A const pointer to the first element of the storage.
+    /// A const pointer to the element at the offset of the view in the storage.
     #[inline]
     pub fn as_ptr(&self) -> *const T {
-        self.storage.as_ptr()
+        self.storage.as_slice()[self.offset..].as_ptr()
+    }
+
+    /// Returns the shape of the view.
+    #[inline]
+    pub fn shape(&self) -> [usize; N] {
+        self.shape
+    }
+
+    /// Returns the strides of the view in number of elements, not bytes.
+    ///
+    /// Multiply by `size_of::<T>()` to get the strides in bytes.
+    #[inline]
+    pub fn strides(&self) -> [usize; N] {
+        self.strides
+    }
+
+    /// Returns the offset of the first element of the view in the storage.
+    ///
+    /// The offset is in number of elements, not bytes.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let data = vec![0u16; 12];
+    /// let tensor = Tensor::<u16, 2, _>::from_shape_vec([3, 4], data, CpuAllocator).unwrap();
+    ///
+    /// let view = tensor.slice([1..3, 2..4]).unwrap();
+    /// assert_eq!(view.offset(), 6);
+    /// assert_eq!(view.strides(), [4, 1]);
+    /// assert_eq!(view.as_ptr(), tensor.as_slice()[6..].as_ptr());
+    /// ```
+    #[inline]
+    pub fn offset(&self) -> usize {
+        self.offset
     }
 
     /// Returns the total number of elements in the view.
@@ -96,12 +213,12 @@ impl<T, const N: usize, A: TensorAllocator> TensorView<'_, T, N, A> {
     /// The total number of elements (product of all dimensions in the shape).
     #[inline]
     pub fn numel(&self) -> usize {
-        self.storage.len() / std::mem::size_of::<T>()
+        self.shape.iter().product()
     }
 
     /// Gets the element at the given index without bounds checking.
     ///
-    /// This method uses the view's strides to compute the offset into the storage,
+    /// This method uses the view's offset and strides to compute the position in the storage,
     /// allowing efficient access to elements in non-contiguous views.
     ///
     /// # Arguments
@@ -134,10 +251,172 @@ impl<T, const N: usize, A: TensorAllocator> TensorView<'_, T, N, A> {
         let offset = index
             .iter()
             .zip(self.strides.iter())
-            .fold(0, |acc, (i, s)| acc + i * s);
+            .fold(self.offset, |acc, (i, s)| acc + i * s);
         unsafe { self.storage.as_slice().get_unchecked(offset) }
     }
 
+    /// Iterate over the elements of the view together with their index.
+    ///
+    /// The elements are visited in row-major order of their logical index, following the
+    /// strides and the offset of the view.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let t = Tensor::<u8, 2, _>::from_shape_vec([2, 3], vec![1, 2, 3, 4, 5, 6], CpuAllocator).unwrap();
+    /// let view = t.permute_axes([1, 0]);
+    ///
+    /// let items = view.indexed_iter().take(3).collect::<Vec<_>>();
+    /// assert_eq!(items, vec![([0, 0], &1), ([0, 1], &4), ([1, 0], &2)]);
+    /// ```
+    pub fn indexed_iter(&self) -> impl Iterator<Item = ([usize; N], &T)> + '_ {
+        row_major_indices(self.shape).map(move |index| (index, self.get_unchecked(index)))
+    }
+
+    /// Check that the view is contiguous before handing its pointer to external code.
+    ///
+    /// # Returns
+    ///
+    /// The view itself, so the check can be chained with the pointer accessors.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`TensorError::NotContiguous`] with the shape and strides if the view is not
+    /// laid out contiguously in row-major order, e.g. after permuting the axes.
+    pub fn assert_contiguous(&self) -> Result<&Self, TensorError> {
+        check_contiguous(self.shape, self.strides)?;
+        Ok(self)
+    }
+
+    /// Check whether the view is laid out contiguously in row-major order.
+    ///
+    /// # Returns
+    ///
+    /// `true` if the elements of the view are adjacent in row-major order, e.g. `false` after
+    /// transposing a tensor.
+    pub fn is_contiguous(&self) -> bool {
+        check_contiguous(self.shape, self.strides).is_ok()
+    }
+
+    /// Copies the view to an owned tensor with contiguous memory layout.
+    ///
+    /// Unlike [`TensorView::as_contiguous`], the new tensor is allocated with the allocator of
+    /// the viewed storage.
+    ///
+    /// # Returns
+    ///
+    /// A new [`Tensor`] instance with a standard row-major layout containing the same
+    /// logical data as this view.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let data = vec![1, 2, 3, 4, 5, 6];
+    /// let tensor = Tensor::<i32, 2, _>::from_shape_vec([2, 3], data, CpuAllocator).unwrap();
+    ///
+    /// let transposed = tensor.transpose(0, 1).unwrap().to_contiguous();
+    /// assert_eq!(transposed.shape, [3, 2]);
+    /// assert_eq!(transposed.as_slice(), &[1, 4, 2, 5, 3, 6]);
+    /// ```
+    pub fn to_contiguous(&self) -> Tensor<T, N, A>
+    where
+        T: Clone,
+    {
+        let data = row_major_indices(self.shape)
+            .map(|index| self.get_unchecked(index).clone())
+            .collect();
+
+        Tensor {
+            storage: TensorStorage::from_vec(data, self.storage.alloc().clone()),
+            shape: self.shape,
+            strides: get_strides_from_shape(self.shape),
+        }
+    }
+
+    /// Apply a function to each element of the view.
+    ///
+    /// The elements are visited in logical row-major order through the strides of the view.
+    ///
+    /// # Arguments
+    ///
+    /// * `f` - The function to apply to each element.
+    ///
+    /// # Returns
+    ///
+    /// A new tensor with the shape of the view and a standard layout, allocated with the
+    /// allocator of the viewed storage.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use kornia_tensor::{Tensor, CpuAllocator};
+    ///
+    /// let data = vec![1, 2, 3, 4, 5, 6];
+    /// let tensor = Tensor::<i32, 2, _>::from_shape_vec([2, 3], data, CpuAllocator).unwrap();
+    ///
+    /// let doubled = tensor.transpose(0, 1).unwrap().map(|x| x * 2);
+    /// assert_eq!(doubled.as_slice(), &[2, 8, 4, 10, 6, 12]);
+    /// ```
+    pub fn map<U, F>(&self, f: F) -> Tensor<U, N, A>
+    where
+        T: Copy,
+        F: Fn(T) -> U,
+    {
+        let data = if self.is_contiguous() {
+            self.as_slice().iter().map(|&v| f(v)).collect()
+        } else {
+            row_major_indices(self.shape)
+                .map(|index| f(*self.get_unchecked(index)))
+                .collect()
+        };
+
+        Tensor {
+            storage: TensorStorage::from_vec(data, self.storage.alloc().clone()),
+            shape: self.shape,
+            strides: get_strides_from_shape(self.shape),
+        }
+    }
+
+    /// Clamp each element of the view into the range `[min, max]`.
+    ///
+    /// NaN elements stay NaN, as in [`f32::clamp`].
+    ///
+    /// # Arguments
+    ///
+    /// * `min` - The lower bound of the range.
+    /// * `max` - The upper bound of the range.
+    ///
+    /// # Returns
+    ///
+    /// A new tensor with the same shape and a standard layout.
+    ///
+    /// # Errors
+    ///
+    /// If `min` is greater than `max`, or they are not comparable, an error is returned.
+    pub fn clamp(&self, min: T, max: T) -> Result<Tensor<T, N, A>, TensorError>
+    where
+        T: Copy + PartialOrd,
+    {
+        check_clamp_range(min, max)?;
+        Ok(self.map(|v| clamp_value(v, min, max)))
+    }
+
+    /// Compute the absolute value of each element of the view.
+    ///
+    /// # Returns
+    ///
+    /// A new tensor with the same shape and a standard layout.
+    pub fn abs(&self) -> Tensor<T, N, A>
+    where
+        T: Copy + num_traits::Signed,
+    {
+        self.map(|v| v.abs())
+    }
+
     /// Converts the view to an owned tensor with contiguous memory layout.
     ///
     /// This method is essential when working with non-contiguous views (e.g., after
@@ -168,6 +447,17 @@ impl<T, const N: usize, A: TensorAllocator> TensorView<'_, T, N, A> {
     where
         T: Clone,
     {
+        let strides = get_strides_from_shape(self.shape);
+
+        // zero-sized views have no elements to visit
+        if self.shape.contains(&0) {
+            return Tensor {
+                storage: TensorStorage::from_vec(Vec::new(), CpuAllocator),
+                shape: self.shape,
+                strides,
+            };
+        }
+
         let mut data = Vec::<T>::with_capacity(self.numel());
         let mut index = [0; N];
 
@@ -187,8 +477,6 @@ impl<T, const N: usize, A: TensorAllocator> TensorView<'_, T, N, A> {
             index[i] += 1;
         }
 
-        let strides = get_strides_from_shape(self.shape);
-
         Tensor {
             storage: TensorStorage::from_vec(data, CpuAllocator),
             shape: self.shape,
@@ -200,18 +488,14 @@ impl<T, const N: usize, A: TensorAllocator> TensorView<'_, T, N, A> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::allocator::{CpuAllocator, TensorAllocatorError};
+    use crate::allocator::CpuAllocator;
 
     #[test]
-    fn test_tensor_view_from_vec() -> Result<(), TensorAllocatorError> {
+    fn test_tensor_view_from_vec() -> Result<(), crate::TensorError> {
         let vec = vec![1, 2, 3, 4, 5, 6, 7, 8];
         let storage = TensorStorage::from_vec(vec, CpuAllocator);
 
-        let view = TensorView::<u8, 1, _> {
-            storage: &storage,
-            shape: [8],
-            strides: [1],
-        };
+        let view = TensorView::<u8, 1, _>::from_storage(&storage, [8], [1], 0)?;
 
         assert_eq!(view.numel(), 8);
         assert!(!view.as_ptr().is_null());
@@ -238,6 +522,31 @@ mod tests {
         assert_eq!(view.get_unchecked([6]), &7);
         assert_eq!(view.get_unchecked([7]), &8);
 
+        // the views past the end of the storage are rejected
+        assert!(TensorView::<u8, 1, _>::from_storage(&storage, [8], [1], 1).is_err());
+        assert!(TensorView::<u8, 2, _>::from_storage(&storage, [2, 4], [5, 1], 0).is_err());
+        assert!(TensorView::<u8, 1, _>::from_storage(&storage, [2], [usize::MAX], 0).is_err());
+        assert!(TensorView::<u8, 1, _>::from_storage(&storage, [0], [1], 100).is_ok());
+
+        Ok(())
+    }
+
+    #[test]
+    fn layout_accessors() -> Result<(), crate::TensorError> {
+        let data = (0..24).collect::<Vec<u32>>();
+        let tensor = Tensor::<u32, 3, _>::from_shape_vec([2, 3, 4], data, CpuAllocator)?;
+
+        let view = tensor.slice([1..2, 0..3, 1..3])?;
+        assert_eq!(view.shape(), [1, 3, 2]);
+        assert_eq!(view.strides(), [12, 4, 1]);
+        assert_eq!(view.offset(), 13);
+        assert_eq!(unsafe { *view.as_ptr() }, 13);
+
+        let transposed = tensor.transpose(0, 2)?;
+        assert_eq!(transposed.shape(), [4, 3, 2]);
+        assert_eq!(transposed.strides(), [1, 4, 12]);
+        assert_eq!(transposed.offset(), 0);
+
         Ok(())
     }
 }
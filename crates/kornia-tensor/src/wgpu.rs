@@ -0,0 +1,239 @@
+use std::{alloc::Layout, sync::Arc, sync::OnceLock};
+
+use thiserror::Error;
+
+use crate::{
+    allocator::{CpuAllocator, TensorAllocator, TensorAllocatorError},
+    Tensor,
+};
+
+/// An error type for the GPU operations.
+#[derive(Debug, Error)]
+pub enum WgpuError {
+    /// No GPU adapter is available.
+    #[error("No GPU adapter available: {0}")]
+    NoAdapter(String),
+
+    /// The GPU device could not be created.
+    #[error("Failed to request the GPU device: {0}")]
+    RequestDevice(String),
+
+    /// The tensor has not been uploaded to the GPU.
+    #[error("The tensor has no GPU buffer")]
+    NoBuffer,
+
+    /// The GPU buffer could not be read back to the host.
+    #[error("Failed to read the GPU buffer: {0}")]
+    BufferMap(String),
+}
+
+/// Allocator for tensors with a copy of their data in a GPU buffer.
+///
+/// The tensor data lives in a host memory buffer that acts as a staging area: the usual
+/// accessors like `as_slice` read the host buffer, [`Tensor::upload`] copies it to the GPU
+/// and [`Tensor::download`] copies the GPU buffer back to it.
+///
+/// Each allocator owns at most one GPU buffer, created on the first upload. As the allocator
+/// is owned by the tensor storage, the GPU memory is released together with the host memory
+/// when the tensor is dropped. Cloning the allocator, e.g. when cloning a tensor, gives a new
+/// allocator on the same device without a GPU buffer.
+pub struct WgpuAllocator {
+    device: Arc<wgpu::Device>,
+    queue: Arc<wgpu::Queue>,
+    buffer: OnceLock<wgpu::Buffer>,
+}
+
+impl WgpuAllocator {
+    /// Create an allocator on the default GPU adapter.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no adapter is available or the device cannot be created.
+    pub fn new() -> Result<Self, WgpuError> {
+        let instance = wgpu::Instance::new(&wgpu::InstanceDescriptor::default());
+        let adapter =
+            pollster::block_on(instance.request_adapter(&wgpu::RequestAdapterOptions::default()))
+                .map_err(|e| WgpuError::NoAdapter(e.to_string()))?;
+        let (device, queue) =
+            pollster::block_on(adapter.request_device(&wgpu::DeviceDescriptor::default()))
+                .map_err(|e| WgpuError::RequestDevice(e.to_string()))?;
+        Ok(Self::from_device(Arc::new(device), Arc::new(queue)))
+    }
+
+    /// Create an allocator from an existing device and queue.
+    ///
+    /// # Arguments
+    ///
+    /// * `device` - The GPU device to allocate the buffers on.
+    /// * `queue` - The queue of the device used for the transfers.
+    pub fn from_device(device: Arc<wgpu::Device>, queue: Arc<wgpu::Queue>) -> Self {
+        Self {
+            device,
+            queue,
+            buffer: OnceLock::new(),
+        }
+    }
+
+    /// The GPU device of the allocator.
+    pub fn device(&self) -> &Arc<wgpu::Device> {
+        &self.device
+    }
+
+    /// The queue of the GPU device.
+    pub fn queue(&self) -> &Arc<wgpu::Queue> {
+        &self.queue
+    }
+
+    /// The GPU buffer of the allocator, if the data has been uploaded.
+    pub fn buffer(&self) -> Option<&wgpu::Buffer> {
+        self.buffer.get()
+    }
+}
+
+impl Clone for WgpuAllocator {
+    fn clone(&self) -> Self {
+        Self::from_device(self.device.clone(), self.queue.clone())
+    }
+}
+
+/// Implements [`TensorAllocator`] for the host staging memory.
+///
+/// The GPU buffer is not managed here: it is destroyed when the allocator is dropped.
+impl TensorAllocator for WgpuAllocator {
+    fn alloc(&self, layout: Layout) -> Result<*mut u8, TensorAllocatorError> {
+        CpuAllocator.alloc(layout)
+    }
+
+    fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        CpuAllocator.dealloc(ptr, layout)
+    }
+}
+
+impl Drop for WgpuAllocator {
+    fn drop(&mut self) {
+        if let Some(buffer) = self.buffer.get() {
+            buffer.destroy();
+        }
+    }
+}
+
+impl<T: bytemuck::Pod, const N: usize> Tensor<T, N, WgpuAllocator> {
+    /// Copy the host data of the tensor to its GPU buffer.
+    ///
+    /// The GPU buffer is created on the first call, with its size rounded up to the copy
+    /// alignment of the device.
+    pub fn upload(&self) -> Result<(), WgpuError> {
+        let alloc = self.storage.alloc();
+        let bytes = host_bytes(self);
+        let buffer = alloc.buffer.get_or_init(|| {
+            alloc.device.create_buffer(&wgpu::BufferDescriptor {
+                label: Some("kornia-tensor"),
+                size: aligned_size(bytes.len()),
+                usage: wgpu::BufferUsages::STORAGE
+                    | wgpu::BufferUsages::COPY_SRC
+                    | wgpu::BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            })
+        });
+
+        // the writes must be a multiple of the copy alignment
+        let padding = aligned_size(bytes.len()) as usize - bytes.len();
+        if padding == 0 {
+            alloc.queue.write_buffer(buffer, 0, bytes);
+        } else {
+            let mut padded = bytes.to_vec();
+            padded.resize(bytes.len() + padding, 0);
+            alloc.queue.write_buffer(buffer, 0, &padded);
+        }
+
+        Ok(())
+    }
+
+    /// Copy the GPU buffer of the tensor back to its host data.
+    ///
+    /// This blocks until the transfer is complete.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`WgpuError::NoBuffer`] if the tensor has not been uploaded.
+    pub fn download(&mut self) -> Result<(), WgpuError> {
+        let alloc = self.storage.alloc();
+        let buffer = alloc.buffer.get().ok_or(WgpuError::NoBuffer)?;
+
+        let staging = alloc.device.create_buffer(&wgpu::BufferDescriptor {
+            label: Some("kornia-tensor-staging"),
+            size: buffer.size(),
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+
+        let mut encoder = alloc
+            .device
+            .create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        encoder.copy_buffer_to_buffer(buffer, 0, &staging, 0, buffer.size());
+        alloc.queue.submit([encoder.finish()]);
+
+        let (tx, rx) = std::sync::mpsc::channel();
+        let slice = staging.slice(..);
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            let _ = tx.send(result);
+        });
+        alloc
+            .device
+            .poll(wgpu::PollType::Wait)
+            .map_err(|e| WgpuError::BufferMap(e.to_string()))?;
+        rx.recv()
+            .map_err(|e| WgpuError::BufferMap(e.to_string()))?
+            .map_err(|e| WgpuError::BufferMap(e.to_string()))?;
+
+        {
+            let mapped = slice.get_mapped_range();
+            let dst = bytemuck::cast_slice_mut::<T, u8>(self.storage.as_mut_slice());
+            let len = dst.len();
+            dst.copy_from_slice(&mapped[..len]);
+        }
+        staging.unmap();
+
+        Ok(())
+    }
+}
+
+/// View the host data of a tensor as bytes.
+fn host_bytes<T: bytemuck::Pod, const N: usize>(tensor: &Tensor<T, N, WgpuAllocator>) -> &[u8] {
+    bytemuck::cast_slice(tensor.storage.as_slice())
+}
+
+/// Round a size in bytes up to the buffer copy alignment.
+fn aligned_size(len: usize) -> u64 {
+    (len as u64).div_ceil(wgpu::COPY_BUFFER_ALIGNMENT).max(1) * wgpu::COPY_BUFFER_ALIGNMENT
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[ignore = "need a GPU adapter in CI"]
+    fn test_wgpu_roundtrip() -> Result<(), Box<dyn std::error::Error>> {
+        let alloc = WgpuAllocator::new()?;
+
+        // 15 bytes to exercise the copy alignment padding
+        let data = (0..15).map(|i| i as u8 * 3).collect::<Vec<_>>();
+        let mut tensor = Tensor::<u8, 2, _>::from_shape_vec([3, 5], data.clone(), alloc)?;
+        assert!(tensor.storage.alloc().buffer().is_none());
+
+        tensor.upload()?;
+        assert_eq!(tensor.storage.alloc().buffer().map(|b| b.size()), Some(16));
+
+        // overwrite the host data and read it back from the GPU
+        tensor.storage.as_mut_slice().fill(0);
+        tensor.download()?;
+        assert_eq!(tensor.as_slice(), data.as_slice());
+
+        // a clone does not share the GPU buffer
+        let mut cloned = tensor.clone();
+        assert!(matches!(cloned.download(), Err(WgpuError::NoBuffer)));
+
+        Ok(())
+    }
+}
@@ -1,11 +1,21 @@
 use pyo3::prelude::*;
 
 use crate::image::{FromPyImage, PyImage, ToPyImage};
-use kornia_image::{allocator::CpuAllocator, Image, ImageSize};
-use kornia_imgproc::{interpolation::InterpolationMode, resize::resize_fast_rgb};
+use kornia_image::{allocator::CpuAllocator, Image, ImageError, ImageSize};
+use kornia_imgproc::{
+    filter::BorderType,
+    interpolation::InterpolationMode,
+    resize::{resize_fast_rgb, resize_separable_with_border},
+};
 
 #[pyfunction]
-pub fn resize(image: PyImage, new_size: (usize, usize), interpolation: &str) -> PyResult<PyImage> {
+#[pyo3(signature = (image, new_size, interpolation, border=None))]
+pub fn resize(
+    image: PyImage,
+    new_size: (usize, usize),
+    interpolation: &str,
+    border: Option<&str>,
+) -> PyResult<PyImage> {
     let image = Image::from_pyimage(image)
         .map_err(|e| PyErr::new::<pyo3::exceptions::PyException, _>(format!("{}", e)))?;
 
@@ -24,11 +34,32 @@ pub fn resize(image: PyImage, new_size: (usize, usize), interpolation: &str) ->
         }
     };
 
-    let mut image_resized = Image::from_size_val(new_size, 0u8, CpuAllocator)
-        .map_err(|e| PyErr::new::<pyo3::exceptions::PyException, _>(format!("{}", e)))?;
+    let border = match border.map(|b| b.to_lowercase()).as_deref() {
+        None => None,
+        Some("clamp") | Some("replicate") => Some(BorderType::Replicate),
+        Some("reflect") => Some(BorderType::Reflect101),
+        Some("symmetric") => Some(BorderType::Reflect),
+        Some(_) => {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "Invalid border mode",
+            ))
+        }
+    };
 
-    resize_fast_rgb(&image, &mut image_resized, interpolation)
-        .map_err(|e| PyErr::new::<pyo3::exceptions::PyException, _>(format!("{}", e)))?;
+    let image_resized = match border {
+        // keep the fast path when the border is not given
+        None => {
+            let mut image_resized = Image::from_size_val(new_size, 0u8, CpuAllocator)
+                .map_err(|e| PyErr::new::<pyo3::exceptions::PyException, _>(format!("{}", e)))?;
+
+            resize_fast_rgb(&image, &mut image_resized, interpolation)
+                .map_err(|e| PyErr::new::<pyo3::exceptions::PyException, _>(format!("{}", e)))?;
+
+            image_resized
+        }
+        Some(border) => resize_with_border(&image, new_size, interpolation, border)
+            .map_err(|e| PyErr::new::<pyo3::exceptions::PyException, _>(format!("{}", e)))?,
+    };
 
     let pyimage_resized = image_resized.to_pyimage().map_err(|e| {
         PyErr::new::<pyo3::exceptions::PyException, _>(format!("failed to convert image: {}", e))
@@ -36,3 +67,26 @@ pub fn resize(image: PyImage, new_size: (usize, usize), interpolation: &str) ->
 
     Ok(pyimage_resized)
 }
+
+/// Resize in floating point with the separable weights, which extrapolate the edges with the
+/// border mode.
+fn resize_with_border(
+    image: &Image<u8, 3, CpuAllocator>,
+    new_size: ImageSize,
+    interpolation: InterpolationMode,
+    border: BorderType,
+) -> Result<Image<u8, 3, CpuAllocator>, ImageError> {
+    let image_f32 = image.cast::<f32>()?;
+    let mut resized_f32 = Image::from_size_val(new_size, 0f32, CpuAllocator)?;
+
+    // the half-pixel centers of the fast path
+    resize_separable_with_border(&image_f32, &mut resized_f32, interpolation, false, border)?;
+
+    let data = resized_f32
+        .as_slice()
+        .iter()
+        .map(|&v| v.round().clamp(0.0, 255.0) as u8)
+        .collect();
+
+    Image::new(new_size, data, CpuAllocator)
+}
@@ -1,6 +1,7 @@
 from pathlib import Path
 import kornia_rs as K
 
+import pytest
 import torch
 import numpy as np
 
@@ -18,3 +19,27 @@ def test_resize():
 
     img_resized: np.ndarray = K.resize(img, (43, 34), "bilinear")
     assert img_resized.shape == (43, 34, 3)
+
+
+def test_resize_border():
+    # a horizontal ramp, upscaled so that the edge pixels sample outside of the image
+    row = np.array([0, 40, 80, 120], dtype=np.uint8)
+    img = np.repeat(np.tile(row[None, :, None], (4, 1, 1)), 3, axis=2)
+
+    img_clamp: np.ndarray = K.resize(img, (8, 8), "bilinear", border="clamp")
+    img_reflect: np.ndarray = K.resize(img, (8, 8), "bilinear", border="reflect")
+    assert img_clamp.shape == img_reflect.shape == (8, 8, 3)
+
+    # the edge pixels differ while the interior pixels match
+    assert img_clamp[0, 0, 0] == 0
+    assert img_reflect[0, 0, 0] == 10
+    assert img_clamp[0, -1, 0] == 120
+    assert img_reflect[0, -1, 0] == 110
+    np.testing.assert_array_equal(img_clamp[:, 1:-1], img_reflect[:, 1:-1])
+
+    # the default keeps the previous behavior
+    img_default: np.ndarray = K.resize(img, (8, 8), "bilinear")
+    assert img_default.shape == (8, 8, 3)
+
+    with pytest.raises(ValueError):
+        K.resize(img, (8, 8), "bilinear", border="wrap")